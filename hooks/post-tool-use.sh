@@ -5,7 +5,8 @@
 # This provides activity-based health monitoring.
 #
 # Input: JSON from stdin (Claude Code passes tool info via stdin)
-#   {"tool_name": "Bash", "tool_input": {...}, "tool_result": {...}, ...}
+#   {"tool_name": "Bash", "tool_input": {...}, "tool_result": {...},
+#    "transcript_path": "...", ...}
 #
 # Environment variables (set by loom worktree settings):
 #   LOOM_STAGE_ID    - The stage being executed
@@ -13,7 +14,8 @@
 #   LOOM_WORK_DIR    - Path to the .work directory
 #
 # Actions:
-#   1. Updates heartbeat in .work/heartbeat/<stage-id>.json
+#   1. Updates heartbeat in .work/heartbeat/<stage-id>.json, including the
+#      model and cumulative token usage summed from the session transcript
 #   2. After git commits in loom stages, reminds Claude to update knowledge/memory
 
 set -euo pipefail
@@ -55,6 +57,75 @@ fi
 HEARTBEAT_DIR="${LOOM_WORK_DIR}/heartbeat"
 mkdir -p "$HEARTBEAT_DIR" 2>/dev/null || exit 0
 
+# Sum token usage from the session transcript. Streamed assistant messages
+# repeat their usage across consecutive entries with the same message id, so
+# the largest value per field is kept for each message before summing.
+#
+# The transcript grows with every tool call, so only the lines appended since
+# the last call are parsed: the byte offset read so far, the totals of
+# finished messages and the maxima of the last message are kept in
+# .work/transcript-usage/<session-id>.json.
+TRANSCRIPT_PATH=$(echo "$INPUT_JSON" | jq -r '.transcript_path // empty' 2>/dev/null || true)
+MODEL="null"
+USAGE="null"
+if [[ -n "$TRANSCRIPT_PATH" ]] && [[ -f "$TRANSCRIPT_PATH" ]]; then
+	USAGE_STATE_DIR="${LOOM_WORK_DIR}/transcript-usage"
+	USAGE_STATE_FILE="${USAGE_STATE_DIR}/${LOOM_SESSION_ID}.json"
+	mkdir -p "$USAGE_STATE_DIR" 2>/dev/null || true
+
+	STATE="null"
+	if [[ -f "$USAGE_STATE_FILE" ]]; then
+		STATE=$(jq -c --arg path "$TRANSCRIPT_PATH" 'select(.transcript == $path)' "$USAGE_STATE_FILE" 2>/dev/null || true)
+	fi
+	OFFSET=$(echo "${STATE:-null}" | jq -r '.offset // 0' 2>/dev/null || echo 0)
+	SIZE=$(wc -c <"$TRANSCRIPT_PATH" | tr -d ' ')
+	# A transcript that shrank was replaced: start over
+	if [[ "$SIZE" -lt "$OFFSET" ]]; then
+		STATE="null"
+		OFFSET=0
+	fi
+
+	CHUNK=$(mktemp)
+	tail -c +"$((OFFSET + 1))" "$TRANSCRIPT_PATH" | head -c "$((SIZE - OFFSET))" >"$CHUNK" || true
+	# A line still being written is left for the next call
+	PARTIAL=0
+	if [[ -s "$CHUNK" ]] && [[ -n "$(tail -c 1 "$CHUNK")" ]]; then
+		PARTIAL=$(tail -n 1 "$CHUNK" | wc -c | tr -d ' ')
+	fi
+	READ=$((SIZE - OFFSET - PARTIAL))
+
+	NEW_STATE=$(head -c "$READ" "$CHUNK" | jq -c -Rn \
+		--argjson state "${STATE:-null}" \
+		--arg path "$TRANSCRIPT_PATH" \
+		--argjson offset "$((OFFSET + READ))" '
+		def tokens: {
+			input_tokens: (.usage.input_tokens // 0),
+			output_tokens: (.usage.output_tokens // 0),
+			cache_creation_tokens: (.usage.cache_creation_input_tokens // 0),
+			cache_read_tokens: (.usage.cache_read_input_tokens // 0)
+		};
+		def pairwise(f; $other): with_entries(.value = ([.value, $other[.key]] | f));
+		def finish_last: if .last != null then . as $s | .done |= pairwise(add; $s.last.usage) else . end;
+		reduce (inputs | fromjson? | select(.type == "assistant" and .message.usage != null) | .message) as $m
+			($state // {model: null, done: ({} | tokens), last: null};
+				(if .last != null and .last.id == $m.id then
+					.last.usage |= pairwise(max; $m | tokens)
+				else
+					finish_last | .last = {id: $m.id, usage: ($m | tokens)}
+				end)
+				| .model = ($m.model // .model))
+		| .transcript = $path
+		| .offset = $offset
+		| .usage = (finish_last | .done)' 2>/dev/null || true)
+	rm -f "$CHUNK"
+
+	if [[ -n "$NEW_STATE" ]]; then
+		echo "$NEW_STATE" >"${USAGE_STATE_FILE}.tmp" && mv "${USAGE_STATE_FILE}.tmp" "$USAGE_STATE_FILE"
+		MODEL=$(echo "$NEW_STATE" | jq -c '.model' 2>/dev/null || echo "null")
+		USAGE=$(echo "$NEW_STATE" | jq -c '.usage' 2>/dev/null || echo "null")
+	fi
+fi
+
 # Get timestamp
 TIMESTAMP=$(date -u +"%Y-%m-%dT%H:%M:%S.000Z")
 
@@ -67,7 +138,9 @@ cat >"$HEARTBEAT_FILE" <<EOF
   "timestamp": "${TIMESTAMP}",
   "context_percent": null,
   "last_tool": "${TOOL_NAME}",
-  "activity": "Tool executed: ${TOOL_NAME}"
+  "activity": "Tool executed: ${TOOL_NAME}",
+  "model": ${MODEL:-null},
+  "usage": ${USAGE:-null}
 }
 EOF
 
@@ -1,52 +1,106 @@
-use anyhow::Result;
+use anyhow::{bail, Result};
+use loom::commands::common::OutputFormat;
 use loom::commands::{
-    clean, diagnose, graph, handoff, hooks, init, knowledge, map, memory, repair, resume, run,
-    sandbox, self_update, sessions, stage, status, stop, verify, worktree_cmd,
+    attach, clean, config, diagnose, graph, handoff, hooks, init, knowledge, map, memory, publish,
+    repair, report, resume, run, sandbox, search, self_update, sessions, stage, status, stop,
+    verify, worktree_cmd,
 };
 use loom::completions::{complete_dynamic, generate_completions, CompletionContext, Shell};
-use std::path::PathBuf;
+use loom::orchestrator::publish::PublishOptions;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 use super::types::{
-    Cli, Commands, GraphCommands, HandoffCommands, HooksCommands, KnowledgeCommands,
-    MemoryCommands, OutputCommands, SandboxCommands, SessionsCommands, StageCommands,
-    WorktreeCommands,
+    CacheCommands, Cli, Commands, ConfigCommands, GraphCommands, HandoffCommands, HooksCommands,
+    KnowledgeCommands, MemoryCommands, OutputCommands, ReportCommands, SandboxCommands,
+    SessionsCommands, StageCommands, WorktreeCommands,
 };
 
-pub fn dispatch(command: Commands) -> Result<()> {
+/// Whether a command can render `--format json|yaml` output
+fn supports_structured_output(command: &Commands) -> bool {
+    matches!(
+        command,
+        Commands::Status { .. }
+            | Commands::Graph {
+                command: GraphCommands::Show
+            }
+            | Commands::Sessions {
+                command: SessionsCommands::List
+            }
+            | Commands::Worktree {
+                command: WorktreeCommands::List
+            }
+            | Commands::Memory {
+                command: MemoryCommands::List { .. }
+            }
+            | Commands::Stage {
+                command: StageCommands::Output {
+                    command: OutputCommands::List { .. }
+                }
+            }
+            | Commands::Report {
+                command: ReportCommands::Cost
+            }
+    )
+}
+
+pub fn dispatch(command: Commands, format: OutputFormat) -> Result<()> {
+    if !format.is_text() && !supports_structured_output(&command) {
+        bail!("--format {format} is not supported by this command");
+    }
+
     match command {
-        Commands::Init { plan_path, clean } => init::execute(Some(PathBuf::from(plan_path)), clean),
+        Commands::Init {
+            plan_path,
+            clean,
+            update,
+            apply,
+        } => {
+            if update {
+                init::execute_update(Path::new(&plan_path), apply)
+            } else {
+                init::execute(Some(PathBuf::from(plan_path)), clean)
+            }
+        }
         Commands::Run {
             manual,
             max_parallel,
             foreground,
             watch,
             no_merge,
+            merge,
+            backend,
         } => {
-            let auto_merge = !no_merge;
+            // Without a flag the saved `auto_merge` setting applies
+            let auto_merge = (merge || no_merge).then_some(merge);
             if foreground {
-                run::execute(manual, max_parallel, watch, auto_merge)
+                run::execute(manual, max_parallel, watch, auto_merge, backend)
             } else {
-                run::execute_background(manual, max_parallel, watch, auto_merge)
+                run::execute_background(manual, max_parallel, watch, auto_merge, backend)
             }
         }
         Commands::Status {
             live,
             compact,
             verbose,
-        } => status::execute(live, compact, verbose),
+        } => status::execute(live, compact, verbose, format),
+        Commands::Attach { stage_id } => attach::execute(&stage_id),
         Commands::Resume { stage_id } => resume::execute(stage_id),
+        Commands::Config { command } => match command {
+            ConfigCommands::Set { key, value } => config::set(key, value),
+            ConfigCommands::Show => config::show(),
+        },
         Commands::Sessions { command } => match command {
-            SessionsCommands::List => sessions::list(),
+            SessionsCommands::List => sessions::list(format),
             SessionsCommands::Kill { session_ids, stage } => sessions::kill(session_ids, stage),
         },
         Commands::Worktree { command } => match command {
-            WorktreeCommands::List => worktree_cmd::list(),
+            WorktreeCommands::List => worktree_cmd::list(format),
             WorktreeCommands::Clean => worktree_cmd::clean(),
             WorktreeCommands::Remove { stage_id } => worktree_cmd::remove(stage_id),
         },
         Commands::Graph { command } => match command {
-            GraphCommands::Show => graph::show(),
+            GraphCommands::Show => graph::show(format),
             GraphCommands::Edit => graph::edit(),
         },
         Commands::Hooks { command } => match command {
@@ -83,12 +137,22 @@ pub fn dispatch(command: Commands) -> Result<()> {
             StageCommands::Retry { stage_id, force } => stage::retry(stage_id, force),
             StageCommands::Recover { stage_id, force } => stage::recover(stage_id, force),
             StageCommands::MergeComplete { stage_id } => stage::merge_complete(stage_id),
+            StageCommands::Merge { stage_id } => stage::merge(stage_id),
             StageCommands::RetryMerge { stage_id } => stage::retry_merge(stage_id),
             StageCommands::Verify {
                 stage_id,
                 no_reload,
             } => stage::verify(stage_id, no_reload),
             StageCommands::CheckAcceptance { stage_id } => stage::check_acceptance(stage_id),
+            StageCommands::History {
+                stage_id,
+                attempt,
+                verbose,
+            } => stage::history(stage_id, attempt, verbose),
+            StageCommands::Cache { command } => match command {
+                CacheCommands::List { stage_id } => stage::cache_list(stage_id),
+                CacheCommands::Clear { stage_id } => stage::cache_clear(stage_id),
+            },
             StageCommands::HumanReview {
                 stage_id,
                 approve,
@@ -106,25 +170,49 @@ pub fn dispatch(command: Commands) -> Result<()> {
                     description,
                 } => stage::output_set(stage_id, key, value, description),
                 OutputCommands::Get { stage_id, key } => stage::output_get(stage_id, key),
-                OutputCommands::List { stage_id } => stage::output_list(stage_id),
+                OutputCommands::List { stage_id } => stage::output_list(stage_id, format),
                 OutputCommands::Remove { stage_id, key } => stage::output_remove(stage_id, key),
             },
         },
         Commands::Knowledge { command } => match command {
             KnowledgeCommands::Show { file } => knowledge::show(file),
-            KnowledgeCommands::Update { file, content } => knowledge::update(file, content),
+            KnowledgeCommands::Update {
+                file,
+                content,
+                tags,
+                paths,
+                structured,
+            } => knowledge::update(
+                file,
+                content,
+                knowledge::EntryOptions {
+                    tags,
+                    paths,
+                    structured,
+                },
+            ),
             KnowledgeCommands::Init => knowledge::init(),
             KnowledgeCommands::List => knowledge::list(),
             KnowledgeCommands::Check {
                 min_coverage,
                 src_path,
+                max_churn,
                 quiet,
-            } => knowledge::check::check(min_coverage, src_path, quiet),
+            } => knowledge::check::check(min_coverage, src_path, max_churn, quiet),
             KnowledgeCommands::Gc {
                 max_file_lines,
                 max_total_lines,
+                max_churn,
                 quiet,
-            } => knowledge::gc::gc(max_file_lines, max_total_lines, quiet),
+                compact,
+                dry_run,
+            } => {
+                if compact {
+                    knowledge::compact::compact(max_file_lines, dry_run)
+                } else {
+                    knowledge::gc::gc(max_file_lines, max_total_lines, max_churn, quiet)
+                }
+            }
         },
         Commands::Memory { command } => match command {
             MemoryCommands::Note { text, stage } => memory::note(text, stage),
@@ -135,12 +223,22 @@ pub fn dispatch(command: Commands) -> Result<()> {
             } => memory::decision(text, context, stage),
             MemoryCommands::Question { text, stage } => memory::question(text, stage),
             MemoryCommands::Query { search, stage } => memory::query(search, stage),
-            MemoryCommands::List { stage, entry_type } => memory::list(stage, entry_type),
+            MemoryCommands::List { stage, entry_type } => memory::list(stage, entry_type, format),
             MemoryCommands::Show { stage, all } => memory::show(stage, all),
+            MemoryCommands::Sessions => memory::sessions(),
+            MemoryCommands::Promote {
+                entry_type,
+                target,
+                stage,
+                search,
+            } => memory::promote(entry_type, target, stage, search),
         },
         Commands::Sandbox { command } => match command {
             SandboxCommands::Suggest => sandbox::suggest(),
         },
+        Commands::Report { command } => match command {
+            ReportCommands::Cost => report::cost(format),
+        },
         Commands::SelfUpdate => self_update::execute(),
         Commands::Clean {
             all,
@@ -157,6 +255,36 @@ pub fn dispatch(command: Commands) -> Result<()> {
         Commands::Stop => stop::execute(),
         Commands::Diagnose { stage_id } => diagnose::execute(&stage_id),
         Commands::Verify { stage_id, suggest } => verify::execute(&stage_id, suggest),
+        Commands::Publish {
+            remote,
+            branch,
+            body_file,
+            dry_run,
+            allow_incomplete,
+        } => publish::execute(PublishOptions {
+            remote,
+            branch,
+            body_file,
+            dry_run,
+            allow_incomplete,
+        }),
+        Commands::Search {
+            query,
+            entry_type,
+            source,
+            stage,
+            since,
+            until,
+            limit,
+        } => search::execute(
+            &query.join(" "),
+            entry_type,
+            source,
+            stage,
+            since,
+            until,
+            limit,
+        ),
         Commands::Completions { shell } => {
             let shell = Shell::from_str(&shell)?;
             let mut cmd = <Cli as clap::CommandFactory>::command();
@@ -2,6 +2,7 @@ mod dispatch;
 mod types;
 mod types_memory;
 mod types_stage;
+mod types_workspace;
 
 pub use dispatch::dispatch;
 pub use types::Cli;
@@ -1,8 +1,14 @@
 use clap::{Parser, Subcommand};
+use loom::commands::common::OutputFormat;
+use loom::orchestrator::terminal::BackendType;
 use loom::validation::clap_id_validator;
+use std::path::PathBuf;
 
 pub use super::types_memory::{KnowledgeCommands, MemoryCommands};
-pub use super::types_stage::{OutputCommands, StageCommands};
+pub use super::types_stage::{CacheCommands, OutputCommands, StageCommands};
+pub use super::types_workspace::{
+    GraphCommands, HandoffCommands, HooksCommands, SessionsCommands, WorktreeCommands,
+};
 
 const HELP_TEMPLATE: &str = "
    ╷
@@ -22,6 +28,12 @@ const HELP_TEMPLATE: &str = "
 #[command(help_template = HELP_TEMPLATE)]
 #[command(subcommand_help_heading = "Commands")]
 pub struct Cli {
+    /// Output format: text, json or yaml. Structured output is supported by
+    /// status, graph show, sessions list, worktree list, memory list and
+    /// stage output list.
+    #[arg(long, global = true, default_value = "text")]
+    pub format: OutputFormat,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -37,6 +49,15 @@ pub enum Commands {
         /// (removes old .work/, prunes worktrees, kills orphaned sessions)
         #[arg(long)]
         clean: bool,
+
+        /// Reconcile an edited plan with existing stage files instead of
+        /// starting over (dry run unless --apply is given)
+        #[arg(long, conflicts_with = "clean")]
+        update: bool,
+
+        /// Write the changes shown by --update
+        #[arg(long, requires = "update")]
+        apply: bool,
     },
 
     /// Run stages from a plan (starts orchestrator in background)
@@ -60,6 +81,17 @@ pub enum Commands {
         /// Disable auto-merge of completed stages (merge is enabled by default)
         #[arg(long)]
         no_merge: bool,
+
+        /// Enable auto-merge of completed stages, overriding `loom config set auto-merge false`
+        #[arg(long, conflicts_with = "no_merge")]
+        merge: bool,
+
+        /// Session backend: native (terminal windows), tmux (one window per
+        /// stage in a `loom-<plan>` session) or headless (background processes
+        /// with logs in .work/logs/). Defaults to `[run] backend` in
+        /// .work/config.toml, then native.
+        #[arg(long)]
+        backend: Option<BackendType>,
     },
 
     /// Show dashboard with context health
@@ -77,6 +109,13 @@ pub enum Commands {
         verbose: bool,
     },
 
+    /// Jump to a stage's tmux window (tmux backend only)
+    Attach {
+        /// Stage ID to attach to (alphanumeric, dash, underscore only; max 128 characters)
+        #[arg(value_parser = clap_id_validator)]
+        stage_id: String,
+    },
+
     /// Resume work on a stage
     Resume {
         /// Stage ID to resume (alphanumeric, dash, underscore only; max 128 characters)
@@ -84,6 +123,12 @@ pub enum Commands {
         stage_id: String,
     },
 
+    /// View or change run settings (applied live when the daemon is running)
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommands,
+    },
+
     /// Manage active sessions
     Sessions {
         #[command(subcommand)]
@@ -138,6 +183,12 @@ pub enum Commands {
         command: SandboxCommands,
     },
 
+    /// Summarize token usage and cost
+    Report {
+        #[command(subcommand)]
+        command: ReportCommands,
+    },
+
     /// Update loom and configuration files
     SelfUpdate,
 
@@ -211,6 +262,65 @@ pub enum Commands {
         suggest: bool,
     },
 
+    /// Push the finished plan's merge point to a remote and render a PR body
+    ///
+    /// Settings come from `publish:` in the plan or [publish] in
+    /// .work/config.toml; the flags below override them.
+    Publish {
+        /// Remote to push to (default: origin)
+        #[arg(long)]
+        remote: Option<String>,
+
+        /// Remote branch to push to (default: plan/<plan-id>)
+        #[arg(long)]
+        branch: Option<String>,
+
+        /// Write the PR body here (default: .work/publish/PR.md)
+        #[arg(long)]
+        body_file: Option<PathBuf>,
+
+        /// Print the PR body without pushing or running the publish command
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Publish even when some stages are not merged
+        #[arg(long)]
+        allow_incomplete: bool,
+    },
+
+    /// Search stage memory, handoffs and knowledge, best matches first
+    ///
+    /// Uses a BM25 index in .work/index/, rebuilt when its sources change.
+    Search {
+        /// Words to search for
+        #[arg(required = true, num_args = 1..)]
+        query: Vec<String>,
+
+        /// Only memory entries of this type (note, decision, question)
+        #[arg(short = 't', long = "type")]
+        entry_type: Option<String>,
+
+        /// Only this source (memory, handoff, knowledge)
+        #[arg(long)]
+        source: Option<String>,
+
+        /// Only results from this stage
+        #[arg(short = 'S', long)]
+        stage: Option<String>,
+
+        /// Only results on or after this date (YYYY-MM-DD)
+        #[arg(long)]
+        since: Option<String>,
+
+        /// Only results on or before this date (YYYY-MM-DD)
+        #[arg(long)]
+        until: Option<String>,
+
+        /// Maximum number of results
+        #[arg(short = 'n', long, default_value = "10")]
+        limit: usize,
+    },
+
     /// Generate shell completion script
     Completions {
         /// Shell to generate completions for (bash, zsh, fish)
@@ -235,83 +345,28 @@ pub enum SandboxCommands {
 }
 
 #[derive(Subcommand)]
-pub enum SessionsCommands {
-    /// List all active sessions
-    List,
-
-    /// Kill one or more sessions
-    Kill {
-        /// Session IDs to kill (alphanumeric, dash, underscore only; max 128 characters)
-        #[arg(num_args = 1.., required_unless_present = "stage", value_parser = clap_id_validator)]
-        session_ids: Vec<String>,
-
-        /// Kill all sessions for a stage
-        #[arg(long, conflicts_with = "session_ids", value_parser = clap_id_validator)]
-        stage: Option<String>,
-    },
+pub enum ReportCommands {
+    /// Show token usage and cost per stage and per model
+    ///
+    /// Prices default to published Claude rates and can be overridden under
+    /// [pricing."<model-prefix>"] in .work/config.toml.
+    Cost,
 }
 
 #[derive(Subcommand)]
-pub enum WorktreeCommands {
-    /// List all worktrees
-    List,
-
-    /// Clean up unused worktrees
-    Clean,
-
-    /// Remove a specific worktree and branch after merge conflict resolution
+pub enum ConfigCommands {
+    /// Change a run setting
     ///
-    /// Use this command after resolving merge conflicts (manually or via Claude Code).
-    /// It cleans up the worktree and branch WITHOUT attempting another merge.
-    Remove {
-        /// Stage ID to clean up (alphanumeric, dash, underscore only; max 128 characters)
-        #[arg(value_parser = clap_id_validator)]
-        stage_id: String,
+    /// Settings: max-parallel <n>, auto-merge <true|false>. The value is saved
+    /// to .work/config.toml; a running daemon applies it immediately.
+    Set {
+        /// Setting name (max-parallel, auto-merge)
+        key: String,
+
+        /// New value
+        value: String,
     },
-}
 
-#[derive(Subcommand)]
-pub enum GraphCommands {
-    /// Show the execution graph
+    /// Show run settings saved in .work/config.toml
     Show,
-
-    /// Edit the execution graph
-    Edit,
-}
-
-#[derive(Subcommand)]
-pub enum HooksCommands {
-    /// Install loom hooks to the current project
-    ///
-    /// Installs hook scripts to ~/.claude/hooks/loom/ and configures
-    /// .claude/settings.local.json with permissions and hooks.
-    ///
-    /// This allows using loom hooks (like prefer-modern-tools and commit-guard)
-    /// in any Claude Code session without running `loom init` with a plan.
-    Install,
-
-    /// List available loom hooks and their status
-    List,
-}
-
-#[derive(Subcommand)]
-pub enum HandoffCommands {
-    /// Create a handoff file capturing current session state
-    Create {
-        /// Stage ID (auto-detected from LOOM_STAGE_ID env var if not provided)
-        #[arg(long, value_parser = clap_id_validator)]
-        stage: Option<String>,
-
-        /// Session ID (auto-detected from LOOM_SESSION_ID env var if not provided)
-        #[arg(long)]
-        session: Option<String>,
-
-        /// Trigger type (e.g., precompact, session_end, manual)
-        #[arg(long, default_value = "manual")]
-        trigger: String,
-
-        /// Optional message to include in the handoff
-        #[arg(long)]
-        message: Option<String>,
-    },
 }
@@ -1,7 +1,9 @@
 //! Memory and knowledge CLI command types
 
 use clap::Subcommand;
-use loom::fs::knowledge::{DEFAULT_MAX_FILE_LINES, DEFAULT_MAX_TOTAL_LINES};
+use loom::fs::knowledge::{
+    DEFAULT_MAX_CHURN_PERCENT, DEFAULT_MAX_FILE_LINES, DEFAULT_MAX_TOTAL_LINES,
+};
 use loom::validation::{clap_id_validator, clap_knowledge_content_validator};
 
 #[derive(Subcommand)]
@@ -21,6 +23,20 @@ pub enum KnowledgeCommands {
         /// Content to append (markdown format). Omit or use "-" to read from stdin.
         #[arg(value_parser = clap_knowledge_content_validator)]
         content: Option<String>,
+
+        /// Tag the entry (repeatable; makes it a structured entry)
+        #[arg(long = "tag", value_name = "TAG")]
+        tags: Vec<String>,
+
+        /// File the entry is about (repeatable; makes it a structured entry).
+        /// `knowledge check` flags the entry once the file is deleted or
+        /// heavily changed.
+        #[arg(long = "path", value_name = "PATH")]
+        paths: Vec<String>,
+
+        /// Record stage, commit and date even without tags or paths
+        #[arg(long)]
+        structured: bool,
     },
 
     /// Initialize the knowledge directory
@@ -39,12 +55,16 @@ pub enum KnowledgeCommands {
         #[arg(long)]
         src_path: Option<String>,
 
+        /// Flag structured entries whose files changed by more than this percentage
+        #[arg(long, default_value_t = DEFAULT_MAX_CHURN_PERCENT)]
+        max_churn: usize,
+
         /// Quiet mode - only output errors
         #[arg(short, long)]
         quiet: bool,
     },
 
-    /// Analyze knowledge files for size, duplicates, and curated blocks
+    /// Analyze knowledge files for size, duplicates, and curated blocks, or compact them
     Gc {
         /// Max lines per file before GC is recommended
         #[arg(long, default_value_t = DEFAULT_MAX_FILE_LINES)]
@@ -54,9 +74,22 @@ pub enum KnowledgeCommands {
         #[arg(long, default_value_t = DEFAULT_MAX_TOTAL_LINES)]
         max_total_lines: usize,
 
+        /// Propose removing structured entries whose files changed by more than this percentage
+        #[arg(long, default_value_t = DEFAULT_MAX_CHURN_PERCENT)]
+        max_churn: usize,
+
         /// Only show metrics, skip compaction instructions
         #[arg(short, long)]
         quiet: bool,
+
+        /// Merge duplicate sections, collapse promoted blocks, drop duplicate
+        /// bullets and trim files to --max-file-lines, showing a diff
+        #[arg(long)]
+        compact: bool,
+
+        /// With --compact, show the diff without writing any file
+        #[arg(long, requires = "compact")]
+        dry_run: bool,
     },
 }
 
@@ -127,4 +160,27 @@ pub enum MemoryCommands {
         #[arg(short, long)]
         all: bool,
     },
+
+    /// List stage memory journals with their entry counts
+    Sessions,
+
+    /// Promote memory entries into a knowledge file
+    ///
+    /// Promoted entries are appended as a "## Promoted from Memory" block;
+    /// entries already in the file are skipped.
+    Promote {
+        /// Entry type to promote (note, decision, question) or "all"
+        entry_type: String,
+
+        /// Knowledge file to append to (e.g. patterns, mistakes, architecture)
+        target: String,
+
+        /// Stage ID (auto-detected from LOOM_STAGE_ID; all stages if neither is set)
+        #[arg(short = 'S', long, value_parser = clap_id_validator)]
+        stage: Option<String>,
+
+        /// Only promote entries containing this text
+        #[arg(short, long)]
+        search: Option<String>,
+    },
 }
@@ -157,6 +157,34 @@ pub enum StageCommands {
         stage_id: String,
     },
 
+    /// Show the recorded acceptance and verification runs of a stage
+    ///
+    /// Lists every run with its attempt number, source commit, and each
+    /// command's outcome and duration. Commands that both passed and failed
+    /// on the same code are marked flaky.
+    History {
+        /// Stage ID (alphanumeric, dash, underscore only; max 128 characters)
+        #[arg(value_parser = clap_id_validator)]
+        stage_id: String,
+
+        /// Show only this attempt
+        #[arg(long)]
+        attempt: Option<u32>,
+
+        /// Include recorded stdout/stderr
+        #[arg(short, long)]
+        verbose: bool,
+    },
+
+    /// Inspect or clear the acceptance criteria result cache
+    ///
+    /// Passing criteria are cached by worktree contents and skipped when
+    /// re-checked on the same contents.
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommands,
+    },
+
     /// Respond to a stage flagged for human review
     ///
     /// Use this to approve, force-complete, or reject a stage in NeedsHumanReview state.
@@ -194,6 +222,16 @@ pub enum StageCommands {
         reason: String,
     },
 
+    /// Merge a completed or merge-failed stage now
+    ///
+    /// With a running daemon the orchestrator performs the merge, spawning a
+    /// resolution session on conflicts. Otherwise falls back to retry-merge.
+    Merge {
+        /// Stage ID (alphanumeric, dash, underscore only; max 128 characters)
+        #[arg(value_parser = clap_id_validator)]
+        stage_id: String,
+    },
+
     /// Re-attempt merge to main from a worktree
     ///
     /// Use this when a stage is in MergeConflict or MergeBlocked status and you
@@ -261,3 +299,20 @@ pub enum OutputCommands {
         key: String,
     },
 }
+
+#[derive(Subcommand)]
+pub enum CacheCommands {
+    /// List cached criteria, of one stage or of every stage
+    List {
+        /// Stage ID (alphanumeric, dash, underscore only; max 128 characters)
+        #[arg(value_parser = clap_id_validator)]
+        stage_id: Option<String>,
+    },
+
+    /// Remove cached results, of one stage or of every stage
+    Clear {
+        /// Stage ID (alphanumeric, dash, underscore only; max 128 characters)
+        #[arg(value_parser = clap_id_validator)]
+        stage_id: Option<String>,
+    },
+}
@@ -0,0 +1,86 @@
+//! Session, worktree, graph, hooks and handoff CLI command types
+
+use clap::Subcommand;
+use loom::validation::clap_id_validator;
+
+#[derive(Subcommand)]
+pub enum SessionsCommands {
+    /// List all active sessions
+    List,
+
+    /// Kill one or more sessions
+    Kill {
+        /// Session IDs to kill (alphanumeric, dash, underscore only; max 128 characters)
+        #[arg(num_args = 1.., required_unless_present = "stage", value_parser = clap_id_validator)]
+        session_ids: Vec<String>,
+
+        /// Kill all sessions for a stage
+        #[arg(long, conflicts_with = "session_ids", value_parser = clap_id_validator)]
+        stage: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum WorktreeCommands {
+    /// List all worktrees
+    List,
+
+    /// Clean up unused worktrees
+    Clean,
+
+    /// Remove a specific worktree and branch after merge conflict resolution
+    ///
+    /// Use this command after resolving merge conflicts (manually or via Claude Code).
+    /// It cleans up the worktree and branch WITHOUT attempting another merge.
+    Remove {
+        /// Stage ID to clean up (alphanumeric, dash, underscore only; max 128 characters)
+        #[arg(value_parser = clap_id_validator)]
+        stage_id: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum GraphCommands {
+    /// Show the execution graph
+    Show,
+
+    /// Edit the execution graph
+    Edit,
+}
+
+#[derive(Subcommand)]
+pub enum HooksCommands {
+    /// Install loom hooks to the current project
+    ///
+    /// Installs hook scripts to ~/.claude/hooks/loom/ and configures
+    /// .claude/settings.local.json with permissions and hooks.
+    ///
+    /// This allows using loom hooks (like prefer-modern-tools and commit-guard)
+    /// in any Claude Code session without running `loom init` with a plan.
+    Install,
+
+    /// List available loom hooks and their status
+    List,
+}
+
+#[derive(Subcommand)]
+pub enum HandoffCommands {
+    /// Create a handoff file capturing current session state
+    Create {
+        /// Stage ID (auto-detected from LOOM_STAGE_ID env var if not provided)
+        #[arg(long, value_parser = clap_id_validator)]
+        stage: Option<String>,
+
+        /// Session ID (auto-detected from LOOM_SESSION_ID env var if not provided)
+        #[arg(long)]
+        session: Option<String>,
+
+        /// Trigger type (e.g., precompact, session_end, manual)
+        #[arg(long, default_value = "manual")]
+        trigger: String,
+
+        /// Optional message to include in the handoff
+        #[arg(long)]
+        message: Option<String>,
+    },
+}
@@ -0,0 +1,39 @@
+//! Attach command - jump to a stage's tmux window
+//! Usage: loom attach <stage-id>
+
+use anyhow::{bail, Result};
+
+use crate::commands::common::find_work_dir;
+use crate::orchestrator::terminal::tmux::{
+    attach_window, has_session, session_name_for_work_dir, tmux_available, window_exists,
+};
+
+/// Window name prefixes tried in order: the stage session itself, then any
+/// merge, base conflict resolution or knowledge session for that stage.
+const WINDOW_PREFIXES: [&str; 4] = ["", "merge-", "base-conflict-", "knowledge-"];
+
+/// Execute the attach command
+pub fn execute(stage_id: &str) -> Result<()> {
+    if !tmux_available() {
+        bail!("tmux is not installed - `loom attach` requires the tmux backend");
+    }
+
+    let work_dir = find_work_dir()?;
+    let session_name = session_name_for_work_dir(&work_dir);
+
+    if !has_session(&session_name) {
+        bail!(
+            "No tmux session '{session_name}' found. Start the plan with `loom run --backend tmux`."
+        );
+    }
+
+    let window = WINDOW_PREFIXES
+        .iter()
+        .map(|prefix| format!("{prefix}{stage_id}"))
+        .find(|name| window_exists(&session_name, name));
+
+    match window {
+        Some(window) => attach_window(&session_name, &window),
+        None => bail!("No tmux window for stage '{stage_id}' in session '{session_name}'"),
+    }
+}
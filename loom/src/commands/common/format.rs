@@ -0,0 +1,135 @@
+//! Machine-readable output for commands that support `--format json|yaml`.
+//!
+//! Structured output is wrapped in a versioned envelope so scripts can detect
+//! shape changes:
+//!
+//! ```json
+//! { "schema_version": 1, "kind": "status", "data": { ... } }
+//! ```
+//!
+//! `SCHEMA_VERSION` is bumped whenever a field is removed, renamed or changes
+//! type. Adding fields does not bump it.
+
+use anyhow::{bail, Context, Result};
+use serde::Serialize;
+use std::fmt;
+use std::str::FromStr;
+
+/// Version of the structured output shapes
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Output format selected with the global `--format` option
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable text (default)
+    #[default]
+    Text,
+    /// JSON document
+    Json,
+    /// YAML document
+    Yaml,
+}
+
+impl OutputFormat {
+    /// Whether this is the human-readable text format
+    pub fn is_text(self) -> bool {
+        self == OutputFormat::Text
+    }
+
+    /// Render `data` wrapped in the versioned envelope
+    ///
+    /// # Arguments
+    /// * `kind` - Identifies the shape of `data` (e.g. "status", "graph")
+    /// * `data` - The payload to serialize
+    pub fn render<T: Serialize>(self, kind: &str, data: &T) -> Result<String> {
+        let envelope = Envelope {
+            schema_version: SCHEMA_VERSION,
+            kind,
+            data,
+        };
+        match self {
+            OutputFormat::Json => {
+                serde_json::to_string_pretty(&envelope).context("Failed to serialize JSON output")
+            }
+            OutputFormat::Yaml => {
+                serde_yaml::to_string(&envelope).context("Failed to serialize YAML output")
+            }
+            OutputFormat::Text => bail!("Text output is rendered by each command"),
+        }
+    }
+
+    /// Print `data` wrapped in the versioned envelope to stdout
+    pub fn print<T: Serialize>(self, kind: &str, data: &T) -> Result<()> {
+        let rendered = self.render(kind, data)?;
+        println!("{}", rendered.trim_end());
+        Ok(())
+    }
+}
+
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OutputFormat::Text => write!(f, "text"),
+            OutputFormat::Json => write!(f, "json"),
+            OutputFormat::Yaml => write!(f, "yaml"),
+        }
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            "yaml" | "yml" => Ok(OutputFormat::Yaml),
+            _ => bail!("Unknown output format '{s}'. Valid formats: text, json, yaml"),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Envelope<'a, T: Serialize> {
+    schema_version: u32,
+    kind: &'a str,
+    data: &'a T,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Sample {
+        id: String,
+    }
+
+    #[test]
+    fn test_parse_output_format() {
+        assert_eq!("json".parse::<OutputFormat>().unwrap(), OutputFormat::Json);
+        assert_eq!("YAML".parse::<OutputFormat>().unwrap(), OutputFormat::Yaml);
+        assert_eq!("text".parse::<OutputFormat>().unwrap(), OutputFormat::Text);
+        assert!("xml".parse::<OutputFormat>().is_err());
+    }
+
+    #[test]
+    fn test_render_wraps_data_in_envelope() {
+        let data = Sample {
+            id: "stage-1".to_string(),
+        };
+
+        let json = OutputFormat::Json.render("sample", &data).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["schema_version"], SCHEMA_VERSION);
+        assert_eq!(value["kind"], "sample");
+        assert_eq!(value["data"]["id"], "stage-1");
+
+        let yaml = OutputFormat::Yaml.render("sample", &data).unwrap();
+        let value: serde_yaml::Value = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(value["kind"].as_str(), Some("sample"));
+        assert_eq!(value["data"]["id"].as_str(), Some("stage-1"));
+
+        assert!(OutputFormat::Text.render("sample", &data).is_err());
+    }
+}
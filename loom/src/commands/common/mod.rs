@@ -4,12 +4,19 @@
 //! - Work directory discovery
 //! - Session ID detection (multiple strategies)
 //! - Stage ID detection from various contexts
+//! - Routing stage commands through a running daemon
 //! - String truncation for display
+//! - Machine-readable (`--format json|yaml`) output
+
+pub mod format;
 
 use anyhow::{bail, Result};
 use std::path::{Path, PathBuf};
 
+use crate::daemon::{DaemonServer, DaemonStatus};
 use crate::git::branch::stage_id_from_branch;
+use crate::models::stage::StageStatus;
+use crate::orchestrator::control::StageCommand;
 
 /// Find the .work directory by walking up from current directory.
 ///
@@ -108,10 +115,28 @@ pub fn detect_stage_id() -> Option<String> {
     Some(stage_id)
 }
 
+/// Send a stage command to the daemon if one is running.
+///
+/// While the daemon runs, its orchestrator owns the stage files; editing them
+/// directly races with its loop. Returns `Ok(None)` when no responsive daemon
+/// is running so the caller can fall back to editing the files itself.
+pub fn send_to_daemon(
+    work_dir: &Path,
+    stage_id: &str,
+    command: StageCommand,
+) -> Result<Option<StageStatus>> {
+    if DaemonServer::check_status(work_dir) != DaemonStatus::Running {
+        return Ok(None);
+    }
+    DaemonServer::send_stage_command(work_dir, stage_id, command).map(Some)
+}
+
 // Re-export truncate utilities from their canonical location in utils module.
 // These are used across multiple layers (commands, orchestrator, verify, fs).
 pub use crate::utils::{truncate, truncate_for_display};
 
+pub use format::OutputFormat;
+
 #[cfg(test)]
 mod tests {
     #[test]
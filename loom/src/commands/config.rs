@@ -0,0 +1,58 @@
+//! Config command - view and change run settings
+//! Usage: loom config set <key> <value>, loom config show
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::daemon::{DaemonServer, DaemonStatus};
+use crate::fs::work_dir::WorkDir;
+use crate::orchestrator::run_settings::{ConfigChange, RunSettings};
+
+/// Change a run setting, applying it live when the daemon is running
+pub fn set(key: String, value: String) -> Result<()> {
+    let work_dir = WorkDir::new(".")?;
+    work_dir.load()?;
+
+    let change = ConfigChange::parse(&key, &value)?;
+
+    if DaemonServer::check_status(work_dir.root()) == DaemonStatus::Running {
+        DaemonServer::send_config_change(work_dir.root(), change)?;
+        println!(
+            "{} Set {} (applied to running daemon)",
+            "✓".green().bold(),
+            change
+        );
+        return Ok(());
+    }
+
+    change.persist(work_dir.root())?;
+    println!("{} Set {}", "✓".green().bold(), change);
+    println!(
+        "  {} Daemon not running; takes effect on the next {}",
+        "→".dimmed(),
+        "loom run".cyan()
+    );
+    Ok(())
+}
+
+/// Show run settings persisted in .work/config.toml
+pub fn show() -> Result<()> {
+    let work_dir = WorkDir::new(".")?;
+    work_dir.load()?;
+
+    let settings = RunSettings::load(work_dir.root());
+    let unset = || "(default)".dimmed().to_string();
+
+    println!("{}", "Run settings".bold());
+    println!(
+        "  max-parallel  {}",
+        settings.max_parallel.map_or_else(unset, |n| n.to_string())
+    );
+    println!(
+        "  auto-merge    {}",
+        settings
+            .auto_merge
+            .map_or_else(unset, |enabled| enabled.to_string())
+    );
+    Ok(())
+}
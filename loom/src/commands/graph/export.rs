@@ -0,0 +1,84 @@
+//! Structured execution graph for `loom graph show --format json|yaml`
+
+use serde::Serialize;
+
+use super::levels::compute_stage_levels;
+use crate::models::stage::{Stage, StageStatus};
+
+/// The execution graph as a flat list of nodes ordered by level, then id
+#[derive(Debug, Clone, Serialize)]
+pub struct GraphData {
+    pub stages: Vec<GraphNode>,
+}
+
+/// A stage in the execution graph
+#[derive(Debug, Clone, Serialize)]
+pub struct GraphNode {
+    pub id: String,
+    pub name: String,
+    pub status: StageStatus,
+    pub dependencies: Vec<String>,
+    /// Topological level (0 = no dependencies)
+    pub level: usize,
+    pub merged: bool,
+    pub held: bool,
+    /// Fan-out parent this stage was expanded from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_stage: Option<String>,
+    /// Stages this fan-out parent was expanded into
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub child_stages: Vec<String>,
+}
+
+/// Build the structured graph from stage files
+pub fn build_graph_data(stages: &[Stage]) -> GraphData {
+    let levels = compute_stage_levels(stages);
+
+    let mut nodes: Vec<GraphNode> = stages
+        .iter()
+        .map(|stage| GraphNode {
+            id: stage.id.clone(),
+            name: stage.name.clone(),
+            status: stage.status.clone(),
+            dependencies: stage.dependencies.clone(),
+            level: levels.get(&stage.id).copied().unwrap_or(0),
+            merged: stage.merged,
+            held: stage.held,
+            parent_stage: stage.parent_stage.clone(),
+            child_stages: stage.child_stages.clone(),
+        })
+        .collect();
+    nodes.sort_by(|a, b| a.level.cmp(&b.level).then_with(|| a.id.cmp(&b.id)));
+
+    GraphData { stages: nodes }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stage(id: &str, deps: &[&str]) -> Stage {
+        let mut stage = Stage::new(id.to_string(), None);
+        stage.id = id.to_string();
+        stage.dependencies = deps.iter().map(|d| d.to_string()).collect();
+        stage
+    }
+
+    #[test]
+    fn test_build_graph_data_orders_by_level() {
+        let stages = vec![
+            stage("deploy", &["build", "test"]),
+            stage("test", &["build"]),
+            stage("build", &[]),
+        ];
+
+        let graph = build_graph_data(&stages);
+        let order: Vec<(&str, usize)> = graph
+            .stages
+            .iter()
+            .map(|n| (n.id.as_str(), n.level))
+            .collect();
+        assert_eq!(order, vec![("build", 0), ("test", 1), ("deploy", 2)]);
+        assert_eq!(graph.stages[2].dependencies, vec!["build", "test"]);
+    }
+}
@@ -8,9 +8,11 @@
 //! - `indicators`: Status indicators and priority ordering
 //! - `levels`: Topological level computation
 //! - `colors`: Stage color assignment for visual differentiation
+//! - `export`: Structured graph for `--format json|yaml`
 
 pub mod colors;
 mod display;
+pub mod export;
 pub mod indicators;
 mod levels;
 mod tree;
@@ -21,18 +23,24 @@ mod tests;
 use anyhow::{bail, Result};
 use colored::Colorize;
 
-use crate::commands::common::find_work_dir;
+use crate::commands::common::{find_work_dir, OutputFormat};
 use crate::verify::transitions::list_all_stages;
 
 // Re-export the public API
 pub use colors::stage_color;
 pub use display::build_graph_display;
+pub use export::{build_graph_data, GraphData, GraphNode};
 pub use indicators::{status_indicator, status_priority};
 pub use levels::compute_stage_levels;
 pub use tree::build_tree_display;
 
 /// Show the execution graph
-pub fn show() -> Result<()> {
+pub fn show(format: OutputFormat) -> Result<()> {
+    if !format.is_text() {
+        let stages = list_all_stages(&find_work_dir()?)?;
+        return format.print("graph", &build_graph_data(&stages));
+    }
+
     println!();
     println!("Execution Graph:");
     println!("================");
@@ -423,3 +423,37 @@ fn test_tree_connector_last_item() {
         "Tree should not end with ├── connector"
     );
 }
+
+#[test]
+fn test_tree_display_nests_fan_out_children() {
+    let mut parent = create_test_stage(
+        "test",
+        "Test",
+        StageStatus::WaitingForDeps,
+        vec!["build", "test-core", "test-cli"],
+    );
+    parent.child_stages = vec!["test-core".to_string(), "test-cli".to_string()];
+    parent.for_each = Some(crate::plan::schema::ForEach {
+        items: vec!["core".to_string(), "cli".to_string()],
+        from_output: None,
+    });
+    let mut stages = vec![
+        create_test_stage("build", "Build", StageStatus::Completed, vec![]),
+        parent,
+    ];
+    for id in ["test-core", "test-cli"] {
+        let mut child = create_test_stage(id, id, StageStatus::Executing, vec!["build"]);
+        child.parent_stage = Some("test".to_string());
+        stages.push(child);
+    }
+
+    let output = strip_ansi(&build_tree_display(&stages));
+    let lines: Vec<&str> = output.lines().collect();
+
+    assert_eq!(lines.len(), 4, "got:\n{output}");
+    assert!(lines[1].contains("test") && lines[1].contains("← build"));
+    assert!(!lines[1].contains("test-core"));
+    assert!(lines[1].contains("⇉ 2 stages"));
+    assert!(lines[2].contains("├─") && lines[2].contains("test-core"));
+    assert!(lines[3].contains("└─") && lines[3].contains("test-cli"));
+}
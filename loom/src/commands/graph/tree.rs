@@ -2,7 +2,7 @@
 //!
 //! Renders stages as a vertical tree with connectors and dependency annotations.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use colored::{Color, Colorize};
 
@@ -10,7 +10,7 @@ use crate::models::stage::{Stage, StageStatus};
 
 use super::colors::color_by_index;
 use super::indicators::status_indicator;
-use super::levels::compute_stage_levels;
+use super::levels::compute_all_levels;
 
 /// Compute the tree connector prefix based on level and position within level
 fn compute_connector(
@@ -111,16 +111,79 @@ fn format_base_branch_info(stage: &Stage, color_map: &HashMap<&str, Color>) -> O
     Some(base_info)
 }
 
-/// Build a vertical tree display of stages
+/// Format the fan-out annotation of a `for_each` parent stage
+fn format_fan_out_annotation(stage: &Stage) -> String {
+    let Some(for_each) = &stage.for_each else {
+        return String::new();
+    };
+    let summary = match (&for_each.from_output, stage.child_stages.len()) {
+        (Some(source), 0) => format!("⇉ from {source}"),
+        (_, count) => format!("⇉ {count} stages"),
+    };
+    format!("  {}", summary.dimmed())
+}
+
+/// Format the child stages of a fan-out parent, nested one level deeper
+fn format_children(level: usize, children: &[&Stage]) -> String {
+    let indent = "    ".repeat(level + 1);
+    let mut output = String::new();
+    for (i, child) in children.iter().enumerate() {
+        let branch = if i + 1 == children.len() {
+            "└─"
+        } else {
+            "├─"
+        };
+        let indicator = status_indicator(&child.status);
+        output.push_str(&format!(
+            "{indent}{} {indicator} {}\n",
+            branch.dimmed(),
+            child.id.dimmed()
+        ));
+    }
+    output
+}
+
+/// Split stages into top-level stages and fan-out children keyed by parent ID
+fn group_fan_out(stages: &[Stage]) -> (Vec<&Stage>, HashMap<&str, Vec<&Stage>>) {
+    let ids: HashSet<&str> = stages.iter().map(|s| s.id.as_str()).collect();
+    let mut top_level = Vec::new();
+    let mut children: HashMap<&str, Vec<&Stage>> = HashMap::new();
+    for stage in stages {
+        match stage.parent_stage.as_deref() {
+            Some(parent) if ids.contains(parent) => children.entry(parent).or_default().push(stage),
+            _ => top_level.push(stage),
+        }
+    }
+    (top_level, children)
+}
+
+/// Build a vertical tree display of stages.
+///
+/// Fan-out children are drawn nested under their parent stage rather than as
+/// separate stages, and the parent's dependencies on them are not annotated.
 pub fn build_tree_display(stages: &[Stage]) -> String {
     if stages.is_empty() {
         return "(no stages found)".to_string();
     }
 
-    let levels = compute_stage_levels(stages);
+    let (top_level, children) = group_fan_out(stages);
+    let display_deps: HashMap<&str, Vec<String>> = top_level
+        .iter()
+        .map(|s| {
+            let deps = s
+                .dependencies
+                .iter()
+                .filter(|d| !s.child_stages.contains(d));
+            (s.id.as_str(), deps.cloned().collect())
+        })
+        .collect();
+    let no_deps = Vec::new();
+    let deps_of = |id: &str| display_deps.get(id).unwrap_or(&no_deps);
+
+    let levels = compute_all_levels(&top_level, |s| s.id.as_str(), |s| deps_of(&s.id));
 
     // Sort stages by level ASC, then id ASC
-    let mut sorted_stages: Vec<&Stage> = stages.iter().collect();
+    let mut sorted_stages = top_level;
     sorted_stages.sort_by(|a, b| {
         let level_a = levels.get(&a.id).copied().unwrap_or(0);
         let level_b = levels.get(&b.id).copied().unwrap_or(0);
@@ -155,18 +218,20 @@ pub fn build_tree_display(stages: &[Stage]) -> String {
 
         let connector = compute_connector(level, index_in_level, level_size, is_last_level);
         let indicator = status_indicator(&stage.status);
-        let deps = format_dep_annotation(
-            &stage.dependencies,
-            max_id_width,
-            stage.id.len(),
-            &color_map,
-        );
+        let deps =
+            format_dep_annotation(deps_of(&stage.id), max_id_width, stage.id.len(), &color_map);
+        let fan_out = format_fan_out_annotation(stage);
         let color = color_by_index(global_index);
         let colored_id = stage.id.color(color);
-        output.push_str(&format!("{connector}{indicator} {colored_id}{deps}\n"));
+        output.push_str(&format!(
+            "{connector}{indicator} {colored_id}{deps}{fan_out}\n"
+        ));
+        if let Some(stage_children) = children.get(stage.id.as_str()) {
+            output.push_str(&format_children(level, stage_children));
+        }
 
         // Increment index for this level
-        *level_indices.get_mut(&level).unwrap() += 1;
+        level_indices.insert(level, index_in_level + 1);
 
         // Show base branch info for executing or queued stages with base branch set
         if matches!(stage.status, StageStatus::Executing | StageStatus::Queued) {
@@ -108,6 +108,8 @@ pub fn execute(
         session_type: Default::default(),
         merge_source_branch: None,
         merge_target_branch: None,
+        model: None,
+        usage: None,
     };
 
     // Generate the handoff file
@@ -1,13 +1,16 @@
 //! Initialize the .work/ directory structure for loom orchestration.
 //!
 //! This module provides the `loom init` command which sets up the workspace,
-//! optionally initializes from a plan file, and creates stage files.
+//! optionally initializes from a plan file, and creates stage files. With
+//! `--update` it instead reconciles an edited plan with existing stage files.
 
 mod cleanup;
 mod execute;
 mod plan_setup;
+mod update;
 
 #[cfg(test)]
 mod tests;
 
 pub use execute::execute;
+pub use update::execute_update;
@@ -3,16 +3,15 @@
 use crate::fs::stage_files::stage_file_path;
 use crate::fs::work_dir::WorkDir;
 use crate::git::branch::current_branch;
-use crate::models::stage::{Stage, StageStatus, StageType};
+use crate::models::stage::Stage;
 use crate::plan::graph::levels::compute_all_levels;
 use crate::plan::parser::parse_plan;
 use crate::plan::schema::{
     check_knowledge_recommendations, check_sandbox_recommendations, validate_structural_preflight,
-    StageDefinition,
 };
+use crate::plan::stages::{expand_init_fan_out, stage_for_plan};
 use crate::verify::serialize_stage_to_markdown;
 use anyhow::{Context, Result};
-use chrono::Utc;
 use colored::Colorize;
 use serde::Serialize;
 use std::fs;
@@ -34,7 +33,7 @@ struct PlanConfig {
 }
 
 /// Initialize with a plan file
-/// Returns the number of stages created
+/// Returns the number of stages created (including fan-out children)
 pub fn initialize_with_plan(work_dir: &WorkDir, plan_path: &Path) -> Result<usize> {
     if !plan_path.exists() {
         anyhow::bail!("Plan file does not exist: {}", plan_path.display());
@@ -119,17 +118,17 @@ pub fn initialize_with_plan(work_dir: &WorkDir, plan_path: &Path) -> Result<usiz
     println!("{}", "─".repeat(40).dimmed());
 
     let max_id_len = stages.iter().map(|s| s.id.len()).max().unwrap_or(0);
+    let mut created = stage_count;
 
     for stage_def in &stages {
-        let stage = create_stage_from_definition(stage_def, &parsed_plan.id);
+        let mut stage = stage_for_plan(stage_def, &parsed_plan);
         let depth = depths.get(&stage.id).copied().unwrap_or(0);
-        let stage_path = stage_file_path(&stages_dir, depth, &stage.id);
-
-        let content = serialize_stage_to_markdown(&stage)
-            .with_context(|| format!("Failed to serialize stage: {}", stage.id))?;
-
-        fs::write(&stage_path, content)
-            .with_context(|| format!("Failed to write stage file: {}", stage_path.display()))?;
+        let children = expand_init_fan_out(&mut stage)?;
+        for child in &children {
+            write_stage_file(&stages_dir, depth, child)?;
+        }
+        write_stage_file(&stages_dir, depth, &stage)?;
+        created += children.len();
 
         let status_indicator = if stage_def.dependencies.is_empty() {
             "●".green()
@@ -144,108 +143,19 @@ pub fn initialize_with_plan(work_dir: &WorkDir, plan_path: &Path) -> Result<usiz
             stage.name,
             width = max_id_len
         );
+        for child in &children {
+            println!("     {} {}", "└".dimmed(), child.id.dimmed());
+        }
     }
 
-    Ok(stage_count)
+    Ok(created)
 }
 
-/// Detect the stage type from the definition.
-///
-/// Uses explicit `stage_type` field if set, otherwise falls back to
-/// detecting stage type based on ID or name patterns (case-insensitive):
-/// - "knowledge" -> Knowledge
-/// - "integration-verify" or "integration verify" -> IntegrationVerify
-fn detect_stage_type(stage_def: &StageDefinition) -> StageType {
-    // Check explicit stage_type field first (if not default Standard)
-    if stage_def.stage_type != StageType::Standard {
-        return stage_def.stage_type;
-    }
-
-    let id_lower = stage_def.id.to_lowercase();
-    let name_lower = stage_def.name.to_lowercase();
-
-    // Detect Knowledge stage
-    if id_lower.contains("knowledge") || name_lower.contains("knowledge") {
-        return StageType::Knowledge;
-    }
-
-    // Detect IntegrationVerify stage
-    if id_lower.contains("integration-verify")
-        || name_lower.contains("integration-verify")
-        || name_lower.contains("integration verify")
-    {
-        return StageType::IntegrationVerify;
-    }
-
-    StageType::Standard
-}
-
-/// Create a Stage from a StageDefinition
-pub(crate) fn create_stage_from_definition(stage_def: &StageDefinition, plan_id: &str) -> Stage {
-    let now = Utc::now();
-
-    let status = if stage_def.dependencies.is_empty() {
-        StageStatus::Queued
-    } else {
-        StageStatus::WaitingForDeps
-    };
-
-    let stage_type = detect_stage_type(stage_def);
-
-    Stage {
-        id: stage_def.id.clone(),
-        name: stage_def.name.clone(),
-        description: stage_def.description.clone(),
-        status,
-        dependencies: stage_def.dependencies.clone(),
-        parallel_group: stage_def.parallel_group.clone(),
-        acceptance: stage_def.acceptance.clone(),
-        setup: stage_def.setup.clone(),
-        files: stage_def.files.clone(),
-        stage_type,
-        plan_id: Some(plan_id.to_string()),
-        worktree: None,
-        session: None,
-        held: false,
-        parent_stage: None,
-        child_stages: Vec::new(),
-        created_at: now,
-        updated_at: now,
-        completed_at: None,
-        started_at: None,
-        duration_secs: None,
-        execution_secs: None,
-        attempt_started_at: None,
-        close_reason: None,
-        auto_merge: stage_def.auto_merge,
-        working_dir: Some(stage_def.working_dir.clone()),
-        retry_count: 0,
-        max_retries: None,
-        last_failure_at: None,
-        failure_info: None,
-        resolved_base: None,
-        base_branch: None,
-        base_merged_from: Vec::new(),
-        outputs: Vec::new(),
-        completed_commit: None,
-        merged: false,
-        merge_conflict: false,
-        verification_status: Default::default(),
-        context_budget: stage_def.context_budget,
-        truths: stage_def.truths.clone(),
-        artifacts: stage_def.artifacts.clone(),
-        wiring: stage_def.wiring.clone(),
-        truth_checks: stage_def.truth_checks.clone(),
-        wiring_tests: stage_def.wiring_tests.clone(),
-        dead_code_check: stage_def.dead_code_check.clone(),
-        before_stage: stage_def.before_stage.clone(),
-        after_stage: stage_def.after_stage.clone(),
-        fix_attempts: 0,
-        sandbox: stage_def.sandbox.clone(),
-        execution_mode: stage_def.execution_mode,
-        max_fix_attempts: None,
-        review_reason: None,
-        bug_fix: stage_def.bug_fix,
-        regression_test: stage_def.regression_test.clone(),
-    }
+/// Write a stage file at the given depth prefix
+fn write_stage_file(stages_dir: &Path, depth: usize, stage: &Stage) -> Result<()> {
+    let stage_path = stage_file_path(stages_dir, depth, &stage.id);
+    let content = serialize_stage_to_markdown(stage)
+        .with_context(|| format!("Failed to serialize stage: {}", stage.id))?;
+    fs::write(&stage_path, content)
+        .with_context(|| format!("Failed to write stage file: {}", stage_path.display()))
 }
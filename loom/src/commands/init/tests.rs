@@ -1,13 +1,16 @@
 //! Tests for loom init command.
 
 use super::cleanup::{cleanup_work_directory, prune_stale_worktrees};
-use super::plan_setup::{create_stage_from_definition, initialize_with_plan};
+use super::plan_setup::initialize_with_plan;
 use crate::fs::work_dir::WorkDir;
 use crate::models::stage::{Stage, StageStatus, StageType as ModelStageType};
+use crate::orchestrator::plan_update::{check_merged_graph, reconcile};
 use crate::plan::schema::{
     LoomConfig, LoomMetadata, SandboxConfig, StageDefinition, StageSandboxConfig, StageType,
 };
+use crate::plan::stages::create_stage_from_definition;
 use crate::verify::serialize_stage_to_markdown;
+use crate::verify::transitions::{list_all_stages, load_stage, save_stage};
 use chrono::Utc;
 use serial_test::serial;
 use std::fs;
@@ -20,8 +23,17 @@ fn create_test_plan(dir: &Path, stages: Vec<StageDefinition>) -> PathBuf {
         loom: LoomConfig {
             version: 1,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             sandbox: SandboxConfig::default(),
             change_impact: None,
+            default_timeout: None,
+            parallel: None,
+            budget: None,
+            models: None,
+            notifiers: Vec::new(),
+            publish: None,
+            promote_decisions: None,
             stages,
         },
     };
@@ -44,10 +56,13 @@ fn test_create_stage_from_definition_no_dependencies() {
         description: Some("Test stage".to_string()),
         dependencies: vec![],
         parallel_group: None,
-        acceptance: vec!["cargo test".to_string()],
+        acceptance: vec!["cargo test".into()],
+        parallel: None,
         setup: vec![],
         files: vec!["src/*.rs".to_string()],
         auto_merge: None,
+        merge_strategy: None,
+        overlap: None,
         working_dir: ".".to_string(),
         stage_type: StageType::default(),
         truths: vec![],
@@ -59,6 +74,12 @@ fn test_create_stage_from_definition_no_dependencies() {
         before_stage: vec![],
         after_stage: vec![],
         context_budget: None,
+        timeout: None,
+        budget: None,
+        model: None,
+        for_each: None,
+        when: None,
+        output_keys: vec![],
         sandbox: StageSandboxConfig::default(),
         execution_mode: None,
         bug_fix: None,
@@ -84,9 +105,12 @@ fn test_create_stage_from_definition_with_dependencies() {
         dependencies: vec!["stage-1".to_string()],
         parallel_group: Some("core".to_string()),
         acceptance: vec![],
+        parallel: None,
         setup: vec!["cargo build".to_string()],
         files: vec![],
         auto_merge: None,
+        merge_strategy: None,
+        overlap: None,
         working_dir: ".".to_string(),
         stage_type: StageType::default(),
         truths: vec![],
@@ -98,6 +122,12 @@ fn test_create_stage_from_definition_with_dependencies() {
         before_stage: vec![],
         after_stage: vec![],
         context_budget: None,
+        timeout: None,
+        budget: None,
+        model: None,
+        for_each: None,
+        when: None,
+        output_keys: vec![],
         sandbox: StageSandboxConfig::default(),
         execution_mode: None,
         bug_fix: None,
@@ -122,10 +152,17 @@ fn test_serialize_stage_to_markdown_minimal() {
         dependencies: vec![],
         parallel_group: None,
         acceptance: vec![],
+        parallel: None,
         setup: vec![],
         files: vec![],
         stage_type: ModelStageType::default(),
         context_budget: None,
+        timeout: None,
+        budget: None,
+        model: None,
+        model_escalation: 0,
+        for_each: None,
+        when: None,
         plan_id: None,
         worktree: None,
         session: None,
@@ -141,6 +178,9 @@ fn test_serialize_stage_to_markdown_minimal() {
         attempt_started_at: None,
         close_reason: None,
         auto_merge: None,
+        merge_strategy: None,
+        overlap: None,
+        overlap_hold: None,
         working_dir: Some(".".to_string()),
         retry_count: 0,
         max_retries: None,
@@ -153,6 +193,7 @@ fn test_serialize_stage_to_markdown_minimal() {
         completed_commit: None,
         merged: false,
         merge_conflict: false,
+        condition_skipped: false,
         verification_status: Default::default(),
         truths: Vec::new(),
         artifacts: Vec::new(),
@@ -169,6 +210,7 @@ fn test_serialize_stage_to_markdown_minimal() {
         review_reason: None,
         bug_fix: None,
         regression_test: None,
+        usage: Default::default(),
     };
 
     let content = serialize_stage_to_markdown(&stage).unwrap();
@@ -187,11 +229,18 @@ fn test_serialize_stage_to_markdown_with_all_fields() {
         status: StageStatus::Executing,
         dependencies: vec!["dep1".to_string(), "dep2".to_string()],
         parallel_group: Some("group1".to_string()),
-        acceptance: vec!["test1".to_string(), "test2".to_string()],
+        acceptance: vec!["test1".into(), "test2".into()],
+        parallel: None,
         setup: vec![],
         files: vec!["file1.rs".to_string(), "file2.rs".to_string()],
         stage_type: ModelStageType::default(),
         context_budget: None,
+        timeout: None,
+        budget: None,
+        model: None,
+        model_escalation: 0,
+        for_each: None,
+        when: None,
         plan_id: Some("plan-123".to_string()),
         worktree: None,
         session: None,
@@ -207,6 +256,9 @@ fn test_serialize_stage_to_markdown_with_all_fields() {
         attempt_started_at: None,
         close_reason: None,
         auto_merge: None,
+        merge_strategy: None,
+        overlap: None,
+        overlap_hold: None,
         working_dir: Some(".".to_string()),
         retry_count: 0,
         max_retries: None,
@@ -219,6 +271,7 @@ fn test_serialize_stage_to_markdown_with_all_fields() {
         completed_commit: None,
         merged: false,
         merge_conflict: false,
+        condition_skipped: false,
         verification_status: Default::default(),
         truths: Vec::new(),
         artifacts: Vec::new(),
@@ -235,6 +288,7 @@ fn test_serialize_stage_to_markdown_with_all_fields() {
         review_reason: None,
         bug_fix: None,
         regression_test: None,
+        usage: Default::default(),
     };
 
     let content = serialize_stage_to_markdown(&stage).unwrap();
@@ -278,9 +332,12 @@ fn test_initialize_with_plan_creates_config() {
         dependencies: vec![],
         parallel_group: None,
         acceptance: vec![],
+        parallel: None,
         setup: vec![],
         files: vec![],
         auto_merge: None,
+        merge_strategy: None,
+        overlap: None,
         working_dir: ".".to_string(),
         stage_type: StageType::default(),
         // Standard stages require goal-backward checks
@@ -293,6 +350,12 @@ fn test_initialize_with_plan_creates_config() {
         before_stage: vec![],
         after_stage: vec![],
         context_budget: None,
+        timeout: None,
+        budget: None,
+        model: None,
+        for_each: None,
+        when: None,
+        output_keys: vec![],
         sandbox: StageSandboxConfig::default(),
         execution_mode: None,
         bug_fix: None,
@@ -327,10 +390,13 @@ fn test_initialize_with_plan_creates_stage_files() {
             description: Some("First stage".to_string()),
             dependencies: vec![],
             parallel_group: None,
-            acceptance: vec!["cargo test".to_string()],
+            acceptance: vec!["cargo test".into()],
+            parallel: None,
             setup: vec![],
             files: vec![],
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             working_dir: ".".to_string(),
             stage_type: StageType::default(),
             // Standard stages require goal-backward checks
@@ -343,6 +409,12 @@ fn test_initialize_with_plan_creates_stage_files() {
             before_stage: vec![],
             after_stage: vec![],
             context_budget: None,
+            timeout: None,
+            budget: None,
+            model: None,
+            for_each: None,
+            when: None,
+            output_keys: vec![],
             sandbox: StageSandboxConfig::default(),
             execution_mode: None,
             bug_fix: None,
@@ -355,9 +427,12 @@ fn test_initialize_with_plan_creates_stage_files() {
             dependencies: vec!["stage-1".to_string()],
             parallel_group: None,
             acceptance: vec![],
+            parallel: None,
             setup: vec![],
             files: vec![],
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             working_dir: ".".to_string(),
             stage_type: StageType::default(),
             truths: vec!["cargo build".to_string()],
@@ -369,6 +444,12 @@ fn test_initialize_with_plan_creates_stage_files() {
             before_stage: vec![],
             after_stage: vec![],
             context_budget: None,
+            timeout: None,
+            budget: None,
+            model: None,
+            for_each: None,
+            when: None,
+            output_keys: vec![],
             sandbox: StageSandboxConfig::default(),
             execution_mode: None,
             bug_fix: None,
@@ -489,3 +570,144 @@ fn test_remove_work_directory_on_failure_nonexistent_ok() {
 
     assert!(!work_dir.exists());
 }
+
+#[test]
+#[serial]
+fn test_initialize_with_plan_expands_static_fan_out() {
+    let temp_dir = TempDir::new().unwrap();
+    let work_dir = WorkDir::new(temp_dir.path()).unwrap();
+    work_dir.initialize().unwrap();
+
+    let stage: StageDefinition = serde_yaml::from_str(
+        r#"
+id: test
+name: Test ${item}
+working_dir: "."
+acceptance: ["cargo test -p ${item}"]
+truths: ["cargo test -p ${item} --no-run"]
+for_each:
+  items: [core, cli]
+"#,
+    )
+    .unwrap();
+    let plan_path = create_test_plan(temp_dir.path(), vec![stage]);
+
+    assert_eq!(initialize_with_plan(&work_dir, &plan_path).unwrap(), 3);
+
+    let load = |id: &str| crate::verify::transitions::load_stage(id, work_dir.root()).unwrap();
+    let parent = load("test");
+    assert_eq!(parent.child_stages, vec!["test-core", "test-cli"]);
+    assert_eq!(parent.dependencies, vec!["test-core", "test-cli"]);
+    assert_eq!(parent.status, StageStatus::WaitingForDeps);
+
+    let child = load("test-cli");
+    assert_eq!(child.name, "Test cli");
+    assert_eq!(child.acceptance, vec!["cargo test -p cli"]);
+    assert_eq!(child.parent_stage.as_deref(), Some("test"));
+    assert_eq!(child.status, StageStatus::Queued);
+}
+
+fn plan_stage(yaml: &str) -> StageDefinition {
+    serde_yaml::from_str(yaml).unwrap()
+}
+
+#[test]
+#[serial]
+fn test_reconcile_plan_update() {
+    let temp_dir = TempDir::new().unwrap();
+    let work_dir = WorkDir::new(temp_dir.path()).unwrap();
+    work_dir.initialize().unwrap();
+    let build =
+        "{id: build, name: Build, working_dir: '.', truths: [true], acceptance: [cargo build]}";
+    let plan_path = create_test_plan(
+        temp_dir.path(),
+        vec![
+            plan_stage(build),
+            plan_stage(
+                "{id: test, name: Test, working_dir: '.', truths: [true], dependencies: [build]}",
+            ),
+            plan_stage(
+                "{id: docs, name: Docs, working_dir: '.', truths: [true], dependencies: [build]}",
+            ),
+        ],
+    );
+    initialize_with_plan(&work_dir, &plan_path).unwrap();
+    let mut completed = load_stage("build", work_dir.root()).unwrap();
+    completed.status = StageStatus::Completed;
+    completed.merged = true;
+    save_stage(&completed, work_dir.root()).unwrap();
+
+    let plan_path = create_test_plan(
+        temp_dir.path(),
+        vec![
+            plan_stage(&build.replace("cargo build", "cargo build --release")),
+            plan_stage(
+                "{id: test, name: Test, working_dir: '.', truths: [true], dependencies: [build], acceptance: [cargo test]}",
+            ),
+            plan_stage("{id: lint, name: Lint, working_dir: '.', truths: [true], dependencies: [test]}"),
+        ],
+    );
+    let plan = crate::plan::parse_plan(&plan_path).unwrap();
+    let existing = list_all_stages(work_dir.root()).unwrap();
+    let update = reconcile(&plan, &existing).unwrap();
+    check_merged_graph(&existing, &update).unwrap();
+
+    assert_eq!(update.added.len(), 1);
+    assert_eq!(update.added[0].id, "lint");
+    assert_eq!(update.added[0].status, StageStatus::WaitingForDeps);
+    let (test, changes) = &update.updated[0];
+    assert_eq!(test.acceptance, vec!["cargo test"]);
+    assert_eq!(test.status, StageStatus::Queued);
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].0, "acceptance");
+    assert_eq!(update.locked[0].1.len(), 1);
+    assert_eq!(update.locked[0].0.id, "build");
+    assert_eq!(update.locked[0].0.acceptance, vec!["cargo build"]);
+    assert_eq!(update.removed, vec!["docs"]);
+
+    // Updated stages must not close a cycle with the stages already on disk
+    let mut cyclic = update;
+    cyclic.updated[0].0.id = "build".to_string();
+    cyclic.updated[0].0.dependencies = vec!["test".to_string()];
+    let err = check_merged_graph(&existing, &cyclic).unwrap_err();
+    assert!(format!("{err:#}").contains("cycle"));
+}
+
+#[test]
+#[serial]
+fn test_reconcile_updates_unstarted_fan_out_parent() {
+    let temp_dir = TempDir::new().unwrap();
+    let work_dir = WorkDir::new(temp_dir.path()).unwrap();
+    work_dir.initialize().unwrap();
+    let fan_out = "{id: test, name: 'Test ${item}', working_dir: '.', truths: [true], \
+                   acceptance: ['cargo test -p ${item}'], for_each: {items: [core, cli]}}";
+    let plan_path = create_test_plan(temp_dir.path(), vec![plan_stage(fan_out)]);
+    initialize_with_plan(&work_dir, &plan_path).unwrap();
+
+    let plan_path = create_test_plan(
+        temp_dir.path(),
+        vec![plan_stage(&fan_out.replace("[core, cli]", "[core, web]"))],
+    );
+    let plan = crate::plan::parse_plan(&plan_path).unwrap();
+    let existing = list_all_stages(work_dir.root()).unwrap();
+    let update = reconcile(&plan, &existing).unwrap();
+    check_merged_graph(&existing, &update).unwrap();
+
+    assert!(update.locked.is_empty());
+    let parent = &update.updated[0].0;
+    assert_eq!(parent.id, "test");
+    assert_eq!(parent.child_stages, vec!["test-core", "test-web"]);
+    assert_eq!(parent.dependencies, vec!["test-core", "test-web"]);
+    assert_eq!(update.added.len(), 1);
+    assert_eq!(update.added[0].id, "test-web");
+    assert_eq!(update.dropped, vec!["test-cli"]);
+
+    // Once a child runs, the parent's definition is locked
+    let mut child = load_stage("test-core", work_dir.root()).unwrap();
+    child.status = StageStatus::Executing;
+    save_stage(&child, work_dir.root()).unwrap();
+    let existing = list_all_stages(work_dir.root()).unwrap();
+    let update = reconcile(&plan, &existing).unwrap();
+    assert_eq!(update.locked[0].0.id, "test");
+    assert!(update.updated.is_empty() && update.dropped.is_empty());
+}
@@ -0,0 +1,159 @@
+//! Reconcile an edited plan with existing stage files (`loom init --update`).
+//!
+//! The diff is computed by [`crate::orchestrator::plan_update`]. Nothing is
+//! written without `--apply`; while the orchestrator runs, the update is sent
+//! to the daemon so it is applied between loop steps and the running
+//! execution graph is reloaded.
+
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use serde_json::Value;
+
+use crate::daemon::DaemonServer;
+use crate::fs::work_dir::load_config_required;
+use crate::orchestrator::plan_update::{
+    apply_plan_update, load_plan_update, AppliedUpdate, FieldChange, PlanUpdate,
+};
+
+/// Execute `loom init <plan> --update`
+pub fn execute_update(plan_path: &Path, apply: bool) -> Result<()> {
+    let work_dir = Path::new(".work");
+    if !work_dir.exists() {
+        bail!(".work/ directory not found. Run 'loom init <plan>' first.");
+    }
+    let canonical_path = plan_path
+        .canonicalize()
+        .with_context(|| format!("Failed to canonicalize plan path: {}", plan_path.display()))?;
+    let config = load_config_required(work_dir)?;
+    if let Some(source) = config.source_path() {
+        if source != canonical_path {
+            bail!(
+                "Plan '{}' is not the initialized plan '{}'. Use 'loom init --clean' to switch plans.",
+                canonical_path.display(),
+                source.display()
+            );
+        }
+    }
+
+    let update = load_plan_update(work_dir)?;
+    print_update(&update, apply);
+    if !apply || !update.has_writes() {
+        return Ok(());
+    }
+
+    let applied = if DaemonServer::is_running(work_dir) {
+        DaemonServer::send_plan_update(work_dir)
+            .context("Failed to apply the plan update through the orchestrator")?
+    } else {
+        apply_plan_update(&update, work_dir)?;
+        update.applied()
+    };
+    print_applied(&applied);
+    Ok(())
+}
+
+/// Print the stage counts of an applied update
+fn print_applied(applied: &AppliedUpdate) {
+    let dropped = if applied.dropped.is_empty() {
+        String::new()
+    } else {
+        format!(", {} removed", applied.dropped.len())
+    };
+    println!(
+        "\n{} Plan update applied: {} added, {} updated{dropped}",
+        "✓".green().bold(),
+        applied.added.len(),
+        applied.updated.len()
+    );
+}
+
+/// Print the update as a diff against the stage files
+fn print_update(update: &PlanUpdate, apply: bool) {
+    let mode = if apply { "" } else { " (dry run)" };
+    println!("\n{}{}", "Plan Update".bold(), mode.dimmed());
+    println!("{}", "─".repeat(40).dimmed());
+
+    if update.added.is_empty()
+        && update.updated.is_empty()
+        && update.locked.is_empty()
+        && update.removed.is_empty()
+    {
+        println!("  {} Stage files match the plan", "✓".green().bold());
+        return;
+    }
+
+    for stage in &update.added {
+        println!(
+            "  {} {}  {}",
+            "+".green().bold(),
+            stage.id,
+            stage.name.dimmed()
+        );
+    }
+    for (stage, changes) in &update.updated {
+        println!("  {} {}", "~".yellow().bold(), stage.id);
+        print_changes(changes);
+    }
+    for (stage, changes) in &update.locked {
+        println!(
+            "  {} {}  {}",
+            "!".red().bold(),
+            stage.id,
+            format!("({}, not updated)", stage.status).red()
+        );
+        print_changes(changes);
+    }
+    for id in &update.dropped {
+        println!(
+            "  {} {}  {}",
+            "-".red().bold(),
+            id,
+            "(no longer expanded, removed)".dimmed()
+        );
+    }
+    for id in &update.removed {
+        println!(
+            "  {} {}  {}",
+            "-".red().bold(),
+            id,
+            "(no longer in plan, left as is)".dimmed()
+        );
+    }
+
+    if !apply && update.has_writes() {
+        println!("\n  Use {} to write these changes", "--apply".cyan());
+    }
+}
+
+/// Print each changed field, item by item for lists
+fn print_changes(changes: &[FieldChange]) {
+    for (field, old, new) in changes {
+        println!("      {}:", field.bold());
+        match (old, new) {
+            (Value::Array(old), Value::Array(new)) => {
+                if old.iter().all(|i| new.contains(i)) && new.iter().all(|i| old.contains(i)) {
+                    println!("        {}", "(reordered)".dimmed());
+                }
+                for item in old.iter().filter(|i| !new.contains(i)) {
+                    println!("        {}", format!("- {}", display_value(item)).red());
+                }
+                for item in new.iter().filter(|i| !old.contains(i)) {
+                    println!("        {}", format!("+ {}", display_value(item)).green());
+                }
+            }
+            _ => {
+                println!("        {}", format!("- {}", display_value(old)).red());
+                println!("        {}", format!("+ {}", display_value(new)).green());
+            }
+        }
+    }
+}
+
+fn display_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
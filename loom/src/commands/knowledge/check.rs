@@ -1,7 +1,9 @@
 //! Knowledge check command - validate knowledge completeness and coverage.
 
+use super::entry::print_stale_entries;
 use crate::fs::knowledge::{
-    KnowledgeDir, KnowledgeFile, DEFAULT_MAX_FILE_LINES, DEFAULT_MAX_TOTAL_LINES,
+    find_stale_entries, KnowledgeDir, KnowledgeFile, DEFAULT_MAX_FILE_LINES,
+    DEFAULT_MAX_TOTAL_LINES,
 };
 use crate::fs::work_dir::WorkDir;
 use anyhow::{bail, Context, Result};
@@ -30,7 +32,12 @@ pub struct KnowledgeCheckResult {
     pub overall_pass: bool,
 }
 
-pub fn check(min_coverage: u8, src_path: Option<String>, quiet: bool) -> Result<()> {
+pub fn check(
+    min_coverage: u8,
+    src_path: Option<String>,
+    max_churn: usize,
+    quiet: bool,
+) -> Result<()> {
     let work_dir = WorkDir::new(".")?;
     let main_project_root = work_dir
         .main_project_root()
@@ -108,6 +115,13 @@ pub fn check(min_coverage: u8, src_path: Option<String>, quiet: bool) -> Result<
             }
         }
 
+        let stale = find_stale_entries(&knowledge, &main_project_root, max_churn)?;
+        if !stale.is_empty() {
+            println!();
+            println!("{}", "Stale Entries:".cyan().bold());
+            print_stale_entries(&stale);
+        }
+
         println!("\n{} Knowledge check passed", "✓".green().bold());
     }
 
@@ -121,8 +135,8 @@ fn analyze_knowledge_completeness(
 ) -> Result<KnowledgeCheckResult> {
     let mut file_results = Vec::new();
 
-    for file_type in KnowledgeFile::all() {
-        let path = knowledge.file_path(*file_type);
+    for file_type in knowledge.file_types()? {
+        let path = knowledge.file_path(&file_type);
         let exists = path.exists();
         let (has_content, section_count) = if exists {
             let content = std::fs::read_to_string(&path).unwrap_or_default();
@@ -132,7 +146,7 @@ fn analyze_knowledge_completeness(
         };
 
         file_results.push(FileCheckResult {
-            file_type: *file_type,
+            file_type,
             exists,
             has_content,
             section_count,
@@ -182,7 +196,7 @@ fn analyze_src_coverage(
         return Ok(None);
     }
 
-    let arch_path = knowledge.file_path(KnowledgeFile::Architecture);
+    let arch_path = knowledge.file_path(&KnowledgeFile::Architecture);
     let arch_content = if arch_path.exists() {
         std::fs::read_to_string(&arch_path).unwrap_or_default()
     } else {
@@ -420,7 +434,7 @@ More description
         let original_dir = std::env::current_dir().expect("Failed to get current dir");
         std::env::set_current_dir(&test_dir).expect("Failed to change dir");
 
-        let result = check(50, None, true);
+        let result = check(50, None, 50, true);
         assert!(result.is_err());
         let err_msg = result.unwrap_err().to_string();
         assert!(err_msg.contains("does not exist"));
@@ -439,7 +453,7 @@ More description
 
         crate::commands::knowledge::init().expect("Failed to init knowledge");
 
-        let result = check(50, None, true);
+        let result = check(50, None, 50, true);
         assert!(result.is_err());
         let err_msg = result.unwrap_err().to_string();
         assert!(err_msg.contains("architecture.md is empty"));
@@ -460,10 +474,11 @@ More description
         crate::commands::knowledge::update(
             "architecture".to_string(),
             Some("## Overview\n\nProject architecture here".to_string()),
+            crate::commands::knowledge::EntryOptions::default(),
         )
         .expect("Failed to update architecture");
 
-        let result = check(50, None, true);
+        let result = check(50, None, 50, true);
         assert!(result.is_ok());
 
         std::env::set_current_dir(original_dir).expect("Failed to restore dir");
@@ -488,13 +503,14 @@ More description
         crate::commands::knowledge::update(
             "architecture".to_string(),
             Some("## Overview\n\n- commands/ - CLI\n- models/ - Data".to_string()),
+            crate::commands::knowledge::EntryOptions::default(),
         )
         .expect("Failed to update architecture");
 
-        let result = check(50, None, true);
+        let result = check(50, None, 50, true);
         assert!(result.is_ok());
 
-        let result = check(75, None, true);
+        let result = check(75, None, 50, true);
         assert!(result.is_err());
 
         std::env::set_current_dir(original_dir).expect("Failed to restore dir");
@@ -530,10 +546,11 @@ More description
         crate::commands::knowledge::update(
             "architecture".to_string(),
             Some("## Overview\n\nProject architecture here".to_string()),
+            crate::commands::knowledge::EntryOptions::default(),
         )
         .expect("Failed to update architecture");
 
-        let result = check(50, None, false);
+        let result = check(50, None, 50, false);
         assert!(result.is_ok());
 
         std::env::set_current_dir(original_dir).expect("Failed to restore dir");
@@ -0,0 +1,134 @@
+//! Knowledge GC compaction - rewrite knowledge files deterministically.
+
+use anyhow::{Context, Result};
+use colored::Colorize;
+use std::fs;
+
+use crate::fs::knowledge::{apply_compaction, plan_compaction, CompactedFile, KnowledgeDir};
+use crate::fs::work_dir::WorkDir;
+use crate::git::run_git;
+
+/// Compact the knowledge files, showing a unified diff of each change.
+/// Originals are backed up under `.work/archive/knowledge/` before writing.
+pub fn compact(max_file_lines: usize, dry_run: bool) -> Result<()> {
+    let work_dir = WorkDir::new(".")?;
+    let main_project_root = work_dir
+        .main_project_root()
+        .context("Could not determine main project root")?;
+    let knowledge = KnowledgeDir::new(&main_project_root);
+
+    if !knowledge.exists() {
+        println!(
+            "{} Knowledge directory not found. Run 'loom knowledge init' to create it.",
+            "─".dimmed()
+        );
+        return Ok(());
+    }
+
+    let files = plan_compaction(&knowledge, max_file_lines)?;
+    if files.is_empty() {
+        println!("{}", "Knowledge files are already compact.".green());
+        return Ok(());
+    }
+
+    for file in &files {
+        print_summary(file);
+        print_diff(&unified_diff(file)?);
+        println!();
+    }
+
+    if dry_run {
+        println!("{} Dry run: no files written", "─".dimmed());
+        return Ok(());
+    }
+
+    let archive_dir = WorkDir::new(&main_project_root)?.archive_dir();
+    let backup = apply_compaction(&knowledge, &archive_dir, &files)?;
+    println!(
+        "{} Compacted {} file(s); originals backed up to {}",
+        "✓".green().bold(),
+        files.len(),
+        backup
+            .strip_prefix(&main_project_root)
+            .unwrap_or(&backup)
+            .display()
+    );
+    Ok(())
+}
+
+fn print_summary(file: &CompactedFile) {
+    let stats = &file.stats;
+    println!(
+        "{} {} ({} merged sections, {} folded promoted blocks, {} duplicate bullets, {} trimmed bullets)",
+        "─".dimmed(),
+        file.file_type.filename().cyan().bold(),
+        stats.merged_sections,
+        stats.folded_promoted_blocks,
+        stats.deduped_bullets,
+        stats.trimmed_bullets
+    );
+}
+
+/// Unified diff of a file's compaction, via `git diff --no-index`
+fn unified_diff(file: &CompactedFile) -> Result<String> {
+    let temp = tempfile::TempDir::new().context("Failed to create temp directory")?;
+    let name = file.file_type.filename();
+    for (side, content) in [("a", &file.before), ("b", &file.after)] {
+        fs::create_dir_all(temp.path().join(side))?;
+        fs::write(temp.path().join(side).join(name), content)?;
+    }
+    let output = run_git(
+        &[
+            "diff",
+            "--no-index",
+            "--no-prefix",
+            "--no-color",
+            &format!("a/{name}"),
+            &format!("b/{name}"),
+        ],
+        temp.path(),
+    )?;
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .skip_while(|line| !line.starts_with("---"))
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
+
+fn print_diff(diff: &str) {
+    for line in diff.lines() {
+        let line = if line.starts_with("---") || line.starts_with("+++") {
+            line.bold()
+        } else if line.starts_with("@@") {
+            line.cyan()
+        } else if line.starts_with('+') {
+            line.green()
+        } else if line.starts_with('-') {
+            line.red()
+        } else {
+            line.normal()
+        };
+        println!("{line}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fs::knowledge::{CompactStats, KnowledgeFile};
+
+    #[test]
+    fn test_unified_diff() {
+        let file = CompactedFile {
+            file_type: KnowledgeFile::Patterns,
+            before: "## A\n\n- one\n- one\n".to_string(),
+            after: "## A\n\n- one\n".to_string(),
+            stats: CompactStats::default(),
+        };
+
+        let diff = unified_diff(&file).unwrap();
+
+        assert!(diff.starts_with("--- a/patterns.md\n+++ b/patterns.md\n@@"));
+        assert!(diff.ends_with("\n-- one"));
+    }
+}
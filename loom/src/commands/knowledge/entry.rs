@@ -0,0 +1,139 @@
+//! Structured knowledge entries: provenance recorded by `knowledge update`
+//! and reporting of stale entries for `knowledge check` and `knowledge gc`.
+
+use anyhow::{bail, Result};
+use chrono::Utc;
+use colored::Colorize;
+use std::path::Path;
+
+use crate::fs::get_merge_point;
+use crate::fs::knowledge::{EntryMeta, StaleEntry};
+use crate::git::run_git_checked;
+
+/// Metadata options of `loom knowledge update`
+#[derive(Debug, Clone, Default)]
+pub struct EntryOptions {
+    pub tags: Vec<String>,
+    /// Files the entry is about, relative to the project root
+    pub paths: Vec<String>,
+    /// Record provenance even without tags or paths
+    pub structured: bool,
+}
+
+impl EntryOptions {
+    /// Metadata for the entry, or `None` for a plain entry. Stage comes from
+    /// `LOOM_STAGE_ID`, the commit from `base_commit`.
+    pub fn to_meta(&self, checkout: &Path) -> Result<Option<EntryMeta>> {
+        if !self.structured && self.tags.is_empty() && self.paths.is_empty() {
+            return Ok(None);
+        }
+        let missing: Vec<&str> = self
+            .paths
+            .iter()
+            .filter(|path| !checkout.join(path).exists())
+            .map(String::as_str)
+            .collect();
+        if !missing.is_empty() {
+            bail!("Referenced files not found: {}", missing.join(", "));
+        }
+
+        Ok(Some(EntryMeta {
+            stage: std::env::var("LOOM_STAGE_ID").ok(),
+            commit: base_commit(checkout),
+            files: self.paths.clone(),
+            tags: self.tags.clone(),
+            date: Some(Utc::now().date_naive()),
+        }))
+    }
+}
+
+/// Where HEAD of `checkout` forks from the merge point. A stage branch's own
+/// commits may never reach the merge point (squash and rebase merges), and
+/// are pruned once the branch is deleted; the fork point stays reachable.
+/// Falls back to HEAD when there is no merge point branch.
+fn base_commit(checkout: &Path) -> Option<String> {
+    let merge_point = get_merge_point(&checkout.join(".work")).unwrap_or_else(|_| "main".into());
+    let commit = run_git_checked(&["merge-base", "HEAD", &merge_point], checkout)
+        .unwrap_or_else(|_| "HEAD".to_string());
+    run_git_checked(&["rev-parse", "--short=12", &commit], checkout).ok()
+}
+
+/// Print stale structured entries, one line per reason
+pub fn print_stale_entries(stale: &[StaleEntry]) {
+    for entry in stale {
+        let stage = entry
+            .stage
+            .as_ref()
+            .map(|s| format!(" (from {s})"))
+            .unwrap_or_default();
+        println!(
+            "  {} {}: \"## {}\"{}",
+            "⚠".yellow(),
+            entry.file_type.filename().cyan(),
+            entry.heading,
+            stage.dimmed()
+        );
+        for reason in &entry.reasons {
+            println!("      {reason}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_to_meta() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(temp.path().join("lib.rs"), "").unwrap();
+
+        assert!(EntryOptions::default()
+            .to_meta(temp.path())
+            .unwrap()
+            .is_none());
+
+        let options = EntryOptions {
+            tags: vec!["core".to_string()],
+            paths: vec!["lib.rs".to_string()],
+            structured: false,
+        };
+        let meta = options.to_meta(temp.path()).unwrap().unwrap();
+        assert_eq!(meta.files, ["lib.rs"]);
+        assert_eq!(meta.tags, ["core"]);
+        assert!(meta.commit.is_none());
+        assert!(meta.date.is_some());
+
+        let missing = EntryOptions {
+            paths: vec!["gone.rs".to_string()],
+            ..Default::default()
+        };
+        assert!(missing.to_meta(temp.path()).is_err());
+    }
+
+    #[test]
+    fn test_to_meta_records_fork_point_of_stage_branch() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path();
+        let git = |args: &[&str]| run_git_checked(args, dir).unwrap();
+        git(&["init", "-q", "-b", "main"]);
+        git(&["config", "user.email", "test@example.com"]);
+        git(&["config", "user.name", "Test"]);
+        git(&["commit", "-q", "--allow-empty", "-m", "Initial"]);
+        let fork_point = git(&["rev-parse", "--short=12", "HEAD"]);
+        git(&["checkout", "-q", "-b", "loom/s1"]);
+        git(&["commit", "-q", "--allow-empty", "-m", "Stage work"]);
+
+        let options = EntryOptions {
+            structured: true,
+            ..Default::default()
+        };
+        let meta = options.to_meta(dir).unwrap().unwrap();
+        assert_eq!(meta.commit, Some(fork_point.clone()));
+
+        git(&["checkout", "-q", "main"]);
+        let meta = options.to_meta(dir).unwrap().unwrap();
+        assert_eq!(meta.commit, Some(fork_point));
+    }
+}
@@ -1,17 +1,23 @@
 //! Knowledge GC command - analyze knowledge files and recommend compaction.
 
-use crate::fs::knowledge::KnowledgeDir;
+use super::entry::print_stale_entries;
+use crate::fs::knowledge::{find_stale_entries, KnowledgeDir};
 use crate::fs::work_dir::WorkDir;
 use anyhow::{Context, Result};
 use colored::Colorize;
 
-pub fn gc(max_file_lines: usize, max_total_lines: usize, quiet: bool) -> Result<()> {
+pub fn gc(
+    max_file_lines: usize,
+    max_total_lines: usize,
+    max_churn: usize,
+    quiet: bool,
+) -> Result<()> {
     let work_dir = WorkDir::new(".")?;
 
     let main_project_root = work_dir
         .main_project_root()
         .context("Could not determine main project root")?;
-    let knowledge = KnowledgeDir::new(main_project_root);
+    let knowledge = KnowledgeDir::new(&main_project_root);
 
     if !knowledge.exists() {
         println!(
@@ -22,6 +28,7 @@ pub fn gc(max_file_lines: usize, max_total_lines: usize, quiet: bool) -> Result<
     }
 
     let metrics = knowledge.analyze_gc_metrics(max_file_lines, max_total_lines)?;
+    let stale = find_stale_entries(&knowledge, &main_project_root, max_churn)?;
 
     println!("{}", "Knowledge GC Analysis".bold());
     println!();
@@ -48,26 +55,33 @@ pub fn gc(max_file_lines: usize, max_total_lines: usize, quiet: bool) -> Result<
     println!("Total: {} lines", metrics.total_lines);
     println!();
 
-    if metrics.gc_recommended {
-        println!("GC recommended: {}", "YES".yellow().bold());
-        for reason in &metrics.reasons {
-            println!("  - {}", reason);
-        }
-
-        if !quiet {
-            println!();
-            println!("{}", "Compaction Instructions:".cyan().bold());
-            println!("  1. Review each knowledge file for outdated or redundant content");
-            println!("  2. Merge duplicate headers into single consolidated sections");
-            println!("  3. Summarize curated memory blocks into concise knowledge");
-            println!("  4. Remove any content that is no longer accurate");
-            println!("  5. Edit files directly in doc/loom/knowledge/");
-        }
-    } else {
+    if !metrics.gc_recommended && stale.is_empty() {
         println!(
             "{}",
             "Knowledge files are clean. No compaction needed.".green()
         );
+        return Ok(());
+    }
+
+    println!("GC recommended: {}", "YES".yellow().bold());
+    for reason in &metrics.reasons {
+        println!("  - {}", reason);
+    }
+
+    if !stale.is_empty() {
+        println!();
+        println!("{}", "Proposed Removals:".cyan().bold());
+        print_stale_entries(&stale);
+    }
+
+    if metrics.gc_recommended && !quiet {
+        println!();
+        println!("{}", "Compaction Instructions:".cyan().bold());
+        println!("  1. Review each knowledge file for outdated or redundant content");
+        println!("  2. Merge duplicate headers into single consolidated sections");
+        println!("  3. Summarize curated memory blocks into concise knowledge");
+        println!("  4. Remove any content that is no longer accurate");
+        println!("  5. Edit files directly in doc/loom/knowledge/");
     }
 
     Ok(())
@@ -96,10 +110,11 @@ mod tests {
         crate::commands::knowledge::update(
             "architecture".to_string(),
             Some("## Overview\n\nSmall content".to_string()),
+            crate::commands::knowledge::EntryOptions::default(),
         )
         .expect("Failed to update");
 
-        let result = gc(200, 800, true);
+        let result = gc(200, 800, 50, true);
         assert!(result.is_ok());
 
         std::env::set_current_dir(original_dir).expect("Failed to restore dir");
@@ -118,10 +133,14 @@ mod tests {
         for i in 0..250 {
             big_content.push_str(&format!("- Line {}\n", i));
         }
-        crate::commands::knowledge::update("architecture".to_string(), Some(big_content))
-            .expect("Failed to update");
+        crate::commands::knowledge::update(
+            "architecture".to_string(),
+            Some(big_content),
+            crate::commands::knowledge::EntryOptions::default(),
+        )
+        .expect("Failed to update");
 
-        let result = gc(200, 800, true);
+        let result = gc(200, 800, 50, true);
         assert!(result.is_ok());
 
         std::env::set_current_dir(original_dir).expect("Failed to restore dir");
@@ -1,11 +1,16 @@
 //! Knowledge command - manage curated codebase knowledge.
 pub mod check;
+pub mod compact;
+pub mod entry;
 pub mod gc;
 
-use crate::fs::knowledge::{KnowledgeDir, KnowledgeFile};
+use crate::fs::knowledge::{with_meta, KnowledgeDir};
 use crate::fs::work_dir::WorkDir;
 use anyhow::{bail, Context, Result};
+use std::path::Path;
+
 use colored::Colorize;
+pub use entry::EntryOptions;
 
 pub fn show(file: Option<String>) -> Result<()> {
     let work_dir = WorkDir::new(".")?;
@@ -24,8 +29,8 @@ pub fn show(file: Option<String>) -> Result<()> {
 
     match file {
         Some(file_name) => {
-            let file_type = parse_file_type(&file_name)?;
-            let content = knowledge.read(file_type)?;
+            let file_type = knowledge.parse_file(&file_name)?;
+            let content = knowledge.read(&file_type)?;
             println!("{content}");
         }
         None => {
@@ -57,7 +62,7 @@ fn read_content_from_stdin() -> Result<String> {
     Ok(trimmed)
 }
 
-pub fn update(file: String, content: Option<String>) -> Result<()> {
+pub fn update(file: String, content: Option<String>, entry: EntryOptions) -> Result<()> {
     let content = match content {
         Some(c) if c == "-" => read_content_from_stdin()?,
         Some(c) => c,
@@ -65,6 +70,10 @@ pub fn update(file: String, content: Option<String>) -> Result<()> {
     };
 
     crate::validation::validate_knowledge_content(&content)?;
+    let content = match entry.to_meta(Path::new("."))? {
+        Some(meta) => with_meta(&content, &meta)?,
+        None => content,
+    };
 
     let work_dir = WorkDir::new(".")?;
     let main_project_root = work_dir
@@ -78,8 +87,8 @@ pub fn update(file: String, content: Option<String>) -> Result<()> {
             .context("Failed to initialize knowledge directory")?;
     }
 
-    let file_type = parse_file_type(&file)?;
-    knowledge.append(file_type, &content)?;
+    let file_type = knowledge.parse_file(&file)?;
+    knowledge.append(&file_type, &content)?;
 
     println!(
         "{} Appended to {}",
@@ -111,7 +120,7 @@ pub fn init() -> Result<()> {
     println!("{} Initialized knowledge directory", "✓".green().bold());
     println!();
     println!("Created files:");
-    for file_type in KnowledgeFile::all() {
+    for file_type in knowledge.file_types()? {
         println!("  {} - {}", file_type.filename(), file_type.description());
     }
 
@@ -159,38 +168,10 @@ pub fn list() -> Result<()> {
     Ok(())
 }
 
-fn parse_file_type(file: &str) -> Result<KnowledgeFile> {
-    if let Some(file_type) = KnowledgeFile::from_filename(file) {
-        return Ok(file_type);
-    }
-
-    let with_ext = format!("{file}.md");
-    if let Some(file_type) = KnowledgeFile::from_filename(&with_ext) {
-        return Ok(file_type);
-    }
-
-    match file.to_lowercase().as_str() {
-        "arch" | "architecture" | "map" | "overview" => Ok(KnowledgeFile::Architecture),
-        "entry" | "entries" | "entry-point" | "entrypoints" => Ok(KnowledgeFile::EntryPoints),
-        "pattern" => Ok(KnowledgeFile::Patterns),
-        "convention" | "conventions" | "code" | "coding" => Ok(KnowledgeFile::Conventions),
-        "mistake" | "mistakes" | "lessons" | "lesson" => Ok(KnowledgeFile::Mistakes),
-        "stack" | "deps" | "dependencies" | "tech" | "tooling" => Ok(KnowledgeFile::Stack),
-        "concerns" | "concern" | "debt" | "issues" | "warnings" => Ok(KnowledgeFile::Concerns),
-        _ => {
-            let valid_files: Vec<_> = KnowledgeFile::all().iter().map(|f| f.filename()).collect();
-            bail!(
-                "Unknown knowledge file: '{}'. Valid files: {}",
-                file,
-                valid_files.join(", ")
-            )
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fs::knowledge::KnowledgeFile;
     use serial_test::serial;
     use std::fs;
     use tempfile::TempDir;
@@ -204,55 +185,82 @@ mod tests {
     #[test]
     fn test_parse_file_type() {
         assert_eq!(
-            parse_file_type("entry-points.md").unwrap(),
+            "entry-points.md".parse::<KnowledgeFile>().unwrap(),
             KnowledgeFile::EntryPoints
         );
         assert_eq!(
-            parse_file_type("entry-points").unwrap(),
+            "entry-points".parse::<KnowledgeFile>().unwrap(),
             KnowledgeFile::EntryPoints
         );
         assert_eq!(
-            parse_file_type("patterns").unwrap(),
+            "patterns".parse::<KnowledgeFile>().unwrap(),
             KnowledgeFile::Patterns
         );
         assert_eq!(
-            parse_file_type("conventions").unwrap(),
+            "conventions".parse::<KnowledgeFile>().unwrap(),
             KnowledgeFile::Conventions
         );
         assert_eq!(
-            parse_file_type("entry").unwrap(),
+            "entry".parse::<KnowledgeFile>().unwrap(),
             KnowledgeFile::EntryPoints
         );
         assert_eq!(
-            parse_file_type("mistakes").unwrap(),
+            "mistakes".parse::<KnowledgeFile>().unwrap(),
+            KnowledgeFile::Mistakes
+        );
+        assert_eq!(
+            "mistakes.md".parse::<KnowledgeFile>().unwrap(),
+            KnowledgeFile::Mistakes
+        );
+        assert_eq!(
+            "mistake".parse::<KnowledgeFile>().unwrap(),
+            KnowledgeFile::Mistakes
+        );
+        assert_eq!(
+            "lessons".parse::<KnowledgeFile>().unwrap(),
             KnowledgeFile::Mistakes
         );
         assert_eq!(
-            parse_file_type("mistakes.md").unwrap(),
+            "lesson".parse::<KnowledgeFile>().unwrap(),
             KnowledgeFile::Mistakes
         );
-        assert_eq!(parse_file_type("mistake").unwrap(), KnowledgeFile::Mistakes);
-        assert_eq!(parse_file_type("lessons").unwrap(), KnowledgeFile::Mistakes);
-        assert_eq!(parse_file_type("lesson").unwrap(), KnowledgeFile::Mistakes);
-        assert_eq!(parse_file_type("stack").unwrap(), KnowledgeFile::Stack);
-        assert_eq!(parse_file_type("stack.md").unwrap(), KnowledgeFile::Stack);
-        assert_eq!(parse_file_type("deps").unwrap(), KnowledgeFile::Stack);
         assert_eq!(
-            parse_file_type("dependencies").unwrap(),
+            "stack".parse::<KnowledgeFile>().unwrap(),
             KnowledgeFile::Stack
         );
-        assert_eq!(parse_file_type("tech").unwrap(), KnowledgeFile::Stack);
         assert_eq!(
-            parse_file_type("concerns").unwrap(),
+            "stack.md".parse::<KnowledgeFile>().unwrap(),
+            KnowledgeFile::Stack
+        );
+        assert_eq!(
+            "deps".parse::<KnowledgeFile>().unwrap(),
+            KnowledgeFile::Stack
+        );
+        assert_eq!(
+            "dependencies".parse::<KnowledgeFile>().unwrap(),
+            KnowledgeFile::Stack
+        );
+        assert_eq!(
+            "tech".parse::<KnowledgeFile>().unwrap(),
+            KnowledgeFile::Stack
+        );
+        assert_eq!(
+            "concerns".parse::<KnowledgeFile>().unwrap(),
+            KnowledgeFile::Concerns
+        );
+        assert_eq!(
+            "concerns.md".parse::<KnowledgeFile>().unwrap(),
+            KnowledgeFile::Concerns
+        );
+        assert_eq!(
+            "debt".parse::<KnowledgeFile>().unwrap(),
             KnowledgeFile::Concerns
         );
         assert_eq!(
-            parse_file_type("concerns.md").unwrap(),
+            "issues".parse::<KnowledgeFile>().unwrap(),
             KnowledgeFile::Concerns
         );
-        assert_eq!(parse_file_type("debt").unwrap(), KnowledgeFile::Concerns);
-        assert_eq!(parse_file_type("issues").unwrap(), KnowledgeFile::Concerns);
-        assert!(parse_file_type("unknown").is_err());
+        assert!("unknown".parse::<KnowledgeFile>().is_err());
     }
 
     #[test]
@@ -288,6 +296,7 @@ mod tests {
         let result = update(
             "entry-points".to_string(),
             Some("## New Section\n\n- New entry".to_string()),
+            EntryOptions::default(),
         );
         assert!(result.is_ok());
 
@@ -358,6 +367,7 @@ mod tests {
         let result = update(
             "entry-points".to_string(),
             Some("## Test Entry\n\n- test/file.rs - Test description".to_string()),
+            EntryOptions::default(),
         );
         assert!(result.is_ok(), "update() failed: {result:?}");
 
@@ -387,6 +397,7 @@ mod tests {
         let result = update(
             "patterns".to_string(),
             Some("## Test Pattern\n\nExplicit content".to_string()),
+            EntryOptions::default(),
         );
         assert!(result.is_ok());
 
@@ -48,25 +48,25 @@ fn write_analysis_results(
     // Write architecture findings
     if !result.architecture.is_empty() {
         println!("  {} architecture.md", "→".cyan());
-        knowledge.append(KnowledgeFile::Architecture, &result.architecture)?;
+        knowledge.append(&KnowledgeFile::Architecture, &result.architecture)?;
     }
 
     // Write stack findings
     if !result.stack.is_empty() {
         println!("  {} stack.md", "→".cyan());
-        knowledge.append(KnowledgeFile::Stack, &result.stack)?;
+        knowledge.append(&KnowledgeFile::Stack, &result.stack)?;
     }
 
     // Write conventions
     if !result.conventions.is_empty() {
         println!("  {} conventions.md", "→".cyan());
-        knowledge.append(KnowledgeFile::Conventions, &result.conventions)?;
+        knowledge.append(&KnowledgeFile::Conventions, &result.conventions)?;
     }
 
     // Write concerns
     if !result.concerns.is_empty() {
         println!("  {} concerns.md", "→".cyan());
-        knowledge.append(KnowledgeFile::Concerns, &result.concerns)?;
+        knowledge.append(&KnowledgeFile::Concerns, &result.concerns)?;
     }
 
     Ok(())
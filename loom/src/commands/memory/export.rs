@@ -0,0 +1,93 @@
+//! Structured memory listing for `loom memory list --format json|yaml`
+
+use anyhow::Result;
+use serde::Serialize;
+use std::path::Path;
+
+use crate::commands::common::OutputFormat;
+use crate::fs::memory::{list_journals, read_journal, MemoryEntry, MemoryEntryType};
+
+/// Memory journals as reported by `loom memory list --format json|yaml`
+#[derive(Debug, Clone, Serialize)]
+pub struct MemoryList {
+    pub journals: Vec<JournalListing>,
+}
+
+/// A stage's journal entries, oldest first
+#[derive(Debug, Clone, Serialize)]
+pub struct JournalListing {
+    pub stage_id: String,
+    pub entries: Vec<MemoryEntry>,
+}
+
+/// Collect one stage's journal, or every journal when `stage` is `None`
+///
+/// Unlike the text listing, all matching entries are included.
+pub(super) fn collect_memory_list(
+    work_dir: &Path,
+    stage: Option<&str>,
+    type_filter: Option<MemoryEntryType>,
+) -> Result<MemoryList> {
+    let stage_ids = match stage {
+        Some(id) => vec![id.to_string()],
+        None => list_journals(work_dir)?,
+    };
+
+    let mut journals = Vec::with_capacity(stage_ids.len());
+    for stage_id in stage_ids {
+        let journal = read_journal(work_dir, &stage_id)?;
+        let entries = journal
+            .entries
+            .into_iter()
+            .filter(|e| type_filter.is_none_or(|t| e.entry_type == t))
+            .collect();
+        journals.push(JournalListing { stage_id, entries });
+    }
+
+    Ok(MemoryList { journals })
+}
+
+/// Print memory journals as structured output
+pub(super) fn print_memory_list(
+    work_dir: &Path,
+    stage: Option<&str>,
+    type_filter: Option<MemoryEntryType>,
+    format: OutputFormat,
+) -> Result<()> {
+    let list = collect_memory_list(work_dir, stage, type_filter)?;
+    format.print("memory", &list)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fs::memory::append_entry;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_collect_memory_list_filters_entries() {
+        let temp_dir = TempDir::new().unwrap();
+        let work_dir = temp_dir.path();
+        append_entry(
+            work_dir,
+            "stage-a",
+            &MemoryEntry::new(MemoryEntryType::Note, "first".to_string()),
+        )
+        .unwrap();
+        append_entry(
+            work_dir,
+            "stage-a",
+            &MemoryEntry::new(MemoryEntryType::Decision, "second".to_string()),
+        )
+        .unwrap();
+
+        let list = collect_memory_list(work_dir, None, Some(MemoryEntryType::Decision)).unwrap();
+        assert_eq!(list.journals.len(), 1);
+        assert_eq!(list.journals[0].stage_id, "stage-a");
+        assert_eq!(list.journals[0].entries.len(), 1);
+        assert_eq!(list.journals[0].entries[0].content, "second");
+
+        let list = collect_memory_list(work_dir, Some("stage-a"), None).unwrap();
+        assert_eq!(list.journals[0].entries.len(), 2);
+    }
+}
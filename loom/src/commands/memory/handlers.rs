@@ -4,12 +4,14 @@ use anyhow::{bail, Context, Result};
 use colored::Colorize;
 use std::env;
 
+use crate::commands::common::OutputFormat;
 use crate::fs::memory::{
     append_entry, list_journals, query_entries, read_journal, validate_content, MemoryEntry,
     MemoryEntryType,
 };
 use crate::git::worktree::{find_repo_root_from_cwd, find_worktree_root_from_cwd};
 
+use super::export::print_memory_list;
 use super::formatters::{
     format_entry_compact, format_entry_full, format_record_success, format_stage_summary,
 };
@@ -19,7 +21,7 @@ use super::formatters::{
 /// When called from within a worktree (or its subdirectory), finds the worktree root
 /// which has a `.work` symlink pointing to the main repo's `.work`.
 /// When called from the main repo, walks up to find the repo root's `.work`.
-fn get_work_dir() -> Result<std::path::PathBuf> {
+pub(super) fn get_work_dir() -> Result<std::path::PathBuf> {
     let cwd = env::current_dir().context("Failed to get current directory")?;
 
     // First check if we're in a worktree
@@ -48,7 +50,7 @@ fn get_work_dir() -> Result<std::path::PathBuf> {
 }
 
 /// Validate stage ID to prevent path traversal attacks
-fn validate_stage_id(id: &str) -> Result<()> {
+pub(super) fn validate_stage_id(id: &str) -> Result<()> {
     if id.contains('/') || id.contains("..") || id.contains('\\') {
         bail!("Invalid stage ID: contains path separators");
     }
@@ -183,56 +185,29 @@ pub fn query(search: String, stage_id: Option<String>) -> Result<()> {
 }
 
 /// List memory entries from a stage
-pub fn list(stage_id: Option<String>, entry_type: Option<String>) -> Result<()> {
+pub fn list(
+    stage_id: Option<String>,
+    entry_type: Option<String>,
+    format: OutputFormat,
+) -> Result<()> {
     if let Some(ref id) = stage_id {
         validate_stage_id(id)?;
     }
 
     let work_dir = get_work_dir()?;
 
+    if !format.is_text() {
+        let stage = stage_id.or_else(|| std::env::var("LOOM_STAGE_ID").ok());
+        let type_filter: Option<MemoryEntryType> = entry_type.map(|t| t.parse()).transpose()?;
+        return print_memory_list(&work_dir, stage.as_deref(), type_filter, format);
+    }
+
     let stage = match stage_id {
         Some(id) => id,
         None => match std::env::var("LOOM_STAGE_ID").ok() {
             Some(id) => id,
-            None => {
-                // No stage specified - show summary of all stages
-                let journals = list_journals(&work_dir)?;
-                if journals.is_empty() {
-                    println!("{} No memory journals found", "ℹ".blue());
-                    return Ok(());
-                }
-                println!("{} Memory Journals ({})", "📚".bold(), journals.len());
-                println!("{}", "─".repeat(60));
-                for stage_name in &journals {
-                    let journal = read_journal(&work_dir, stage_name)?;
-                    let notes = journal
-                        .entries
-                        .iter()
-                        .filter(|e| e.entry_type == MemoryEntryType::Note)
-                        .count();
-                    let decisions = journal
-                        .entries
-                        .iter()
-                        .filter(|e| e.entry_type == MemoryEntryType::Decision)
-                        .count();
-                    let questions = journal
-                        .entries
-                        .iter()
-                        .filter(|e| e.entry_type == MemoryEntryType::Question)
-                        .count();
-                    println!(
-                        "{}",
-                        format_stage_summary(
-                            stage_name,
-                            journal.entries.len(),
-                            notes,
-                            decisions,
-                            questions
-                        )
-                    );
-                }
-                return Ok(());
-            }
+            // No stage specified - show summary of all stages
+            None => return sessions(),
         },
     };
 
@@ -287,6 +262,40 @@ pub fn list(stage_id: Option<String>, entry_type: Option<String>) -> Result<()>
     Ok(())
 }
 
+/// List the stage memory journals with their entry counts
+pub fn sessions() -> Result<()> {
+    let work_dir = get_work_dir()?;
+    let journals = list_journals(&work_dir)?;
+    if journals.is_empty() {
+        println!("{} No memory journals found", "ℹ".blue());
+        return Ok(());
+    }
+
+    println!("{} Memory Journals ({})", "📚".bold(), journals.len());
+    println!("{}", "─".repeat(60));
+    for stage_name in &journals {
+        let journal = read_journal(&work_dir, stage_name)?;
+        let count = |entry_type: MemoryEntryType| {
+            journal
+                .entries
+                .iter()
+                .filter(|e| e.entry_type == entry_type)
+                .count()
+        };
+        println!(
+            "{}",
+            format_stage_summary(
+                stage_name,
+                journal.entries.len(),
+                count(MemoryEntryType::Note),
+                count(MemoryEntryType::Decision),
+                count(MemoryEntryType::Question)
+            )
+        );
+    }
+    Ok(())
+}
+
 /// Show full memory journal
 pub fn show(stage_id: Option<String>, all: bool) -> Result<()> {
     if let Some(ref id) = stage_id {
@@ -5,11 +5,15 @@
 //! - `loom memory decision <text> [--context <ctx>]` - Record a decision
 //! - `loom memory question <text>` - Record a question
 //! - `loom memory query <search>` - Search memory entries
-//! - `loom memory list [--stage <id>]` - List memory entries
+//! - `loom memory list [--stage <id>]` - List memory entries (also as JSON/YAML)
 //! - `loom memory show [--stage <id>] [--all]` - Show full memory journal
+//! - `loom memory sessions` - List memory journals
+//! - `loom memory promote <entry-type|all> <target>` - Promote entries into knowledge
 
+mod export;
 mod formatters;
 mod handlers;
+mod promote;
 
 // Re-export all public command handlers
 pub use handlers::decision;
@@ -17,4 +21,8 @@ pub use handlers::list;
 pub use handlers::note;
 pub use handlers::query;
 pub use handlers::question;
+pub use handlers::sessions;
 pub use handlers::show;
+pub use promote::promote;
+
+pub use export::{JournalListing, MemoryList};
@@ -0,0 +1,76 @@
+//! `loom memory promote` - copy journal entries into curated knowledge.
+
+use anyhow::{Context, Result};
+use colored::Colorize;
+
+use crate::fs::knowledge::KnowledgeDir;
+use crate::fs::memory::{list_journals, promote_entries, MemoryEntryType, PromoteFilter};
+
+use super::handlers::{get_work_dir, validate_stage_id};
+
+/// Promote memory entries of one type (or `all`) into a knowledge file
+///
+/// Promotes from the given stage, else `LOOM_STAGE_ID`, else every journal.
+pub fn promote(
+    entry_type: String,
+    target: String,
+    stage_id: Option<String>,
+    search: Option<String>,
+) -> Result<()> {
+    if let Some(ref id) = stage_id {
+        validate_stage_id(id)?;
+    }
+    let filter = PromoteFilter {
+        entry_type: match entry_type.to_lowercase().as_str() {
+            "all" => None,
+            other => Some(other.parse::<MemoryEntryType>()?),
+        },
+        search,
+    };
+
+    let work_dir = get_work_dir()?;
+    // In a worktree .work is a symlink to the main repo's, where knowledge lives
+    let project_root = work_dir
+        .canonicalize()
+        .ok()
+        .and_then(|dir| dir.parent().map(|p| p.to_path_buf()))
+        .context("Could not determine main project root")?;
+    let knowledge = KnowledgeDir::new(project_root);
+    let target = knowledge.parse_file(&target)?;
+    if !knowledge.exists() {
+        knowledge
+            .initialize()
+            .context("Failed to initialize knowledge directory")?;
+    }
+
+    let stages = match stage_id.or_else(|| std::env::var("LOOM_STAGE_ID").ok()) {
+        Some(id) => vec![id],
+        None => list_journals(&work_dir)?,
+    };
+    let mut total = 0;
+    for stage in &stages {
+        let count = promote_entries(&work_dir, &knowledge, stage, &filter, &target)?;
+        if count > 0 {
+            println!(
+                "{} Promoted {} entries from '{}' to {}",
+                "✓".green().bold(),
+                count,
+                stage.cyan(),
+                target.filename()
+            );
+        }
+        total += count;
+    }
+
+    if total == 0 {
+        println!(
+            "{} No new {} entries to promote",
+            "ℹ".blue(),
+            filter
+                .entry_type
+                .map(|t| t.to_string())
+                .unwrap_or_else(|| "memory".to_string())
+        );
+    }
+    Ok(())
+}
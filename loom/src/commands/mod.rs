@@ -1,5 +1,7 @@
+pub mod attach;
 pub mod clean;
 pub mod common;
+pub mod config;
 pub mod diagnose;
 pub mod graph;
 pub mod handoff;
@@ -8,10 +10,13 @@ pub mod init;
 pub mod knowledge;
 pub mod map;
 pub mod memory;
+pub mod publish;
 pub mod repair;
+pub mod report;
 pub mod resume;
 pub mod run;
 pub mod sandbox;
+pub mod search;
 pub mod self_update;
 pub mod sessions;
 pub mod stage;
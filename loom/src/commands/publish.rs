@@ -0,0 +1,56 @@
+//! Publish command - push the finished plan and render its PR body
+//! Usage: loom publish [--remote <name>] [--branch <name>] [--body-file <path>] [--dry-run]
+
+use anyhow::{Context, Result};
+use colored::Colorize;
+use std::path::PathBuf;
+
+use crate::fs::work_dir::WorkDir;
+use crate::orchestrator::publish::{publish_plan, PublishOptions};
+
+/// Execute the publish command
+pub fn execute(options: PublishOptions) -> Result<()> {
+    let work_dir = WorkDir::new(".")?;
+    work_dir.load()?;
+    let repo_root = work_dir
+        .main_project_root()
+        .context("Failed to resolve the project root")?;
+    let work_dir = WorkDir::new(&repo_root)?;
+
+    let publication = publish_plan(&work_dir, &repo_root, &options)?;
+
+    if options.dry_run {
+        println!("{}\n", publication.body);
+        println!(
+            "{} Would push {} ({}) to {}/{}",
+            "→".dimmed(),
+            publication.merge_point.cyan(),
+            &publication.commit[..publication.commit.len().min(7)],
+            publication.remote,
+            publication.branch.cyan()
+        );
+        return Ok(());
+    }
+
+    println!(
+        "{} Pushed {} ({}) to {}/{}",
+        "✓".green().bold(),
+        publication.merge_point.cyan(),
+        &publication.commit[..publication.commit.len().min(7)],
+        publication.remote,
+        publication.branch.cyan()
+    );
+    println!(
+        "  {} PR body: {}",
+        "→".dimmed(),
+        relative_to(&publication.body_file, &repo_root).display()
+    );
+    if let Some(command) = &publication.command {
+        println!("  {} Ran {}", "→".dimmed(), command.cyan());
+    }
+    Ok(())
+}
+
+fn relative_to(path: &std::path::Path, root: &std::path::Path) -> PathBuf {
+    path.strip_prefix(root).unwrap_or(path).to_path_buf()
+}
@@ -0,0 +1,223 @@
+//! Report commands - summaries derived from stage state
+//! Usage: loom report cost [--format json|yaml]
+
+use anyhow::Result;
+use colored::Colorize;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use crate::commands::common::{truncate, OutputFormat};
+use crate::fs::work_dir::WorkDir;
+use crate::models::stage::{Stage, StageStatus};
+use crate::models::usage::{format_tokens, SessionUsage, TokenUsage};
+use crate::orchestrator::pricing::{format_cost, PriceTable};
+use crate::verify::transitions::list_all_stages;
+
+/// Label used for sessions whose heartbeat never reported a model
+const UNKNOWN_MODEL: &str = "unknown";
+
+/// Token usage and cost of the plan, as reported by `loom report cost`
+#[derive(Debug, Clone, Serialize)]
+pub struct CostReport {
+    pub stages: Vec<StageCost>,
+    pub models: Vec<ModelCost>,
+    pub tokens: TokenUsage,
+    /// Total cost in USD (None if any usage could not be priced)
+    pub cost_usd: Option<f64>,
+}
+
+/// Usage of all sessions that worked on one stage
+#[derive(Debug, Clone, Serialize)]
+pub struct StageCost {
+    pub id: String,
+    pub status: StageStatus,
+    pub sessions: usize,
+    pub tokens: TokenUsage,
+    pub cost_usd: Option<f64>,
+}
+
+/// Usage of all sessions that ran on one model
+#[derive(Debug, Clone, Serialize)]
+pub struct ModelCost {
+    pub model: String,
+    pub sessions: usize,
+    pub tokens: TokenUsage,
+    pub cost_usd: Option<f64>,
+}
+
+impl CostReport {
+    /// Build the report from stage usage records
+    pub fn build(stages: &[Stage], prices: &PriceTable) -> Self {
+        let stage_costs: Vec<StageCost> = stages
+            .iter()
+            .map(|stage| StageCost {
+                id: stage.id.clone(),
+                status: stage.status.clone(),
+                sessions: stage.usage.len(),
+                tokens: stage.total_usage(),
+                cost_usd: prices.sessions_cost(stage.usage.values()),
+            })
+            .collect();
+
+        let mut by_model: BTreeMap<&str, Vec<&SessionUsage>> = BTreeMap::new();
+        for usage in stages.iter().flat_map(|s| s.usage.values()) {
+            let model = usage.model.as_deref().unwrap_or(UNKNOWN_MODEL);
+            by_model.entry(model).or_default().push(usage);
+        }
+        let models = by_model
+            .into_iter()
+            .map(|(model, sessions)| ModelCost {
+                model: model.to_string(),
+                sessions: sessions.len(),
+                tokens: sessions.iter().map(|u| &u.tokens).sum(),
+                cost_usd: prices.sessions_cost(sessions),
+            })
+            .collect();
+
+        Self {
+            tokens: stage_costs.iter().map(|s| &s.tokens).sum(),
+            cost_usd: stage_costs.iter().map(|s| s.cost_usd).sum(),
+            stages: stage_costs,
+            models,
+        }
+    }
+}
+
+/// Show token usage and cost per stage and per model
+pub fn cost(format: OutputFormat) -> Result<()> {
+    let work_dir = WorkDir::new(".")?;
+    work_dir.load()?;
+
+    let mut stages = list_all_stages(work_dir.root())?;
+    stages.sort_by(|a, b| a.id.cmp(&b.id));
+    let prices = PriceTable::load(work_dir.root())?;
+    let report = CostReport::build(&stages, &prices);
+
+    if !format.is_text() {
+        return format.print("cost-report", &report);
+    }
+
+    print_cost_report(&report);
+    Ok(())
+}
+
+fn print_cost_report(report: &CostReport) {
+    if report.tokens.is_empty() {
+        println!("{}", "No token usage recorded yet.".dimmed());
+        return;
+    }
+
+    println!("{}", "Cost by stage".bold());
+    print_header("Stage");
+    for stage in report.stages.iter().filter(|s| s.sessions > 0) {
+        print_row(&stage.id, stage.sessions, &stage.tokens, stage.cost_usd);
+    }
+
+    println!();
+    println!("{}", "Cost by model".bold());
+    print_header("Model");
+    for model in &report.models {
+        print_row(&model.model, model.sessions, &model.tokens, model.cost_usd);
+    }
+
+    println!("{}", "─".repeat(96));
+    let sessions = report.models.iter().map(|m| m.sessions).sum();
+    print_row("Total", sessions, &report.tokens, report.cost_usd);
+
+    if report.cost_usd.is_none() {
+        println!();
+        println!(
+            "{} Some models have no price; add them under [pricing.\"<model>\"] in .work/config.toml",
+            "→".dimmed()
+        );
+    }
+}
+
+fn print_header(label: &str) {
+    println!(
+        "  {label:<30} {:>8} {:>9} {:>9} {:>11} {:>10} {:>10}",
+        "Sessions", "Input", "Output", "Cache write", "Cache read", "Cost"
+    );
+}
+
+fn print_row(label: &str, sessions: usize, tokens: &TokenUsage, cost_usd: Option<f64>) {
+    let cost = cost_usd.map_or_else(|| "-".to_string(), format_cost);
+    println!(
+        "  {:<30} {:>8} {:>9} {:>9} {:>11} {:>10} {:>10}",
+        truncate(label, 30),
+        sessions,
+        format_tokens(tokens.input_tokens),
+        format_tokens(tokens.output_tokens),
+        format_tokens(tokens.cache_creation_tokens),
+        format_tokens(tokens.cache_read_tokens),
+        cost
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(model: Option<&str>, input: u64) -> SessionUsage {
+        SessionUsage {
+            model: model.map(String::from),
+            tokens: TokenUsage {
+                input_tokens: input,
+                ..TokenUsage::default()
+            },
+        }
+    }
+
+    fn stage(id: &str, sessions: &[(&str, SessionUsage)]) -> Stage {
+        let mut stage = Stage::new(id.to_string(), None);
+        stage.id = id.to_string();
+        for (session_id, usage) in sessions {
+            stage.record_session_usage(session_id, usage.clone());
+        }
+        stage
+    }
+
+    #[test]
+    fn test_cost_report_groups_by_stage_and_model() {
+        let stages = vec![
+            stage(
+                "build",
+                &[
+                    ("s1", session(Some("claude-sonnet-4-5"), 1_000_000)),
+                    ("s2", session(Some("claude-haiku-4-5"), 1_000_000)),
+                ],
+            ),
+            stage(
+                "test",
+                &[("s3", session(Some("claude-sonnet-4-5"), 1_000_000))],
+            ),
+            stage("docs", &[]),
+        ];
+
+        let report = CostReport::build(&stages, &PriceTable::default());
+
+        assert_eq!(report.stages.len(), 3);
+        assert_eq!(report.stages[0].sessions, 2);
+        assert_eq!(report.stages[0].cost_usd, Some(4.0));
+        assert_eq!(report.stages[2].cost_usd, Some(0.0));
+
+        assert_eq!(report.models.len(), 2);
+        assert_eq!(report.models[1].model, "claude-sonnet-4-5");
+        assert_eq!(report.models[1].sessions, 2);
+        assert_eq!(report.models[1].cost_usd, Some(6.0));
+
+        assert_eq!(report.tokens.input_tokens, 3_000_000);
+        assert_eq!(report.cost_usd, Some(7.0));
+    }
+
+    #[test]
+    fn test_cost_report_unknown_model_is_unpriced() {
+        let stages = vec![stage("build", &[("s1", session(None, 10))])];
+
+        let report = CostReport::build(&stages, &PriceTable::default());
+
+        assert_eq!(report.models[0].model, UNKNOWN_MODEL);
+        assert_eq!(report.models[0].cost_usd, None);
+        assert_eq!(report.cost_usd, None);
+    }
+}
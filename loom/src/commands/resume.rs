@@ -3,11 +3,15 @@ use crate::handoff::{
     continue_session, find_latest_handoff, load_handoff_content, prepare_continuation,
     ContinuationConfig,
 };
+use crate::models::session::SessionType;
 use crate::models::stage::StageStatus;
 use crate::models::worktree::Worktree;
+use crate::orchestrator::models::model_for_session;
+use crate::orchestrator::terminal::configured_backend_type;
 use crate::verify::transitions::{load_stage, save_stage};
 use anyhow::{bail, Context, Result};
 use std::io::{stdin, stdout, Write};
+use std::path::Path;
 
 /// Resume failed/blocked stages with handoff context
 /// Usage: loom resume <stage_id>
@@ -63,8 +67,14 @@ pub fn execute(stage_id: String) -> Result<()> {
 
     // Create continuation configuration with auto_spawn enabled
     let config = ContinuationConfig {
+        backend_type: configured_backend_type(work_dir.root()),
         auto_spawn: true,
-        ..Default::default()
+        model: model_for_session(
+            work_dir.root(),
+            work_dir.project_root().unwrap_or(Path::new(".")),
+            &context.stage,
+            &SessionType::Stage,
+        ),
     };
 
     // Check if we have a worktree to spawn the session in
@@ -7,7 +7,9 @@ use std::time::Duration;
 use crate::commands::status::render::print_completion_summary;
 use crate::daemon::collect_completion_summary;
 use crate::fs::work_dir::WorkDir;
-use crate::orchestrator::terminal::BackendType;
+use crate::orchestrator::publish;
+use crate::orchestrator::run_settings::RunSettings;
+use crate::orchestrator::terminal::{configured_backend_type, BackendType};
 use crate::orchestrator::{Orchestrator, OrchestratorConfig, OrchestratorResult};
 use crate::plan::schema::SandboxConfig;
 
@@ -16,12 +18,13 @@ use super::graph_loader::build_execution_graph;
 use crate::fs::plan_lifecycle;
 
 /// Execute plan stages in foreground (for --foreground flag)
-/// Usage: loom run --foreground [--manual] [--max-parallel <n>] [--watch] [--no-merge]
+/// Usage: loom run --foreground [--manual] [--max-parallel <n>] [--watch] [--no-merge | --merge] [--backend <type>]
 pub fn execute(
     manual: bool,
     max_parallel: Option<usize>,
     watch: bool,
-    auto_merge: bool,
+    auto_merge: Option<bool>,
+    backend: Option<BackendType>,
 ) -> Result<()> {
     // Check for uncommitted changes before starting
     let repo_root = std::env::current_dir()?;
@@ -33,7 +36,21 @@ pub fn execute(
     // Mark plan as in-progress when starting execution
     plan_lifecycle::mark_plan_in_progress(&work_dir)?;
 
-    execute_foreground(manual, max_parallel, watch, auto_merge, &work_dir)
+    let backend_type = backend.unwrap_or_else(|| configured_backend_type(work_dir.root()));
+
+    // Settings saved by `loom config set` apply when no flag overrides them
+    let settings = RunSettings::load(work_dir.root());
+    let max_parallel = max_parallel.or(settings.max_parallel);
+    let auto_merge = auto_merge.or(settings.auto_merge).unwrap_or(true);
+
+    execute_foreground(
+        manual,
+        max_parallel,
+        watch,
+        auto_merge,
+        backend_type,
+        &work_dir,
+    )
 }
 
 /// Execute orchestrator in foreground mode (for debugging)
@@ -42,6 +59,7 @@ fn execute_foreground(
     max_parallel: Option<usize>,
     watch: bool,
     auto_merge: bool,
+    backend_type: BackendType,
     work_dir: &WorkDir,
 ) -> Result<()> {
     let graph = build_execution_graph(work_dir)?;
@@ -57,7 +75,7 @@ fn execute_foreground(
         work_dir: work_dir.root().to_path_buf(),
         repo_root: std::env::current_dir()?,
         status_update_interval: Duration::from_secs(30),
-        backend_type: BackendType::Native,
+        backend_type,
         auto_merge,
         base_branch,
         skills_dir: None, // Use default ~/.claude/skills/
@@ -65,6 +83,7 @@ fn execute_foreground(
         max_skill_recommendations: 5,
         sandbox_config: SandboxConfig::default(),
         shutdown_flag: None,
+        control_queue: None,
     };
 
     let mut orchestrator =
@@ -100,6 +119,7 @@ fn execute_foreground(
     // If successful, check if all stages are merged and mark plan as done
     if result.is_success() {
         plan_lifecycle::mark_plan_done_if_all_merged(work_dir)?;
+        publish::auto_publish(work_dir, &std::env::current_dir()?);
         Ok(())
     } else {
         bail!("Orchestration completed with failures")
@@ -17,6 +17,8 @@ use colored::Colorize;
 use crate::daemon::{DaemonConfig, DaemonServer};
 use crate::fs::plan_lifecycle;
 use crate::fs::work_dir::WorkDir;
+use crate::orchestrator::run_settings::RunSettings;
+use crate::orchestrator::terminal::{configured_backend_type, BackendType};
 
 use checks::check_for_uncommitted_changes;
 
@@ -27,12 +29,13 @@ pub use foreground::execute;
 pub use crate::fs::plan_lifecycle::mark_plan_done_if_all_merged;
 
 /// Execute orchestrator in background (daemon mode)
-/// Usage: loom run [--manual] [--max-parallel <n>] [--watch] [--no-merge]
+/// Usage: loom run [--manual] [--max-parallel <n>] [--watch] [--no-merge | --merge] [--backend <type>]
 pub fn execute_background(
     manual: bool,
     max_parallel: Option<usize>,
     _watch: bool, // Daemon always runs in watch mode; CLI flag is accepted but ignored
-    auto_merge: bool,
+    auto_merge: Option<bool>,
+    backend: Option<BackendType>,
 ) -> Result<()> {
     // Check for uncommitted changes before starting
     let repo_root = std::env::current_dir()?;
@@ -52,19 +55,29 @@ pub fn execute_background(
         return Ok(());
     }
 
+    let backend_type = backend.unwrap_or_else(|| configured_backend_type(work_dir.root()));
+
     // Detect terminal BEFORE daemonizing (daemon loses terminal context after fork)
     // Store in environment variable so it can be read back after the fork
-    if let Ok(terminal) = crate::orchestrator::terminal::native::detect_terminal() {
-        // SAFETY: This runs in main() before the tokio runtime spawns any threads,
-        // so there are no concurrent readers of the environment.
-        unsafe { std::env::set_var("LOOM_TERMINAL", terminal.display_name()) };
+    if backend_type == BackendType::Native {
+        if let Ok(terminal) = crate::orchestrator::terminal::native::detect_terminal() {
+            // SAFETY: This runs in main() before the tokio runtime spawns any threads,
+            // so there are no concurrent readers of the environment.
+            unsafe { std::env::set_var("LOOM_TERMINAL", terminal.display_name()) };
+        }
     }
 
+    // Settings saved by `loom config set` apply when no flag overrides them
+    let settings = RunSettings::load(work_dir.root());
+    let max_parallel = max_parallel.or(settings.max_parallel);
+    let auto_merge = auto_merge.or(settings.auto_merge).unwrap_or(true);
+
     let daemon_config = DaemonConfig {
         manual_mode: manual,
         max_parallel,
         watch_mode: true, // Daemon always runs in watch mode (ignores CLI flag)
         auto_merge,
+        backend_type,
     };
 
     let daemon = DaemonServer::with_config(work_dir.root(), daemon_config);
@@ -74,6 +87,13 @@ pub fn execute_background(
     if !auto_merge {
         println!("  {} Auto-merge disabled", "→".dimmed());
     }
+    if backend_type == BackendType::Headless {
+        println!(
+            "  {} Headless sessions, logs in {}",
+            "→".dimmed(),
+            ".work/logs/".dimmed()
+        );
+    }
     println!();
     println!("  {}  Monitor progress", "loom status".cyan());
     println!("  {}  Stop daemon", "loom stop".cyan());
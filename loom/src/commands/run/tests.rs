@@ -16,8 +16,17 @@ fn create_test_plan(dir: &Path, stages: Vec<StageDefinition>) -> PathBuf {
         loom: LoomConfig {
             version: 1,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             sandbox: SandboxConfig::default(),
             change_impact: None,
+            default_timeout: None,
+            parallel: None,
+            budget: None,
+            models: None,
+            notifiers: Vec::new(),
+            publish: None,
+            promote_decisions: None,
             stages,
         },
     };
@@ -43,9 +52,12 @@ fn setup_work_dir_with_plan(temp_dir: &TempDir) -> (PathBuf, WorkDir) {
         dependencies: vec![],
         parallel_group: None,
         acceptance: vec![],
+        parallel: None,
         setup: vec![],
         files: vec![],
         auto_merge: None,
+        merge_strategy: None,
+        overlap: None,
         working_dir: ".".to_string(),
         stage_type: StageType::default(),
         // Standard stages require goal-backward checks
@@ -58,6 +70,12 @@ fn setup_work_dir_with_plan(temp_dir: &TempDir) -> (PathBuf, WorkDir) {
         before_stage: vec![],
         after_stage: vec![],
         context_budget: None,
+        timeout: None,
+        budget: None,
+        model: None,
+        for_each: None,
+        when: None,
+        output_keys: vec![],
         sandbox: StageSandboxConfig::default(),
         execution_mode: None,
         bug_fix: None,
@@ -0,0 +1,97 @@
+//! Search command - ranked full-text search over memory, handoffs and knowledge
+//! Usage: loom search <query> [--type <entry-type>] [--source <source>] [--since <date>]
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Days, NaiveDate, Utc};
+use colored::Colorize;
+
+use crate::fs::knowledge::KnowledgeDir;
+use crate::fs::memory::MemoryEntryType;
+use crate::fs::search::{load_or_build_index, SearchFilter, SearchHit};
+use crate::fs::work_dir::WorkDir;
+use crate::validation::validate_id;
+
+/// Execute the search command
+pub fn execute(
+    query: &str,
+    entry_type: Option<String>,
+    source: Option<String>,
+    stage: Option<String>,
+    since: Option<String>,
+    until: Option<String>,
+    limit: usize,
+) -> Result<()> {
+    if let Some(ref id) = stage {
+        validate_id(id).context("Invalid stage ID")?;
+    }
+    let filter = SearchFilter {
+        entry_type: entry_type
+            .map(|t| t.parse::<MemoryEntryType>())
+            .transpose()?,
+        source: source.map(|s| s.parse()).transpose()?,
+        stage_id: stage,
+        exclude_stage: None,
+        since: since.map(|date| parse_date(&date, 0)).transpose()?,
+        // --until is inclusive: stop at the start of the following day
+        until: until.map(|date| parse_date(&date, 1)).transpose()?,
+    };
+
+    let work_dir = WorkDir::new(".")?;
+    work_dir.load()?;
+    let repo_root = work_dir
+        .main_project_root()
+        .context("Failed to resolve the project root")?;
+    let work_dir = WorkDir::new(&repo_root)?;
+    let index = load_or_build_index(work_dir.root(), &KnowledgeDir::new(&repo_root))?;
+
+    let hits = index.search(query, &filter, limit);
+    if hits.is_empty() {
+        println!("{} No results for '{}'", "ℹ".blue(), query);
+        return Ok(());
+    }
+    println!(
+        "\n{} ({} of {} documents)",
+        format!("Results for '{query}'").bold(),
+        hits.len(),
+        index.len()
+    );
+    println!("{}", "─".repeat(60));
+    for hit in &hits {
+        print_hit(hit);
+    }
+    Ok(())
+}
+
+fn print_hit(hit: &SearchHit) {
+    let document = &hit.document;
+    let kind = document
+        .entry_type
+        .map(|t| t.to_string())
+        .unwrap_or_else(|| document.source.to_string());
+    let mut origin = document
+        .stage_id
+        .clone()
+        .or_else(|| document.heading.clone())
+        .unwrap_or_default();
+    if let Some(time) = document.timestamp {
+        origin.push_str(&format!(" · {}", time.format("%Y-%m-%d %H:%M")));
+    }
+    println!(
+        "{} {} {}",
+        format!("[{kind}]").cyan(),
+        origin,
+        format!("({:.2})", hit.score).dimmed()
+    );
+    println!("  {}", document.path.dimmed());
+    println!("  {}\n", hit.snippet);
+}
+
+/// Start of the day `offset` days after a YYYY-MM-DD date, in UTC
+fn parse_date(date: &str, offset: u64) -> Result<DateTime<Utc>> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .ok()
+        .and_then(|day| day.checked_add_days(Days::new(offset)))
+        .and_then(|day| day.and_hms_opt(0, 0, 0))
+        .map(|time| time.and_utc())
+        .with_context(|| format!("Invalid date '{date}', expected YYYY-MM-DD"))
+}
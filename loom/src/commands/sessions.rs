@@ -2,16 +2,59 @@
 //! Usage: loom sessions [list|kill <id>...]
 
 use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::path::Path;
 
-use crate::commands::common::find_work_dir;
+use crate::commands::common::{find_work_dir, send_to_daemon, OutputFormat};
 use crate::fs::session_files::find_session_file;
 use crate::fs::worktree_files::find_sessions_for_stage;
-use crate::models::session::Session;
-use crate::orchestrator::terminal::{create_backend, BackendType};
+use crate::models::session::{Session, SessionStatus, SessionType};
+use crate::orchestrator::control::StageCommand;
+use crate::orchestrator::terminal::headless::log_file_path;
+use crate::orchestrator::terminal::{create_backend, tmux, BackendType};
 use crate::parser::frontmatter::parse_from_markdown;
 
+/// Session files as reported by `loom sessions list --format json|yaml`
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionList {
+    pub sessions: Vec<SessionListing>,
+}
+
+/// A session as reported by `loom sessions list --format json|yaml`
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionListing {
+    pub id: String,
+    pub stage_id: Option<String>,
+    pub status: SessionStatus,
+    pub session_type: SessionType,
+    pub model: Option<String>,
+    pub pid: Option<u32>,
+    pub created_at: DateTime<Utc>,
+    pub last_active: DateTime<Utc>,
+}
+
+impl From<Session> for SessionListing {
+    fn from(session: Session) -> Self {
+        Self {
+            id: session.id,
+            stage_id: session.stage_id,
+            status: session.status,
+            session_type: session.session_type,
+            model: session.model,
+            pid: session.pid,
+            created_at: session.created_at,
+            last_active: session.last_active,
+        }
+    }
+}
+
 /// List all sessions
-pub fn list() -> Result<()> {
+pub fn list(format: OutputFormat) -> Result<()> {
+    if !format.is_text() {
+        return list_structured(format);
+    }
+
     println!("Active sessions:");
     println!("─────────────────────────────────────────────────────────");
 
@@ -47,10 +90,44 @@ pub fn list() -> Result<()> {
     Ok(())
 }
 
+/// Print all session files as structured output, sorted by id
+fn list_structured(format: OutputFormat) -> Result<()> {
+    let work_dir = find_work_dir()?;
+    let mut sessions: Vec<SessionListing> = Vec::new();
+
+    if let Ok(entries) = std::fs::read_dir(work_dir.join("sessions")) {
+        for path in entries.flatten().map(|entry| entry.path()) {
+            if path.extension().is_none_or(|ext| ext != "md") {
+                continue;
+            }
+            let content = std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            match parse_from_markdown::<Session>(&content, "Session") {
+                Ok(session) => sessions.push(session.into()),
+                Err(e) => eprintln!("Warning: Skipping {}: {e}", path.display()),
+            }
+        }
+    }
+    sessions.sort_by(|a, b| a.id.cmp(&b.id));
+
+    format.print("sessions", &SessionList { sessions })
+}
+
 /// Kill one or more sessions by ID/prefix, or all sessions for a stage
 pub fn kill(session_ids: Vec<String>, stage: Option<String>) -> Result<()> {
     let work_dir = find_work_dir()?;
 
+    // A running daemon tracks the stage's session; let its orchestrator kill it
+    if session_ids.is_empty() {
+        if let Some(stage_id) = &stage {
+            let command = StageCommand::KillSession;
+            if let Some(status) = send_to_daemon(&work_dir, stage_id, command)? {
+                println!("Killed session for stage '{stage_id}' (stage is {status})");
+                return Ok(());
+            }
+        }
+    }
+
     // Collect all session IDs to kill
     let mut ids_to_kill = session_ids;
 
@@ -104,7 +181,7 @@ pub fn kill(session_ids: Vec<String>, stage: Option<String>) -> Result<()> {
 }
 
 /// Kill a single session by ID or prefix
-fn kill_single_session(work_dir: &std::path::Path, session_id: &str) -> Result<()> {
+fn kill_single_session(work_dir: &Path, session_id: &str) -> Result<()> {
     let session_file = match find_session_file(work_dir, session_id)? {
         Some(path) => path,
         None => bail!("Session '{session_id}' not found"),
@@ -127,7 +204,7 @@ fn kill_single_session(work_dir: &std::path::Path, session_id: &str) -> Result<(
         .context("Failed to parse session from markdown")?;
 
     // Detect backend type from session metadata
-    let backend_type = detect_backend_type(&session);
+    let backend_type = detect_backend_type(&session, work_dir);
 
     // Kill the session using the appropriate backend
     if let Some(backend_type) = backend_type {
@@ -167,12 +244,22 @@ fn kill_single_session(work_dir: &std::path::Path, session_id: &str) -> Result<(
 
 /// Detect backend type from session metadata
 ///
-/// Returns Native if pid is set, otherwise None.
-fn detect_backend_type(session: &Session) -> Option<BackendType> {
-    if session.pid.is_some() {
-        Some(BackendType::Native)
+/// Returns None if the session was never spawned (no pid). Headless sessions
+/// are recognized by their log file in .work/logs/ and tmux sessions by their
+/// window in the plan's tmux session; anything else is Native.
+fn detect_backend_type(session: &Session, work_dir: &Path) -> Option<BackendType> {
+    session.pid?;
+    if log_file_path(work_dir, &session.id).exists() {
+        return Some(BackendType::Headless);
+    }
+    let in_tmux = tmux::window_name(session).is_some_and(|name| {
+        tmux::tmux_available()
+            && tmux::window_exists(&tmux::session_name_for_work_dir(work_dir), &name)
+    });
+    if in_tmux {
+        Some(BackendType::Tmux)
     } else {
-        None
+        Some(BackendType::Native)
     }
 }
 
@@ -182,17 +269,38 @@ mod tests {
 
     #[test]
     fn test_detect_backend_type_native() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
         let mut session = Session::new();
         session.pid = Some(12345);
 
-        assert_eq!(detect_backend_type(&session), Some(BackendType::Native));
+        assert_eq!(
+            detect_backend_type(&session, temp_dir.path()),
+            Some(BackendType::Native)
+        );
+    }
+
+    #[test]
+    fn test_detect_backend_type_headless() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut session = Session::new();
+        session.pid = Some(12345);
+
+        let log_path = log_file_path(temp_dir.path(), &session.id);
+        std::fs::create_dir_all(log_path.parent().unwrap()).unwrap();
+        std::fs::write(&log_path, "").unwrap();
+
+        assert_eq!(
+            detect_backend_type(&session, temp_dir.path()),
+            Some(BackendType::Headless)
+        );
     }
 
     #[test]
     fn test_detect_backend_type_none() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
         let session = Session::new();
 
-        assert_eq!(detect_backend_type(&session), None);
+        assert_eq!(detect_backend_type(&session, temp_dir.path()), None);
     }
 
     #[test]
@@ -4,11 +4,15 @@
 //! where acceptance criteria should be executed.
 
 use anyhow::{Context, Result};
+use chrono::Utc;
 use std::path::{Path, PathBuf};
 
+use super::history::record_acceptance_run;
 use crate::git::worktree::{find_repo_root_from_cwd, find_worktree_root_from_cwd};
+use crate::models::failure::{FailureInfo, FailureType};
 use crate::models::stage::Stage;
-use crate::verify::criteria::run_acceptance;
+use crate::verify::criteria::{run_acceptance_with_config, AcceptanceResult, CriteriaConfig};
+use crate::verify::outputs::interpolate_stage_outputs;
 
 /// Resolved execution paths for a standard stage.
 #[derive(Debug, Clone)]
@@ -149,18 +153,22 @@ pub(crate) fn resolve_knowledge_acceptance_dir(stage: &Stage) -> Result<Option<P
 
 /// Run acceptance criteria and print standardized output.
 ///
-/// Returns `true` when all criteria pass, `false` otherwise.
+/// Stage output references in the criteria are resolved from `work_dir`.
+/// A stage without criteria passes with no results.
 pub(crate) fn run_acceptance_with_display(
     stage: &Stage,
     stage_id: &str,
     acceptance_dir: Option<&Path>,
+    work_dir: &Path,
     options: AcceptanceDisplayOptions<'_>,
-) -> Result<bool> {
+) -> Result<AcceptanceResult> {
     if stage.acceptance.is_empty() {
         if options.show_empty_message {
             println!("No acceptance criteria defined, treating as passed.");
         }
-        return Ok(true);
+        return Ok(AcceptanceResult::AllPassed {
+            results: Vec::new(),
+        });
     }
 
     if let Some(label) = options.stage_label {
@@ -170,24 +178,43 @@ pub(crate) fn run_acceptance_with_display(
         println!("  (working directory: {})", dir.display());
     }
 
-    let result =
-        run_acceptance(stage, acceptance_dir).context("Failed to run acceptance criteria")?;
+    let mut stage = stage.clone();
+    interpolate_stage_outputs(&mut stage, work_dir)?;
+    let config = CriteriaConfig::default().with_cache(work_dir);
+    let result = run_acceptance_with_config(&stage, acceptance_dir, &config)
+        .context("Failed to run acceptance criteria")?;
+    record_acceptance_run(stage_id, &result, acceptance_dir, work_dir);
 
     for criterion_result in result.results() {
-        if criterion_result.success {
+        if criterion_result.cached {
+            println!("  ✓ passed (cached): {}", criterion_result.command);
+        } else if criterion_result.success {
             println!("  ✓ passed: {}", criterion_result.command);
         } else if criterion_result.timed_out {
             println!("  ✗ TIMEOUT: {}", criterion_result.command);
         } else {
             println!("  ✗ FAILED: {}", criterion_result.command);
         }
+        for test in criterion_result.failed_tests() {
+            println!("      ✗ {}", test.summary());
+        }
     }
 
     if result.all_passed() {
         println!("All acceptance criteria passed!");
     }
 
-    Ok(result.all_passed())
+    Ok(result)
+}
+
+/// Failure info for failed acceptance criteria, naming failed tests where
+/// a criterion has a test report
+pub(crate) fn acceptance_failure_info(result: &AcceptanceResult) -> FailureInfo {
+    FailureInfo {
+        failure_type: FailureType::TestFailure,
+        detected_at: Utc::now(),
+        evidence: result.failure_evidence(),
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,56 @@
+//! Acceptance criteria result cache commands
+//!
+//! Lists and clears the passing results kept under `.work/criteria-cache/`.
+
+use anyhow::Result;
+use colored::Colorize;
+use std::path::Path;
+
+use crate::fs::criteria_cache::{cached_stage_ids, clear_cache, load_cache};
+
+/// List cached criteria of one stage, or of every stage
+pub fn list(stage_id: Option<String>) -> Result<()> {
+    let work_dir = Path::new(".work");
+    let ids = match stage_id {
+        Some(id) => vec![id],
+        None => cached_stage_ids(work_dir)?,
+    };
+
+    let mut any = false;
+    for id in ids {
+        let cache = load_cache(&id, work_dir);
+        if cache.is_empty() {
+            continue;
+        }
+        any = true;
+        println!("{}", format!("Stage '{id}':").bold());
+        for entry in cache.values() {
+            println!(
+                "  {}  ({:.1}s, tree {}, {})",
+                entry.command,
+                entry.duration_ms as f64 / 1000.0,
+                entry.tree.get(..12).unwrap_or(&entry.tree),
+                entry
+                    .passed_at
+                    .format("%Y-%m-%d %H:%M:%S")
+                    .to_string()
+                    .dimmed()
+            );
+        }
+    }
+    if !any {
+        println!("No cached acceptance results.");
+    }
+    Ok(())
+}
+
+/// Clear cached results of one stage, or of every stage
+pub fn clear(stage_id: Option<String>) -> Result<()> {
+    let removed = clear_cache(stage_id.as_deref(), Path::new(".work"))?;
+    let scope = match &stage_id {
+        Some(id) => format!("stage '{id}'"),
+        None => "all stages".to_string(),
+    };
+    println!("Cleared {removed} cached result(s) for {scope}.");
+    Ok(())
+}
@@ -1,17 +1,22 @@
 //! Check acceptance criteria without changing stage status
 //!
-//! Runs acceptance criteria and prints detailed results including full
-//! stdout/stderr for each criterion. Increments fix_attempts on failure
+//! Runs acceptance criteria and prints detailed results including the
+//! expanded command and full stdout/stderr for each criterion. Increments fix_attempts on failure
 //! but does NOT transition stage status.
 
 use anyhow::{bail, Context, Result};
 use std::path::Path;
 
 use crate::models::stage::{StageStatus, StageType};
-use crate::verify::criteria::run_acceptance;
+use crate::verify::context::CriteriaContext;
+use crate::verify::criteria::{
+    run_acceptance_with_config, CriteriaConfig, CriterionResult, TestStatus,
+};
+use crate::verify::outputs::interpolate_stage_outputs;
 use crate::verify::transitions::{load_stage, save_stage};
 
-use super::acceptance_runner::resolve_stage_execution_paths;
+use super::acceptance_runner::{acceptance_failure_info, resolve_stage_execution_paths};
+use super::history::record_acceptance_run;
 
 /// Default maximum fix attempts before suggesting dispute-criteria
 const DEFAULT_MAX_FIX_ATTEMPTS: u32 = 3;
@@ -20,8 +25,8 @@ const DEFAULT_MAX_FIX_ATTEMPTS: u32 = 3;
 ///
 /// This command:
 /// 1. Loads the stage and validates its status (Executing or CompletedWithFailures)
-/// 2. Runs all acceptance criteria
-/// 3. Prints full stdout/stderr for each criterion
+/// 2. Runs all acceptance criteria, with context variables and stage outputs expanded
+/// 3. Prints the expanded command and full stdout/stderr for each criterion
 /// 4. Increments fix_attempts if any failed
 /// 5. Does NOT change stage status
 pub fn check_acceptance(stage_id: String) -> Result<()> {
@@ -58,18 +63,32 @@ pub fn check_acceptance(stage_id: String) -> Result<()> {
     }
     println!();
 
-    // Run acceptance criteria
-    let result = run_acceptance(&stage, acceptance_dir.as_deref())
+    // Run acceptance criteria with stage outputs interpolated
+    let mut expanded_stage = stage.clone();
+    interpolate_stage_outputs(&mut expanded_stage, work_dir)?;
+    let config = CriteriaConfig::default().with_cache(work_dir);
+    let result = run_acceptance_with_config(&expanded_stage, acceptance_dir.as_deref(), &config)
         .context("Failed to run acceptance criteria")?;
+    record_acceptance_run(&stage_id, &result, acceptance_dir.as_deref(), work_dir);
+    let context = CriteriaContext::with_stage_id(
+        acceptance_dir.as_deref().unwrap_or(Path::new(".")),
+        &stage_id,
+    );
 
     // Print detailed results for each criterion
     let total = result.results().len();
-    for (i, cr) in result.results().iter().enumerate() {
+    for (i, (cr, criterion)) in result.results().iter().zip(&stage.acceptance).enumerate() {
         let num = i + 1;
-        println!("Criterion {num}: {}", cr.command);
+        println!("Criterion {num}: {criterion}");
+        let expanded = context.expand(&cr.command);
+        if expanded != criterion.command {
+            println!("Expanded: {expanded}");
+        }
 
         if cr.timed_out {
             println!("Result: TIMEOUT");
+        } else if cr.cached {
+            println!("Result: PASSED (cached)");
         } else if cr.success {
             println!("Result: PASSED");
         } else {
@@ -82,6 +101,7 @@ pub fn check_acceptance(stage_id: String) -> Result<()> {
 
         let duration_secs = cr.duration.as_secs_f64();
         println!("Duration: {duration_secs:.1}s");
+        print_test_cases(cr);
 
         // Print stdout/stderr when non-empty (for all criteria, not just failures)
         if !cr.stdout.is_empty() {
@@ -108,6 +128,7 @@ pub fn check_acceptance(stage_id: String) -> Result<()> {
 
     if !result.all_passed() {
         stage.fix_attempts += 1;
+        stage.failure_info = Some(acceptance_failure_info(&result));
         save_stage(&stage, work_dir)?;
 
         let max = stage.max_retries.unwrap_or(DEFAULT_MAX_FIX_ATTEMPTS);
@@ -133,6 +154,26 @@ pub fn check_acceptance(stage_id: String) -> Result<()> {
     Ok(())
 }
 
+/// Print a criterion's test report counts and each failed test
+fn print_test_cases(cr: &CriterionResult) {
+    if cr.tests.is_empty() {
+        return;
+    }
+    let count = |status| cr.tests.iter().filter(|t| t.status == status).count();
+    println!(
+        "Tests: {} passed, {} failed, {} skipped",
+        count(TestStatus::Passed),
+        count(TestStatus::Failed),
+        count(TestStatus::Skipped)
+    );
+    for test in cr.failed_tests() {
+        println!("  ✗ {}", test.name);
+        for line in test.message.iter().flat_map(|m| m.lines()) {
+            println!("      {line}");
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -146,7 +187,7 @@ mod tests {
             id: id.to_string(),
             name: "Test Stage".to_string(),
             status,
-            acceptance: vec!["echo test".to_string()],
+            acceptance: vec!["echo test".into()],
             worktree: Some(id.to_string()),
             ..Stage::default()
         }
@@ -232,7 +273,7 @@ mod tests {
         std::fs::create_dir_all(&worktree_dir).unwrap();
 
         let mut stage = create_test_stage("test-stage", StageStatus::Executing);
-        stage.acceptance = vec!["false".to_string()]; // always fails
+        stage.acceptance = vec!["false".into()]; // always fails
         stage.working_dir = Some(".".to_string());
         stage.fix_attempts = 0;
         save_stage(&stage, &work_dir).unwrap();
@@ -249,6 +290,18 @@ mod tests {
         // Reload stage and check fix_attempts was incremented
         let reloaded = load_stage("test-stage", &work_dir).unwrap();
         assert_eq!(reloaded.fix_attempts, 1);
+        let failure = reloaded.failure_info.unwrap();
+        assert_eq!(
+            failure.evidence,
+            vec!["Command 'false' failed with exit code Some(1)"]
+        );
+
+        // The failed run is recorded in the stage's history
+        let history = crate::fs::criteria_history::load_history("test-stage", &work_dir).unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].attempt, 1);
+        assert_eq!(history[0].command, "false");
+        assert!(!history[0].success);
     }
 
     #[test]
@@ -272,4 +325,39 @@ mod tests {
         // Empty acceptance check exits early before worktree resolution
         assert!(result.is_ok());
     }
+
+    #[test]
+    #[serial]
+    fn test_check_acceptance_interpolates_dependency_outputs() {
+        let temp_dir = TempDir::new().unwrap();
+        let work_dir = temp_dir.path().join(".work");
+        std::fs::create_dir_all(work_dir.join("stages")).unwrap();
+        std::fs::create_dir_all(temp_dir.path().join(".worktrees/test-stage")).unwrap();
+
+        let mut detect = create_test_stage("detect", StageStatus::Completed);
+        detect.outputs = vec![crate::models::stage::StageOutput {
+            key: "engine".to_string(),
+            value: serde_json::json!("postgres"),
+            description: String::new(),
+        }];
+        save_stage(&detect, &work_dir).unwrap();
+        let mut stage = create_test_stage("test-stage", StageStatus::Executing);
+        stage.acceptance = vec![r#"test "${outputs.detect.engine}" = postgres"#.into()];
+        stage.working_dir = Some(".".to_string());
+        save_stage(&stage, &work_dir).unwrap();
+
+        let original_dir = std::env::current_dir().unwrap();
+        std::env::set_current_dir(temp_dir.path()).unwrap();
+
+        let passed = check_acceptance("test-stage".to_string());
+        stage.acceptance = vec!["echo ${outputs.detect.port}".into()];
+        save_stage(&stage, &work_dir).unwrap();
+        let unresolved = check_acceptance("test-stage".to_string());
+
+        std::env::set_current_dir(original_dir).unwrap();
+
+        assert!(passed.is_ok(), "{passed:?}");
+        let err = format!("{:#}", unresolved.unwrap_err());
+        assert!(err.contains("${outputs.detect.port}"), "{err}");
+    }
 }
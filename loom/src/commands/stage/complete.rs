@@ -12,10 +12,12 @@ use crate::models::stage::{StageStatus, StageType};
 use crate::plan::parser::{parse_plan, ParsedPlan};
 use crate::plan::schema::{ChangeImpactConfig, ChangeImpactPolicy};
 use crate::verify::baseline::compare_to_baseline;
+use crate::verify::criteria::AcceptanceResult;
 use crate::verify::transitions::{load_stage, save_stage, trigger_dependents};
 
 use super::acceptance_runner::{
-    resolve_stage_execution_paths, run_acceptance_with_display, AcceptanceDisplayOptions,
+    acceptance_failure_info, resolve_stage_execution_paths, run_acceptance_with_display,
+    AcceptanceDisplayOptions,
 };
 use super::knowledge_complete::complete_knowledge_stage;
 use super::progressive_complete::complete_with_merge;
@@ -93,13 +95,21 @@ pub fn complete(
     sync_worktree_permissions(&working_dir, &acceptance_dir);
 
     // Run acceptance criteria phase
-    let acceptance_result =
-        run_acceptance_phase(&stage, &stage_id, no_verify, acceptance_dir.as_deref())?;
+    let acceptance_result = run_acceptance_phase(
+        &stage,
+        &stage_id,
+        no_verify,
+        acceptance_dir.as_deref(),
+        work_dir,
+    )?;
 
     // Handle acceptance failure - keep stage in Executing, agent can fix and retry
     // Do NOT transition state - stage stays Executing so agent can fix and re-run
     // Do NOT clean up session - agent is still alive
-    if acceptance_result == Some(false) {
+    if let Some(result) = acceptance_result.filter(|r| !r.all_passed()) {
+        // Keep the failed tests on the stage for status, diagnosis and recovery
+        stage.failure_info = Some(acceptance_failure_info(&result));
+        save_stage(&stage, work_dir)?;
         eprintln!("Acceptance criteria FAILED for stage '{stage_id}'");
         eprintln!("  Fix the issues and run 'loom stage complete {stage_id}' again");
         anyhow::bail!("Acceptance criteria failed for stage '{stage_id}'");
@@ -226,15 +236,16 @@ fn sync_worktree_permissions(working_dir: &Option<PathBuf>, acceptance_dir: &Opt
 
 /// Run acceptance criteria phase
 ///
-/// Returns Some(true) if criteria passed, Some(false) if failed, None if skipped.
+/// Returns the acceptance result, or None if skipped.
 fn run_acceptance_phase(
     stage: &crate::models::stage::Stage,
     stage_id: &str,
     no_verify: bool,
     acceptance_dir: Option<&Path>,
-) -> Result<Option<bool>> {
+    work_dir: &Path,
+) -> Result<Option<AcceptanceResult>> {
     // Track whether acceptance criteria passed (None = skipped via --no-verify)
-    let acceptance_result: Option<bool> = if no_verify {
+    let acceptance_result: Option<AcceptanceResult> = if no_verify {
         // --no-verify means we skip criteria entirely (deliberate skip)
         None
     } else {
@@ -242,6 +253,7 @@ fn run_acceptance_phase(
             stage,
             stage_id,
             acceptance_dir,
+            work_dir,
             AcceptanceDisplayOptions {
                 stage_label: Some("stage"),
                 show_empty_message: false,
@@ -0,0 +1,195 @@
+//! Acceptance and verification run history
+//!
+//! Records each run under `.work/history/` and browses it with
+//! `loom stage history <id>`.
+
+use anyhow::Result;
+use chrono::Utc;
+use colored::Colorize;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::fs::criteria_history::{
+    append_history, flaky_commands, load_history, next_attempt, truncate_output, HistoryRecord,
+    RunKind,
+};
+use crate::git::working_tree_hash;
+use crate::verify::criteria::AcceptanceResult;
+use crate::verify::goal_backward::GoalBackwardResult;
+
+/// Record an acceptance run; failing to record only warns.
+///
+/// Criteria skipped by the result cache did not run and are left out.
+pub(crate) fn record_acceptance_run(
+    stage_id: &str,
+    result: &AcceptanceResult,
+    dir: Option<&Path>,
+    work_dir: &Path,
+) {
+    let records = |attempt, state: Option<String>| {
+        result
+            .results()
+            .iter()
+            .filter(|cr| !cr.cached)
+            .map(|cr| HistoryRecord {
+                attempt,
+                kind: RunKind::Acceptance,
+                timestamp: Utc::now(),
+                source_state: state.clone(),
+                command: cr.command.clone(),
+                success: cr.success,
+                exit_code: cr.exit_code,
+                duration_ms: cr.duration.as_millis() as u64,
+                timed_out: cr.timed_out,
+                stdout: truncate_output(&cr.stdout),
+                stderr: truncate_output(&cr.stderr),
+                failed_tests: cr.failed_tests().cloned().collect(),
+            })
+            .collect::<Vec<_>>()
+    };
+    record_run(stage_id, dir, work_dir, records);
+}
+
+/// Record a goal-backward verification run as one record listing its gaps
+pub(crate) fn record_goal_run(
+    stage_id: &str,
+    result: &GoalBackwardResult,
+    duration: Duration,
+    dir: &Path,
+    work_dir: &Path,
+) {
+    let gaps: Vec<String> = result
+        .gaps()
+        .iter()
+        .map(|gap| format!("{:?}: {}", gap.gap_type, gap.description))
+        .collect();
+    let records = |attempt, state| {
+        vec![HistoryRecord {
+            attempt,
+            kind: RunKind::GoalBackward,
+            timestamp: Utc::now(),
+            source_state: state,
+            command: "goal-backward verification".to_string(),
+            success: result.is_passed(),
+            exit_code: None,
+            duration_ms: duration.as_millis() as u64,
+            timed_out: false,
+            stdout: truncate_output(&gaps.join("\n")),
+            stderr: String::new(),
+            failed_tests: Vec::new(),
+        }]
+    };
+    record_run(stage_id, Some(dir), work_dir, records);
+}
+
+fn record_run(
+    stage_id: &str,
+    dir: Option<&Path>,
+    work_dir: &Path,
+    records: impl FnOnce(u32, Option<String>) -> Vec<HistoryRecord>,
+) {
+    let result = next_attempt(stage_id, work_dir).and_then(|attempt| {
+        let records = records(attempt, source_state(dir.unwrap_or(Path::new("."))));
+        append_history(stage_id, &records, work_dir)
+    });
+    if let Err(e) = result {
+        eprintln!("Warning: Failed to record run history for '{stage_id}': {e:#}");
+    }
+}
+
+/// Hash of `dir`'s worktree contents, untracked files included; the
+/// criteria cache keys passing results by the same hash
+fn source_state(dir: &Path) -> Option<String> {
+    working_tree_hash(dir).ok()
+}
+
+/// Show a stage's acceptance and verification history
+pub fn history(stage_id: String, attempt: Option<u32>, verbose: bool) -> Result<()> {
+    let work_dir = Path::new(".work");
+    let records = load_history(&stage_id, work_dir)?;
+    if records.is_empty() {
+        println!("No acceptance history for stage '{stage_id}'.");
+        return Ok(());
+    }
+
+    let flaky = flaky_commands(&records);
+    let mut attempts: Vec<u32> = records.iter().map(|r| r.attempt).collect();
+    attempts.dedup();
+    if let Some(only) = attempt {
+        attempts.retain(|a| *a == only);
+        if attempts.is_empty() {
+            anyhow::bail!("Stage '{stage_id}' has no attempt {only}");
+        }
+    }
+
+    println!("History for stage '{stage_id}':");
+    for number in attempts {
+        let run: Vec<&HistoryRecord> = records.iter().filter(|r| r.attempt == number).collect();
+        let Some(first) = run.first() else {
+            continue;
+        };
+        println!(
+            "\n{} {}  {}  {}",
+            format!("Attempt {number}").bold(),
+            first.kind,
+            first.timestamp.format("%Y-%m-%d %H:%M:%S"),
+            first
+                .source_state
+                .as_deref()
+                .map_or("unknown state", |state| &state[..state.len().min(12)])
+                .dimmed()
+        );
+        for record in run {
+            print_record(record, flaky.contains(&record.command), verbose);
+        }
+    }
+
+    if !flaky.is_empty() {
+        println!(
+            "\n{}",
+            "Flaky (passed and failed on the same code):".yellow()
+        );
+        for command in &flaky {
+            println!("  {command}");
+        }
+    }
+    Ok(())
+}
+
+fn print_record(record: &HistoryRecord, flaky: bool, verbose: bool) {
+    let outcome = if record.timed_out {
+        "TIMEOUT".red()
+    } else if record.success {
+        "passed".green()
+    } else {
+        match record.exit_code {
+            Some(code) => format!("exit {code}").red(),
+            None => "failed".red(),
+        }
+    };
+    let flaky_label = if flaky {
+        " [flaky]".yellow()
+    } else {
+        "".normal()
+    };
+    println!(
+        "  {}  ({outcome}, {:.1}s){flaky_label}",
+        record.command,
+        record.duration_ms as f64 / 1000.0
+    );
+    for test in &record.failed_tests {
+        println!("    {} {}", "✗".red(), test.summary());
+    }
+
+    if verbose {
+        for (label, output) in [("stdout", &record.stdout), ("stderr", &record.stderr)] {
+            if output.is_empty() {
+                continue;
+            }
+            println!("    {label}:");
+            for line in output.lines() {
+                println!("      {}", line.dimmed());
+            }
+        }
+    }
+}
@@ -10,7 +10,8 @@ use crate::models::stage::StageType;
 use crate::verify::transitions::{load_stage, save_stage, trigger_dependents};
 
 use super::acceptance_runner::{
-    resolve_knowledge_acceptance_dir, run_acceptance_with_display, AcceptanceDisplayOptions,
+    acceptance_failure_info, resolve_knowledge_acceptance_dir, run_acceptance_with_display,
+    AcceptanceDisplayOptions,
 };
 use super::session::cleanup_session_resources;
 
@@ -79,7 +80,7 @@ pub fn complete_knowledge_stage(
     }
 
     // Run acceptance criteria unless --no-verify
-    let acceptance_result: Option<bool> = if no_verify {
+    let acceptance_result = if no_verify {
         None
     } else {
         let acceptance_dir = resolve_knowledge_acceptance_dir(&stage)?;
@@ -87,6 +88,7 @@ pub fn complete_knowledge_stage(
             &stage,
             stage_id,
             acceptance_dir.as_deref(),
+            work_dir,
             AcceptanceDisplayOptions {
                 stage_label: Some("knowledge stage"),
                 show_empty_message: false,
@@ -95,7 +97,10 @@ pub fn complete_knowledge_stage(
     };
 
     // Handle acceptance failure - keep stage in Executing, agent can fix and retry
-    if acceptance_result == Some(false) {
+    if let Some(result) = acceptance_result.filter(|r| !r.all_passed()) {
+        // Keep the failed tests on the stage for status, diagnosis and recovery
+        stage.failure_info = Some(acceptance_failure_info(&result));
+        save_stage(&stage, work_dir)?;
         eprintln!("Acceptance criteria FAILED for knowledge stage '{stage_id}'");
         eprintln!("  Fix the issues and run 'loom stage complete {stage_id}' again");
         anyhow::bail!("Acceptance criteria failed for knowledge stage '{stage_id}'");
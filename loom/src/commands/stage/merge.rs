@@ -0,0 +1,35 @@
+//! Merge command - merge a stage through the running orchestrator
+//! Usage: loom stage merge <stage-id>
+
+use anyhow::Result;
+use std::path::Path;
+
+use super::retry_merge::retry_merge;
+use crate::commands::common::send_to_daemon;
+use crate::models::stage::StageStatus;
+use crate::orchestrator::control::StageCommand;
+
+/// Merge a completed, merge-conflict or merge-blocked stage now
+///
+/// With a running daemon the orchestrator performs the merge from the repo
+/// root and spawns a resolution session on conflicts. Without one this falls
+/// back to `loom stage retry-merge`, which must be run from the stage worktree.
+pub fn merge(stage_id: String) -> Result<()> {
+    let work_dir = Path::new(".work");
+
+    let Some(status) = send_to_daemon(work_dir, &stage_id, StageCommand::Merge)? else {
+        return retry_merge(Some(stage_id));
+    };
+
+    match status {
+        StageStatus::Completed => println!("Stage '{stage_id}' merged"),
+        StageStatus::MergeConflict => {
+            println!("Stage '{stage_id}' has merge conflicts - a resolution session was started")
+        }
+        other => {
+            println!("Stage '{stage_id}' was not merged (status: {other})");
+            println!("See the daemon log for details: .work/orchestrator.log");
+        }
+    }
+    Ok(())
+}
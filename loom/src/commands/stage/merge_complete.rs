@@ -7,7 +7,7 @@
 use anyhow::{bail, Context, Result};
 use std::path::Path;
 
-use crate::git::get_conflicting_files;
+use crate::git::{get_branch_head, get_conflicting_files};
 use crate::models::stage::StageStatus;
 use crate::verify::transitions::{load_stage, save_stage, trigger_dependents};
 
@@ -51,6 +51,11 @@ pub fn merge_complete(stage_id: String) -> Result<()> {
         );
     }
 
+    // Squash and rebase resolutions land new commits; verify against HEAD
+    if stage.merge_strategy.unwrap_or_default().rewrites_commits() {
+        stage.completed_commit = get_branch_head("HEAD", &repo_root).ok();
+    }
+
     // Transition to Completed with merged=true
     stage.try_complete_merge()?;
     save_stage(&stage, work_dir)?;
@@ -11,9 +11,9 @@ use crate::git::branch::branch_name_for_stage;
 use crate::models::session::Session;
 use crate::models::stage::{Stage, StageStatus};
 use crate::orchestrator::continuation::save_session;
+use crate::orchestrator::models::model_for_session;
 use crate::orchestrator::signals::generate_merge_signal;
-use crate::orchestrator::terminal::native::NativeBackend;
-use crate::orchestrator::terminal::TerminalBackend;
+use crate::orchestrator::terminal::{configured_backend_type, create_backend};
 
 /// Result of attempting to spawn a merge resolver session.
 pub enum MergeResolverResult {
@@ -60,14 +60,15 @@ pub fn spawn_merge_resolver(
     }
 
     // Create terminal backend for spawning
-    let backend =
-        NativeBackend::new(work_dir.to_path_buf()).context("Failed to create terminal backend")?;
+    let backend = create_backend(configured_backend_type(work_dir), work_dir)
+        .context("Failed to create terminal backend")?;
 
     // Get the source branch name for this stage
     let source_branch = branch_name_for_stage(&stage.id);
 
     // Create a merge resolution session
-    let session = Session::new_merge(source_branch.clone(), merge_point.to_string());
+    let mut session = Session::new_merge(source_branch.clone(), merge_point.to_string());
+    session.model = model_for_session(work_dir, repo_root, stage, &session.session_type);
     let session_id = session.id.clone();
 
     // Generate the merge signal file
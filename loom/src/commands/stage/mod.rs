@@ -1,13 +1,16 @@
 //! Stage state manipulation
-//! Usage: loom stage <id> [complete|block|reset|ready|merge-complete|recover|verify|check-acceptance]
+//! Usage: loom stage <id> [complete|block|reset|ready|merge-complete|recover|verify|check-acceptance|history|cache]
 
 pub(crate) mod acceptance_runner;
+mod cache;
 mod check_acceptance;
 mod complete;
 mod criteria_runner;
 mod dispute_criteria;
+pub(crate) mod history;
 mod human_review;
 mod knowledge_complete;
+mod merge;
 mod merge_complete;
 mod merge_resolver;
 mod output;
@@ -23,10 +26,13 @@ mod verify;
 mod tests;
 
 // Re-export public API
+pub use cache::{clear as cache_clear, list as cache_list};
 pub use check_acceptance::check_acceptance;
 pub use complete::complete;
 pub use dispute_criteria::dispute_criteria;
+pub use history::history;
 pub use human_review::human_review;
+pub use merge::merge;
 pub use merge_complete::merge_complete;
 pub use output::{
     get as output_get, list as output_list, remove as output_remove, set as output_set,
@@ -3,9 +3,11 @@
 //! Allows stages to emit structured outputs that can be consumed by dependent stages.
 
 use anyhow::{bail, Result};
+use serde::Serialize;
 use serde_json::Value;
 use std::path::Path;
 
+use crate::commands::common::OutputFormat;
 use crate::models::stage::StageOutput;
 use crate::verify::transitions::{load_stage, save_stage};
 
@@ -61,12 +63,27 @@ pub fn set(
     Ok(())
 }
 
+/// Stage outputs as reported by `loom stage output list --format json|yaml`
+#[derive(Debug, Clone, Serialize)]
+pub struct OutputList {
+    pub stage_id: String,
+    pub outputs: Vec<StageOutput>,
+}
+
 /// List all outputs for a stage.
-pub fn list(stage_id: String) -> Result<()> {
+pub fn list(stage_id: String, format: OutputFormat) -> Result<()> {
     let work_dir = Path::new(".work");
 
     let stage = load_stage(&stage_id, work_dir)?;
 
+    if !format.is_text() {
+        let list = OutputList {
+            stage_id,
+            outputs: stage.outputs,
+        };
+        return format.print("stage-outputs", &list);
+    }
+
     if stage.outputs.is_empty() {
         println!("No outputs for stage '{stage_id}'");
         return Ok(());
@@ -8,7 +8,7 @@ use std::path::Path;
 
 use crate::git::branch::branch_name_for_stage;
 use crate::git::cleanup::{cleanup_after_merge, CleanupConfig};
-use crate::git::get_branch_head;
+use crate::git::merged_commit;
 use crate::models::stage::Stage;
 use crate::orchestrator::{get_merge_point, merge_completed_stage, ProgressiveMergeResult};
 use crate::verify::transitions::save_stage;
@@ -39,27 +39,28 @@ pub fn attempt_progressive_merge(
 ) -> Result<MergeOutcome> {
     let merge_point = get_merge_point(work_dir)?;
 
-    // Capture the completed commit SHA before merge (the HEAD of the stage branch)
-    let branch_name = branch_name_for_stage(&stage.id);
-    let completed_commit = get_branch_head(&branch_name, repo_root).ok();
+    let strategy = stage.merge_strategy.unwrap_or_default();
 
     println!("Attempting progressive merge into '{merge_point}'...");
     match merge_completed_stage(stage, repo_root, &merge_point) {
-        Ok(ProgressiveMergeResult::Success { files_changed }) => {
-            println!("  ✓ Merged {files_changed} file(s) into '{merge_point}'");
-            stage.completed_commit = completed_commit;
+        Ok(ProgressiveMergeResult::Success {
+            files_changed,
+            strategy,
+        }) => {
+            println!("  ✓ Merged {files_changed} file(s) into '{merge_point}' ({strategy})");
+            stage.completed_commit = merged_commit(&stage.id, &merge_point, strategy, repo_root);
             stage.merged = true;
             Ok(MergeOutcome::Success)
         }
         Ok(ProgressiveMergeResult::FastForward) => {
             println!("  ✓ Fast-forward merge into '{merge_point}'");
-            stage.completed_commit = completed_commit;
+            stage.completed_commit = merged_commit(&stage.id, &merge_point, strategy, repo_root);
             stage.merged = true;
             Ok(MergeOutcome::Success)
         }
         Ok(ProgressiveMergeResult::AlreadyMerged) => {
             println!("  ✓ Already up to date with '{merge_point}'");
-            stage.completed_commit = completed_commit;
+            stage.completed_commit = merged_commit(&stage.id, &merge_point, strategy, repo_root);
             stage.merged = true;
             Ok(MergeOutcome::Success)
         }
@@ -68,8 +69,11 @@ pub fn attempt_progressive_merge(
             stage.merged = true;
             Ok(MergeOutcome::Success)
         }
-        Ok(ProgressiveMergeResult::Conflict { conflicting_files }) => {
-            println!("  ✗ Merge conflict detected!");
+        Ok(ProgressiveMergeResult::Conflict {
+            conflicting_files,
+            strategy,
+        }) => {
+            println!("  ✗ Merge conflict detected ({strategy})!");
             println!("    Conflicting files:");
             for file in &conflicting_files {
                 println!("      - {file}");
@@ -146,7 +150,8 @@ pub fn complete_with_merge(stage: &mut Stage, repo_root: &Path, work_dir: &Path)
             let cleanup_config = CleanupConfig {
                 verbose: true,
                 force_worktree_removal: false,
-                force_branch_deletion: false,
+                // Squashed and rebased branches never show as merged to git
+                force_branch_deletion: stage.merge_strategy.unwrap_or_default().rewrites_commits(),
                 prune_worktrees: true,
             };
 
@@ -256,6 +256,7 @@ mod tests {
             dependencies: vec![],
             parallel_group: None,
             acceptance: vec![],
+            parallel: None,
             setup: vec![],
             files: vec![],
             stage_type: StageType::default(),
@@ -274,6 +275,9 @@ mod tests {
             attempt_started_at: None,
             close_reason: None,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
+            overlap_hold: None,
             working_dir: Some(".".to_string()),
             retry_count: 0,
             max_retries: None,
@@ -286,8 +290,15 @@ mod tests {
             completed_commit: None,
             merged: false,
             merge_conflict: false,
+            condition_skipped: false,
             verification_status: Default::default(),
             context_budget: None,
+            timeout: None,
+            budget: None,
+            model: None,
+            model_escalation: 0,
+            for_each: None,
+            when: None,
             truths: Vec::new(),
             artifacts: Vec::new(),
             wiring: Vec::new(),
@@ -303,6 +314,7 @@ mod tests {
             review_reason: None,
             bug_fix: None,
             regression_test: None,
+            usage: Default::default(),
         };
 
         // No reason - should be Manual
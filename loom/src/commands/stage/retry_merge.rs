@@ -10,8 +10,9 @@ use std::path::Path;
 
 use crate::commands::common::detect_stage_id;
 use crate::git::branch::branch_name_for_stage;
-use crate::git::{default_branch, merge_stage, MergeResult};
+use crate::git::{default_branch, merge_stage, merged_commit, MergeResult};
 use crate::models::stage::StageStatus;
+use crate::orchestrator::progressive_merge::merge_options;
 use crate::verify::transitions::{load_stage, save_stage, trigger_dependents};
 
 /// Re-attempt merge for a stage in MergeConflict or MergeBlocked status.
@@ -110,16 +111,19 @@ pub fn retry_merge(stage_id: Option<String>) -> Result<()> {
     println!("Merging {branch_name} into {target_branch}...");
 
     // Attempt the merge
-    let merge_result = merge_stage(&stage_id, &target_branch, &repo_root, work_dir);
+    let options = merge_options(&stage, &target_branch, &repo_root, work_dir);
+    let merge_result = merge_stage(&stage_id, &target_branch, &repo_root, work_dir, &options);
 
     match merge_result {
         Ok(MergeResult::Success {
             files_changed,
             insertions,
             deletions,
+            strategy,
         }) => {
-            println!("Merge successful!");
+            println!("Merge successful ({strategy})!");
             println!("  {files_changed} files changed, +{insertions} -{deletions}");
+            stage.completed_commit = merged_commit(&stage_id, &target_branch, strategy, &repo_root);
 
             // Clear merge conflict flag and mark as completed+merged
             stage.merge_conflict = false;
@@ -146,6 +150,8 @@ pub fn retry_merge(stage_id: Option<String>) -> Result<()> {
 
         Ok(MergeResult::FastForward) => {
             println!("Fast-forward merge completed!");
+            stage.completed_commit =
+                merged_commit(&stage_id, &target_branch, options.strategy, &repo_root);
 
             stage.merge_conflict = false;
             stage.try_complete_merge()?;
@@ -168,6 +174,8 @@ pub fn retry_merge(stage_id: Option<String>) -> Result<()> {
 
         Ok(MergeResult::AlreadyUpToDate) => {
             println!("Branch is already up to date with {target_branch}.");
+            stage.completed_commit =
+                merged_commit(&stage_id, &target_branch, options.strategy, &repo_root);
 
             stage.merge_conflict = false;
             stage.try_complete_merge()?;
@@ -185,7 +193,9 @@ pub fn retry_merge(stage_id: Option<String>) -> Result<()> {
             }
         }
 
-        Ok(MergeResult::Conflict { conflicting_files }) => {
+        Ok(MergeResult::Conflict {
+            conflicting_files, ..
+        }) => {
             // Save the incremented fix_attempts
             save_stage(&stage, work_dir)?;
 
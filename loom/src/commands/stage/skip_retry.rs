@@ -3,15 +3,22 @@
 use anyhow::{bail, Result};
 use std::path::Path;
 
-use crate::models::stage::StageStatus;
+use crate::commands::common::send_to_daemon;
+use crate::orchestrator::control::StageCommand;
+use crate::orchestrator::retry::retry_stage;
 use crate::orchestrator::skip::skip_stage;
-use crate::verify::transitions::{load_stage, save_stage};
+use crate::verify::transitions::load_stage;
 
 /// Skip a stage
 pub fn skip(stage_id: String, reason: Option<String>) -> Result<()> {
     let work_dir = Path::new(".work");
 
-    skip_stage(&stage_id, reason.clone(), work_dir)?;
+    let command = StageCommand::Skip {
+        reason: reason.clone(),
+    };
+    if send_to_daemon(work_dir, &stage_id, command)?.is_none() {
+        skip_stage(&stage_id, reason.clone(), work_dir)?;
+    }
 
     println!("Stage '{stage_id}' skipped.");
     if let Some(r) = reason {
@@ -23,10 +30,18 @@ pub fn skip(stage_id: String, reason: Option<String>) -> Result<()> {
 }
 
 /// Retry a stage that is blocked, completed with failures, or merge-blocked
+///
+/// When the daemon is running its orchestrator applies the retry and knows
+/// exactly which sessions are live; otherwise the stage file is edited directly.
 pub fn retry(stage_id: String, force: bool) -> Result<()> {
     let work_dir = Path::new(".work");
 
-    let mut stage = load_stage(&stage_id, work_dir)?;
+    if send_to_daemon(work_dir, &stage_id, StageCommand::Retry { force })?.is_some() {
+        print_retry_queued(&stage_id, force);
+        return Ok(());
+    }
+
+    let stage = load_stage(&stage_id, work_dir)?;
 
     // Defense-in-depth: check for active session to prevent parallel session spawning
     if let Some(ref session_id) = stage.session {
@@ -47,47 +62,15 @@ pub fn retry(stage_id: String, force: bool) -> Result<()> {
         }
     }
 
-    // Allow retry for Blocked, CompletedWithFailures, and MergeBlocked states
-    let retryable = matches!(
-        stage.status,
-        StageStatus::Blocked | StageStatus::CompletedWithFailures | StageStatus::MergeBlocked
-    );
-
-    if !retryable {
-        bail!(
-            "Cannot retry stage in status: {}. Only blocked, completed-with-failures, or merge-blocked stages can be retried.",
-            stage.status
-        );
-    }
-
-    let max = stage.max_retries.unwrap_or(3);
-    if !force && stage.retry_count >= max {
-        bail!(
-            "Stage '{}' has exceeded retry limit ({}/{}). Use --force to override.",
-            stage_id,
-            stage.retry_count,
-            max
-        );
-    }
-
-    // Reset or increment for retry
-    if force {
-        stage.retry_count = 0;
-        stage.failure_info = None;
-    } else {
-        // Increment retry count for non-forced retries
-        // This ensures retry limit is enforced for manual retry attempts
-        stage.retry_count += 1;
-    }
-    stage.last_failure_at = None;
-    stage.try_mark_queued()?;
+    retry_stage(&stage_id, force, work_dir)?;
 
-    save_stage(&stage, work_dir)?;
+    print_retry_queued(&stage_id, force);
+    Ok(())
+}
 
+fn print_retry_queued(stage_id: &str, force: bool) {
     println!("Stage '{stage_id}' queued for retry.");
     if force {
         println!("Retry count reset (--force used).");
     }
-
-    Ok(())
 }
@@ -3,7 +3,10 @@
 use anyhow::Result;
 use std::path::Path;
 
+use crate::commands::common::send_to_daemon;
 use crate::models::stage::StageStatus;
+use crate::orchestrator::control::StageCommand;
+use crate::orchestrator::reset::reset_stage;
 use crate::verify::transitions::{load_stage, save_stage};
 
 /// Block a stage with a reason
@@ -23,46 +26,20 @@ pub fn block(stage_id: String, reason: String) -> Result<()> {
 
 /// Reset a stage to pending
 ///
-/// NOTE: This is a manual recovery command that intentionally bypasses state machine validation.
-/// WaitingForDeps has no incoming transitions because it's the initial state. For recovery scenarios,
-/// we allow direct assignment to reset stages to their initial state.
-pub fn reset(stage_id: String, hard: bool, _kill_session: bool) -> Result<()> {
+/// When the daemon is running the reset is applied by its orchestrator, which
+/// can also kill the stage's session; otherwise the stage file is edited directly.
+pub fn reset(stage_id: String, hard: bool, kill_session: bool) -> Result<()> {
     let work_dir = Path::new(".work");
+    let mode = if hard { "hard" } else { "soft" };
 
-    let mut stage = load_stage(&stage_id, work_dir)?;
-
-    // INTENTIONAL STATE MACHINE BYPASS: WaitingForDeps is the initial state
-    // and has no valid incoming transitions. This manual recovery command
-    // allows resetting stages to their initial state for recovery scenarios.
-    eprintln!(
-        "Warning: Bypassing state machine to reset stage to initial state (was: {:?})",
-        stage.status
-    );
-    stage.status = StageStatus::WaitingForDeps;
-
-    // Clear completion state
-    stage.completed_at = None;
-    stage.close_reason = None;
-
-    // Clear timing fields
-    stage.started_at = None;
-    stage.duration_secs = None;
-
-    // Clear retry state
-    stage.retry_count = 0;
-    stage.last_failure_at = None;
-    stage.failure_info = None;
-
-    stage.updated_at = chrono::Utc::now();
-
-    // Hard reset also clears session assignment
-    if hard {
-        stage.session = None;
+    let command = StageCommand::Reset { hard, kill_session };
+    if let Some(status) = send_to_daemon(work_dir, &stage_id, command)? {
+        println!("Stage '{stage_id}' reset to {status} ({mode} reset)");
+        return Ok(());
     }
 
-    save_stage(&stage, work_dir)?;
+    reset_stage(&stage_id, hard, work_dir)?;
 
-    let mode = if hard { "hard" } else { "soft" };
     println!("Stage '{stage_id}' reset to pending ({mode} reset)");
     Ok(())
 }
@@ -129,6 +106,12 @@ pub fn resume_from_waiting(stage_id: String) -> Result<()> {
 pub fn hold(stage_id: String) -> Result<()> {
     let work_dir = Path::new(".work");
 
+    if send_to_daemon(work_dir, &stage_id, StageCommand::Hold)?.is_some() {
+        println!("Stage '{stage_id}' held");
+        println!("The stage will not auto-execute. Use 'loom stage release {stage_id}' to unlock.");
+        return Ok(());
+    }
+
     let mut stage = load_stage(&stage_id, work_dir)?;
 
     if stage.held {
@@ -148,6 +131,11 @@ pub fn hold(stage_id: String) -> Result<()> {
 pub fn release(stage_id: String) -> Result<()> {
     let work_dir = Path::new(".work");
 
+    if send_to_daemon(work_dir, &stage_id, StageCommand::Release)?.is_some() {
+        println!("Stage '{stage_id}' released");
+        return Ok(());
+    }
+
     let mut stage = load_stage(&stage_id, work_dir)?;
 
     if !stage.held {
@@ -13,7 +13,7 @@ fn test_complete_with_passing_acceptance() {
     let work_dir_path = temp_dir.path().join(".work");
 
     let mut stage = create_test_stage("test-stage", StageStatus::Executing);
-    stage.acceptance = vec!["exit 0".to_string()];
+    stage.acceptance = vec!["exit 0".into()];
     save_test_stage(&work_dir_path, &stage);
 
     let original_dir = std::env::current_dir().unwrap();
@@ -37,7 +37,7 @@ fn test_complete_with_no_verify_flag() {
     let work_dir_path = temp_dir.path().join(".work");
 
     let mut stage = create_test_stage("test-stage", StageStatus::Executing);
-    stage.acceptance = vec!["exit 1".to_string()];
+    stage.acceptance = vec!["exit 1".into()];
     save_test_stage(&work_dir_path, &stage);
 
     let original_dir = std::env::current_dir().unwrap();
@@ -91,7 +91,7 @@ fn test_complete_knowledge_stage_with_passing_acceptance() {
     // Create a knowledge stage with passing acceptance criteria
     let mut stage = create_test_stage("knowledge-stage", StageStatus::Executing);
     stage.stage_type = StageType::Knowledge;
-    stage.acceptance = vec!["exit 0".to_string()];
+    stage.acceptance = vec!["exit 0".into()];
     save_test_stage(&work_dir_path, &stage);
 
     let original_dir = std::env::current_dir().unwrap();
@@ -117,7 +117,7 @@ fn test_complete_knowledge_stage_with_failing_acceptance() {
     // Create a knowledge stage with failing acceptance criteria
     let mut stage = create_test_stage("knowledge-stage", StageStatus::Executing);
     stage.stage_type = StageType::Knowledge;
-    stage.acceptance = vec!["exit 1".to_string()];
+    stage.acceptance = vec!["exit 1".into()];
     save_test_stage(&work_dir_path, &stage);
 
     let original_dir = std::env::current_dir().unwrap();
@@ -138,6 +138,8 @@ fn test_complete_knowledge_stage_with_failing_acceptance() {
     assert_eq!(loaded_stage.status, StageStatus::Executing);
     // merged should NOT be set when acceptance fails
     assert!(!loaded_stage.merged);
+    // The failed criterion is recorded like in the standard completion path
+    assert!(loaded_stage.failure_info.is_some());
 }
 
 #[test]
@@ -187,7 +189,7 @@ fn test_complete_standard_stage_not_routed_to_knowledge() {
 
     // Create a standard stage (default stage_type)
     let mut stage = create_test_stage("standard-stage", StageStatus::Executing);
-    stage.acceptance = vec!["exit 0".to_string()];
+    stage.acceptance = vec!["exit 0".into()];
     // Ensure it's explicitly standard (default)
     stage.stage_type = StageType::Standard;
     save_test_stage(&work_dir_path, &stage);
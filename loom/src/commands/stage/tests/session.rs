@@ -62,6 +62,8 @@ fn test_session_to_markdown() {
         session_type: SessionType::default(),
         merge_source_branch: None,
         merge_target_branch: None,
+        model: None,
+        usage: None,
     };
 
     let content = session_to_markdown(&session);
@@ -155,6 +157,8 @@ fn test_cleanup_session_resources() {
         session_type: SessionType::default(),
         merge_source_branch: None,
         merge_target_branch: None,
+        model: None,
+        usage: None,
     };
 
     let session_content = session_to_markdown(&session);
@@ -8,7 +8,8 @@ use crate::models::stage::{StageStatus, StageType};
 use crate::verify::transitions::{load_stage, save_stage, trigger_dependents};
 
 use super::acceptance_runner::{
-    resolve_stage_execution_paths, run_acceptance_with_display, AcceptanceDisplayOptions,
+    acceptance_failure_info, resolve_stage_execution_paths, run_acceptance_with_display,
+    AcceptanceDisplayOptions,
 };
 use super::criteria_runner::reload_acceptance_from_plan;
 use super::progressive_complete::attempt_progressive_merge;
@@ -58,6 +59,7 @@ pub fn verify(stage_id: String, no_reload: bool) -> Result<()> {
         &stage,
         &stage_id,
         acceptance_dir.as_deref(),
+        work_dir,
         AcceptanceDisplayOptions {
             stage_label: Some("stage"),
             show_empty_message: true,
@@ -65,11 +67,13 @@ pub fn verify(stage_id: String, no_reload: bool) -> Result<()> {
     )?;
 
     // Handle acceptance failure
-    if !acceptance_result {
+    if !acceptance_result.all_passed() {
         // If stage is Executing, keep it Executing (don't transition to CompletedWithFailures)
         // If stage is already CompletedWithFailures, save updated criteria only
         if stage.status == StageStatus::CompletedWithFailures {
-            // Save any updated acceptance criteria (from plan reload) without state change
+            // Save any updated acceptance criteria (from plan reload) and the
+            // latest failures without state change
+            stage.failure_info = Some(acceptance_failure_info(&acceptance_result));
             save_stage(&stage, work_dir)?;
         }
         // If Executing, don't save or transition - just bail
@@ -175,7 +179,7 @@ mod tests {
             id: id.to_string(),
             name: "Test Stage".to_string(),
             status,
-            acceptance: vec!["echo test".to_string()],
+            acceptance: vec!["echo test".into()],
             worktree: Some(id.to_string()),
             ..Stage::default()
         }
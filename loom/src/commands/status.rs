@@ -7,9 +7,10 @@ pub mod render;
 pub mod ui;
 mod validation;
 
+use crate::commands::common::OutputFormat;
 use crate::daemon::DaemonServer;
 use crate::fs::work_dir::WorkDir;
-use anyhow::Result;
+use anyhow::{bail, Result};
 use colored::Colorize;
 
 use diagnostics::{check_directory_structure, check_parsing_errors};
@@ -17,12 +18,21 @@ use display::{count_files, display_stages, display_worktrees};
 use validation::{validate_markdown_files, validate_references};
 
 /// Show the status dashboard with context health
-pub fn execute(live: bool, compact: bool, verbose: bool) -> Result<()> {
+pub fn execute(live: bool, compact: bool, verbose: bool, format: OutputFormat) -> Result<()> {
     let work_dir = WorkDir::new(".")?;
     work_dir.load()?;
 
     let work_path = work_dir.root();
 
+    // Structured output: a snapshot of the same data the dashboard renders
+    if !format.is_text() {
+        if live {
+            bail!("--live cannot be combined with --format {format}");
+        }
+        let status_data = data::collect_status_data(&work_dir)?;
+        return format.print("status", &status_data);
+    }
+
     // Compact mode: single-line output for scripting
     if compact {
         return execute_compact(&work_dir);
@@ -84,6 +94,7 @@ fn execute_static(work_dir: &WorkDir, verbose: bool) -> Result<()> {
 
     // Show progress bar with stage counts
     render::render_progress(&mut out, &status_data.progress)?;
+    render::render_usage(&mut out, &status_data.usage)?;
 
     let stage_count = count_files(&work_dir.stages_dir())?;
 
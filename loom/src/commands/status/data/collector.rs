@@ -3,16 +3,20 @@ use chrono::Utc;
 use std::fs;
 
 use crate::commands::status::merge_status::build_merge_report;
+use crate::fs::criteria_history::stage_flaky_commands;
 use crate::fs::work_dir::WorkDir;
 use crate::models::constants::STALENESS_THRESHOLD_SECS;
 use crate::models::session::{Session, SessionStatus};
 use crate::models::stage::{Stage, StageStatus};
 use crate::orchestrator::get_merge_point;
 use crate::orchestrator::monitor::heartbeat::{read_heartbeat, Heartbeat};
+use crate::orchestrator::pricing::PriceTable;
 use crate::parser::frontmatter::parse_from_markdown;
 use crate::verify::transitions::list_all_stages;
 
-use super::{ActivityStatus, MergeSummary, ProgressSummary, StageSummary, StatusData};
+use super::{
+    ActivityStatus, MergeSummary, ProgressSummary, StageSummary, StatusData, UsageSummary,
+};
 
 #[cfg(test)]
 use super::SessionSummary;
@@ -94,7 +98,12 @@ fn load_session_from_file(path: &std::path::Path) -> Result<Session> {
 }
 
 /// Build a StageSummary from a Stage and optional associated Session
-fn build_stage_summary(stage: &Stage, sessions: &[Session], work_dir: &WorkDir) -> StageSummary {
+fn build_stage_summary(
+    stage: &Stage,
+    sessions: &[Session],
+    prices: &PriceTable,
+    work_dir: &WorkDir,
+) -> StageSummary {
     let session = sessions
         .iter()
         .find(|s| s.stage_id.as_ref() == Some(&stage.id));
@@ -142,6 +151,19 @@ fn build_stage_summary(stage: &Stage, sessions: &[Session], work_dir: &WorkDir)
         staleness_secs,
         context_budget_pct: None, // TODO: Read from plan if needed
         review_reason: stage.review_reason.clone(),
+        tokens: stage.total_usage(),
+        cost_usd: prices.sessions_cost(stage.usage.values()),
+        model: session.and_then(|s| s.model.clone()),
+        flaky_criteria: stage_flaky_commands(&stage.id, work_dir.root()),
+        overlap_hold: stage.overlap_hold.clone(),
+    }
+}
+
+/// Sum token usage and cost over stage summaries
+fn calculate_usage(stages: &[StageSummary]) -> UsageSummary {
+    UsageSummary {
+        tokens: stages.iter().map(|s| &s.tokens).sum(),
+        cost_usd: stages.iter().map(|s| s.cost_usd).sum(),
     }
 }
 
@@ -214,10 +236,12 @@ pub fn collect_status_data(work_dir: &WorkDir) -> Result<StatusData> {
     let sessions = load_all_sessions(work_dir)?;
 
     // Build stage summaries
+    let prices = PriceTable::load(work_dir.root())?;
     let stage_summaries: Vec<StageSummary> = stages
         .iter()
-        .map(|stage| build_stage_summary(stage, &sessions, work_dir))
+        .map(|stage| build_stage_summary(stage, &sessions, &prices, work_dir))
         .collect();
+    let usage = calculate_usage(&stage_summaries);
 
     // Get merge point for merge report
     let merge_point = if let Some(project_root) = work_dir.project_root() {
@@ -242,6 +266,7 @@ pub fn collect_status_data(work_dir: &WorkDir) -> Result<StatusData> {
         stages: stage_summaries,
         merge: merge_summary,
         progress,
+        usage,
     })
 }
 
@@ -260,6 +285,7 @@ mod tests {
             dependencies: vec![],
             parallel_group: None,
             acceptance: vec![],
+            parallel: None,
             setup: vec![],
             files: vec![],
             stage_type: StageType::default(),
@@ -278,6 +304,9 @@ mod tests {
             attempt_started_at: None,
             close_reason: None,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
+            overlap_hold: None,
             working_dir: Some(".".to_string()),
             retry_count: 0,
             max_retries: None,
@@ -290,8 +319,15 @@ mod tests {
             completed_commit: None,
             merged: false,
             merge_conflict: false,
+            condition_skipped: false,
             verification_status: Default::default(),
             context_budget: None,
+            timeout: None,
+            budget: None,
+            model: None,
+            model_escalation: 0,
+            for_each: None,
+            when: None,
             truths: Vec::new(),
             artifacts: Vec::new(),
             wiring: Vec::new(),
@@ -307,6 +343,7 @@ mod tests {
             review_reason: None,
             bug_fix: None,
             regression_test: None,
+            usage: Default::default(),
         }
     }
 
@@ -369,7 +406,7 @@ mod tests {
         session.context_tokens = 50000;
         session.context_limit = 200000;
 
-        let summary = build_stage_summary(&stage, &[session], &work_dir);
+        let summary = build_stage_summary(&stage, &[session], &PriceTable::default(), &work_dir);
 
         assert_eq!(summary.id, "test-stage");
         assert_eq!(summary.status, StageStatus::Executing);
@@ -390,7 +427,7 @@ mod tests {
 
         let stage = make_test_stage("test-stage", StageStatus::WaitingForDeps);
 
-        let summary = build_stage_summary(&stage, &[], &work_dir);
+        let summary = build_stage_summary(&stage, &[], &PriceTable::default(), &work_dir);
 
         assert_eq!(summary.id, "test-stage");
         assert_eq!(summary.status, StageStatus::WaitingForDeps);
@@ -401,6 +438,39 @@ mod tests {
         assert_eq!(summary.activity_status, ActivityStatus::Idle);
     }
 
+    #[test]
+    fn test_stage_summary_usage_and_cost() {
+        use crate::models::usage::{SessionUsage, TokenUsage};
+
+        let tmp = tempfile::TempDir::new().unwrap();
+        let work_dir = WorkDir::new(tmp.path()).unwrap();
+        work_dir.initialize().unwrap();
+
+        let usage = |model: &str| SessionUsage {
+            model: Some(model.to_string()),
+            tokens: TokenUsage {
+                input_tokens: 1_000_000,
+                ..TokenUsage::default()
+            },
+        };
+        let mut priced = make_test_stage("priced", StageStatus::Completed);
+        priced.record_session_usage("s1", usage("claude-sonnet-4-5"));
+        priced.record_session_usage("s2", usage("claude-sonnet-4-5"));
+        let mut unpriced = make_test_stage("unpriced", StageStatus::Executing);
+        unpriced.record_session_usage("s3", usage("custom-model"));
+
+        let prices = PriceTable::default();
+        let priced = build_stage_summary(&priced, &[], &prices, &work_dir);
+        assert_eq!(priced.tokens.input_tokens, 2_000_000);
+        assert_eq!(priced.cost_usd, Some(6.0));
+
+        let unpriced = build_stage_summary(&unpriced, &[], &prices, &work_dir);
+        let usage = calculate_usage(&[priced.clone(), unpriced]);
+        assert_eq!(usage.tokens.input_tokens, 3_000_000);
+        assert_eq!(usage.cost_usd, None);
+        assert_eq!(calculate_usage(&[priced]).cost_usd, Some(6.0));
+    }
+
     #[test]
     fn test_build_session_summary() {
         let mut session = Session::new();
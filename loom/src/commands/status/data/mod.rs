@@ -6,7 +6,9 @@ use serde::{Deserialize, Serialize};
 
 // Re-export types that consumers will need
 pub use crate::models::failure::FailureInfo;
+pub use crate::models::stage::OverlapHold;
 pub use crate::models::stage::StageStatus;
+pub use crate::models::usage::TokenUsage;
 
 /// Activity status derived from heartbeat and session state
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
@@ -50,6 +52,9 @@ pub struct StatusData {
     pub stages: Vec<StageSummary>,
     pub merge: MergeSummary,
     pub progress: ProgressSummary,
+    /// Token usage and cost across all stages
+    #[serde(default)]
+    pub usage: UsageSummary,
 }
 
 /// Stage display data
@@ -78,6 +83,21 @@ pub struct StageSummary {
     pub context_budget_pct: Option<f32>,
     /// Reason the stage was flagged for human review
     pub review_reason: Option<String>,
+    /// Token usage across all sessions of the stage
+    #[serde(default)]
+    pub tokens: TokenUsage,
+    /// Cost of that usage in USD (None if a model has no configured price)
+    #[serde(default)]
+    pub cost_usd: Option<f64>,
+    /// Claude model of the stage's current session (None = claude CLI default)
+    #[serde(default)]
+    pub model: Option<String>,
+    /// Acceptance commands that passed and failed on the same code
+    #[serde(default)]
+    pub flaky_criteria: Vec<String>,
+    /// In-flight stages a ready stage is waiting on because they overlap it
+    #[serde(default)]
+    pub overlap_hold: Option<OverlapHold>,
 }
 
 /// Session display data (test-only: production code uses SessionInfo in display/stages.rs)
@@ -110,3 +130,11 @@ pub struct ProgressSummary {
     pub pending: usize,
     pub blocked: usize,
 }
+
+/// Token usage and cost totals
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UsageSummary {
+    pub tokens: TokenUsage,
+    /// Total cost in USD (None if any usage could not be priced)
+    pub cost_usd: Option<f64>,
+}
@@ -22,6 +22,7 @@ pub enum ActivityType {
     MergeStarted,
     MergeCompleted,
     MergeConflict,
+    ConfigChanged,
 }
 
 /// A single activity entry
@@ -59,6 +60,7 @@ impl ActivityEntry {
             ActivityType::MergeStarted => ("⟳", |s: &str| s.yellow().to_string()),
             ActivityType::MergeCompleted => ("✓", |s: &str| s.green().to_string()),
             ActivityType::MergeConflict => ("⚡", |s: &str| s.red().to_string()),
+            ActivityType::ConfigChanged => ("⚙", |s: &str| s.yellow().to_string()),
         };
 
         format!(
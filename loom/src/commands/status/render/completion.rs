@@ -10,6 +10,8 @@ use colored::Colorize;
 use crate::commands::common::truncate;
 use crate::daemon::{CompletionSummary, StageCompletionInfo};
 use crate::models::stage::StageStatus;
+use crate::models::usage::format_tokens;
+use crate::orchestrator::pricing::format_cost;
 use crate::utils::format_elapsed;
 
 /// Get stage duration - prefer execution_secs (excludes wait time) over duration_secs (wall clock)
@@ -48,6 +50,23 @@ fn build_duration_display(stage: &StageCompletionInfo) -> String {
     }
 }
 
+/// Build the token usage line for a completion summary (None if nothing was recorded)
+pub fn usage_display(summary: &CompletionSummary) -> Option<String> {
+    if summary.tokens.is_empty() {
+        return None;
+    }
+    let cost = summary
+        .cost_usd
+        .map(format_cost)
+        .unwrap_or_else(|| "cost unknown".to_string());
+    Some(format!(
+        "Tokens: {} in | {} out | {} cached | {cost}",
+        format_tokens(summary.tokens.input_tokens),
+        format_tokens(summary.tokens.output_tokens),
+        format_tokens(summary.tokens.cache_read_tokens + summary.tokens.cache_creation_tokens),
+    ))
+}
+
 /// Render the completion screen to stdout
 pub fn render_completion_screen(summary: &CompletionSummary) {
     // Clear screen
@@ -83,6 +102,9 @@ pub fn render_completion_screen(summary: &CompletionSummary) {
         "\u{2717}".red(),
         summary.failure_count
     );
+    if let Some(usage) = usage_display(summary) {
+        println!("{usage}");
+    }
 
     // Stage table header
     println!("\n{}", "Stage Results".bold());
@@ -159,6 +181,7 @@ pub fn render_completion_lines(summary: &CompletionSummary) -> Vec<String> {
         "Total: {} | \u{2713} {} | \u{2717} {}",
         total_time, summary.success_count, summary.failure_count
     ));
+    lines.extend(usage_display(summary));
 
     // Stage table
     lines.push(String::new());
@@ -209,6 +232,8 @@ mod tests {
             retry_count: 0,
             merged: completed,
             dependencies: vec![],
+            tokens: Default::default(),
+            cost_usd: None,
         }
     }
 
@@ -223,6 +248,8 @@ mod tests {
             success_count: 2,
             failure_count: 0,
             plan_path: "doc/plans/PLAN-test.md".to_string(),
+            tokens: Default::default(),
+            cost_usd: None,
         };
 
         let lines = render_completion_lines(&summary);
@@ -243,10 +270,35 @@ mod tests {
             success_count: 1,
             failure_count: 1,
             plan_path: "doc/plans/PLAN-test.md".to_string(),
+            tokens: Default::default(),
+            cost_usd: None,
         };
 
         let lines = render_completion_lines(&summary);
         assert!(lines.iter().any(|l| l.contains("with failures")));
         assert!(lines.iter().any(|l| l.contains("failing")));
+        assert!(!lines.iter().any(|l| l.starts_with("Tokens:")));
+    }
+
+    #[test]
+    fn test_render_completion_lines_with_usage() {
+        let summary = CompletionSummary {
+            stages: vec![make_stage_info("bootstrap", StageStatus::Completed, true)],
+            total_duration_secs: 60,
+            success_count: 1,
+            failure_count: 0,
+            plan_path: "doc/plans/PLAN-test.md".to_string(),
+            tokens: crate::models::usage::TokenUsage {
+                input_tokens: 12_345,
+                output_tokens: 900,
+                ..Default::default()
+            },
+            cost_usd: Some(0.25),
+        };
+
+        let lines = render_completion_lines(&summary);
+        assert!(lines
+            .iter()
+            .any(|l| l == "Tokens: 12.3k in | 900 out | 0 cached | $0.25"));
     }
 }
@@ -194,6 +194,9 @@ pub fn render_graph<W: Write>(w: &mut W, data: &StatusData) -> std::io::Result<(
                 let elapsed = format_elapsed(secs);
                 write!(w, " {}", elapsed.dimmed())?;
             }
+            if let Some(model) = &stage.model {
+                write!(w, " {}", model.dimmed())?;
+            }
 
             // Show activity status for executing stages
             let activity_icon = stage.activity_status.icon();
@@ -207,6 +210,11 @@ pub fn render_graph<W: Write>(w: &mut W, data: &StatusData) -> std::io::Result<(
             }
         }
 
+        if !stage.flaky_criteria.is_empty() {
+            let flaky = format!(" [{} flaky]", stage.flaky_criteria.len());
+            write!(w, "{}", flaky.yellow())?;
+        }
+
         writeln!(w)?;
 
         // Increment index for this level
@@ -218,6 +226,17 @@ pub fn render_graph<W: Write>(w: &mut W, data: &StatusData) -> std::io::Result<(
                 writeln!(w, "{base_info}")?;
             }
         }
+
+        // Show which in-flight stages a ready stage is serialized behind
+        if let Some(hold) = &stage.overlap_hold {
+            writeln!(
+                w,
+                "  {} {} {}",
+                "Waiting on overlap:".dimmed(),
+                hold.stages.join(", ").yellow(),
+                format!("({})", hold.files.join(", ")).dimmed()
+            )?;
+        }
     }
 
     writeln!(w)?;
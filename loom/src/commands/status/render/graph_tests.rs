@@ -19,6 +19,11 @@ fn make_stage_summary(id: &str, deps: Vec<&str>, status: StageStatus) -> StageSu
         staleness_secs: None,
         context_budget_pct: None,
         review_reason: None,
+        tokens: Default::default(),
+        cost_usd: None,
+        model: None,
+        flaky_criteria: Vec::new(),
+        overlap_hold: None,
     }
 }
 
@@ -37,6 +42,7 @@ fn make_status_data(stages: Vec<StageSummary>) -> StatusData {
             pending: 0,
             blocked: 0,
         },
+        usage: Default::default(),
     }
 }
 
@@ -106,6 +112,7 @@ fn test_render_graph_with_context() {
     let mut stage = make_stage_summary("executing", vec![], StageStatus::Executing);
     stage.context_pct = Some(0.45);
     stage.elapsed_secs = Some(120);
+    stage.model = Some("claude-haiku-4-5".to_string());
 
     let data = make_status_data(vec![stage]);
     let mut output = Vec::new();
@@ -113,6 +120,24 @@ fn test_render_graph_with_context() {
     let output_str = String::from_utf8(output).unwrap();
     assert!(output_str.contains("45%"));
     assert!(output_str.contains("2m0s"));
+    assert!(output_str.contains("claude-haiku-4-5"));
+}
+
+#[test]
+fn test_render_graph_with_overlap_hold() {
+    let mut stage = make_stage_summary("routes", vec![], StageStatus::Queued);
+    stage.overlap_hold = Some(crate::models::stage::OverlapHold {
+        stages: vec!["api".to_string()],
+        files: vec!["src/api/routes.rs".to_string()],
+    });
+
+    let data = make_status_data(vec![stage]);
+    let mut output = Vec::new();
+    render_graph(&mut output, &data).unwrap();
+    let output_str = String::from_utf8(output).unwrap();
+    assert!(output_str.contains("Waiting on overlap:"));
+    assert!(output_str.contains("api"));
+    assert!(output_str.contains("(src/api/routes.rs)"));
 }
 
 #[test]
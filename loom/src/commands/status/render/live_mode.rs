@@ -234,6 +234,18 @@ impl LiveMode {
             Response::OrchestrationComplete { summary } => {
                 self.completion_summary = Some(summary);
             }
+            Response::ConfigUpdated { config } => {
+                let max_parallel = config
+                    .max_parallel
+                    .map_or_else(|| "default".to_string(), |n| n.to_string());
+                self.activity.push(
+                    ActivityType::ConfigChanged,
+                    format!(
+                        "Config changed: max-parallel {max_parallel}, auto-merge {}",
+                        config.auto_merge
+                    ),
+                );
+            }
             Response::Error { message } => {
                 eprintln!("\n{}", format!("Daemon error: {message}").red());
                 self.running.store(false, Ordering::SeqCst);
@@ -8,13 +8,15 @@ pub mod merge;
 pub mod progress;
 pub mod spinner;
 pub mod summary;
+pub mod usage;
 
 pub use activity::{render_activity_status, render_staleness_warning};
 pub use attention::render_attention;
 pub use compact::render_compact;
-pub use completion::{render_completion_lines, render_completion_screen};
+pub use completion::{render_completion_lines, render_completion_screen, usage_display};
 pub use graph::render_graph;
 pub use live_mode::run_live_mode;
 pub use merge::render_merge_status;
 pub use progress::{render_context_bar, render_progress};
 pub use summary::print_completion_summary;
+pub use usage::render_usage;
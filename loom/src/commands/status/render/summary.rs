@@ -38,6 +38,9 @@ pub fn print_completion_summary(summary: &CompletionSummary) {
     let success_count = summary.success_count;
     let failure = summary.failure_count;
     println!("Total: {total_time} | \u{2713} {success_count} | \u{2717} {failure}");
+    if let Some(usage) = super::usage_display(summary) {
+        println!("{usage}");
+    }
     println!();
 
     // Build and display execution graph
@@ -0,0 +1,61 @@
+//! Token usage and cost line for the status dashboard
+
+use colored::Colorize;
+use std::io::Write;
+
+use crate::commands::status::data::UsageSummary;
+use crate::models::usage::format_tokens;
+use crate::orchestrator::pricing::format_cost;
+
+/// Render plan-wide token usage and cost
+/// Shows: Tokens: 1.20M in | 85.3k out | 4.10M cache read | 310.0k cache write | $4.12
+pub fn render_usage<W: Write>(w: &mut W, usage: &UsageSummary) -> std::io::Result<()> {
+    if usage.tokens.is_empty() {
+        return Ok(());
+    }
+
+    let tokens = &usage.tokens;
+    write!(
+        w,
+        "Tokens: {} in | {} out | {} cache read | {} cache write",
+        format_tokens(tokens.input_tokens),
+        format_tokens(tokens.output_tokens),
+        format_tokens(tokens.cache_read_tokens),
+        format_tokens(tokens.cache_creation_tokens),
+    )?;
+
+    match usage.cost_usd {
+        Some(cost) => write!(w, " | {}", format_cost(cost).bold())?,
+        None => write!(w, " | {}", "cost unknown (unpriced model)".dimmed())?,
+    }
+
+    writeln!(w)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::usage::TokenUsage;
+
+    #[test]
+    fn test_render_usage() {
+        colored::control::set_override(false);
+        let mut out = Vec::new();
+        render_usage(&mut out, &UsageSummary::default()).unwrap();
+        assert!(out.is_empty());
+
+        let usage = UsageSummary {
+            tokens: TokenUsage {
+                input_tokens: 1_200_000,
+                output_tokens: 85_300,
+                ..TokenUsage::default()
+            },
+            cost_usd: Some(4.12),
+        };
+        render_usage(&mut out, &usage).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert!(text.contains("1.20M in | 85.3k out"));
+        assert!(text.contains("$4.12"));
+    }
+}
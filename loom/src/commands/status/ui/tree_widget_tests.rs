@@ -12,6 +12,7 @@ fn make_stage(id: &str, deps: Vec<&str>, status: StageStatus) -> Stage {
         dependencies: deps.into_iter().map(String::from).collect(),
         parallel_group: None,
         acceptance: vec![],
+        parallel: None,
         setup: vec![],
         files: vec![],
         stage_type: Default::default(),
@@ -30,6 +31,9 @@ fn make_stage(id: &str, deps: Vec<&str>, status: StageStatus) -> Stage {
         attempt_started_at: None,
         close_reason: None,
         auto_merge: None,
+        merge_strategy: None,
+        overlap: None,
+        overlap_hold: None,
         working_dir: None,
         retry_count: 0,
         max_retries: None,
@@ -42,8 +46,15 @@ fn make_stage(id: &str, deps: Vec<&str>, status: StageStatus) -> Stage {
         completed_commit: None,
         merged: false,
         merge_conflict: false,
+        condition_skipped: false,
         verification_status: Default::default(),
         context_budget: None,
+        timeout: None,
+        budget: None,
+        model: None,
+        model_escalation: 0,
+        for_each: None,
+        when: None,
         truths: Vec::new(),
         artifacts: Vec::new(),
         wiring: Vec::new(),
@@ -59,6 +70,7 @@ fn make_stage(id: &str, deps: Vec<&str>, status: StageStatus) -> Stage {
         review_reason: None,
         bug_fix: None,
         regression_test: None,
+        usage: Default::default(),
     }
 }
 
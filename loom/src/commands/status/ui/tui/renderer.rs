@@ -71,6 +71,7 @@ pub fn unified_stage_to_stage(us: &UnifiedStage) -> Stage {
         dependencies: us.dependencies.clone(),
         parallel_group: None,
         acceptance: vec![],
+        parallel: None,
         setup: vec![],
         files: vec![],
         stage_type: Default::default(),
@@ -92,6 +93,9 @@ pub fn unified_stage_to_stage(us: &UnifiedStage) -> Stage {
         attempt_started_at: None,
         close_reason: None,
         auto_merge: None,
+        merge_strategy: None,
+        overlap: None,
+        overlap_hold: None,
         working_dir: None,
         retry_count: 0,
         max_retries: None,
@@ -104,8 +108,15 @@ pub fn unified_stage_to_stage(us: &UnifiedStage) -> Stage {
         completed_commit: None,
         merged: us.merged,
         merge_conflict: false,
+        condition_skipped: false,
         verification_status: Default::default(),
         context_budget: None,
+        timeout: None,
+        budget: None,
+        model: None,
+        model_escalation: 0,
+        for_each: None,
+        when: None,
         truths: vec![],
         artifacts: vec![],
         wiring: vec![],
@@ -121,6 +132,7 @@ pub fn unified_stage_to_stage(us: &UnifiedStage) -> Stage {
         review_reason: None,
         bug_fix: None,
         regression_test: None,
+        usage: Default::default(),
     }
 }
 
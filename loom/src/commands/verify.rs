@@ -8,14 +8,17 @@
 use anyhow::{Context, Result};
 use colored::Colorize;
 use std::path::Path;
+use std::time::Instant;
 
 use crate::commands::stage::acceptance_runner::{
     resolve_stage_execution_paths, run_acceptance_with_display, AcceptanceDisplayOptions,
 };
+use crate::commands::stage::history::record_goal_run;
 use crate::fs::work_dir::load_config_required;
 use crate::plan::parser::parse_plan;
 use crate::plan::schema::StageDefinition;
 use crate::verify::goal_backward::{run_goal_backward_verification, GoalBackwardResult};
+use crate::verify::outputs::interpolate_definition_outputs;
 use crate::verify::transitions::load_stage;
 
 /// Execute the verify command
@@ -62,11 +65,13 @@ pub fn execute(stage_id: &str, suggest: bool) -> Result<()> {
         &stage,
         stage_id,
         acceptance_dir.as_deref(),
+        work_dir,
         AcceptanceDisplayOptions {
             stage_label: None,
             show_empty_message: false,
         },
-    )?;
+    )?
+    .all_passed();
 
     // 2. Run goal-backward verification
     if stage_def.has_any_goal_checks() {
@@ -160,20 +165,31 @@ pub fn run_and_verify_stage_goal(
     let plan = parse_plan(&plan_path)
         .with_context(|| format!("Failed to parse plan: {}", plan_path.display()))?;
 
-    let stage_def = plan
+    let mut stage_def = plan
         .stages
-        .iter()
+        .into_iter()
         .find(|s| s.id == stage_id)
         .with_context(|| format!("Stage '{stage_id}' not found in plan"))?;
+    interpolate_definition_outputs(&mut stage_def, work_dir)?;
 
     // Run goal-backward verification
-    run_goal_backward_verification(stage_def, verification_dir)
+    let started = Instant::now();
+    let result = run_goal_backward_verification(&stage_def, verification_dir)?;
+    record_goal_run(
+        stage_id,
+        &result,
+        started.elapsed(),
+        verification_dir,
+        work_dir,
+    );
+    Ok(result)
 }
 
 /// Load stage definition from the active plan
 ///
 /// Used by callers that need the stage definition for other purposes
 /// (e.g., checking has_any_goal_checks() before calling verification).
+/// Stage output references in its commands are already interpolated.
 pub fn load_stage_definition_from_plan(
     stage_id: &str,
     work_dir: &Path,
@@ -191,5 +207,9 @@ pub fn load_stage_definition_from_plan(
     let plan = parse_plan(&plan_path)
         .with_context(|| format!("Failed to parse plan: {}", plan_path.display()))?;
 
-    Ok(plan.stages.into_iter().find(|s| s.id == stage_id))
+    let Some(mut stage_def) = plan.stages.into_iter().find(|s| s.id == stage_id) else {
+        return Ok(None);
+    };
+    interpolate_definition_outputs(&mut stage_def, work_dir)?;
+    Ok(Some(stage_def))
 }
@@ -1,6 +1,8 @@
 //! Worktree management commands
 //! Usage: loom worktree [list|clean|remove <stage-id>]
 
+mod list;
+
 use anyhow::{bail, Context, Result};
 use colored::Colorize;
 use std::path::PathBuf;
@@ -12,35 +14,7 @@ use crate::git::worktree::find_worktree_by_prefix;
 use crate::models::stage::StageStatus;
 use crate::verify::transitions::{load_stage, parse_stage_from_markdown, save_stage};
 
-/// List all worktrees
-pub fn list() -> Result<()> {
-    println!("Git worktrees:");
-    println!("─────────────────────────────────────────────────────────");
-
-    let worktrees_dir = std::env::current_dir()?.join(".worktrees");
-    if !worktrees_dir.exists() {
-        println!("(no .worktrees/ directory)");
-        return Ok(());
-    }
-
-    if let Ok(entries) = std::fs::read_dir(&worktrees_dir) {
-        let mut found = false;
-        for entry in entries.flatten() {
-            if entry.path().is_dir() {
-                let name = entry.file_name();
-                let stage_name = name.to_string_lossy();
-                let branch = branch_name_for_stage(&stage_name);
-                println!("  {stage_name} -> {branch}");
-                found = true;
-            }
-        }
-        if !found {
-            println!("(no worktrees found)");
-        }
-    }
-
-    Ok(())
-}
+pub use list::{list, WorktreeList, WorktreeListing};
 
 /// Clean orphaned worktrees
 ///
@@ -0,0 +1,98 @@
+//! Worktree listing
+//! Usage: loom worktree list [--format json|yaml]
+
+use anyhow::Result;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+use crate::commands::common::OutputFormat;
+use crate::git::branch::branch_name_for_stage;
+
+/// Worktrees as reported by `loom worktree list --format json|yaml`
+#[derive(Debug, Clone, Serialize)]
+pub struct WorktreeList {
+    pub worktrees: Vec<WorktreeListing>,
+}
+
+/// A stage worktree under `.worktrees/`
+#[derive(Debug, Clone, Serialize)]
+pub struct WorktreeListing {
+    pub stage_id: String,
+    pub branch: String,
+    pub path: PathBuf,
+}
+
+/// Collect stage worktrees under `worktrees_dir`, sorted by stage id
+fn collect_worktrees(worktrees_dir: &Path) -> Vec<WorktreeListing> {
+    let mut worktrees: Vec<WorktreeListing> = std::fs::read_dir(worktrees_dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|entry| entry.path().is_dir())
+                .map(|entry| {
+                    let stage_id = entry.file_name().to_string_lossy().to_string();
+                    WorktreeListing {
+                        branch: branch_name_for_stage(&stage_id),
+                        path: entry.path(),
+                        stage_id,
+                    }
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    worktrees.sort_by(|a, b| a.stage_id.cmp(&b.stage_id));
+    worktrees
+}
+
+/// List all worktrees
+pub fn list(format: OutputFormat) -> Result<()> {
+    let worktrees_dir = std::env::current_dir()?.join(".worktrees");
+
+    if !format.is_text() {
+        let worktrees = collect_worktrees(&worktrees_dir);
+        return format.print("worktrees", &WorktreeList { worktrees });
+    }
+
+    println!("Git worktrees:");
+    println!("─────────────────────────────────────────────────────────");
+
+    if !worktrees_dir.exists() {
+        println!("(no .worktrees/ directory)");
+        return Ok(());
+    }
+
+    let worktrees = collect_worktrees(&worktrees_dir);
+    if worktrees.is_empty() {
+        println!("(no worktrees found)");
+    }
+    for worktree in &worktrees {
+        println!("  {} -> {}", worktree.stage_id, worktree.branch);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_collect_worktrees_sorted_dirs_only() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::create_dir(temp_dir.path().join("stage-b")).unwrap();
+        std::fs::create_dir(temp_dir.path().join("stage-a")).unwrap();
+        std::fs::write(temp_dir.path().join("README"), "not a worktree").unwrap();
+
+        let worktrees = collect_worktrees(temp_dir.path());
+        let ids: Vec<&str> = worktrees.iter().map(|w| w.stage_id.as_str()).collect();
+        assert_eq!(ids, vec!["stage-a", "stage-b"]);
+        assert_eq!(worktrees[0].branch, branch_name_for_stage("stage-a"));
+    }
+
+    #[test]
+    fn test_collect_worktrees_missing_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        assert!(collect_worktrees(&temp_dir.path().join(".worktrees")).is_empty());
+    }
+}
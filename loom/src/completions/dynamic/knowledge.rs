@@ -1,30 +1,28 @@
 //! Knowledge file completions for shell tab-completion.
 
 use anyhow::Result;
+use std::path::Path;
 
-/// Valid knowledge file names
-const KNOWLEDGE_FILES: &[&str] = &[
-    "architecture",
-    "entry-points",
-    "patterns",
-    "conventions",
-    "mistakes",
-    "stack",
-    "concerns",
-];
+use crate::fs::knowledge::{KnowledgeDir, KnowledgeFile};
 
 /// Complete knowledge file names for `loom knowledge show/update`
 ///
 /// # Arguments
 ///
+/// * `cwd` - Project root, whose knowledge manifest may declare extra files
 /// * `prefix` - Partial file name prefix to filter results
 ///
 /// # Returns
 ///
 /// List of matching knowledge file names
-pub fn complete_knowledge_files(prefix: &str) -> Result<Vec<String>> {
-    let results: Vec<String> = KNOWLEDGE_FILES
+pub fn complete_knowledge_files(cwd: &Path, prefix: &str) -> Result<Vec<String>> {
+    // An invalid manifest should not break completion of the built-in files
+    let file_types = KnowledgeDir::new(cwd)
+        .file_types()
+        .unwrap_or_else(|_| KnowledgeFile::built_in().to_vec());
+    let results: Vec<String> = file_types
         .iter()
+        .map(KnowledgeFile::name)
         .filter(|name| prefix.is_empty() || name.starts_with(prefix))
         .map(|s| s.to_string())
         .collect();
@@ -12,6 +12,7 @@ mod stages;
 #[cfg(test)]
 mod tests;
 
+use crate::orchestrator::run_settings::SETTING_KEYS;
 use anyhow::Result;
 use std::path::Path;
 
@@ -98,11 +99,20 @@ pub fn complete_dynamic(ctx: &CompletionContext) -> Result<()> {
             complete_stage_ids(cwd, prefix)?
         }
 
+        // Config set setting names
+        "set" if ctx.cmdline.contains("config") => SETTING_KEYS
+            .iter()
+            .filter(|key| key.starts_with(prefix.as_str()))
+            .map(|key| key.to_string())
+            .collect(),
+
         // Worktree remove (must come before general stage commands)
         "remove" if ctx.cmdline.contains("worktree") => complete_stage_ids(cwd, prefix)?,
 
         // Knowledge show/update file completions (must come before general stage commands)
-        "show" | "update" if ctx.cmdline.contains("knowledge") => complete_knowledge_files(prefix)?,
+        "show" | "update" if ctx.cmdline.contains("knowledge") => {
+            complete_knowledge_files(cwd, prefix)?
+        }
 
         // Memory --stage flag completion
         "--stage" if ctx.cmdline.contains("memory") => complete_stage_ids(cwd, prefix)?,
@@ -112,6 +122,27 @@ pub fn complete_dynamic(ctx: &CompletionContext) -> Result<()> {
             complete_memory_entry_types(prefix)?
         }
 
+        // Memory promote <entry-type|all> <target>
+        "promote" if ctx.cmdline.contains("memory") => {
+            let mut types = complete_memory_entry_types(prefix)?;
+            if "all".starts_with(prefix.as_str()) {
+                types.push("all".to_string());
+            }
+            types
+        }
+        "note" | "decision" | "question" | "all" if ctx.cmdline.contains("promote") => {
+            complete_knowledge_files(cwd, prefix)?
+        }
+
+        // Search filters
+        "--type" | "-t" if ctx.cmdline.contains("search") => complete_memory_entry_types(prefix)?,
+        "--source" if ctx.cmdline.contains("search") => ["memory", "handoff", "knowledge"]
+            .iter()
+            .filter(|source| source.starts_with(prefix.as_str()))
+            .map(|source| source.to_string())
+            .collect(),
+        "--stage" | "-S" if ctx.cmdline.contains("search") => complete_stage_ids(cwd, prefix)?,
+
         // Stage subcommands that take stage_id (all in one pattern)
         "complete" | "block" | "reset" | "waiting" | "hold" | "release" | "skip" | "retry"
         | "recover" | "resume" | "verify" | "merge-complete"
@@ -121,7 +152,7 @@ pub fn complete_dynamic(ctx: &CompletionContext) -> Result<()> {
         }
 
         // Top-level commands that take stage_id (verify/merge/resume outside stage context)
-        "verify" | "merge" | "resume" | "diagnose" => complete_stage_ids(cwd, prefix)?,
+        "verify" | "merge" | "resume" | "diagnose" | "attach" => complete_stage_ids(cwd, prefix)?,
 
         _ => Vec::new(),
     };
@@ -1,7 +1,8 @@
-//! Tests for other command completions (diagnose, worktree, knowledge)
+//! Tests for other command completions (diagnose, attach, worktree, knowledge)
 
 use super::super::*;
 use super::setup_test_workspace;
+use tempfile::TempDir;
 
 #[test]
 fn test_complete_dynamic_diagnose() {
@@ -19,6 +20,38 @@ fn test_complete_dynamic_diagnose() {
     assert!(complete_dynamic(&ctx).is_ok());
 }
 
+#[test]
+fn test_complete_dynamic_attach() {
+    let temp_dir = setup_test_workspace();
+    let root = temp_dir.path();
+
+    let ctx = CompletionContext {
+        cwd: root.to_string_lossy().to_string(),
+        shell: "bash".to_string(),
+        cmdline: "loom attach".to_string(),
+        current_word: "".to_string(),
+        prev_word: "attach".to_string(),
+    };
+
+    assert!(complete_dynamic(&ctx).is_ok());
+}
+
+#[test]
+fn test_complete_dynamic_config_set() {
+    let temp_dir = setup_test_workspace();
+    let root = temp_dir.path();
+
+    let ctx = CompletionContext {
+        cwd: root.to_string_lossy().to_string(),
+        shell: "bash".to_string(),
+        cmdline: "loom config set".to_string(),
+        current_word: "max".to_string(),
+        prev_word: "set".to_string(),
+    };
+
+    assert!(complete_dynamic(&ctx).is_ok());
+}
+
 #[test]
 fn test_complete_dynamic_worktree_remove() {
     let temp_dir = setup_test_workspace();
@@ -53,7 +86,7 @@ fn test_complete_dynamic_sessions_kill_stage_flag() {
 
 #[test]
 fn test_complete_knowledge_files_all() {
-    let results = complete_knowledge_files("").unwrap();
+    let results = complete_knowledge_files(Path::new("/nonexistent"), "").unwrap();
     assert_eq!(results.len(), 7);
     assert!(results.contains(&"architecture".to_string()));
     assert!(results.contains(&"entry-points".to_string()));
@@ -64,23 +97,39 @@ fn test_complete_knowledge_files_all() {
     assert!(results.contains(&"concerns".to_string()));
 }
 
+#[test]
+fn test_complete_knowledge_files_from_manifest() {
+    let temp = TempDir::new().unwrap();
+    let knowledge = temp.path().join("doc/loom/knowledge");
+    std::fs::create_dir_all(&knowledge).unwrap();
+    std::fs::write(
+        knowledge.join("index.toml"),
+        "[[files]]\nname = \"runbooks\"\ndescription = \"Operational runbooks\"\n",
+    )
+    .unwrap();
+
+    let results = complete_knowledge_files(temp.path(), "").unwrap();
+    assert_eq!(results.len(), 8);
+    assert_eq!(results.last().map(String::as_str), Some("runbooks"));
+}
+
 #[test]
 fn test_complete_knowledge_files_with_prefix() {
-    let results = complete_knowledge_files("pa").unwrap();
+    let results = complete_knowledge_files(Path::new("/nonexistent"), "pa").unwrap();
     assert_eq!(results.len(), 1);
     assert!(results.contains(&"patterns".to_string()));
 }
 
 #[test]
 fn test_complete_knowledge_files_prefix_e() {
-    let results = complete_knowledge_files("e").unwrap();
+    let results = complete_knowledge_files(Path::new("/nonexistent"), "e").unwrap();
     assert_eq!(results.len(), 1);
     assert!(results.contains(&"entry-points".to_string()));
 }
 
 #[test]
 fn test_complete_knowledge_files_no_match() {
-    let results = complete_knowledge_files("xyz").unwrap();
+    let results = complete_knowledge_files(Path::new("/nonexistent"), "xyz").unwrap();
     assert_eq!(results.len(), 0);
 }
 
@@ -173,3 +222,42 @@ fn test_complete_dynamic_memory_list_entry_type() {
 
     assert!(complete_dynamic(&ctx).is_ok());
 }
+
+#[test]
+fn test_complete_memory_promote() {
+    let temp_dir = setup_test_workspace();
+    let root = temp_dir.path();
+
+    let ctx = CompletionContext {
+        cwd: root.to_string_lossy().to_string(),
+        shell: "bash".to_string(),
+        cmdline: "loom memory promote".to_string(),
+        current_word: "".to_string(),
+        prev_word: "promote".to_string(),
+    };
+    assert!(complete_dynamic(&ctx).is_ok());
+
+    let ctx = CompletionContext {
+        cmdline: "loom memory promote decision".to_string(),
+        prev_word: "decision".to_string(),
+        ..ctx
+    };
+    assert!(complete_dynamic(&ctx).is_ok());
+}
+
+#[test]
+fn test_complete_search_filters() {
+    let temp_dir = setup_test_workspace();
+    let root = temp_dir.path();
+
+    for prev_word in ["--type", "--source", "--stage"] {
+        let ctx = CompletionContext {
+            cwd: root.to_string_lossy().to_string(),
+            shell: "bash".to_string(),
+            cmdline: format!("loom search auth {prev_word}"),
+            current_word: "".to_string(),
+            prev_word: prev_word.to_string(),
+        };
+        assert!(complete_dynamic(&ctx).is_ok());
+    }
+}
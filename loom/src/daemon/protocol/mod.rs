@@ -0,0 +1,362 @@
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+
+use crate::models::stage::StageStatus;
+use crate::models::usage::TokenUsage;
+use crate::models::worktree::WorktreeStatus;
+use crate::orchestrator::control::StageCommand;
+use crate::orchestrator::plan_update::AppliedUpdate;
+use crate::orchestrator::run_settings::ConfigChange;
+use crate::orchestrator::terminal::BackendType;
+
+/// Information about a single stage's completion status.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StageCompletionInfo {
+    /// Stage identifier
+    pub id: String,
+    /// Human-readable stage name
+    pub name: String,
+    /// Final status of the stage
+    pub status: StageStatus,
+    /// Duration in seconds from start to completion (None if never started)
+    pub duration_secs: Option<i64>,
+    /// Accumulated execution time (excludes wait/backoff time)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub execution_secs: Option<i64>,
+    /// Number of retry attempts
+    #[serde(default)]
+    pub retry_count: u32,
+    /// Whether the stage was merged
+    pub merged: bool,
+    /// Dependencies of this stage
+    #[serde(default)]
+    pub dependencies: Vec<String>,
+    /// Token usage across all sessions of the stage
+    #[serde(default)]
+    pub tokens: TokenUsage,
+    /// Cost of that usage in USD (None if a model has no configured price)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cost_usd: Option<f64>,
+}
+
+/// Summary of orchestration completion.
+///
+/// Sent to all status subscribers when the orchestrator finishes
+/// executing all stages (successfully or with failures).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompletionSummary {
+    /// Total orchestration duration in seconds
+    pub total_duration_secs: i64,
+    /// Completion info for each stage
+    pub stages: Vec<StageCompletionInfo>,
+    /// Number of successfully completed stages
+    pub success_count: usize,
+    /// Number of failed/blocked stages
+    pub failure_count: usize,
+    /// Path to the plan that was executed
+    pub plan_path: String,
+    /// Token usage across all stages
+    #[serde(default)]
+    pub tokens: TokenUsage,
+    /// Total cost in USD (None if any usage could not be priced)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cost_usd: Option<f64>,
+}
+
+/// Configuration parameters for daemon mode.
+///
+/// These parameters control how the daemon executes stages,
+/// matching the CLI flags available with `loom run`.
+///
+/// `max_parallel` and `auto_merge` can be changed while the daemon runs
+/// with `loom config set`, which sends a `SetConfig` request. The other
+/// fields are fixed until the daemon is restarted with `loom stop` and
+/// `loom run`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DaemonConfig {
+    /// Manual mode - don't auto-start stages (maps to --manual)
+    pub manual_mode: bool,
+    /// Maximum concurrent stages (maps to --max-parallel)
+    pub max_parallel: Option<usize>,
+    /// Watch mode - monitor for changes (maps to --watch)
+    pub watch_mode: bool,
+    /// Auto-merge completed stages (default: true, disable with --no-merge)
+    pub auto_merge: bool,
+    /// Terminal backend for spawning sessions (maps to --backend)
+    #[serde(default)]
+    pub backend_type: BackendType,
+}
+
+impl Default for DaemonConfig {
+    fn default() -> Self {
+        Self {
+            manual_mode: false,
+            max_parallel: None,
+            watch_mode: true,
+            auto_merge: true,
+            backend_type: BackendType::default(),
+        }
+    }
+}
+
+impl DaemonConfig {
+    /// Apply a run setting changed at runtime
+    pub fn apply(&mut self, change: ConfigChange) {
+        match change {
+            ConfigChange::MaxParallel(n) => self.max_parallel = Some(n),
+            ConfigChange::AutoMerge(enabled) => self.auto_merge = enabled,
+        }
+    }
+}
+
+/// Client request to daemon
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Request {
+    /// Subscribe to live status updates
+    SubscribeStatus { auth_token: String },
+    /// Subscribe to raw log stream
+    SubscribeLogs { auth_token: String },
+    /// Request daemon shutdown
+    Stop { auth_token: String },
+    /// Disconnect cleanly
+    Unsubscribe { auth_token: String },
+    /// Ping to check if daemon is alive
+    Ping { auth_token: String },
+    /// Hold a stage so it will not auto-execute
+    HoldStage {
+        auth_token: String,
+        stage_id: String,
+    },
+    /// Release a held stage
+    ReleaseStage {
+        auth_token: String,
+        stage_id: String,
+    },
+    /// Re-queue a failed stage (maps to `loom stage retry`)
+    RetryStage {
+        auth_token: String,
+        stage_id: String,
+        force: bool,
+    },
+    /// Skip a stage (maps to `loom stage skip`)
+    SkipStage {
+        auth_token: String,
+        stage_id: String,
+        reason: Option<String>,
+    },
+    /// Reset a stage to its initial state (maps to `loom stage reset`)
+    ResetStage {
+        auth_token: String,
+        stage_id: String,
+        hard: bool,
+        kill_session: bool,
+    },
+    /// Kill the session running for a stage
+    KillSession {
+        auth_token: String,
+        stage_id: String,
+    },
+    /// Merge a completed or merge-failed stage now
+    MergeStage {
+        auth_token: String,
+        stage_id: String,
+    },
+    /// Change a run setting live (maps to `loom config set`)
+    SetConfig {
+        auth_token: String,
+        change: ConfigChange,
+    },
+    /// Reconcile the edited plan with the stage files and reload the
+    /// execution graph (maps to `loom init --update --apply`)
+    UpdatePlan { auth_token: String },
+}
+
+impl Request {
+    /// Build the request for a stage command
+    pub fn stage_command(auth_token: String, stage_id: String, command: StageCommand) -> Self {
+        match command {
+            StageCommand::Hold => Request::HoldStage {
+                auth_token,
+                stage_id,
+            },
+            StageCommand::Release => Request::ReleaseStage {
+                auth_token,
+                stage_id,
+            },
+            StageCommand::Retry { force } => Request::RetryStage {
+                auth_token,
+                stage_id,
+                force,
+            },
+            StageCommand::Skip { reason } => Request::SkipStage {
+                auth_token,
+                stage_id,
+                reason,
+            },
+            StageCommand::Reset { hard, kill_session } => Request::ResetStage {
+                auth_token,
+                stage_id,
+                hard,
+                kill_session,
+            },
+            StageCommand::KillSession => Request::KillSession {
+                auth_token,
+                stage_id,
+            },
+            StageCommand::Merge => Request::MergeStage {
+                auth_token,
+                stage_id,
+            },
+        }
+    }
+
+    /// Get the stage ID and command if this is a stage command request
+    pub fn as_stage_command(&self) -> Option<(&str, StageCommand)> {
+        let (stage_id, command) = match self {
+            Request::HoldStage { stage_id, .. } => (stage_id, StageCommand::Hold),
+            Request::ReleaseStage { stage_id, .. } => (stage_id, StageCommand::Release),
+            Request::RetryStage {
+                stage_id, force, ..
+            } => (stage_id, StageCommand::Retry { force: *force }),
+            Request::SkipStage {
+                stage_id, reason, ..
+            } => (
+                stage_id,
+                StageCommand::Skip {
+                    reason: reason.clone(),
+                },
+            ),
+            Request::ResetStage {
+                stage_id,
+                hard,
+                kill_session,
+                ..
+            } => (
+                stage_id,
+                StageCommand::Reset {
+                    hard: *hard,
+                    kill_session: *kill_session,
+                },
+            ),
+            Request::KillSession { stage_id, .. } => (stage_id, StageCommand::KillSession),
+            Request::MergeStage { stage_id, .. } => (stage_id, StageCommand::Merge),
+            _ => return None,
+        };
+        Some((stage_id.as_str(), command))
+    }
+}
+
+/// Daemon response to client
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Response {
+    Ok,
+    Error {
+        message: String,
+    },
+    AuthenticationFailed,
+    StatusUpdate {
+        stages_executing: Vec<StageInfo>,
+        stages_pending: Vec<StageInfo>,
+        stages_completed: Vec<StageInfo>,
+        stages_blocked: Vec<StageInfo>,
+    },
+    /// Orchestration has completed (all stages terminal)
+    OrchestrationComplete {
+        summary: CompletionSummary,
+    },
+    LogLine {
+        line: String,
+    },
+    Pong,
+    /// A stage command was applied by the orchestrator
+    StageUpdated {
+        stage_id: String,
+        status: StageStatus,
+    },
+    /// A run setting was changed; sent to the requester and status subscribers
+    ConfigUpdated {
+        config: DaemonConfig,
+    },
+    /// A plan update was applied by the orchestrator
+    PlanUpdated {
+        applied: AppliedUpdate,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StageInfo {
+    pub id: String,
+    pub name: String,
+    pub session_pid: Option<u32>,
+    pub started_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub worktree_status: Option<WorktreeStatus>,
+    /// Current status of the stage in the execution lifecycle
+    pub status: StageStatus,
+    /// Whether this stage's changes have been merged to the merge point
+    #[serde(default)]
+    pub merged: bool,
+    /// IDs of stages this stage depends on
+    #[serde(default)]
+    pub dependencies: Vec<String>,
+}
+
+/// Write a length-prefixed JSON message to a stream.
+///
+/// Format: 4-byte big-endian length prefix + JSON data
+///
+/// # Arguments
+/// * `stream` - The stream to write to
+/// * `message` - The message to serialize and write
+///
+/// # Returns
+/// `Ok(())` on success, error if serialization or write fails
+pub fn write_message<T: Serialize, W: Write>(stream: &mut W, message: &T) -> Result<()> {
+    let json = serde_json::to_vec(message).context("Failed to serialize message")?;
+    let len = json.len() as u32;
+    let len_bytes = len.to_be_bytes();
+
+    stream
+        .write_all(&len_bytes)
+        .context("Failed to write message length")?;
+    stream
+        .write_all(&json)
+        .context("Failed to write message body")?;
+    stream.flush().context("Failed to flush stream")?;
+
+    Ok(())
+}
+
+/// Read a length-prefixed JSON message from a stream.
+///
+/// Format: 4-byte big-endian length prefix + JSON data
+///
+/// # Arguments
+/// * `stream` - The stream to read from
+///
+/// # Returns
+/// `Ok(T)` with the deserialized message on success, error if read or deserialization fails
+pub fn read_message<T: for<'de> Deserialize<'de>, R: Read>(stream: &mut R) -> Result<T> {
+    let mut len_bytes = [0u8; 4];
+    stream
+        .read_exact(&mut len_bytes)
+        .context("Failed to read message length")?;
+    let len = u32::from_be_bytes(len_bytes) as usize;
+
+    // Sanity check: prevent DOS via huge length claim (max 10 MB)
+    if len > 10 * 1024 * 1024 {
+        bail!("Message too large: {len} bytes");
+    }
+
+    let mut json_bytes = vec![0u8; len];
+    stream
+        .read_exact(&mut json_bytes)
+        .context("Failed to read message body")?;
+
+    serde_json::from_slice(&json_bytes).context("Failed to deserialize message")
+}
+
+#[cfg(test)]
+mod tests;
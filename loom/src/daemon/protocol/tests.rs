@@ -0,0 +1,246 @@
+//! Tests for the daemon protocol.
+
+use super::*;
+use crate::models::stage::StageStatus;
+use crate::models::worktree::WorktreeStatus;
+use crate::orchestrator::control::StageCommand;
+use std::io::Cursor;
+
+#[test]
+fn test_write_and_read_request() {
+    let mut buffer = Vec::new();
+    let request = Request::Ping {
+        auth_token: "test-token".to_string(),
+    };
+
+    write_message(&mut buffer, &request).expect("Failed to write message");
+
+    let mut cursor = Cursor::new(buffer);
+    let decoded: Request = read_message(&mut cursor).expect("Failed to read message");
+
+    match decoded {
+        Request::Ping { auth_token } => {
+            assert_eq!(auth_token, "test-token");
+        }
+        _ => panic!("Expected Ping request"),
+    }
+}
+
+#[test]
+fn test_write_and_read_response() {
+    let mut buffer = Vec::new();
+    let response = Response::Pong;
+
+    write_message(&mut buffer, &response).expect("Failed to write message");
+
+    let mut cursor = Cursor::new(buffer);
+    let decoded: Response = read_message(&mut cursor).expect("Failed to read message");
+
+    match decoded {
+        Response::Pong => {}
+        _ => panic!("Expected Pong response"),
+    }
+}
+
+#[test]
+fn test_write_and_read_status_update() {
+    let mut buffer = Vec::new();
+    let now = Utc::now();
+    let response = Response::StatusUpdate {
+        stages_executing: vec![StageInfo {
+            id: "stage-1".to_string(),
+            name: "Test Stage".to_string(),
+            session_pid: Some(12345),
+            started_at: now,
+            completed_at: None,
+            worktree_status: Some(WorktreeStatus::Active),
+            status: StageStatus::Executing,
+            merged: false,
+            dependencies: vec!["stage-0".to_string()],
+        }],
+        stages_pending: vec![StageInfo {
+            id: "stage-2".to_string(),
+            name: "Pending Stage".to_string(),
+            session_pid: None,
+            started_at: now,
+            completed_at: None,
+            worktree_status: None,
+            status: StageStatus::WaitingForDeps,
+            merged: false,
+            dependencies: vec!["stage-1".to_string()],
+        }],
+        stages_completed: vec![StageInfo {
+            id: "stage-0".to_string(),
+            name: "Completed Stage".to_string(),
+            session_pid: None,
+            started_at: now,
+            completed_at: Some(now),
+            worktree_status: None,
+            status: StageStatus::Completed,
+            merged: true,
+            dependencies: vec![],
+        }],
+        stages_blocked: vec![],
+    };
+
+    write_message(&mut buffer, &response).expect("Failed to write message");
+
+    let mut cursor = Cursor::new(buffer);
+    let decoded: Response = read_message(&mut cursor).expect("Failed to read message");
+
+    match decoded {
+        Response::StatusUpdate {
+            stages_executing, ..
+        } => {
+            assert_eq!(stages_executing.len(), 1);
+            assert_eq!(stages_executing[0].id, "stage-1");
+        }
+        _ => panic!("Expected StatusUpdate response"),
+    }
+}
+
+#[test]
+fn test_read_message_too_large() {
+    let mut buffer = Vec::new();
+    let len: u32 = 20 * 1024 * 1024; // 20 MB (exceeds 10 MB limit)
+    buffer.extend_from_slice(&len.to_be_bytes());
+
+    let mut cursor = Cursor::new(buffer);
+    let result: Result<Request> = read_message(&mut cursor);
+
+    assert!(result.is_err());
+    let err = result.unwrap_err();
+    assert!(err.to_string().contains("too large"));
+}
+
+#[test]
+fn test_daemon_config_default() {
+    let config = DaemonConfig::default();
+
+    assert!(!config.manual_mode);
+    assert!(config.max_parallel.is_none());
+    assert!(config.watch_mode);
+    assert!(config.auto_merge);
+    assert_eq!(config.backend_type, BackendType::Native);
+}
+
+#[test]
+fn test_write_and_read_orchestration_complete() {
+    use super::{CompletionSummary, StageCompletionInfo};
+
+    let mut buffer = Vec::new();
+    let response = Response::OrchestrationComplete {
+        summary: CompletionSummary {
+            total_duration_secs: 120,
+            stages: vec![
+                StageCompletionInfo {
+                    id: "stage-1".to_string(),
+                    name: "First Stage".to_string(),
+                    status: StageStatus::Completed,
+                    duration_secs: Some(60),
+                    execution_secs: None,
+                    retry_count: 0,
+                    merged: true,
+                    dependencies: vec![],
+                    tokens: Default::default(),
+                    cost_usd: None,
+                },
+                StageCompletionInfo {
+                    id: "stage-2".to_string(),
+                    name: "Second Stage".to_string(),
+                    status: StageStatus::Blocked,
+                    duration_secs: Some(45),
+                    execution_secs: None,
+                    retry_count: 0,
+                    merged: false,
+                    dependencies: vec!["stage-1".to_string()],
+                    tokens: Default::default(),
+                    cost_usd: None,
+                },
+            ],
+            success_count: 1,
+            failure_count: 1,
+            plan_path: "doc/plans/PLAN-test.md".to_string(),
+            tokens: Default::default(),
+            cost_usd: None,
+        },
+    };
+
+    write_message(&mut buffer, &response).expect("Failed to write message");
+
+    let mut cursor = Cursor::new(buffer);
+    let decoded: Response = read_message(&mut cursor).expect("Failed to read message");
+
+    match decoded {
+        Response::OrchestrationComplete { summary } => {
+            assert_eq!(summary.total_duration_secs, 120);
+            assert_eq!(summary.stages.len(), 2);
+            assert_eq!(summary.stages[0].id, "stage-1");
+            assert_eq!(summary.stages[0].status, StageStatus::Completed);
+            assert_eq!(summary.stages[1].id, "stage-2");
+            assert_eq!(summary.stages[1].status, StageStatus::Blocked);
+            assert_eq!(summary.success_count, 1);
+            assert_eq!(summary.failure_count, 1);
+            assert_eq!(summary.plan_path, "doc/plans/PLAN-test.md");
+        }
+        _ => panic!("Expected OrchestrationComplete response"),
+    }
+}
+
+#[test]
+fn test_stage_command_request_round_trip() {
+    let commands = vec![
+        StageCommand::Hold,
+        StageCommand::Release,
+        StageCommand::Retry { force: true },
+        StageCommand::Skip {
+            reason: Some("not needed".to_string()),
+        },
+        StageCommand::Reset {
+            hard: true,
+            kill_session: false,
+        },
+        StageCommand::KillSession,
+        StageCommand::Merge,
+    ];
+
+    for command in commands {
+        let request =
+            Request::stage_command("token".to_string(), "stage-1".to_string(), command.clone());
+
+        let mut buffer = Vec::new();
+        write_message(&mut buffer, &request).expect("Failed to write message");
+        let decoded: Request =
+            read_message(&mut Cursor::new(buffer)).expect("Failed to read message");
+
+        assert_eq!(decoded.as_stage_command(), Some(("stage-1", command)));
+    }
+
+    let ping = Request::Ping {
+        auth_token: "token".to_string(),
+    };
+    assert_eq!(ping.as_stage_command(), None);
+}
+
+#[test]
+fn test_set_config_round_trip_and_apply() {
+    let request = Request::SetConfig {
+        auth_token: "token".to_string(),
+        change: ConfigChange::MaxParallel(6),
+    };
+
+    let mut buffer = Vec::new();
+    write_message(&mut buffer, &request).expect("Failed to write message");
+    let decoded: Request = read_message(&mut Cursor::new(buffer)).expect("Failed to read message");
+
+    let Request::SetConfig { change, .. } = decoded else {
+        panic!("Expected SetConfig request");
+    };
+    let mut config = DaemonConfig::default();
+    config.apply(change);
+    config.apply(ConfigChange::AutoMerge(false));
+
+    assert_eq!(config.max_parallel, Some(6));
+    assert!(!config.auto_merge);
+    assert!(config.watch_mode);
+}
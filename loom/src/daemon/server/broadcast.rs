@@ -199,14 +199,17 @@ fn run_status_broadcaster(
 }
 
 /// Broadcast a response to all subscribers, removing any that fail.
-fn broadcast_to_subscribers(subscribers: &Arc<Mutex<Vec<UnixStream>>>, response: &Response) {
+pub(super) fn broadcast_to_subscribers(
+    subscribers: &Arc<Mutex<Vec<UnixStream>>>,
+    response: &Response,
+) {
     let mut subs = lock_or_recover(subscribers);
     subs.retain_mut(|stream| write_message(stream, response).is_ok());
 }
 
 /// Lock a mutex, recovering from poison if necessary.
 /// Logs a warning if the mutex was poisoned but continues with the data.
-fn lock_or_recover<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+pub(super) fn lock_or_recover<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
     mutex.lock().unwrap_or_else(|poisoned: PoisonError<_>| {
         eprintln!("Warning: mutex was poisoned (another thread panicked), recovering");
         poisoned.into_inner()
@@ -1,11 +1,21 @@
 //! Client connection handling.
 
-use super::super::protocol::{read_message, write_message, Request, Response};
+use super::super::protocol::{read_message, write_message, DaemonConfig, Request, Response};
+use super::broadcast::{broadcast_to_subscribers, lock_or_recover};
+use crate::orchestrator::control::ControlQueue;
+use crate::orchestrator::run_settings::ConfigChange;
 use anyhow::Result;
 use std::os::unix::net::UnixStream;
 use std::path::Path;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// How long a client waits for the orchestrator to apply a stage command.
+///
+/// Commands are applied between orchestrator loop steps, and a merge runs git
+/// operations, so this is deliberately generous.
+pub(crate) const STAGE_COMMAND_TIMEOUT: Duration = Duration::from_secs(60);
 
 /// Read the auth token from the daemon token file.
 pub fn read_auth_token(work_dir: &Path) -> Option<String> {
@@ -37,6 +47,8 @@ pub fn handle_client_connection(
     shutdown_flag: Arc<AtomicBool>,
     status_subscribers: Arc<Mutex<Vec<UnixStream>>>,
     log_subscribers: Arc<Mutex<Vec<UnixStream>>>,
+    control_queue: ControlQueue,
+    config: Arc<Mutex<DaemonConfig>>,
     work_dir: &Path,
 ) -> Result<()> {
     // Ensure stream is in blocking mode - on macOS, accepted streams from
@@ -60,6 +72,15 @@ pub fn handle_client_connection(
             Request::SubscribeStatus { auth_token } => (auth_token, "SubscribeStatus"),
             Request::SubscribeLogs { auth_token } => (auth_token, "SubscribeLogs"),
             Request::Unsubscribe { auth_token } => (auth_token, "Unsubscribe"),
+            Request::HoldStage { auth_token, .. } => (auth_token, "HoldStage"),
+            Request::ReleaseStage { auth_token, .. } => (auth_token, "ReleaseStage"),
+            Request::RetryStage { auth_token, .. } => (auth_token, "RetryStage"),
+            Request::SkipStage { auth_token, .. } => (auth_token, "SkipStage"),
+            Request::ResetStage { auth_token, .. } => (auth_token, "ResetStage"),
+            Request::KillSession { auth_token, .. } => (auth_token, "KillSession"),
+            Request::MergeStage { auth_token, .. } => (auth_token, "MergeStage"),
+            Request::SetConfig { auth_token, .. } => (auth_token, "SetConfig"),
+            Request::UpdatePlan { auth_token } => (auth_token, "UpdatePlan"),
         };
 
         if !verify_auth_token(work_dir, auth_token) {
@@ -133,8 +154,118 @@ pub fn handle_client_connection(
                 write_message(&mut stream, &Response::Ok)?;
                 break;
             }
+            Request::HoldStage { .. }
+            | Request::ReleaseStage { .. }
+            | Request::RetryStage { .. }
+            | Request::SkipStage { .. }
+            | Request::ResetStage { .. }
+            | Request::KillSession { .. }
+            | Request::MergeStage { .. } => {
+                let response = execute_stage_command(&control_queue, &request);
+                write_message(&mut stream, &response)?;
+            }
+            Request::SetConfig { change, .. } => {
+                let response = apply_config_change(
+                    change,
+                    &config,
+                    &control_queue,
+                    &status_subscribers,
+                    work_dir,
+                );
+                write_message(&mut stream, &response)?;
+            }
+            Request::UpdatePlan { .. } => {
+                let response = execute_plan_update(&control_queue);
+                write_message(&mut stream, &response)?;
+            }
         }
     }
 
     Ok(())
 }
+
+/// Queue a plan update for the orchestrator and wait for its outcome.
+fn execute_plan_update(control_queue: &ControlQueue) -> Response {
+    let receiver = match control_queue.submit_plan_update() {
+        Ok(receiver) => receiver,
+        Err(e) => {
+            return Response::Error {
+                message: e.to_string(),
+            }
+        }
+    };
+
+    match receiver.recv_timeout(STAGE_COMMAND_TIMEOUT) {
+        Ok(Ok(applied)) => Response::PlanUpdated { applied },
+        Ok(Err(message)) => Response::Error { message },
+        Err(_) => Response::Error {
+            message: format!(
+                "Orchestrator did not apply the plan update within {}s",
+                STAGE_COMMAND_TIMEOUT.as_secs()
+            ),
+        },
+    }
+}
+
+/// Queue a stage command for the orchestrator and wait for its outcome.
+fn execute_stage_command(control_queue: &ControlQueue, request: &Request) -> Response {
+    let Some((stage_id, command)) = request.as_stage_command() else {
+        return Response::Error {
+            message: "Not a stage command".to_string(),
+        };
+    };
+
+    let receiver = match control_queue.submit(stage_id, command) {
+        Ok(receiver) => receiver,
+        Err(e) => {
+            return Response::Error {
+                message: e.to_string(),
+            }
+        }
+    };
+
+    match receiver.recv_timeout(STAGE_COMMAND_TIMEOUT) {
+        Ok(Ok(status)) => Response::StageUpdated {
+            stage_id: stage_id.to_string(),
+            status,
+        },
+        Ok(Err(message)) => Response::Error { message },
+        Err(_) => Response::Error {
+            message: format!(
+                "Orchestrator did not apply the command within {}s",
+                STAGE_COMMAND_TIMEOUT.as_secs()
+            ),
+        },
+    }
+}
+
+/// Persist a run setting change, hand it to the orchestrator and notify status subscribers.
+///
+/// The change is written to config.toml first so it survives a daemon restart
+/// even if the orchestrator exits before applying it.
+fn apply_config_change(
+    change: ConfigChange,
+    config: &Mutex<DaemonConfig>,
+    control_queue: &ControlQueue,
+    status_subscribers: &Arc<Mutex<Vec<UnixStream>>>,
+    work_dir: &Path,
+) -> Response {
+    if let Err(e) = change
+        .persist(work_dir)
+        .and_then(|()| control_queue.push_config(change))
+    {
+        return Response::Error {
+            message: format!("{e:#}"),
+        };
+    }
+
+    let updated = {
+        let mut config = lock_or_recover(config);
+        config.apply(change);
+        config.clone()
+    };
+
+    let response = Response::ConfigUpdated { config: updated };
+    broadcast_to_subscribers(status_subscribers, &response);
+    response
+}
@@ -0,0 +1,120 @@
+//! Client side of stage commands, config changes and plan updates sent to a running daemon.
+
+use super::super::protocol::{read_message, write_message, DaemonConfig, Request, Response};
+use super::client::{read_auth_token, STAGE_COMMAND_TIMEOUT};
+use super::core::DaemonServer;
+use crate::models::stage::StageStatus;
+use crate::orchestrator::control::StageCommand;
+use crate::orchestrator::plan_update::AppliedUpdate;
+use crate::orchestrator::run_settings::ConfigChange;
+use anyhow::{bail, Context, Result};
+use std::os::unix::net::UnixStream;
+use std::path::Path;
+use std::time::Duration;
+
+impl DaemonServer {
+    /// Send a stage command to the daemon and wait for the orchestrator to apply it.
+    ///
+    /// # Arguments
+    /// * `work_dir` - The .work/ directory path
+    /// * `stage_id` - The stage the command applies to
+    /// * `command` - The command to execute
+    ///
+    /// # Returns
+    /// The stage's status after the command was applied
+    pub fn send_stage_command(
+        work_dir: &Path,
+        stage_id: &str,
+        command: StageCommand,
+    ) -> Result<StageStatus> {
+        let auth_token = read_auth_token(work_dir).context("Failed to read auth token")?;
+        let socket_path = work_dir.join("orchestrator.sock");
+
+        let mut stream =
+            UnixStream::connect(&socket_path).context("Failed to connect to daemon socket")?;
+
+        // Leave headroom over the daemon's own wait so its timeout error arrives first
+        stream
+            .set_read_timeout(Some(STAGE_COMMAND_TIMEOUT + Duration::from_secs(5)))
+            .context("Failed to set read timeout")?;
+
+        let request = Request::stage_command(auth_token, stage_id.to_string(), command);
+        write_message(&mut stream, &request).context("Failed to send stage command")?;
+
+        let response: Response =
+            read_message(&mut stream).context("Failed to read stage command response")?;
+
+        match response {
+            Response::StageUpdated { status, .. } => Ok(status),
+            Response::AuthenticationFailed => bail!("Authentication failed - invalid token"),
+            Response::Error { message } => bail!("{message}"),
+            _ => bail!("Unexpected response from daemon"),
+        }
+    }
+
+    /// Send a run setting change to the daemon.
+    ///
+    /// The daemon persists the change to config.toml, applies it to the running
+    /// orchestrator and broadcasts it to status subscribers.
+    ///
+    /// # Arguments
+    /// * `work_dir` - The .work/ directory path
+    /// * `change` - The setting to change
+    ///
+    /// # Returns
+    /// The daemon configuration after the change
+    pub fn send_config_change(work_dir: &Path, change: ConfigChange) -> Result<DaemonConfig> {
+        let auth_token = read_auth_token(work_dir).context("Failed to read auth token")?;
+        let socket_path = work_dir.join("orchestrator.sock");
+
+        let mut stream =
+            UnixStream::connect(&socket_path).context("Failed to connect to daemon socket")?;
+        stream
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .context("Failed to set read timeout")?;
+
+        let request = Request::SetConfig { auth_token, change };
+        write_message(&mut stream, &request).context("Failed to send config change")?;
+
+        let response: Response =
+            read_message(&mut stream).context("Failed to read config change response")?;
+
+        match response {
+            Response::ConfigUpdated { config } => Ok(config),
+            Response::AuthenticationFailed => bail!("Authentication failed - invalid token"),
+            Response::Error { message } => bail!("{message}"),
+            _ => bail!("Unexpected response from daemon"),
+        }
+    }
+
+    /// Ask the daemon to apply the edited plan and wait for the orchestrator.
+    ///
+    /// # Arguments
+    /// * `work_dir` - The .work/ directory path
+    ///
+    /// # Returns
+    /// The stages the orchestrator added, updated or dropped
+    pub fn send_plan_update(work_dir: &Path) -> Result<AppliedUpdate> {
+        let auth_token = read_auth_token(work_dir).context("Failed to read auth token")?;
+        let socket_path = work_dir.join("orchestrator.sock");
+
+        let mut stream =
+            UnixStream::connect(&socket_path).context("Failed to connect to daemon socket")?;
+        stream
+            .set_read_timeout(Some(STAGE_COMMAND_TIMEOUT + Duration::from_secs(5)))
+            .context("Failed to set read timeout")?;
+
+        let request = Request::UpdatePlan { auth_token };
+        write_message(&mut stream, &request).context("Failed to send plan update")?;
+
+        let response: Response =
+            read_message(&mut stream).context("Failed to read plan update response")?;
+
+        match response {
+            Response::PlanUpdated { applied } => Ok(applied),
+            Response::AuthenticationFailed => bail!("Authentication failed - invalid token"),
+            Response::Error { message } => bail!("{message}"),
+            _ => bail!("Unexpected response from daemon"),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 //! Core DaemonServer struct and constructors.
 
 use super::super::protocol::DaemonConfig;
+use crate::orchestrator::control::ControlQueue;
 use std::os::unix::net::UnixStream;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
@@ -27,11 +28,12 @@ pub struct DaemonServer {
     pub(super) pid_path: PathBuf,
     pub(super) log_path: PathBuf,
     pub(super) work_dir: PathBuf,
-    pub(super) config: DaemonConfig,
+    pub(super) config: Arc<Mutex<DaemonConfig>>,
     pub(super) shutdown_flag: Arc<AtomicBool>,
     pub(super) connection_count: Arc<AtomicUsize>,
     pub(super) status_subscribers: Arc<Mutex<Vec<UnixStream>>>,
     pub(super) log_subscribers: Arc<Mutex<Vec<UnixStream>>>,
+    pub(super) control_queue: ControlQueue,
 }
 
 impl DaemonServer {
@@ -60,11 +62,12 @@ impl DaemonServer {
             pid_path: work_dir.join("orchestrator.pid"),
             log_path: work_dir.join("orchestrator.log"),
             work_dir: work_dir.to_path_buf(),
-            config,
+            config: Arc::new(Mutex::new(config)),
             shutdown_flag: Arc::new(AtomicBool::new(false)),
             connection_count: Arc::new(AtomicUsize::new(0)),
             status_subscribers: Arc::new(Mutex::new(Vec::new())),
             log_subscribers: Arc::new(Mutex::new(Vec::new())),
+            control_queue: ControlQueue::new(),
         }
     }
 
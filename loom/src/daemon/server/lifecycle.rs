@@ -241,6 +241,8 @@ impl DaemonServer {
                     let status_subscribers = Arc::clone(&self.status_subscribers);
                     let log_subscribers = Arc::clone(&self.log_subscribers);
                     let connection_count = Arc::clone(&self.connection_count);
+                    let control_queue = self.control_queue.clone();
+                    let config = Arc::clone(&self.config);
                     let work_dir = self.work_dir.clone();
 
                     thread::spawn(move || {
@@ -249,6 +251,8 @@ impl DaemonServer {
                             shutdown_flag,
                             status_subscribers,
                             log_subscribers,
+                            control_queue,
+                            config,
                             &work_dir,
                         );
                         // Decrement connection count when thread exits
@@ -2,6 +2,7 @@
 
 mod broadcast;
 mod client;
+mod control;
 mod core;
 mod lifecycle;
 mod orchestrator;
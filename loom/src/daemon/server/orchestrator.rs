@@ -1,6 +1,7 @@
 //! Orchestrator spawning and execution graph building.
 
 use super::super::protocol::DaemonConfig;
+use super::broadcast::lock_or_recover;
 use super::core::DaemonServer;
 use anyhow::{Context, Result};
 use std::fs;
@@ -13,8 +14,8 @@ use std::time::Duration;
 use crate::fs::mark_plan_done_if_all_merged;
 use crate::fs::parse_base_branch_from_config;
 use crate::fs::work_dir::WorkDir;
-use crate::orchestrator::terminal::BackendType;
-use crate::orchestrator::{Orchestrator, OrchestratorConfig};
+use crate::orchestrator::publish::auto_publish;
+use crate::orchestrator::{ControlQueue, Orchestrator, OrchestratorConfig};
 use crate::plan::graph::ExecutionGraph;
 use crate::plan::schema::SandboxConfig;
 
@@ -23,11 +24,12 @@ use crate::plan::schema::SandboxConfig;
 /// Returns a join handle for the orchestrator thread.
 pub fn spawn_orchestrator(server: &DaemonServer) -> Option<JoinHandle<()>> {
     let work_dir = server.work_dir.clone();
-    let daemon_config = server.config.clone();
+    let daemon_config = lock_or_recover(&server.config).clone();
     let shutdown_flag = Arc::clone(&server.shutdown_flag);
+    let control_queue = server.control_queue.clone();
 
     Some(thread::spawn(move || {
-        if let Err(e) = run_orchestrator(&work_dir, &daemon_config, shutdown_flag) {
+        if let Err(e) = run_orchestrator(&work_dir, &daemon_config, shutdown_flag, control_queue) {
             eprintln!("Orchestrator error: {e}");
         }
     }))
@@ -38,6 +40,7 @@ fn run_orchestrator(
     work_dir: &Path,
     daemon_config: &DaemonConfig,
     shutdown_flag: Arc<AtomicBool>,
+    control_queue: ControlQueue,
 ) -> Result<()> {
     // Build execution graph from stage files
     let graph = build_execution_graph(work_dir)?;
@@ -75,7 +78,7 @@ fn run_orchestrator(
         work_dir: work_dir.to_path_buf(),
         repo_root,
         status_update_interval: Duration::from_secs(30),
-        backend_type: BackendType::Native,
+        backend_type: daemon_config.backend_type,
         auto_merge: daemon_config.auto_merge,
         base_branch,
         skills_dir: None, // Use default ~/.claude/skills/
@@ -83,6 +86,7 @@ fn run_orchestrator(
         max_skill_recommendations: 5,
         sandbox_config: SandboxConfig::default(),
         shutdown_flag: Some(shutdown_flag.clone()),
+        control_queue: Some(control_queue),
     };
 
     // Create and run orchestrator
@@ -117,6 +121,7 @@ fn run_orchestrator(
                     if let Err(e) = mark_plan_done_if_all_merged(&work_dir_obj) {
                         eprintln!("Warning: Failed to mark plan as done: {e}");
                     }
+                    auto_publish(&work_dir_obj, &repo_root_for_plan);
                 }
             }
 
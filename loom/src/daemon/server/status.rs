@@ -10,6 +10,7 @@ use std::process::Command;
 use crate::git::branch::branch_name_for_stage;
 use crate::models::stage::{Stage, StageStatus};
 use crate::models::worktree::WorktreeStatus;
+use crate::orchestrator::pricing::PriceTable;
 use crate::parser::frontmatter::{extract_yaml_frontmatter, parse_from_markdown};
 
 /// Collect current stage status from the work directory.
@@ -40,7 +41,7 @@ pub fn collect_status(work_dir: &Path) -> Result<Response> {
                                 get_session_pid(&sessions_dir, stage.session.as_deref());
                             let started_at = stage.started_at.unwrap_or_else(chrono::Utc::now);
                             let completed_at = stage.completed_at;
-                            let worktree_status = detect_worktree_status(&stage.id, &repo_root);
+                            let worktree_status = detect_worktree_status(&stage, &repo_root);
 
                             let stage_info = StageInfo {
                                 id: stage.id,
@@ -97,8 +98,8 @@ pub fn collect_status(work_dir: &Path) -> Result<Response> {
 /// - Whether there are merge conflicts
 /// - Whether a merge is in progress
 /// - Whether the branch was manually merged outside of loom
-pub fn detect_worktree_status(stage_id: &str, repo_root: &Path) -> Option<WorktreeStatus> {
-    let worktree_path = repo_root.join(".worktrees").join(stage_id);
+pub fn detect_worktree_status(stage: &Stage, repo_root: &Path) -> Option<WorktreeStatus> {
+    let worktree_path = repo_root.join(".worktrees").join(&stage.id);
 
     if !worktree_path.exists() {
         return None;
@@ -135,7 +136,7 @@ pub fn detect_worktree_status(stage_id: &str, repo_root: &Path) -> Option<Worktr
 
     // Check if the branch was manually merged outside loom
     // This detects when users run `git merge loom/stage-id` manually
-    if is_manually_merged(stage_id, repo_root) {
+    if is_manually_merged(stage, repo_root) {
         return Some(WorktreeStatus::Merged);
     }
 
@@ -146,7 +147,11 @@ pub fn detect_worktree_status(stage_id: &str, repo_root: &Path) -> Option<Worktr
 ///
 /// This is used to detect merges performed outside of loom (e.g., via CLI).
 /// When detected, the orchestrator can trigger cleanup of the worktree.
-pub fn is_manually_merged(stage_id: &str, repo_root: &Path) -> bool {
+/// Squashed and rebased branches never show as merged to git, so the
+/// stage's `completed_commit`, which records the commit such merges land,
+/// is checked too.
+pub fn is_manually_merged(stage: &Stage, repo_root: &Path) -> bool {
+    use crate::git::branch::is_ancestor_of;
     use crate::git::{default_branch, is_branch_merged};
 
     // Get the default branch (main/master)
@@ -156,8 +161,11 @@ pub fn is_manually_merged(stage_id: &str, repo_root: &Path) -> bool {
     };
 
     // Check if the loom branch has been merged into the target branch
-    let branch_name = branch_name_for_stage(stage_id);
+    let branch_name = branch_name_for_stage(&stage.id);
     is_branch_merged(&branch_name, &target_branch, repo_root).unwrap_or_default()
+        || stage.completed_commit.as_deref().is_some_and(|commit| {
+            is_ancestor_of(commit, &target_branch, repo_root).unwrap_or_default()
+        })
 }
 
 /// Check if there are unmerged paths (merge conflicts) in the worktree
@@ -237,6 +245,7 @@ pub fn collect_completion_summary(work_dir: &Path) -> Result<CompletionSummary>
         "unknown".to_string()
     };
 
+    let prices = PriceTable::load(work_dir)?;
     let mut stages: Vec<StageCompletionInfo> = Vec::new();
     let mut earliest_start: Option<DateTime<Utc>> = None;
     let mut latest_completion: Option<DateTime<Utc>> = None;
@@ -284,6 +293,8 @@ pub fn collect_completion_summary(work_dir: &Path) -> Result<CompletionSummary>
                             let duration_secs = completed_at
                                 .map(|completed| (completed - started_at).num_seconds());
 
+                            let tokens = stage.total_usage();
+                            let cost_usd = prices.sessions_cost(stage.usage.values());
                             stages.push(StageCompletionInfo {
                                 id: stage.id,
                                 name: stage.name,
@@ -293,6 +304,8 @@ pub fn collect_completion_summary(work_dir: &Path) -> Result<CompletionSummary>
                                 retry_count: stage.retry_count,
                                 merged: stage.merged,
                                 dependencies: stage.dependencies,
+                                tokens,
+                                cost_usd,
                             });
                         }
                     }
@@ -310,11 +323,16 @@ pub fn collect_completion_summary(work_dir: &Path) -> Result<CompletionSummary>
         _ => 0,
     };
 
+    let tokens = stages.iter().map(|s| &s.tokens).sum();
+    let cost_usd = stages.iter().map(|s| s.cost_usd).sum();
+
     Ok(CompletionSummary {
         total_duration_secs,
         stages,
         success_count,
         failure_count,
         plan_path,
+        tokens,
+        cost_usd,
     })
 }
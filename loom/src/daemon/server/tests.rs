@@ -1,11 +1,20 @@
 //! Tests for daemon server module.
 
-use super::super::protocol::Response;
+use super::super::protocol::{read_message, write_message, DaemonConfig, Request, Response};
+use super::client::handle_client_connection;
 use super::core::DaemonServer;
 use super::status::{collect_status, detect_worktree_status, is_manually_merged};
+use crate::models::stage::{Stage, StageStatus};
 use crate::models::worktree::WorktreeStatus;
+use crate::orchestrator::control::{ControlQueue, StageCommand};
+use crate::orchestrator::plan_update::AppliedUpdate;
+use crate::orchestrator::run_settings::{ConfigChange, RunSettings};
 use std::fs;
-use std::sync::atomic::Ordering;
+use std::os::unix::net::UnixStream;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
 use tempfile::TempDir;
 
 #[test]
@@ -176,13 +185,20 @@ updated_at: "2024-01-01T00:00:00Z"
     }
 }
 
+fn stage(id: &str) -> Stage {
+    Stage {
+        id: id.to_string(),
+        ..Stage::default()
+    }
+}
+
 #[test]
 fn test_detect_worktree_status_no_worktree() {
     let temp_dir = TempDir::new().expect("Failed to create temp dir");
     let repo_root = temp_dir.path();
 
     // When worktree doesn't exist, should return None
-    let status = detect_worktree_status("nonexistent-stage", repo_root);
+    let status = detect_worktree_status(&stage("nonexistent-stage"), repo_root);
     assert!(status.is_none());
 }
 
@@ -201,7 +217,7 @@ fn test_detect_worktree_status_active() {
 
     // Since this is not a real git repo, is_manually_merged will return false
     // and there's no MERGE_HEAD, so status should be Active
-    let status = detect_worktree_status("test-stage", repo_root);
+    let status = detect_worktree_status(&stage("test-stage"), repo_root);
     assert_eq!(status, Some(WorktreeStatus::Active));
 }
 
@@ -211,7 +227,7 @@ fn test_is_manually_merged_no_git_repo() {
     let repo_root = temp_dir.path();
 
     // When not in a git repo, is_manually_merged should gracefully return false
-    let result = is_manually_merged("test-stage", repo_root);
+    let result = is_manually_merged(&stage("test-stage"), repo_root);
     assert!(!result);
 }
 
@@ -220,4 +236,170 @@ fn test_is_manually_merged_no_git_repo() {
 // The function:
 // 1. Gets the default branch (main/master)
 // 2. Checks if loom/{stage_id} is in `git branch --merged {target}`
-// 3. Returns true if the branch has been merged, false otherwise
+// 3. Otherwise checks if the stage's completed_commit is on the target
+// 4. Returns true if either holds, false otherwise
+
+#[test]
+fn test_stage_command_routed_through_control_queue() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let work_dir = temp_dir.path().to_path_buf();
+    fs::write(work_dir.join("daemon.token"), "secret").unwrap();
+
+    let queue = ControlQueue::new();
+    let (mut client, server) = UnixStream::pair().unwrap();
+
+    let handler_queue = queue.clone();
+    let handler = thread::spawn(move || {
+        handle_client_connection(
+            server,
+            Arc::new(AtomicBool::new(false)),
+            Arc::new(Mutex::new(Vec::new())),
+            Arc::new(Mutex::new(Vec::new())),
+            handler_queue,
+            Arc::new(Mutex::new(DaemonConfig::default())),
+            &work_dir,
+        )
+    });
+
+    // Stand-in for the orchestrator loop draining the queue
+    let orchestrator = thread::spawn(move || loop {
+        if let Some(request) = queue.drain().pop() {
+            assert_eq!(request.command, StageCommand::Retry { force: false });
+            request.respond(Ok(StageStatus::Queued));
+            return;
+        }
+        thread::sleep(Duration::from_millis(10));
+    });
+
+    let request = Request::RetryStage {
+        auth_token: "secret".to_string(),
+        stage_id: "stage-1".to_string(),
+        force: false,
+    };
+    write_message(&mut client, &request).unwrap();
+    match read_message::<Response, _>(&mut client).unwrap() {
+        Response::StageUpdated { stage_id, status } => {
+            assert_eq!(stage_id, "stage-1");
+            assert_eq!(status, StageStatus::Queued);
+        }
+        other => panic!("Expected StageUpdated, got {other:?}"),
+    }
+    orchestrator.join().unwrap();
+
+    // Stage commands require the daemon token like every other request
+    let request = Request::HoldStage {
+        auth_token: "wrong".to_string(),
+        stage_id: "stage-1".to_string(),
+    };
+    write_message(&mut client, &request).unwrap();
+    assert!(matches!(
+        read_message::<Response, _>(&mut client).unwrap(),
+        Response::AuthenticationFailed
+    ));
+
+    drop(client);
+    handler.join().unwrap().unwrap();
+}
+
+#[test]
+fn test_set_config_persists_applies_and_broadcasts() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let work_dir = temp_dir.path().to_path_buf();
+    fs::write(work_dir.join("daemon.token"), "secret").unwrap();
+    fs::write(
+        work_dir.join("config.toml"),
+        "[plan]\nplan_id = \"PLAN-1\"\n",
+    )
+    .unwrap();
+
+    let queue = ControlQueue::new();
+    let config = Arc::new(Mutex::new(DaemonConfig::default()));
+    let (subscriber, mut subscriber_peer) = UnixStream::pair().unwrap();
+    let status_subscribers = Arc::new(Mutex::new(vec![subscriber]));
+    let (mut client, server) = UnixStream::pair().unwrap();
+
+    let handler_queue = queue.clone();
+    let handler_config = Arc::clone(&config);
+    let handler_dir = work_dir.clone();
+    let handler = thread::spawn(move || {
+        handle_client_connection(
+            server,
+            Arc::new(AtomicBool::new(false)),
+            status_subscribers,
+            Arc::new(Mutex::new(Vec::new())),
+            handler_queue,
+            handler_config,
+            &handler_dir,
+        )
+    });
+
+    let request = Request::SetConfig {
+        auth_token: "secret".to_string(),
+        change: ConfigChange::MaxParallel(6),
+    };
+    write_message(&mut client, &request).unwrap();
+    match read_message::<Response, _>(&mut client).unwrap() {
+        Response::ConfigUpdated { config } => assert_eq!(config.max_parallel, Some(6)),
+        other => panic!("Expected ConfigUpdated, got {other:?}"),
+    }
+    drop(client);
+    handler.join().unwrap().unwrap();
+
+    assert!(matches!(
+        read_message::<Response, _>(&mut subscriber_peer).unwrap(),
+        Response::ConfigUpdated { .. }
+    ));
+    assert_eq!(config.lock().unwrap().max_parallel, Some(6));
+    assert_eq!(queue.drain_config(), vec![ConfigChange::MaxParallel(6)]);
+    assert_eq!(RunSettings::load(&work_dir).max_parallel, Some(6));
+}
+
+#[test]
+fn test_plan_update_routed_through_control_queue() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let work_dir = temp_dir.path().to_path_buf();
+    fs::write(work_dir.join("daemon.token"), "secret").unwrap();
+
+    let queue = ControlQueue::new();
+    let (mut client, server) = UnixStream::pair().unwrap();
+
+    let handler_queue = queue.clone();
+    let handler = thread::spawn(move || {
+        handle_client_connection(
+            server,
+            Arc::new(AtomicBool::new(false)),
+            Arc::new(Mutex::new(Vec::new())),
+            Arc::new(Mutex::new(Vec::new())),
+            handler_queue,
+            Arc::new(Mutex::new(DaemonConfig::default())),
+            &work_dir,
+        )
+    });
+
+    let applied = AppliedUpdate {
+        added: vec!["lint".to_string()],
+        updated: vec!["test".to_string()],
+        dropped: Vec::new(),
+    };
+    let reply = applied.clone();
+    let orchestrator = thread::spawn(move || loop {
+        if let Some(request) = queue.drain_plan_updates().pop() {
+            request.respond(Ok(reply));
+            return;
+        }
+        thread::sleep(Duration::from_millis(10));
+    });
+
+    let request = Request::UpdatePlan {
+        auth_token: "secret".to_string(),
+    };
+    write_message(&mut client, &request).unwrap();
+    match read_message::<Response, _>(&mut client).unwrap() {
+        Response::PlanUpdated { applied: received } => assert_eq!(received, applied),
+        other => panic!("Expected PlanUpdated, got {other:?}"),
+    }
+    orchestrator.join().unwrap();
+
+    drop(client);
+    handler.join().unwrap().unwrap();
+}
@@ -0,0 +1,142 @@
+//! Acceptance criteria result cache
+//!
+//! A passing criterion is cached in `.work/criteria-cache/<stage-id>.json`,
+//! keyed by the worktree's content hash (see `git::working_tree_hash`), the
+//! directory it ran in and the fully expanded command. A re-check of an
+//! unchanged worktree skips it; any change to the worktree, committed or
+//! not, produces a different key.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::validation::validate_id;
+
+/// A criterion that passed on a worktree state
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheEntry {
+    /// Expanded command, including any setup prefix
+    pub command: String,
+    /// Worktree content hash the command passed on
+    pub tree: String,
+    pub passed_at: DateTime<Utc>,
+    pub duration_ms: u64,
+}
+
+/// Cached entries of one stage, by cache key
+pub type StageCache = BTreeMap<String, CacheEntry>;
+
+fn cache_dir(work_dir: &Path) -> PathBuf {
+    work_dir.join("criteria-cache")
+}
+
+fn cache_path(stage_id: &str, work_dir: &Path) -> Result<PathBuf> {
+    validate_id(stage_id).context("Invalid stage ID")?;
+    Ok(cache_dir(work_dir).join(format!("{stage_id}.json")))
+}
+
+/// Cache key of a command run in `dir` on worktree state `tree`
+pub fn cache_key(tree: &str, dir: &Path, command: &str) -> String {
+    let mut hasher = Sha256::new();
+    for part in [tree, &dir.to_string_lossy(), command] {
+        hasher.update(part.as_bytes());
+        hasher.update([0]);
+    }
+    hex::encode(hasher.finalize())
+}
+
+/// Load a stage's cache; a missing or unreadable cache is empty
+pub fn load_cache(stage_id: &str, work_dir: &Path) -> StageCache {
+    cache_path(stage_id, work_dir)
+        .ok()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Write a stage's cache
+pub fn save_cache(stage_id: &str, cache: &StageCache, work_dir: &Path) -> Result<()> {
+    let path = cache_path(stage_id, work_dir)?;
+    fs::create_dir_all(cache_dir(work_dir)).context("Failed to create criteria cache directory")?;
+    let content = serde_json::to_string_pretty(cache).context("Failed to serialize cache")?;
+    fs::write(&path, content)
+        .with_context(|| format!("Failed to write criteria cache: {}", path.display()))
+}
+
+/// IDs of stages with a cache, sorted
+pub fn cached_stage_ids(work_dir: &Path) -> Result<Vec<String>> {
+    let dir = cache_dir(work_dir);
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut ids = Vec::new();
+    for entry in fs::read_dir(&dir).context("Failed to read criteria cache directory")? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "json") {
+            if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
+                ids.push(stem.to_string());
+            }
+        }
+    }
+    ids.sort();
+    Ok(ids)
+}
+
+/// Remove the cache of one stage, or of every stage; returns the entries removed
+pub fn clear_cache(stage_id: Option<&str>, work_dir: &Path) -> Result<usize> {
+    let ids = match stage_id {
+        Some(id) => vec![id.to_string()],
+        None => cached_stage_ids(work_dir)?,
+    };
+    let mut removed = 0;
+    for id in ids {
+        let path = cache_path(&id, work_dir)?;
+        if path.exists() {
+            removed += load_cache(&id, work_dir).len();
+            fs::remove_file(&path)
+                .with_context(|| format!("Failed to remove criteria cache: {}", path.display()))?;
+        }
+    }
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn entry(command: &str) -> CacheEntry {
+        CacheEntry {
+            command: command.to_string(),
+            tree: "abc".to_string(),
+            passed_at: Utc::now(),
+            duration_ms: 1200,
+        }
+    }
+
+    #[test]
+    fn test_save_load_and_clear_cache() {
+        let temp_dir = TempDir::new().unwrap();
+        let work_dir = temp_dir.path();
+        let key = cache_key("abc", Path::new("/wt"), "cargo test");
+        assert_ne!(key, cache_key("abd", Path::new("/wt"), "cargo test"));
+        assert_ne!(key, cache_key("abc", Path::new("/wt/sub"), "cargo test"));
+
+        let mut cache = StageCache::new();
+        cache.insert(key.clone(), entry("cargo test"));
+        save_cache("build", &cache, work_dir).unwrap();
+        save_cache("lint", &cache, work_dir).unwrap();
+
+        assert_eq!(load_cache("build", work_dir)[&key].command, "cargo test");
+        assert_eq!(cached_stage_ids(work_dir).unwrap(), vec!["build", "lint"]);
+
+        assert_eq!(clear_cache(Some("build"), work_dir).unwrap(), 1);
+        assert!(load_cache("build", work_dir).is_empty());
+        assert_eq!(clear_cache(None, work_dir).unwrap(), 1);
+        assert!(cached_stage_ids(work_dir).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,256 @@
+//! Acceptance and verification run history
+//!
+//! Every acceptance and goal-backward verification run of a stage is appended
+//! to `.work/history/<stage-id>.jsonl`, one record per command. Records of one
+//! run share an attempt number. A command that both passed and failed on the
+//! same source state is flaky.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::validation::validate_id;
+use crate::verify::criteria::TestCase;
+
+/// Maximum characters of stdout/stderr kept per record
+pub const MAX_OUTPUT_CHARS: usize = 4000;
+
+/// What kind of check a history record comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RunKind {
+    Acceptance,
+    GoalBackward,
+}
+
+impl std::fmt::Display for RunKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Acceptance => write!(f, "acceptance"),
+            Self::GoalBackward => write!(f, "goal-backward"),
+        }
+    }
+}
+
+/// One command of one acceptance or verification run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryRecord {
+    /// Run number within the stage, starting at 1
+    pub attempt: u32,
+    pub kind: RunKind,
+    pub timestamp: DateTime<Utc>,
+    /// Hash of the worktree contents the run was made on
+    pub source_state: Option<String>,
+    pub command: String,
+    pub success: bool,
+    pub exit_code: Option<i32>,
+    pub duration_ms: u64,
+    pub timed_out: bool,
+    /// Tail of stdout, truncated to `MAX_OUTPUT_CHARS`
+    pub stdout: String,
+    /// Tail of stderr, truncated to `MAX_OUTPUT_CHARS`
+    pub stderr: String,
+    /// Failed test cases from the command's test report
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub failed_tests: Vec<TestCase>,
+}
+
+/// Keep the end of command output, where failures are usually reported
+pub fn truncate_output(output: &str) -> String {
+    let count = output.chars().count();
+    if count <= MAX_OUTPUT_CHARS {
+        return output.to_string();
+    }
+    let tail: String = output.chars().skip(count - MAX_OUTPUT_CHARS).collect();
+    format!(
+        "[{} characters truncated]\n{tail}",
+        count - MAX_OUTPUT_CHARS
+    )
+}
+
+fn history_path(stage_id: &str, work_dir: &Path) -> Result<PathBuf> {
+    validate_id(stage_id).context("Invalid stage ID")?;
+    Ok(work_dir.join("history").join(format!("{stage_id}.jsonl")))
+}
+
+/// Load a stage's history, oldest first
+pub fn load_history(stage_id: &str, work_dir: &Path) -> Result<Vec<HistoryRecord>> {
+    let path = history_path(stage_id, work_dir)?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let content = fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read history: {}", path.display()))?;
+    // Skip unreadable lines (e.g. a write cut short) rather than losing the history
+    Ok(content
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect())
+}
+
+/// Attempt number for the next run of a stage
+pub fn next_attempt(stage_id: &str, work_dir: &Path) -> Result<u32> {
+    let last = load_history(stage_id, work_dir)?
+        .iter()
+        .map(|r| r.attempt)
+        .max()
+        .unwrap_or(0);
+    Ok(last + 1)
+}
+
+/// Append the records of one run to a stage's history
+pub fn append_history(stage_id: &str, records: &[HistoryRecord], work_dir: &Path) -> Result<()> {
+    let path = history_path(stage_id, work_dir)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).context("Failed to create history directory")?;
+    }
+
+    let mut lines = String::new();
+    for record in records {
+        lines.push_str(&serde_json::to_string(record).context("Failed to serialize record")?);
+        lines.push('\n');
+    }
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("Failed to open history: {}", path.display()))?;
+    file.write_all(lines.as_bytes())
+        .with_context(|| format!("Failed to write history: {}", path.display()))
+}
+
+/// Commands that both passed and failed on the same source state
+pub fn flaky_commands(records: &[HistoryRecord]) -> Vec<String> {
+    let mut flaky: Vec<String> = Vec::new();
+    for record in records.iter().filter(|r| !r.success) {
+        let Some(state) = &record.source_state else {
+            continue;
+        };
+        let passed_on_same_state = records.iter().any(|r| {
+            r.success && r.command == record.command && r.source_state.as_ref() == Some(state)
+        });
+        if passed_on_same_state && !flaky.contains(&record.command) {
+            flaky.push(record.command.clone());
+        }
+    }
+    flaky
+}
+
+/// Flaky commands of a stage, or none if its history cannot be read
+pub fn stage_flaky_commands(stage_id: &str, work_dir: &Path) -> Vec<String> {
+    load_history(stage_id, work_dir)
+        .map(|records| flaky_commands(&records))
+        .unwrap_or_default()
+}
+
+/// Failed tests of a stage's latest acceptance run, or none if its history
+/// cannot be read
+pub fn last_failed_tests(stage_id: &str, work_dir: &Path) -> Vec<String> {
+    let records = load_history(stage_id, work_dir).unwrap_or_default();
+    let Some(last) = records
+        .iter()
+        .filter(|r| r.kind == RunKind::Acceptance)
+        .map(|r| r.attempt)
+        .max()
+    else {
+        return Vec::new();
+    };
+    records
+        .iter()
+        .filter(|r| r.attempt == last)
+        .flat_map(|r| r.failed_tests.iter().map(TestCase::summary))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn record(attempt: u32, command: &str, state: &str, success: bool) -> HistoryRecord {
+        HistoryRecord {
+            attempt,
+            kind: RunKind::Acceptance,
+            timestamp: Utc::now(),
+            source_state: Some(state.to_string()),
+            command: command.to_string(),
+            success,
+            exit_code: Some(if success { 0 } else { 1 }),
+            duration_ms: 10,
+            timed_out: false,
+            stdout: String::new(),
+            stderr: String::new(),
+            failed_tests: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_append_and_load_history() {
+        let temp_dir = TempDir::new().unwrap();
+        assert_eq!(next_attempt("build", temp_dir.path()).unwrap(), 1);
+
+        append_history(
+            "build",
+            &[
+                record(1, "cargo test", "abc", false),
+                record(1, "cargo build", "abc", true),
+            ],
+            temp_dir.path(),
+        )
+        .unwrap();
+        append_history(
+            "build",
+            &[record(2, "cargo test", "abc", true)],
+            temp_dir.path(),
+        )
+        .unwrap();
+
+        let history = load_history("build", temp_dir.path()).unwrap();
+        assert_eq!(history.len(), 3);
+        assert_eq!(next_attempt("build", temp_dir.path()).unwrap(), 3);
+        assert_eq!(flaky_commands(&history), vec!["cargo test"]);
+    }
+
+    #[test]
+    fn test_flaky_requires_same_source_state() {
+        let records = vec![
+            record(1, "cargo test", "abc", false),
+            record(2, "cargo test", "def", true),
+            record(3, "cargo clippy", "def", false),
+        ];
+        assert!(flaky_commands(&records).is_empty());
+    }
+
+    #[test]
+    fn test_last_failed_tests_reads_latest_acceptance_run() {
+        let temp_dir = TempDir::new().unwrap();
+        let failed_test = |name: &str| TestCase {
+            name: name.to_string(),
+            status: crate::verify::criteria::TestStatus::Failed,
+            message: Some("assertion failed".to_string()),
+        };
+        let mut first = record(1, "cargo test", "abc", false);
+        first.failed_tests = vec![failed_test("old_test")];
+        let mut second = record(2, "cargo test", "def", false);
+        second.failed_tests = vec![failed_test("parses_config")];
+        append_history("build", &[first, second], temp_dir.path()).unwrap();
+
+        assert_eq!(
+            last_failed_tests("build", temp_dir.path()),
+            vec!["parses_config: assertion failed"]
+        );
+        assert!(last_failed_tests("other", temp_dir.path()).is_empty());
+    }
+
+    #[test]
+    fn test_truncate_output_keeps_tail() {
+        let output = format!("{}END", "x".repeat(MAX_OUTPUT_CHARS));
+        let truncated = truncate_output(&output);
+        assert!(truncated.starts_with("[3 characters truncated]"));
+        assert!(truncated.ends_with("END"));
+    }
+}
@@ -0,0 +1,392 @@
+//! Deterministic knowledge compaction for `loom knowledge gc --compact`.
+//!
+//! Compaction rewrites a knowledge file in four steps:
+//! 1. Sections with the same heading are merged into the first one.
+//!    Structured entries (see `entry`) are never merged.
+//! 2. Each `## Promoted from Memory` block is folded into the plain section
+//!    it was appended under. Blocks with no such section collapse into one
+//!    `## Promoted from Memory` section, placed where the first block was.
+//! 3. Bullets repeating an earlier bullet of the file, ignoring case,
+//!    punctuation and spacing, are dropped.
+//! 4. While the file is over the line limit, the oldest bullets are dropped,
+//!    promoted ones first. Files are append-only, so file order is age order
+//!    within a section.
+//!
+//! Fenced code blocks are kept whole: their lines are never read as
+//! headings or bullets.
+//!
+//! Running it twice gives the same result as running it once.
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::dir::KnowledgeDir;
+use super::entry::{ENTRY_META_END, ENTRY_META_START};
+use super::types::KnowledgeFile;
+use crate::fs::memory::{MemoryEntryType, PROMOTED_BLOCK_HEADER};
+
+/// What compaction changed in a file
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CompactStats {
+    pub merged_sections: usize,
+    pub folded_promoted_blocks: usize,
+    pub deduped_bullets: usize,
+    pub trimmed_bullets: usize,
+}
+
+/// A knowledge file before and after compaction
+#[derive(Debug, Clone)]
+pub struct CompactedFile {
+    pub file_type: KnowledgeFile,
+    pub before: String,
+    pub after: String,
+    pub stats: CompactStats,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Item {
+    /// A list item with its indented continuation lines
+    Bullet(String),
+    /// Any other line; a metadata block or fenced code block is kept as one item
+    Text(String),
+}
+
+#[derive(Debug, Clone)]
+struct Section {
+    heading: String,
+    items: Vec<Item>,
+}
+
+impl Section {
+    fn is_structured(&self) -> bool {
+        self.items
+            .iter()
+            .find(|item| !matches!(item, Item::Text(t) if t.trim().is_empty()))
+            .is_some_and(|item| matches!(item, Item::Text(t) if t.starts_with(ENTRY_META_START)))
+    }
+
+    fn is_promoted(&self) -> bool {
+        self.heading
+            .starts_with(PROMOTED_BLOCK_HEADER.trim_start_matches("## "))
+    }
+}
+
+/// Compact every knowledge file; only files that change are returned
+pub fn plan_compaction(
+    knowledge: &KnowledgeDir,
+    max_file_lines: usize,
+) -> Result<Vec<CompactedFile>> {
+    let mut files = Vec::new();
+    for (file_type, before) in knowledge.read_all()? {
+        let (after, stats) = compact_content(&before, max_file_lines);
+        if after != before {
+            files.push(CompactedFile {
+                file_type,
+                before,
+                after,
+                stats,
+            });
+        }
+    }
+    Ok(files)
+}
+
+/// Back up the original files under `archive_dir/knowledge/<timestamp>/`,
+/// then write the compacted ones. Returns the backup directory.
+pub fn apply_compaction(
+    knowledge: &KnowledgeDir,
+    archive_dir: &Path,
+    files: &[CompactedFile],
+) -> Result<PathBuf> {
+    let backup = create_backup_dir(&archive_dir.join("knowledge"))?;
+    for file in files {
+        let name = file.file_type.filename();
+        fs::write(backup.join(name), &file.before)
+            .with_context(|| format!("Failed to back up {name}"))?;
+    }
+    for file in files {
+        fs::write(knowledge.file_path(&file.file_type), &file.after)
+            .with_context(|| format!("Failed to write {}", file.file_type.filename()))?;
+    }
+    Ok(backup)
+}
+
+/// Create a new backup directory named after the current time; a run in
+/// the same second gets a numbered suffix instead of reusing a backup
+fn create_backup_dir(parent: &Path) -> Result<PathBuf> {
+    fs::create_dir_all(parent).with_context(|| format!("Failed to create {}", parent.display()))?;
+    let timestamp = Utc::now().format("%Y%m%d-%H%M%S").to_string();
+    for attempt in 1.. {
+        let name = match attempt {
+            1 => timestamp.clone(),
+            n => format!("{timestamp}-{n}"),
+        };
+        let dir = parent.join(name);
+        match fs::create_dir(&dir) {
+            Ok(()) => return Ok(dir),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e).with_context(|| format!("Failed to create {}", dir.display())),
+        }
+    }
+    unreachable!("backup directory attempts are unbounded")
+}
+
+/// Compact one knowledge file's content to at most `max_lines` lines,
+/// where dropping bullets allows it
+pub fn compact_content(content: &str, max_lines: usize) -> (String, CompactStats) {
+    let mut stats = CompactStats::default();
+    let (preamble, sections) = parse_sections(content);
+    let mut sections = merge_sections(sections, &mut stats);
+    dedupe_bullets(&mut sections, &mut stats);
+
+    let preamble_lines = preamble.lines().count();
+    while preamble_lines + sections.iter().map(section_lines).sum::<usize>() > max_lines {
+        if !drop_oldest_bullet(&mut sections) {
+            break;
+        }
+        stats.trimmed_bullets += 1;
+    }
+    (render(&preamble, &sections), stats)
+}
+
+fn parse_sections(content: &str) -> (String, Vec<Section>) {
+    let mut preamble = Vec::new();
+    let mut sections: Vec<Section> = Vec::new();
+    let mut lines = content.lines().peekable();
+    while let Some(line) = lines.next() {
+        if let Some(fence) = fence_marker(line) {
+            let block = fenced_block(line, fence, &mut lines);
+            match sections.last_mut() {
+                Some(section) => section.items.push(Item::Text(block)),
+                None => preamble.push(block),
+            }
+            continue;
+        }
+        let Some(section) = sections.last_mut() else {
+            match line.strip_prefix("## ") {
+                Some(heading) => sections.push(new_section(heading)),
+                None => preamble.push(line.to_string()),
+            }
+            continue;
+        };
+        if let Some(heading) = line.strip_prefix("## ") {
+            sections.push(new_section(heading));
+        } else if line.trim() == ENTRY_META_START {
+            let mut block = vec![line.to_string()];
+            while let Some(next) =
+                lines.next_if(|_| block.last().map(|l| l.trim()) != Some(ENTRY_META_END))
+            {
+                block.push(next.to_string());
+            }
+            section.items.push(Item::Text(block.join("\n")));
+        } else if is_bullet_start(line) {
+            let mut bullet = line.to_string();
+            while let Some(next) =
+                lines.next_if(|l| l.starts_with([' ', '\t']) && !l.trim().is_empty())
+            {
+                bullet.push('\n');
+                bullet.push_str(next);
+            }
+            section.items.push(Item::Bullet(bullet));
+        } else {
+            section.items.push(Item::Text(line.to_string()));
+        }
+    }
+    (preamble.join("\n").trim_end().to_string(), sections)
+}
+
+/// The backtick or tilde run opening a fenced code block
+fn fence_marker(line: &str) -> Option<&str> {
+    let trimmed = line.trim_start();
+    let marker = ['`', '~'].into_iter().find(|c| trimmed.starts_with(*c))?;
+    let fence = &trimmed[..trimmed.len() - trimmed.trim_start_matches(marker).len()];
+    (fence.len() >= 3).then_some(fence)
+}
+
+/// A fenced code block up to its closing fence, or to the end of the file
+/// when it is never closed; its lines are never read as headings or bullets
+fn fenced_block<'a>(first: &str, fence: &str, lines: &mut impl Iterator<Item = &'a str>) -> String {
+    let mut block = first.to_string();
+    for line in lines {
+        block.push('\n');
+        block.push_str(line);
+        let closing = line.trim();
+        if closing.starts_with(fence) && closing.trim_start_matches(&fence[..1]).is_empty() {
+            break;
+        }
+    }
+    block
+}
+
+fn new_section(heading: &str) -> Section {
+    Section {
+        heading: heading.trim().to_string(),
+        items: Vec::new(),
+    }
+}
+
+fn is_bullet_start(line: &str) -> bool {
+    line.starts_with("- ")
+        || line.starts_with("* ")
+        || line
+            .split_once(". ")
+            .is_some_and(|(n, _)| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Merge duplicate plain sections and fold promoted blocks into the plain
+/// section they follow
+fn merge_sections(sections: Vec<Section>, stats: &mut CompactStats) -> Vec<Section> {
+    let mut merged: Vec<Section> = Vec::new();
+    let mut parent = None;
+    for section in sections {
+        let promoted = section.is_promoted();
+        let target = if promoted {
+            parent.or_else(|| merged.iter().position(Section::is_promoted))
+        } else if section.is_structured() {
+            None
+        } else {
+            merged
+                .iter()
+                .position(|s| s.heading == section.heading && !s.is_structured())
+        };
+        let index = match target {
+            Some(index) => {
+                if promoted {
+                    stats.folded_promoted_blocks += 1;
+                } else {
+                    stats.merged_sections += 1;
+                }
+                merged[index].items.push(Item::Text(String::new()));
+                merged[index].items.extend(section.items);
+                index
+            }
+            None => {
+                merged.push(section);
+                merged.len() - 1
+            }
+        };
+        let kept = &mut merged[index];
+        if kept.is_promoted() && target.is_some() {
+            // The per-stage heading no longer applies once blocks are collapsed
+            kept.heading = PROMOTED_BLOCK_HEADER.trim_start_matches("## ").to_string();
+        } else if !kept.is_promoted() && !kept.is_structured() {
+            parent = Some(index);
+        }
+    }
+    merged
+}
+
+fn dedupe_bullets(sections: &mut [Section], stats: &mut CompactStats) {
+    let mut seen = HashSet::new();
+    for section in sections {
+        section.items.retain(|item| match item {
+            Item::Bullet(text) => {
+                let new = seen.insert(normalize(text));
+                if !new {
+                    stats.deduped_bullets += 1;
+                }
+                new
+            }
+            Item::Text(_) => true,
+        });
+    }
+}
+
+/// Lowercased words of a bullet, without its marker and punctuation
+fn normalize(bullet: &str) -> String {
+    bullet
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Drop the oldest promoted bullet, else the oldest bullet of a plain
+/// section; sections left without content are removed
+fn drop_oldest_bullet(sections: &mut Vec<Section>) -> bool {
+    let find = |promoted_only: bool| {
+        sections
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| !s.is_structured())
+            .find_map(|(index, s)| {
+                s.items
+                    .iter()
+                    .position(|item| match item {
+                        Item::Bullet(text) => !promoted_only || is_promoted_bullet(text),
+                        Item::Text(_) => false,
+                    })
+                    .map(|item| (index, item))
+            })
+    };
+    let Some((index, item)) = find(true).or_else(|| find(false)) else {
+        return false;
+    };
+    let section = &mut sections[index];
+    section.items.remove(item);
+    let empty = section
+        .items
+        .iter()
+        .all(|item| matches!(item, Item::Text(t) if t.trim().is_empty()));
+    if empty {
+        sections.remove(index);
+    }
+    true
+}
+
+/// Whether a bullet was written by memory promotion (`- **Decision:** ...`)
+fn is_promoted_bullet(text: &str) -> bool {
+    MemoryEntryType::all()
+        .iter()
+        .any(|entry_type| text.starts_with(&format!("- **{}:** ", entry_type.display_name())))
+}
+
+fn section_lines(section: &Section) -> usize {
+    render_section(section).lines().count() + 1
+}
+
+fn render(preamble: &str, sections: &[Section]) -> String {
+    let mut parts = Vec::new();
+    if !preamble.is_empty() {
+        parts.push(preamble.to_string());
+    }
+    parts.extend(sections.iter().map(render_section));
+    format!("{}\n", parts.join("\n\n"))
+}
+
+/// A section with runs of blank lines collapsed and no blank line at its
+/// ends; a metadata block stays directly under the heading
+fn render_section(section: &Section) -> String {
+    let mut lines: Vec<&str> = Vec::new();
+    for item in &section.items {
+        let text = match item {
+            Item::Bullet(text) | Item::Text(text) => text.as_str(),
+        };
+        let blank = text.trim().is_empty();
+        if blank && lines.last().is_none_or(|last| last.trim().is_empty()) {
+            continue;
+        }
+        lines.push(if blank { "" } else { text });
+    }
+    while lines.last().is_some_and(|last| last.is_empty()) {
+        lines.pop();
+    }
+
+    let separator = if section.is_structured() || lines.is_empty() {
+        "\n"
+    } else {
+        "\n\n"
+    };
+    format!("## {}{separator}{}", section.heading, lines.join("\n"))
+        .trim_end()
+        .to_string()
+}
+
+#[cfg(test)]
+#[path = "compact_tests.rs"]
+mod tests;
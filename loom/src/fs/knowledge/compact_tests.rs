@@ -0,0 +1,134 @@
+use super::*;
+
+const FILE: &str = "# Patterns
+
+> Patterns discovered in the codebase.
+
+## Errors
+
+- Use anyhow for errors
+  - with context on every ?
+
+## Promoted from Memory: auth (2026-10-01)
+
+- **Decision:** Store sessions in redis
+
+## Errors
+
+- use anyhow for errors!
+  - With context on every `?`.
+- Wrap io errors
+
+## Promoted from Memory: billing (2026-10-02)
+
+- **Decision:** Render invoices with tera
+";
+
+#[test]
+fn test_compact_merges_folds_and_dedupes() {
+    let (after, stats) = compact_content(FILE, 200);
+
+    assert_eq!(
+        stats,
+        CompactStats {
+            merged_sections: 1,
+            folded_promoted_blocks: 2,
+            deduped_bullets: 1,
+            trimmed_bullets: 0,
+        }
+    );
+    assert_eq!(
+        after,
+        "# Patterns
+
+> Patterns discovered in the codebase.
+
+## Errors
+
+- Use anyhow for errors
+  - with context on every ?
+
+- **Decision:** Store sessions in redis
+
+- Wrap io errors
+
+- **Decision:** Render invoices with tera
+"
+    );
+    assert_eq!(compact_content(&after, 200).0, after);
+}
+
+#[test]
+fn test_compact_trims_oldest_promoted_bullets_first() {
+    let (after, stats) = compact_content(FILE, 12);
+
+    assert_eq!(stats.trimmed_bullets, 1);
+    assert!(after.lines().count() <= 12);
+    assert!(!after.contains("Store sessions in redis"));
+    assert!(after.contains("- Use anyhow for errors"));
+    assert!(after.contains("- Wrap io errors"));
+}
+
+#[test]
+fn test_compact_keeps_structured_entries_apart() {
+    let content = "## Sessions\n<!-- loom\nstage: auth\n-->\n\n- Redis\n\n\
+                   ## Sessions\n<!-- loom\nstage: api\n-->\n\n- Redis\n";
+
+    let (after, stats) = compact_content(content, 200);
+
+    assert_eq!(stats.merged_sections, 0);
+    assert_eq!(after.matches("<!-- loom").count(), 2);
+    assert_eq!(after.matches("## Sessions\n<!-- loom").count(), 2);
+}
+
+#[test]
+fn test_compact_collapses_promoted_blocks_without_parent() {
+    let content = "# Stack\n\n## Promoted from Memory: auth (2026-10-01)\n\n\
+                   - **Note:** Redis 7\n\n\
+                   ## Promoted from Memory: api (2026-10-02)\n\n\
+                   - **Decision:** Axum for HTTP\n";
+
+    let (after, stats) = compact_content(content, 200);
+
+    assert_eq!(stats.folded_promoted_blocks, 1);
+    assert_eq!(
+        after,
+        "# Stack\n\n## Promoted from Memory\n\n- **Note:** Redis 7\n\n\
+         - **Decision:** Axum for HTTP\n"
+    );
+    assert_eq!(compact_content(&after, 200).0, after);
+}
+
+#[test]
+fn test_compact_keeps_fenced_code_intact() {
+    let content = "## Config\n\n- Stages list their files\n\n\
+                   ```yaml\n\
+                   files:\n\
+                   - src/api/**\n\
+                   - src/api/**\n\
+                   ## not a heading\n\
+                   ```\n\n\
+                   - Keep plans small\n";
+
+    let (after, stats) = compact_content(content, 200);
+
+    assert_eq!(stats, CompactStats::default());
+    assert_eq!(after, content);
+
+    // Trimming drops bullets around the block, never lines inside it
+    let (after, stats) = compact_content(content, 11);
+    assert_eq!(stats.trimmed_bullets, 1);
+    assert!(after.contains("- src/api/**\n- src/api/**\n## not a heading\n```"));
+    assert!(!after.contains("Stages list their files"));
+}
+
+#[test]
+fn test_backup_dirs_are_unique_within_a_second() {
+    let temp = tempfile::TempDir::new().unwrap();
+
+    let first = create_backup_dir(temp.path()).unwrap();
+    let second = create_backup_dir(temp.path()).unwrap();
+
+    assert_ne!(first, second);
+    assert!(first.is_dir() && second.is_dir());
+}
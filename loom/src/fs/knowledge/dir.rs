@@ -1,6 +1,8 @@
 //! Knowledge directory manager.
 
+use super::entry::{ENTRY_META_END, ENTRY_META_START};
 use super::gc::{analyze_gc_metrics, GcMetrics};
+use super::manifest::{knowledge_files, resolve_knowledge_file};
 use super::types::KnowledgeFile;
 use anyhow::{Context, Result};
 use std::fs;
@@ -38,8 +40,11 @@ impl KnowledgeDir {
             return false;
         }
 
-        for file_type in KnowledgeFile::all() {
-            let path = self.file_path(*file_type);
+        let file_types = self
+            .file_types()
+            .unwrap_or_else(|_| KnowledgeFile::built_in().to_vec());
+        for file_type in file_types {
+            let path = self.file_path(&file_type);
             if path.exists() {
                 if let Ok(content) = fs::read_to_string(&path) {
                     // Check if content has more than just the default template
@@ -65,10 +70,10 @@ impl KnowledgeDir {
         }
 
         // Create default files if they don't exist
-        for file_type in KnowledgeFile::all() {
-            let path = self.file_path(*file_type);
+        for file_type in self.file_types()? {
+            let path = self.file_path(&file_type);
             if !path.exists() {
-                let content = self.default_content(*file_type);
+                let content = self.default_content(&file_type);
                 fs::write(&path, content)
                     .with_context(|| format!("Failed to create {}", file_type.filename()))?;
             }
@@ -77,13 +82,24 @@ impl KnowledgeDir {
         Ok(())
     }
 
+    /// Built-in knowledge files followed by those declared in `index.toml`
+    pub fn file_types(&self) -> Result<Vec<KnowledgeFile>> {
+        knowledge_files(&self.root)
+    }
+
+    /// Resolve a knowledge file name, alias or filename, including files
+    /// declared in `index.toml`
+    pub fn parse_file(&self, file: &str) -> Result<KnowledgeFile> {
+        resolve_knowledge_file(&self.root, file)
+    }
+
     /// Get the path to a specific knowledge file
-    pub fn file_path(&self, file_type: KnowledgeFile) -> PathBuf {
+    pub fn file_path(&self, file_type: &KnowledgeFile) -> PathBuf {
         self.root.join(file_type.filename())
     }
 
     /// Read a knowledge file
-    pub fn read(&self, file_type: KnowledgeFile) -> Result<String> {
+    pub fn read(&self, file_type: &KnowledgeFile) -> Result<String> {
         let path = self.file_path(file_type);
         fs::read_to_string(&path)
             .with_context(|| format!("Failed to read {}", file_type.filename()))
@@ -92,19 +108,19 @@ impl KnowledgeDir {
     /// Read all knowledge files and return as a map
     pub fn read_all(&self) -> Result<Vec<(KnowledgeFile, String)>> {
         let mut results = Vec::new();
-        for file_type in KnowledgeFile::all() {
-            let path = self.file_path(*file_type);
+        for file_type in self.file_types()? {
+            let path = self.file_path(&file_type);
             if path.exists() {
                 let content = fs::read_to_string(&path)
                     .with_context(|| format!("Failed to read {}", file_type.filename()))?;
-                results.push((*file_type, content));
+                results.push((file_type, content));
             }
         }
         Ok(results)
     }
 
     /// Append content to a knowledge file (knowledge files are append-only)
-    pub fn append(&self, file_type: KnowledgeFile, content: &str) -> Result<()> {
+    pub fn append(&self, file_type: &KnowledgeFile, content: &str) -> Result<()> {
         let path = self.file_path(file_type);
 
         // Read existing content
@@ -134,8 +150,8 @@ impl KnowledgeDir {
         summary.push_str("## Knowledge Summary\n\n");
         summary.push_str("> Curated knowledge to help you navigate the codebase.\n\n");
 
-        for file_type in KnowledgeFile::all() {
-            let path = self.file_path(*file_type);
+        for file_type in self.file_types()? {
+            let path = self.file_path(&file_type);
             if path.exists() {
                 let content = fs::read_to_string(&path).ok();
                 if let Some(content) = content {
@@ -164,10 +180,24 @@ impl KnowledgeDir {
     fn extract_compact_summary(&self, content: &str) -> String {
         let mut summary = String::new();
         let mut in_section = false;
+        let mut in_meta = false;
         let mut line_count = 0;
         const MAX_LINES_PER_SECTION: usize = 5;
 
         for line in content.lines() {
+            // Skip structured entry metadata
+            match line.trim() {
+                ENTRY_META_START => in_meta = true,
+                ENTRY_META_END if in_meta => {
+                    in_meta = false;
+                    continue;
+                }
+                _ => {}
+            }
+            if in_meta {
+                continue;
+            }
+
             // Skip the title and intro lines
             if line.starts_with("# ") || line.starts_with("> ") {
                 continue;
@@ -200,7 +230,7 @@ impl KnowledgeDir {
     }
 
     /// Get default content for a knowledge file type
-    fn default_content(&self, file_type: KnowledgeFile) -> String {
+    fn default_content(&self, file_type: &KnowledgeFile) -> String {
         match file_type {
             KnowledgeFile::Architecture => r#"# Architecture
 
@@ -260,16 +290,22 @@ impl KnowledgeDir {
 (Add concerns as you discover them)
 "#
             .to_string(),
+            KnowledgeFile::Custom(custom) => format!(
+                "# {}\n\n> {}\n> This file is append-only - agents add discoveries, never delete.\n\n\
+                 (Add entries as you discover them)\n",
+                custom.title(),
+                custom.description
+            ),
         }
     }
 
     /// List all knowledge files that exist
     pub fn list_files(&self) -> Result<Vec<(KnowledgeFile, PathBuf)>> {
         let mut files = Vec::new();
-        for file_type in KnowledgeFile::all() {
-            let path = self.file_path(*file_type);
+        for file_type in self.file_types()? {
+            let path = self.file_path(&file_type);
             if path.exists() {
-                files.push((*file_type, path));
+                files.push((file_type, path));
             }
         }
         Ok(files)
@@ -303,8 +339,8 @@ mod tests {
         assert!(project_root.join("doc/loom/knowledge").exists());
 
         // Check all files were created
-        for file_type in KnowledgeFile::all() {
-            let path = knowledge.file_path(*file_type);
+        for file_type in KnowledgeFile::built_in() {
+            let path = knowledge.file_path(file_type);
             assert!(path.exists(), "File should exist: {}", file_type.filename());
         }
     }
@@ -320,12 +356,12 @@ mod tests {
         // Append to entry-points
         knowledge
             .append(
-                KnowledgeFile::EntryPoints,
+                &KnowledgeFile::EntryPoints,
                 "## New Section\n\n- New entry point",
             )
             .unwrap();
 
-        let content = knowledge.read(KnowledgeFile::EntryPoints).unwrap();
+        let content = knowledge.read(&KnowledgeFile::EntryPoints).unwrap();
         assert!(content.contains("## New Section"));
         assert!(content.contains("- New entry point"));
     }
@@ -341,7 +377,7 @@ mod tests {
         // Add some content
         knowledge
             .append(
-                KnowledgeFile::EntryPoints,
+                &KnowledgeFile::EntryPoints,
                 "## CLI Entry Point\n\n- main.rs - CLI definition",
             )
             .unwrap();
@@ -0,0 +1,192 @@
+//! Structured knowledge entries.
+//!
+//! A `## ` section of a knowledge file may start with a metadata block
+//! recording where the entry came from:
+//!
+//! ```text
+//! ## Sessions are stored in redis
+//! <!-- loom
+//! stage: auth
+//! commit: 3f2a9c1
+//! files: ["src/auth/session.rs"]
+//! tags: ["auth", "redis"]
+//! date: 2026-10-17
+//! -->
+//!
+//! - Session TTL is refreshed on every request
+//! ```
+//!
+//! The block is an HTML comment, so it does not show in rendered markdown.
+//! Sections without one are plain entries.
+
+use anyhow::{bail, Context, Result};
+use chrono::NaiveDate;
+use serde::Deserialize;
+
+/// First line of an entry metadata block
+pub const ENTRY_META_START: &str = "<!-- loom";
+/// Last line of an entry metadata block
+pub const ENTRY_META_END: &str = "-->";
+
+/// Provenance of a structured knowledge entry
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EntryMeta {
+    /// Stage that wrote the entry
+    #[serde(default)]
+    pub stage: Option<String>,
+    /// Commit the entry describes
+    #[serde(default)]
+    pub commit: Option<String>,
+    /// Files the entry is about, relative to the project root
+    #[serde(default)]
+    pub files: Vec<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub date: Option<NaiveDate>,
+}
+
+impl EntryMeta {
+    /// Render as a metadata block (without trailing newline)
+    pub fn to_block(&self) -> String {
+        let mut lines = vec![ENTRY_META_START.to_string()];
+        if let Some(stage) = &self.stage {
+            lines.push(format!("stage: {stage}"));
+        }
+        if let Some(commit) = &self.commit {
+            lines.push(format!("commit: {commit}"));
+        }
+        // JSON arrays are valid YAML flow sequences and quote paths safely
+        if !self.files.is_empty() {
+            lines.push(format!("files: {}", json_list(&self.files)));
+        }
+        if !self.tags.is_empty() {
+            lines.push(format!("tags: {}", json_list(&self.tags)));
+        }
+        if let Some(date) = self.date {
+            lines.push(format!("date: {}", date.format("%Y-%m-%d")));
+        }
+        lines.push(ENTRY_META_END.to_string());
+        lines.join("\n")
+    }
+}
+
+/// A `## ` section of a knowledge file
+#[derive(Debug, Clone)]
+pub struct KnowledgeEntry {
+    /// Heading text without the `## ` prefix
+    pub heading: String,
+    /// Metadata of a structured entry; `None` for plain entries and for
+    /// blocks that do not parse
+    pub meta: Option<EntryMeta>,
+    /// Section content after the heading and metadata block
+    pub body: String,
+}
+
+/// Split a knowledge file into its `## ` sections; text before the first
+/// section (title and intro) is skipped
+pub fn parse_entries(content: &str) -> Vec<KnowledgeEntry> {
+    let mut sections: Vec<(String, Vec<&str>)> = Vec::new();
+    for line in content.lines() {
+        if let Some(heading) = line.strip_prefix("## ") {
+            sections.push((heading.trim().to_string(), Vec::new()));
+        } else if let Some((_, lines)) = sections.last_mut() {
+            lines.push(line);
+        }
+    }
+    sections
+        .into_iter()
+        .map(|(heading, lines)| {
+            let (meta, body) = split_meta(&lines);
+            KnowledgeEntry {
+                heading,
+                meta,
+                body: body.join("\n").trim().to_string(),
+            }
+        })
+        .collect()
+}
+
+/// Insert a metadata block after the first heading of `content`
+pub fn with_meta(content: &str, meta: &EntryMeta) -> Result<String> {
+    let content = content.trim();
+    if !content.starts_with("## ") {
+        bail!("Structured knowledge entries must start with a '## ' heading");
+    }
+    let (heading, rest) = content.split_once('\n').unwrap_or((content, ""));
+    Ok(
+        format!("{heading}\n{}\n\n{}", meta.to_block(), rest.trim_start())
+            .trim_end()
+            .to_string(),
+    )
+}
+
+/// Split a leading metadata block off a section's lines
+fn split_meta<'a>(lines: &'a [&'a str]) -> (Option<EntryMeta>, &'a [&'a str]) {
+    let start = lines.iter().position(|line| !line.trim().is_empty());
+    let Some(start) = start.filter(|&i| lines[i].trim() == ENTRY_META_START) else {
+        return (None, lines);
+    };
+    let Some(len) = lines[start..]
+        .iter()
+        .position(|line| line.trim() == ENTRY_META_END)
+    else {
+        return (None, lines);
+    };
+    let yaml = lines[start + 1..start + len].join("\n");
+    (parse_meta(&yaml).ok(), &lines[start + len + 1..])
+}
+
+fn parse_meta(yaml: &str) -> Result<EntryMeta> {
+    if yaml.trim().is_empty() {
+        return Ok(EntryMeta::default());
+    }
+    serde_yaml::from_str(yaml).context("Invalid knowledge entry metadata")
+}
+
+fn json_list(items: &[String]) -> String {
+    serde_json::to_string(items).unwrap_or_else(|_| "[]".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn meta() -> EntryMeta {
+        EntryMeta {
+            stage: Some("auth".to_string()),
+            commit: Some("3f2a9c1".to_string()),
+            files: vec!["src/auth/session.rs".to_string()],
+            tags: vec!["auth".to_string(), "redis".to_string()],
+            date: NaiveDate::from_ymd_opt(2026, 10, 17),
+        }
+    }
+
+    #[test]
+    fn test_structured_entry_round_trip() {
+        let content = with_meta("## Sessions\n\n- Stored in redis", &meta()).unwrap();
+        let file = format!("# Patterns\n\n> intro\n\n{content}\n\n## Plain\n\n- Other\n");
+
+        let entries = parse_entries(&file);
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].heading, "Sessions");
+        assert_eq!(entries[0].meta, Some(meta()));
+        assert_eq!(entries[0].body, "- Stored in redis");
+        assert_eq!(entries[1].heading, "Plain");
+        assert!(entries[1].meta.is_none());
+    }
+
+    #[test]
+    fn test_with_meta_requires_heading() {
+        assert!(with_meta("- no heading", &meta()).is_err());
+    }
+
+    #[test]
+    fn test_invalid_meta_is_plain_entry() {
+        let entries = parse_entries("## Broken\n<!-- loom\nfiles: [\n-->\n\n- Body\n");
+        assert!(entries[0].meta.is_none());
+        assert_eq!(entries[0].body, "- Body");
+    }
+}
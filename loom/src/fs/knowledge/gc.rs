@@ -1,5 +1,6 @@
 //! Knowledge GC (garbage collection) analysis.
 
+use super::manifest::knowledge_files;
 use super::types::KnowledgeFile;
 use anyhow::{Context, Result};
 use std::collections::HashMap;
@@ -58,7 +59,7 @@ pub fn analyze_gc_metrics_with_promoted(
     let mut per_file = Vec::new();
     let mut total_lines = 0;
 
-    for file_type in KnowledgeFile::all() {
+    for file_type in knowledge_files(knowledge_root)? {
         let path = knowledge_root.join(file_type.filename());
         if !path.exists() {
             continue;
@@ -96,7 +97,7 @@ pub fn analyze_gc_metrics_with_promoted(
             || promoted_block_count > max_promoted_blocks;
 
         per_file.push(FileGcMetrics {
-            file_type: *file_type,
+            file_type,
             line_count,
             duplicate_headers,
             promoted_block_count,
@@ -168,7 +169,7 @@ mod tests {
         // Add small content to architecture
         knowledge
             .append(
-                KnowledgeFile::Architecture,
+                &KnowledgeFile::Architecture,
                 "## Small Section\n\n- Line 1\n- Line 2",
             )
             .unwrap();
@@ -196,7 +197,7 @@ mod tests {
             big_content.push_str(&format!("- Line {}\n", i));
         }
         knowledge
-            .append(KnowledgeFile::Architecture, &big_content)
+            .append(&KnowledgeFile::Architecture, &big_content)
             .unwrap();
 
         let metrics = analyze_gc_metrics(knowledge.root(), 200, 800).unwrap();
@@ -219,7 +220,7 @@ mod tests {
         // Add duplicate headers
         knowledge
             .append(
-                KnowledgeFile::Patterns,
+                &KnowledgeFile::Patterns,
                 "## Same Header\n\n- Content 1\n\n## Same Header\n\n- Content 2",
             )
             .unwrap();
@@ -256,7 +257,9 @@ mod tests {
         for i in 0..4 {
             content.push_str(&format!("## Promoted from Memory {}\n\n- Content\n\n", i));
         }
-        knowledge.append(KnowledgeFile::Mistakes, &content).unwrap();
+        knowledge
+            .append(&KnowledgeFile::Mistakes, &content)
+            .unwrap();
 
         let metrics = analyze_gc_metrics(knowledge.root(), 200, 800).unwrap();
         assert!(
@@ -292,19 +295,19 @@ mod tests {
 
         // Add to multiple files
         knowledge
-            .append(KnowledgeFile::Architecture, &medium_content)
+            .append(&KnowledgeFile::Architecture, &medium_content)
             .unwrap();
         knowledge
-            .append(KnowledgeFile::Patterns, &medium_content)
+            .append(&KnowledgeFile::Patterns, &medium_content)
             .unwrap();
         knowledge
-            .append(KnowledgeFile::Conventions, &medium_content)
+            .append(&KnowledgeFile::Conventions, &medium_content)
             .unwrap();
         knowledge
-            .append(KnowledgeFile::Mistakes, &medium_content)
+            .append(&KnowledgeFile::Mistakes, &medium_content)
             .unwrap();
         knowledge
-            .append(KnowledgeFile::Stack, &medium_content)
+            .append(&KnowledgeFile::Stack, &medium_content)
             .unwrap();
 
         let metrics = analyze_gc_metrics(knowledge.root(), 200, 800).unwrap();
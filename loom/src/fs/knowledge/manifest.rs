@@ -0,0 +1,171 @@
+//! Knowledge manifest: extra knowledge files declared in `index.toml`.
+//!
+//! ```toml
+//! [[files]]
+//! name = "api-contracts"
+//! description = "Request and response contracts between services"
+//! ```
+//!
+//! Declared files are used alongside the built-in ones, after them.
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use super::types::{CustomKnowledgeFile, KnowledgeFile};
+
+/// Manifest filename inside the knowledge directory
+pub const MANIFEST_FILE: &str = "index.toml";
+
+/// Contents of `index.toml`
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct KnowledgeManifest {
+    #[serde(default)]
+    pub files: Vec<ManifestFile>,
+}
+
+/// A knowledge file declared in the manifest
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ManifestFile {
+    /// Name without the `.md` extension
+    pub name: String,
+    pub description: String,
+}
+
+impl KnowledgeManifest {
+    /// Load the manifest of a knowledge directory; an empty manifest when
+    /// the directory has none
+    pub fn load(knowledge_root: &Path) -> Result<Self> {
+        let path = knowledge_root.join(MANIFEST_FILE);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        Self::parse(&content)
+            .with_context(|| format!("Invalid knowledge manifest: {}", path.display()))
+    }
+
+    /// Parse and validate manifest content
+    pub fn parse(content: &str) -> Result<Self> {
+        let manifest: Self = toml::from_str(content)?;
+        let mut seen = HashSet::new();
+        for file in &manifest.files {
+            validate_custom_name(&file.name)?;
+            if !seen.insert(file.name.as_str()) {
+                bail!("Knowledge file '{}' is declared twice", file.name);
+            }
+        }
+        Ok(manifest)
+    }
+
+    /// The declared files as knowledge file types
+    pub fn file_types(&self) -> Vec<KnowledgeFile> {
+        self.files
+            .iter()
+            .map(|file| {
+                KnowledgeFile::Custom(CustomKnowledgeFile {
+                    filename: format!("{}.md", file.name),
+                    description: file.description.clone(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Check that `name` can name a custom knowledge file: lowercase letters,
+/// digits and dashes, not clashing with a built-in file or its aliases
+pub fn validate_custom_name(name: &str) -> Result<()> {
+    let valid = name
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+    if name.is_empty() || name.starts_with('-') || !valid {
+        bail!(
+            "Invalid knowledge file name '{name}': use lowercase letters, digits and dashes, without '.md'"
+        );
+    }
+    if name.parse::<KnowledgeFile>().is_ok() {
+        bail!("Knowledge file name '{name}' clashes with a built-in knowledge file");
+    }
+    Ok(())
+}
+
+/// Built-in knowledge files followed by those declared in the manifest
+pub fn knowledge_files(knowledge_root: &Path) -> Result<Vec<KnowledgeFile>> {
+    let mut files = KnowledgeFile::built_in().to_vec();
+    files.extend(KnowledgeManifest::load(knowledge_root)?.file_types());
+    Ok(files)
+}
+
+/// Resolve a built-in file (by filename, name or alias) or a declared file
+/// (by filename or name)
+pub fn resolve_knowledge_file(knowledge_root: &Path, file: &str) -> Result<KnowledgeFile> {
+    if let Ok(file_type) = file.parse::<KnowledgeFile>() {
+        return Ok(file_type);
+    }
+    let files = knowledge_files(knowledge_root)?;
+    if let Some(file_type) = files
+        .iter()
+        .find(|f| f.filename() == file || f.name() == file)
+    {
+        return Ok(file_type.clone());
+    }
+    let valid_files: Vec<_> = files.iter().map(|f| f.filename()).collect();
+    bail!(
+        "Unknown knowledge file: '{}'. Valid files: {}",
+        file,
+        valid_files.join(", ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_resolve_declared_files() {
+        let temp = TempDir::new().unwrap();
+        fs::write(
+            temp.path().join(MANIFEST_FILE),
+            "[[files]]\nname = \"runbooks\"\ndescription = \"Operational runbooks\"\n",
+        )
+        .unwrap();
+
+        let files = knowledge_files(temp.path()).unwrap();
+        assert_eq!(files.len(), KnowledgeFile::built_in().len() + 1);
+
+        let runbooks = resolve_knowledge_file(temp.path(), "runbooks").unwrap();
+        assert_eq!(runbooks.filename(), "runbooks.md");
+        assert_eq!(runbooks.description(), "Operational runbooks");
+        assert_eq!(
+            resolve_knowledge_file(temp.path(), "runbooks.md").unwrap(),
+            runbooks
+        );
+        assert_eq!(
+            resolve_knowledge_file(temp.path(), "lessons").unwrap(),
+            KnowledgeFile::Mistakes
+        );
+        let error = resolve_knowledge_file(temp.path(), "unknown").unwrap_err();
+        assert!(error.to_string().contains("runbooks.md"));
+    }
+
+    #[test]
+    fn test_manifest_validation() {
+        assert!(KnowledgeManifest::load(Path::new("/nonexistent"))
+            .unwrap()
+            .files
+            .is_empty());
+        for name in ["", "Runbooks", "api.md", "-x", "patterns", "debt"] {
+            let content = format!("[[files]]\nname = \"{name}\"\ndescription = \"d\"\n");
+            assert!(KnowledgeManifest::parse(&content).is_err(), "{name}");
+        }
+        let twice = "[[files]]\nname = \"a\"\ndescription = \"d\"\n\
+                     [[files]]\nname = \"a\"\ndescription = \"d\"\n";
+        assert!(KnowledgeManifest::parse(twice).is_err());
+    }
+}
@@ -4,14 +4,27 @@
 //! We curate high-level knowledge that helps agents know WHERE to look,
 //! not raw indexing.
 
+pub mod compact;
 pub mod dir;
+pub mod entry;
 pub mod gc;
+pub mod manifest;
+pub mod staleness;
 pub mod types;
 
 // Re-export commonly used types
+pub use compact::{
+    apply_compaction, compact_content, plan_compaction, CompactStats, CompactedFile,
+};
 pub use dir::KnowledgeDir;
+pub use entry::{parse_entries, with_meta, EntryMeta, KnowledgeEntry};
 pub use gc::{
     analyze_gc_metrics, FileGcMetrics, GcMetrics, DEFAULT_MAX_FILE_LINES,
     DEFAULT_MAX_PROMOTED_BLOCKS, DEFAULT_MAX_TOTAL_LINES,
 };
-pub use types::KnowledgeFile;
+pub use manifest::{
+    knowledge_files, resolve_knowledge_file, validate_custom_name, KnowledgeManifest, ManifestFile,
+    MANIFEST_FILE,
+};
+pub use staleness::{find_stale_entries, StaleEntry, StaleReason, DEFAULT_MAX_CHURN_PERCENT};
+pub use types::{CustomKnowledgeFile, KnowledgeFile};
@@ -0,0 +1,203 @@
+//! Staleness of structured knowledge entries.
+//!
+//! An entry that records a commit and the files it describes is stale when
+//! one of those files was deleted, or has changed by more than a threshold
+//! since that commit. `knowledge check` reports stale entries and
+//! `knowledge gc` proposes removing them.
+
+use anyhow::Result;
+use std::fmt;
+use std::path::Path;
+
+use super::dir::KnowledgeDir;
+use super::entry::{parse_entries, EntryMeta};
+use super::types::KnowledgeFile;
+use crate::git::{run_git, run_git_bool};
+
+/// Changed lines, as a percentage of the file's lines at the recorded
+/// commit, above which an entry is stale
+pub const DEFAULT_MAX_CHURN_PERCENT: usize = 50;
+
+/// Why a structured entry may no longer be accurate
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StaleReason {
+    /// A referenced file no longer exists
+    Deleted(String),
+    /// A referenced file changed by this percentage since the recorded commit
+    Changed { path: String, percent: usize },
+    /// The recorded commit is not in the repository
+    UnknownCommit(String),
+}
+
+impl fmt::Display for StaleReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StaleReason::Deleted(path) => write!(f, "{path} was deleted"),
+            StaleReason::Changed { path, percent } => {
+                write!(f, "{path} changed {percent}% since the entry was written")
+            }
+            StaleReason::UnknownCommit(commit) => write!(f, "commit {commit} not found"),
+        }
+    }
+}
+
+/// A structured entry with at least one stale reason
+#[derive(Debug, Clone)]
+pub struct StaleEntry {
+    pub file_type: KnowledgeFile,
+    pub heading: String,
+    pub stage: Option<String>,
+    pub reasons: Vec<StaleReason>,
+}
+
+/// Stale structured entries across all knowledge files, in file order
+pub fn find_stale_entries(
+    knowledge: &KnowledgeDir,
+    repo_root: &Path,
+    max_churn_percent: usize,
+) -> Result<Vec<StaleEntry>> {
+    let mut stale = Vec::new();
+    for (file_type, content) in knowledge.read_all()? {
+        for entry in parse_entries(&content) {
+            let Some(meta) = entry.meta else {
+                continue;
+            };
+            let reasons = stale_reasons(&meta, repo_root, max_churn_percent);
+            if !reasons.is_empty() {
+                stale.push(StaleEntry {
+                    file_type: file_type.clone(),
+                    heading: entry.heading,
+                    stage: meta.stage,
+                    reasons,
+                });
+            }
+        }
+    }
+    Ok(stale)
+}
+
+fn stale_reasons(meta: &EntryMeta, repo_root: &Path, max_churn_percent: usize) -> Vec<StaleReason> {
+    let commit = meta.commit.as_deref();
+    if let Some(commit) = commit {
+        if !run_git_bool(
+            &["cat-file", "-e", &format!("{commit}^{{commit}}")],
+            repo_root,
+        ) {
+            return vec![StaleReason::UnknownCommit(commit.to_string())];
+        }
+    }
+
+    let mut reasons = Vec::new();
+    for path in &meta.files {
+        if !repo_root.join(path).exists() {
+            reasons.push(StaleReason::Deleted(path.clone()));
+            continue;
+        }
+        let Some(percent) = commit.and_then(|c| churn_percent(c, path, repo_root)) else {
+            continue;
+        };
+        if percent > max_churn_percent {
+            reasons.push(StaleReason::Changed {
+                path: path.clone(),
+                percent,
+            });
+        }
+    }
+    reasons
+}
+
+/// Lines added and removed in `path` between `commit` and the working
+/// tree, as a percentage of its lines at `commit`. `None` when the file did
+/// not exist at `commit` or is binary.
+fn churn_percent(commit: &str, path: &str, repo_root: &Path) -> Option<usize> {
+    let original = run_git(&["show", &format!("{commit}:{path}")], repo_root).ok()?;
+    if !original.status.success() {
+        return None;
+    }
+    let original_lines = String::from_utf8_lossy(&original.stdout).lines().count();
+
+    let diff = run_git(&["diff", "--numstat", commit, "--", path], repo_root).ok()?;
+    let stdout = String::from_utf8_lossy(&diff.stdout);
+    let changed: usize = match stdout.lines().next() {
+        Some(line) => line
+            .split('\t')
+            .take(2)
+            .map(|n| n.parse::<usize>().ok())
+            .sum::<Option<usize>>()?,
+        None => 0,
+    };
+    Some(changed * 100 / original_lines.max(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fs::knowledge::entry::with_meta;
+    use crate::git::run_git_checked;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn commit_all(root: &Path) -> String {
+        run_git_checked(&["add", "-A"], root).unwrap();
+        run_git_checked(&["commit", "-qm", "snapshot"], root).unwrap();
+        run_git_checked(&["rev-parse", "HEAD"], root).unwrap()
+    }
+
+    #[test]
+    fn test_find_stale_entries() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        run_git_checked(&["init", "-q"], root).unwrap();
+        run_git_checked(&["config", "user.email", "t@example.com"], root).unwrap();
+        run_git_checked(&["config", "user.name", "Test"], root).unwrap();
+        let lines: String = (0..10).map(|i| format!("line {i}\n")).collect();
+        for name in ["kept.rs", "rewritten.rs", "removed.rs"] {
+            fs::write(root.join(name), &lines).unwrap();
+        }
+        let commit = commit_all(root);
+        fs::write(root.join("kept.rs"), format!("{lines}line 10\n")).unwrap();
+        fs::write(root.join("rewritten.rs"), "fn main() {}\n").unwrap();
+        fs::remove_file(root.join("removed.rs")).unwrap();
+
+        let knowledge = KnowledgeDir::new(root);
+        knowledge.initialize().unwrap();
+        let entry = |heading: &str, files: &[&str], commit: &str| {
+            let meta = EntryMeta {
+                commit: Some(commit.to_string()),
+                files: files.iter().map(|f| f.to_string()).collect(),
+                ..Default::default()
+            };
+            with_meta(&format!("## {heading}\n\n- Body"), &meta).unwrap()
+        };
+        for content in [
+            entry("Fresh", &["kept.rs"], &commit),
+            entry("Rewritten", &["rewritten.rs", "removed.rs"], &commit),
+            entry(
+                "Lost",
+                &["kept.rs"],
+                "0000000000000000000000000000000000000000",
+            ),
+            "## Plain\n\n- removed.rs is not tracked".to_string(),
+        ] {
+            knowledge
+                .append(&KnowledgeFile::Patterns, &content)
+                .unwrap();
+        }
+
+        let stale = find_stale_entries(&knowledge, root, DEFAULT_MAX_CHURN_PERCENT).unwrap();
+
+        let headings: Vec<&str> = stale.iter().map(|e| e.heading.as_str()).collect();
+        assert_eq!(headings, ["Rewritten", "Lost"]);
+        assert_eq!(
+            stale[0].reasons,
+            [
+                StaleReason::Changed {
+                    path: "rewritten.rs".to_string(),
+                    percent: 110
+                },
+                StaleReason::Deleted("removed.rs".to_string()),
+            ]
+        );
+        assert!(matches!(stale[1].reasons[0], StaleReason::UnknownCommit(_)));
+    }
+}
@@ -1,7 +1,8 @@
 //! Knowledge file type definitions.
 
-/// Known knowledge file types
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Knowledge file types: the built-in files and those declared in the
+/// knowledge manifest
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum KnowledgeFile {
     Architecture,
     EntryPoints,
@@ -10,11 +11,49 @@ pub enum KnowledgeFile {
     Mistakes,
     Stack,
     Concerns,
+    /// A file declared in the knowledge manifest (`index.toml`)
+    Custom(CustomKnowledgeFile),
 }
 
+/// A knowledge file declared in the knowledge manifest
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomKnowledgeFile {
+    pub filename: String,
+    pub description: String,
+}
+
+impl CustomKnowledgeFile {
+    /// Title of the file: its name with capitalized words
+    pub fn title(&self) -> String {
+        self.filename
+            .trim_end_matches(".md")
+            .split('-')
+            .map(|word| {
+                let mut chars = word.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_uppercase().chain(chars).collect())
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+}
+
+/// The built-in knowledge files, in display order
+const BUILT_IN: &[KnowledgeFile] = &[
+    KnowledgeFile::Architecture,
+    KnowledgeFile::EntryPoints,
+    KnowledgeFile::Patterns,
+    KnowledgeFile::Conventions,
+    KnowledgeFile::Mistakes,
+    KnowledgeFile::Stack,
+    KnowledgeFile::Concerns,
+];
+
 impl KnowledgeFile {
     /// Get the filename for this knowledge file type
-    pub fn filename(&self) -> &'static str {
+    pub fn filename(&self) -> &str {
         match self {
             KnowledgeFile::Architecture => "architecture.md",
             KnowledgeFile::EntryPoints => "entry-points.md",
@@ -23,11 +62,17 @@ impl KnowledgeFile {
             KnowledgeFile::Mistakes => "mistakes.md",
             KnowledgeFile::Stack => "stack.md",
             KnowledgeFile::Concerns => "concerns.md",
+            KnowledgeFile::Custom(custom) => &custom.filename,
         }
     }
 
+    /// Get the name used on the command line: the filename without `.md`
+    pub fn name(&self) -> &str {
+        self.filename().trim_end_matches(".md")
+    }
+
     /// Get a description of what this file contains
-    pub fn description(&self) -> &'static str {
+    pub fn description(&self) -> &str {
         match self {
             KnowledgeFile::Architecture => {
                 "High-level component relationships, data flow, module dependencies"
@@ -38,10 +83,11 @@ impl KnowledgeFile {
             KnowledgeFile::Mistakes => "Mistakes made and lessons learned - what to avoid",
             KnowledgeFile::Stack => "Dependencies, frameworks, and tooling used in the project",
             KnowledgeFile::Concerns => "Technical debt, warnings, and issues to address",
+            KnowledgeFile::Custom(custom) => &custom.description,
         }
     }
 
-    /// Parse from filename
+    /// Parse a built-in file from its filename
     pub fn from_filename(filename: &str) -> Option<Self> {
         match filename {
             "architecture.md" => Some(KnowledgeFile::Architecture),
@@ -55,17 +101,45 @@ impl KnowledgeFile {
         }
     }
 
-    /// All known knowledge file types
-    pub fn all() -> &'static [KnowledgeFile] {
-        &[
-            KnowledgeFile::Architecture,
-            KnowledgeFile::EntryPoints,
-            KnowledgeFile::Patterns,
-            KnowledgeFile::Conventions,
-            KnowledgeFile::Mistakes,
-            KnowledgeFile::Stack,
-            KnowledgeFile::Concerns,
-        ]
+    /// The built-in knowledge file types. Custom files are listed by
+    /// `KnowledgeDir::file_types`.
+    pub fn built_in() -> &'static [KnowledgeFile] {
+        BUILT_IN
+    }
+}
+
+impl std::str::FromStr for KnowledgeFile {
+    type Err = anyhow::Error;
+
+    /// Parse a built-in filename, a name without `.md`, or a common alias
+    fn from_str(file: &str) -> Result<Self, Self::Err> {
+        if let Some(file_type) = KnowledgeFile::from_filename(file) {
+            return Ok(file_type);
+        }
+        if let Some(file_type) = KnowledgeFile::from_filename(&format!("{file}.md")) {
+            return Ok(file_type);
+        }
+
+        match file.to_lowercase().as_str() {
+            "arch" | "architecture" | "map" | "overview" => Ok(KnowledgeFile::Architecture),
+            "entry" | "entries" | "entry-point" | "entrypoints" => Ok(KnowledgeFile::EntryPoints),
+            "pattern" => Ok(KnowledgeFile::Patterns),
+            "convention" | "conventions" | "code" | "coding" => Ok(KnowledgeFile::Conventions),
+            "mistake" | "mistakes" | "lessons" | "lesson" => Ok(KnowledgeFile::Mistakes),
+            "stack" | "deps" | "dependencies" | "tech" | "tooling" => Ok(KnowledgeFile::Stack),
+            "concerns" | "concern" | "debt" | "issues" | "warnings" => Ok(KnowledgeFile::Concerns),
+            _ => {
+                let valid_files: Vec<_> = KnowledgeFile::built_in()
+                    .iter()
+                    .map(|f| f.filename())
+                    .collect();
+                anyhow::bail!(
+                    "Unknown knowledge file: '{}'. Valid files: {}",
+                    file,
+                    valid_files.join(", ")
+                )
+            }
+        }
     }
 }
 
@@ -82,6 +156,20 @@ mod tests {
         assert_eq!(KnowledgeFile::Mistakes.filename(), "mistakes.md");
         assert_eq!(KnowledgeFile::Stack.filename(), "stack.md");
         assert_eq!(KnowledgeFile::Concerns.filename(), "concerns.md");
+        assert_eq!(KnowledgeFile::EntryPoints.name(), "entry-points");
+    }
+
+    #[test]
+    fn test_custom_knowledge_file() {
+        let custom = CustomKnowledgeFile {
+            filename: "api-contracts.md".to_string(),
+            description: "Contracts between services".to_string(),
+        };
+        assert_eq!(custom.title(), "Api Contracts");
+
+        let file_type = KnowledgeFile::Custom(custom);
+        assert_eq!(file_type.name(), "api-contracts");
+        assert_eq!(file_type.description(), "Contracts between services");
     }
 
     #[test]
@@ -17,6 +17,7 @@ mod constants;
 mod export;
 mod parser;
 mod persistence;
+mod promote;
 mod query;
 mod storage;
 mod types;
@@ -36,6 +37,9 @@ pub use query::{generate_summary, get_recent_entries, query_entries};
 // Re-export export functions
 pub use export::{format_memory_for_handoff, format_memory_for_signal};
 
+// Re-export promotion to knowledge
+pub use promote::{format_promoted_entry, promote_entries, PromoteFilter, PROMOTED_BLOCK_HEADER};
+
 // Re-export persistence functions
 pub use persistence::{extract_key_notes, list_journals, preserve_for_crash, validate_content};
 
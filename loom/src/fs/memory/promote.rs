@@ -0,0 +1,189 @@
+//! Promotion of memory journal entries into curated knowledge.
+//!
+//! Journals live in `.work/memory/` and are lost on `loom clean`; promotion
+//! copies selected entries into a knowledge file as a
+//! `## Promoted from Memory` block, which `loom knowledge gc` counts.
+//! Entries already present in the target file are skipped, so promoting
+//! the same journal twice adds nothing.
+
+use anyhow::Result;
+use chrono::Utc;
+
+use super::storage::read_journal;
+use super::types::{MemoryEntry, MemoryEntryType};
+use crate::fs::knowledge::{KnowledgeDir, KnowledgeFile};
+use std::path::Path;
+
+/// Heading that starts every promoted block
+pub const PROMOTED_BLOCK_HEADER: &str = "## Promoted from Memory";
+
+/// Which journal entries to promote
+#[derive(Debug, Clone, Default)]
+pub struct PromoteFilter {
+    /// Only entries of this type (all types if not set)
+    pub entry_type: Option<MemoryEntryType>,
+    /// Only entries whose content or context contains this text (case-insensitive)
+    pub search: Option<String>,
+}
+
+impl PromoteFilter {
+    fn matches(&self, entry: &MemoryEntry) -> bool {
+        if self.entry_type.is_some_and(|t| t != entry.entry_type) {
+            return false;
+        }
+        let Some(search) = &self.search else {
+            return true;
+        };
+        let search = search.to_lowercase();
+        entry.content.to_lowercase().contains(&search)
+            || entry
+                .context
+                .as_ref()
+                .is_some_and(|c| c.to_lowercase().contains(&search))
+    }
+}
+
+/// Promote the matching entries of a stage's journal into `target`
+///
+/// Returns the number of entries promoted; nothing is written when no new
+/// entries match.
+pub fn promote_entries(
+    work_dir: &Path,
+    knowledge: &KnowledgeDir,
+    stage_id: &str,
+    filter: &PromoteFilter,
+    target: &KnowledgeFile,
+) -> Result<usize> {
+    let journal = read_journal(work_dir, stage_id)?;
+    let existing = if knowledge.file_path(target).exists() {
+        knowledge.read(target)?
+    } else {
+        String::new()
+    };
+
+    let lines: Vec<String> = journal
+        .entries
+        .iter()
+        .filter(|entry| filter.matches(entry))
+        .map(format_promoted_entry)
+        .filter(|line| !is_promoted(&existing, line))
+        .collect();
+    if lines.is_empty() {
+        return Ok(0);
+    }
+
+    let block = format!(
+        "{PROMOTED_BLOCK_HEADER}: {stage_id} ({})\n\n{}",
+        Utc::now().format("%Y-%m-%d"),
+        lines.join("\n")
+    );
+    knowledge.append(target, &block)?;
+    Ok(lines.len())
+}
+
+/// Format an entry as a bullet of a promoted block
+pub fn format_promoted_entry(entry: &MemoryEntry) -> String {
+    let mut line = format!(
+        "- **{}:** {}",
+        entry.entry_type.display_name(),
+        single_line(&entry.content)
+    );
+    if let Some(context) = &entry.context {
+        line.push_str(&format!("\n  - Rationale: {}", single_line(context)));
+    }
+    line
+}
+
+/// Whether a formatted entry's bullet line is already in `content`
+fn is_promoted(content: &str, formatted: &str) -> bool {
+    let bullet = formatted.lines().next().unwrap_or(formatted);
+    content.lines().any(|line| line.trim_end() == bullet)
+}
+
+fn single_line(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fs::memory::append_entry;
+    use tempfile::TempDir;
+
+    fn setup() -> (TempDir, KnowledgeDir) {
+        let temp = TempDir::new().unwrap();
+        let work_dir = temp.path().join(".work");
+        let entries = [
+            MemoryEntry::with_context(
+                MemoryEntryType::Decision,
+                "Use sqlx for queries".to_string(),
+                "compile-time checked".to_string(),
+            ),
+            MemoryEntry::new(MemoryEntryType::Note, "Auth lives in src/auth".to_string()),
+            MemoryEntry::new(
+                MemoryEntryType::Decision,
+                "Keep JWT expiry at 1h".to_string(),
+            ),
+        ];
+        for entry in &entries {
+            append_entry(&work_dir, "stage-a", entry).unwrap();
+        }
+        let knowledge = KnowledgeDir::new(temp.path());
+        knowledge.initialize().unwrap();
+        (temp, knowledge)
+    }
+
+    #[test]
+    fn test_promote_entries_by_type_and_search() {
+        let (temp, knowledge) = setup();
+        let work_dir = temp.path().join(".work");
+        let filter = PromoteFilter {
+            entry_type: Some(MemoryEntryType::Decision),
+            search: Some("SQLX".to_string()),
+        };
+
+        let count = promote_entries(
+            &work_dir,
+            &knowledge,
+            "stage-a",
+            &filter,
+            &KnowledgeFile::Patterns,
+        )
+        .unwrap();
+
+        assert_eq!(count, 1);
+        let content = knowledge.read(&KnowledgeFile::Patterns).unwrap();
+        assert!(content.contains("## Promoted from Memory: stage-a ("));
+        assert!(content
+            .contains("- **Decision:** Use sqlx for queries\n  - Rationale: compile-time checked"));
+        assert!(!content.contains("JWT"));
+    }
+
+    #[test]
+    fn test_promote_entries_skips_promoted_entries() {
+        let (temp, knowledge) = setup();
+        let work_dir = temp.path().join(".work");
+        let filter = PromoteFilter::default();
+
+        let first = promote_entries(
+            &work_dir,
+            &knowledge,
+            "stage-a",
+            &filter,
+            &KnowledgeFile::Mistakes,
+        )
+        .unwrap();
+        let second = promote_entries(
+            &work_dir,
+            &knowledge,
+            "stage-a",
+            &filter,
+            &KnowledgeFile::Mistakes,
+        )
+        .unwrap();
+
+        assert_eq!((first, second), (3, 0));
+        let content = knowledge.read(&KnowledgeFile::Mistakes).unwrap();
+        assert_eq!(content.matches(PROMOTED_BLOCK_HEADER).count(), 1);
+    }
+}
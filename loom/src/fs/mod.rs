@@ -1,8 +1,11 @@
+pub mod criteria_cache;
+pub mod criteria_history;
 pub mod knowledge;
 pub mod locking;
 pub mod memory;
 pub mod permissions;
 pub mod plan_lifecycle;
+pub mod search;
 pub mod session_files;
 pub mod stage_files;
 pub mod stage_loading;
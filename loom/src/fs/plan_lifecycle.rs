@@ -12,6 +12,7 @@ use std::fs;
 use std::path::{Path, PathBuf};
 
 use crate::fs::work_dir::WorkDir;
+use crate::models::stage::Stage;
 use crate::parser::frontmatter::extract_frontmatter_field;
 
 // Filename prefix constants
@@ -86,6 +87,9 @@ pub fn update_plan_source_path(work_dir: &WorkDir, new_path: &Path) -> Result<()
 // ===== Merge Status =====
 
 /// Check if all stages are merged by reading stage files.
+///
+/// Stages skipped by an unmet `when:` condition have nothing to merge and
+/// count as merged.
 pub fn all_stages_merged(work_dir: &WorkDir) -> Result<bool> {
     let stages_dir = work_dir.root().join("stages");
 
@@ -111,14 +115,10 @@ pub fn all_stages_merged(work_dir: &WorkDir) -> Result<bool> {
             .with_context(|| format!("Failed to read stage file: {}", path.display()))?;
 
         // Parse YAML frontmatter to check merged status
-        match extract_frontmatter_field(&content, "merged") {
-            Ok(Some(value)) if value == "true" => {
-                // Stage is merged, continue checking others
-            }
-            _ => {
-                // Not merged or error parsing
-                return Ok(false);
-            }
+        let is_true = |field| matches!(extract_frontmatter_field(&content, field), Ok(Some(value)) if value == "true");
+        if !Stage::landed(is_true("merged"), is_true("condition_skipped")) {
+            // Not merged or error parsing
+            return Ok(false);
         }
     }
 
@@ -371,6 +371,21 @@ mod tests {
         assert!(!result);
     }
 
+    #[test]
+    fn test_all_stages_merged_counts_condition_skipped_stages() {
+        let temp_dir = TempDir::new().unwrap();
+        let work_dir = create_test_work_dir(&temp_dir);
+
+        create_stage_file(&work_dir, "stage-1", true);
+        fs::write(
+            work_dir.root().join("stages").join("1-stage-2.md"),
+            "---\nid: stage-2\nname: Test Stage\nstatus: Skipped\nmerged: false\ncondition_skipped: true\n---\n# Stage\n",
+        )
+        .unwrap();
+
+        assert!(all_stages_merged(&work_dir).unwrap());
+    }
+
     // Plan lifecycle tests
     #[test]
     fn test_mark_plan_in_progress_renames_file() {
@@ -0,0 +1,120 @@
+//! Reading memory journals, handoffs and knowledge files into documents.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::{SearchDocument, SourceKind};
+use crate::fs::knowledge::{parse_entries, KnowledgeDir};
+use crate::fs::memory::{list_journals, memory_dir, read_journal};
+
+/// Every file the index is built from, sorted
+pub(super) fn source_files(work_dir: &Path, knowledge: &KnowledgeDir) -> Result<Vec<PathBuf>> {
+    let mut files = markdown_files(&memory_dir(work_dir))?;
+    files.extend(markdown_files(&work_dir.join("handoffs"))?);
+    files.extend(knowledge.list_files()?.into_iter().map(|(_, path)| path));
+    files.sort();
+    Ok(files)
+}
+
+/// Split every source file into search documents
+pub fn collect_documents(work_dir: &Path, knowledge: &KnowledgeDir) -> Result<Vec<SearchDocument>> {
+    let mut documents = Vec::new();
+    let mut stages = list_journals(work_dir)?;
+    stages.sort();
+    for stage_id in stages {
+        let journal = read_journal(work_dir, &stage_id)?;
+        let path = format!(".work/memory/{stage_id}.md");
+        documents.extend(journal.entries.into_iter().map(|entry| {
+            let text = match entry.context {
+                Some(context) => format!("{}\nRationale: {context}", entry.content),
+                None => entry.content,
+            };
+            SearchDocument {
+                source: SourceKind::Memory,
+                path: path.clone(),
+                stage_id: Some(stage_id.clone()),
+                entry_type: Some(entry.entry_type),
+                timestamp: Some(entry.timestamp),
+                heading: None,
+                text,
+            }
+        }));
+    }
+
+    for file in markdown_files(&work_dir.join("handoffs"))? {
+        documents.push(handoff_document(&file)?);
+    }
+    for (file_type, file) in knowledge.list_files()? {
+        let content = knowledge.read(&file_type)?;
+        let path = format!("doc/loom/knowledge/{}", file_type.filename());
+        documents.extend(knowledge_documents(&content, &path, modified(&file)));
+    }
+    Ok(documents)
+}
+
+fn handoff_document(file: &Path) -> Result<SearchDocument> {
+    let text = fs::read_to_string(file)
+        .with_context(|| format!("Failed to read handoff: {}", file.display()))?;
+    let name = file
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+    // Handoffs are named {stage_id}-handoff-{NNN}.md
+    let stage_id = name
+        .rsplit_once("-handoff-")
+        .map(|(stage, _)| stage.to_string());
+    Ok(SearchDocument {
+        source: SourceKind::Handoff,
+        path: format!(".work/handoffs/{name}.md"),
+        stage_id,
+        entry_type: None,
+        timestamp: modified(file),
+        heading: None,
+        text,
+    })
+}
+
+/// One document per `## ` section, without structured entry metadata
+fn knowledge_documents(
+    content: &str,
+    path: &str,
+    timestamp: Option<DateTime<Utc>>,
+) -> Vec<SearchDocument> {
+    parse_entries(content)
+        .into_iter()
+        .filter(|entry| !entry.body.is_empty())
+        .map(|entry| SearchDocument {
+            source: SourceKind::Knowledge,
+            path: path.to_string(),
+            stage_id: entry.meta.and_then(|meta| meta.stage),
+            entry_type: None,
+            timestamp,
+            heading: Some(entry.heading),
+            text: entry.body,
+        })
+        .collect()
+}
+
+fn markdown_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir).with_context(|| format!("Failed to read {}", dir.display()))? {
+        let path = entry?.path();
+        if path.is_file() && path.extension().is_some_and(|ext| ext == "md") {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+pub(super) fn modified(file: &Path) -> Option<DateTime<Utc>> {
+    fs::metadata(file)
+        .and_then(|meta| meta.modified())
+        .ok()
+        .map(DateTime::<Utc>::from)
+}
@@ -0,0 +1,205 @@
+//! Inverted index with BM25 ranking, persisted under `.work/index/`.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::documents::{collect_documents, modified, source_files};
+use super::{tokenize, SearchDocument, SearchFilter, SearchHit};
+use crate::fs::knowledge::KnowledgeDir;
+use crate::fs::memory::MemoryEntryType;
+
+/// Bumped when the document or index layout changes, to force a rebuild
+const INDEX_VERSION: u32 = 2;
+
+/// BM25 term frequency saturation
+const K1: f64 = 1.2;
+/// BM25 document length normalization
+const B: f64 = 0.75;
+
+/// Words of context kept on each side of the first match in a snippet
+const SNIPPET_WORDS: usize = 12;
+
+/// Documents and the postings (document, term frequency) of every term
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SearchIndex {
+    version: u32,
+    /// Hash of the paths, sizes and modification times of the source files
+    fingerprint: String,
+    documents: Vec<SearchDocument>,
+    /// Token count of each document
+    lengths: Vec<u32>,
+    postings: BTreeMap<String, Vec<(u32, u32)>>,
+}
+
+/// Location of the persisted index
+pub fn index_path(work_dir: &Path) -> PathBuf {
+    work_dir.join("index").join("search.json")
+}
+
+/// Load the persisted index, rebuilding and saving it when its source files
+/// changed since it was written
+pub fn load_or_build_index(work_dir: &Path, knowledge: &KnowledgeDir) -> Result<SearchIndex> {
+    let fingerprint = fingerprint(&source_files(work_dir, knowledge)?);
+    let path = index_path(work_dir);
+    let cached = fs::read_to_string(&path)
+        .ok()
+        .and_then(|content| serde_json::from_str::<SearchIndex>(&content).ok())
+        .filter(|index| index.version == INDEX_VERSION && index.fingerprint == fingerprint);
+    if let Some(index) = cached {
+        return Ok(index);
+    }
+
+    let index = SearchIndex::build(collect_documents(work_dir, knowledge)?, fingerprint);
+    index.save(&path)?;
+    Ok(index)
+}
+
+/// Decisions recorded by other stages that best match `text`, best first
+pub fn relevant_decisions(
+    work_dir: &Path,
+    knowledge: &KnowledgeDir,
+    stage_id: &str,
+    text: &str,
+    limit: usize,
+) -> Result<Vec<SearchHit>> {
+    let index = load_or_build_index(work_dir, knowledge)?;
+    let filter = SearchFilter {
+        entry_type: Some(MemoryEntryType::Decision),
+        exclude_stage: Some(stage_id.to_string()),
+        ..Default::default()
+    };
+    Ok(index.search(text, &filter, limit))
+}
+
+impl SearchIndex {
+    /// Index `documents`, recording the fingerprint of their sources
+    pub fn build(documents: Vec<SearchDocument>, fingerprint: String) -> Self {
+        let mut postings: BTreeMap<String, Vec<(u32, u32)>> = BTreeMap::new();
+        let mut lengths = Vec::with_capacity(documents.len());
+        for (id, document) in documents.iter().enumerate() {
+            let mut text = document.text.clone();
+            if let Some(heading) = &document.heading {
+                text = format!("{heading}\n{text}");
+            }
+            let tokens = tokenize(&text);
+            lengths.push(tokens.len() as u32);
+            let mut counts: BTreeMap<String, u32> = BTreeMap::new();
+            for token in tokens {
+                *counts.entry(token).or_default() += 1;
+            }
+            for (term, count) in counts {
+                postings.entry(term).or_default().push((id as u32, count));
+            }
+        }
+        Self {
+            version: INDEX_VERSION,
+            fingerprint,
+            documents,
+            lengths,
+            postings,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.documents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.documents.is_empty()
+    }
+
+    /// The `limit` best matches of `query` among the documents `filter` allows
+    pub fn search(&self, query: &str, filter: &SearchFilter, limit: usize) -> Vec<SearchHit> {
+        let terms: BTreeSet<String> = tokenize(query).into_iter().collect();
+        let mut hits: Vec<SearchHit> = self
+            .scores(&terms)
+            .into_iter()
+            .map(|(id, score)| (&self.documents[id], score))
+            .filter(|(document, _)| filter.matches(document))
+            .map(|(document, score)| SearchHit {
+                snippet: snippet(&document.text, &terms),
+                document: document.clone(),
+                score,
+            })
+            .collect();
+        hits.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then_with(|| b.document.timestamp.cmp(&a.document.timestamp))
+        });
+        hits.truncate(limit);
+        hits
+    }
+
+    /// BM25 score of every document containing at least one term
+    fn scores(&self, terms: &BTreeSet<String>) -> HashMap<usize, f64> {
+        let count = self.documents.len() as f64;
+        let total: u64 = self.lengths.iter().map(|&l| u64::from(l)).sum();
+        let average = (total as f64 / count.max(1.0)).max(1.0);
+
+        let mut scores = HashMap::new();
+        for term in terms {
+            let Some(postings) = self.postings.get(term) else {
+                continue;
+            };
+            let frequency = postings.len() as f64;
+            let idf = (1.0 + (count - frequency + 0.5) / (frequency + 0.5)).ln();
+            for &(id, tf) in postings {
+                let tf = f64::from(tf);
+                let length = f64::from(self.lengths[id as usize]);
+                let norm = K1 * (1.0 - B + B * length / average);
+                *scores.entry(id as usize).or_insert(0.0) += idf * tf * (K1 + 1.0) / (tf + norm);
+            }
+        }
+        scores
+    }
+
+    fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).context("Failed to create index directory")?;
+        }
+        let content = serde_json::to_string(self).context("Failed to serialize search index")?;
+        // Write then rename, so a concurrent reader never sees a partial index
+        let temp = path.with_extension(format!("json.{}", std::process::id()));
+        fs::write(&temp, content)
+            .with_context(|| format!("Failed to write search index: {}", temp.display()))?;
+        fs::rename(&temp, path)
+            .with_context(|| format!("Failed to write search index: {}", path.display()))
+    }
+}
+
+/// Hash of the path, size and modification time of each file
+fn fingerprint(files: &[PathBuf]) -> String {
+    let mut hasher = Sha256::new();
+    for file in files {
+        let size = fs::metadata(file).map(|meta| meta.len()).unwrap_or(0);
+        let time = modified(file).map(|t| t.timestamp_nanos_opt().unwrap_or(0));
+        hasher.update(file.to_string_lossy().as_bytes());
+        hasher.update(format!("\0{size}\0{}\0", time.unwrap_or(0)).as_bytes());
+    }
+    hex::encode(hasher.finalize())
+}
+
+/// A single-line excerpt of `text` around the first word containing a term
+fn snippet(text: &str, terms: &BTreeSet<String>) -> String {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let first = words
+        .iter()
+        .position(|word| tokenize(word).iter().any(|token| terms.contains(token)))
+        .unwrap_or(0);
+    let start = first.saturating_sub(SNIPPET_WORDS);
+    let end = (first + SNIPPET_WORDS + 1).min(words.len());
+
+    let mut snippet = words[start..end].join(" ");
+    if start > 0 {
+        snippet.insert_str(0, "… ");
+    }
+    if end < words.len() {
+        snippet.push_str(" …");
+    }
+    snippet
+}
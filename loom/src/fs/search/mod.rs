@@ -0,0 +1,128 @@
+//! Full-text search over stage memory, handoffs and knowledge.
+//!
+//! Every memory journal entry, handoff file and `## ` section of a knowledge
+//! file becomes one document of a local inverted index, persisted in
+//! `.work/index/search.json`. Queries are ranked with BM25. The index records
+//! a fingerprint of its source files and is rebuilt when any of them is
+//! added, removed or modified.
+
+mod documents;
+mod index;
+#[cfg(test)]
+mod tests;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::fs::memory::MemoryEntryType;
+
+pub use documents::collect_documents;
+pub use index::{index_path, load_or_build_index, relevant_decisions, SearchIndex};
+
+/// Where an indexed document came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SourceKind {
+    Memory,
+    Handoff,
+    Knowledge,
+}
+
+impl std::fmt::Display for SourceKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SourceKind::Memory => write!(f, "memory"),
+            SourceKind::Handoff => write!(f, "handoff"),
+            SourceKind::Knowledge => write!(f, "knowledge"),
+        }
+    }
+}
+
+impl std::str::FromStr for SourceKind {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "memory" => Ok(SourceKind::Memory),
+            "handoff" | "handoffs" => Ok(SourceKind::Handoff),
+            "knowledge" => Ok(SourceKind::Knowledge),
+            _ => anyhow::bail!("Invalid source: {s}. Use: memory, handoff, knowledge"),
+        }
+    }
+}
+
+/// One searchable unit: a memory entry, a handoff or a knowledge section
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchDocument {
+    pub source: SourceKind,
+    /// File the document was read from, relative to the project root
+    pub path: String,
+    /// Stage the document belongs to (for knowledge, the stage a structured
+    /// entry records)
+    pub stage_id: Option<String>,
+    /// Entry type of a memory entry
+    pub entry_type: Option<MemoryEntryType>,
+    /// Entry time for memory, file modification time otherwise
+    pub timestamp: Option<DateTime<Utc>>,
+    /// Section heading of a knowledge document
+    pub heading: Option<String>,
+    pub text: String,
+}
+
+/// Which documents a query may return
+#[derive(Debug, Clone, Default)]
+pub struct SearchFilter {
+    /// Only memory entries of this type
+    pub entry_type: Option<MemoryEntryType>,
+    pub source: Option<SourceKind>,
+    /// Only documents from this stage
+    pub stage_id: Option<String>,
+    /// Skip documents from this stage
+    pub exclude_stage: Option<String>,
+    /// Only documents at or after this time
+    pub since: Option<DateTime<Utc>>,
+    /// Only documents before this time
+    pub until: Option<DateTime<Utc>>,
+}
+
+impl SearchFilter {
+    fn matches(&self, doc: &SearchDocument) -> bool {
+        if self.entry_type.is_some() && doc.entry_type != self.entry_type {
+            return false;
+        }
+        if self.source.is_some_and(|s| s != doc.source) {
+            return false;
+        }
+        let stage = doc.stage_id.as_deref();
+        if self.stage_id.is_some() && stage != self.stage_id.as_deref() {
+            return false;
+        }
+        if self.exclude_stage.is_some() && stage == self.exclude_stage.as_deref() {
+            return false;
+        }
+        match doc.timestamp {
+            Some(time) => {
+                self.since.is_none_or(|since| time >= since)
+                    && self.until.is_none_or(|until| time < until)
+            }
+            None => self.since.is_none() && self.until.is_none(),
+        }
+    }
+}
+
+/// A ranked search result
+#[derive(Debug, Clone)]
+pub struct SearchHit {
+    pub document: SearchDocument,
+    pub score: f64,
+    /// Excerpt around the first matching term
+    pub snippet: String,
+}
+
+/// Lowercased alphanumeric terms of `text`, without very short words
+pub fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric() && c != '_')
+        .filter(|word| word.chars().count() > 1)
+        .map(str::to_lowercase)
+        .collect()
+}
@@ -0,0 +1,143 @@
+use super::*;
+use crate::fs::knowledge::{KnowledgeDir, KnowledgeFile};
+use crate::fs::memory::{append_entry, MemoryEntry};
+use chrono::Duration;
+use std::fs;
+use tempfile::TempDir;
+
+fn setup() -> (TempDir, KnowledgeDir) {
+    let temp = TempDir::new().unwrap();
+    let work_dir = temp.path().join(".work");
+    let entries = [
+        (
+            "auth",
+            MemoryEntry::with_context(
+                MemoryEntryType::Decision,
+                "Store sessions in redis".to_string(),
+                "sessions must survive restarts".to_string(),
+            ),
+        ),
+        (
+            "auth",
+            MemoryEntry::new(
+                MemoryEntryType::Note,
+                "Login handler lives in src/auth/login.rs".to_string(),
+            ),
+        ),
+        (
+            "billing",
+            MemoryEntry::new(
+                MemoryEntryType::Decision,
+                "Invoices are rendered with tera templates".to_string(),
+            ),
+        ),
+    ];
+    for (stage, entry) in &entries {
+        append_entry(&work_dir, stage, entry).unwrap();
+    }
+
+    let handoffs = work_dir.join("handoffs");
+    fs::create_dir_all(&handoffs).unwrap();
+    fs::write(
+        handoffs.join("auth-handoff-001.md"),
+        "# Handoff\n\nRedis connection pooling still needs a retry policy.\n",
+    )
+    .unwrap();
+
+    let knowledge = KnowledgeDir::new(temp.path());
+    knowledge.initialize().unwrap();
+    knowledge
+        .append(
+            &KnowledgeFile::Patterns,
+            "## Caching\n\nAll caches go through the redis client wrapper.",
+        )
+        .unwrap();
+    (temp, knowledge)
+}
+
+#[test]
+fn test_tokenize() {
+    assert_eq!(
+        tokenize("Use `sqlx::query!` for a DB_URL"),
+        vec!["use", "sqlx", "query", "for", "db_url"]
+    );
+}
+
+#[test]
+fn test_search_ranks_and_attributes_sources() {
+    let (temp, knowledge) = setup();
+    let index = load_or_build_index(&temp.path().join(".work"), &knowledge).unwrap();
+
+    let hits = index.search("redis sessions", &SearchFilter::default(), 10);
+
+    let first = &hits[0].document;
+    assert_eq!(first.source, SourceKind::Memory);
+    assert_eq!(first.stage_id.as_deref(), Some("auth"));
+    assert_eq!(first.entry_type, Some(MemoryEntryType::Decision));
+    assert!(hits[0].snippet.contains("redis"));
+    let handoff = hits
+        .iter()
+        .find(|hit| hit.document.source == SourceKind::Handoff)
+        .unwrap();
+    assert_eq!(handoff.document.stage_id.as_deref(), Some("auth"));
+    let section = hits
+        .iter()
+        .find(|hit| hit.document.source == SourceKind::Knowledge)
+        .unwrap();
+    assert_eq!(section.document.heading.as_deref(), Some("Caching"));
+    assert!(hits.iter().all(|hit| hit.score > 0.0));
+    assert!(hits.windows(2).all(|w| w[0].score >= w[1].score));
+}
+
+#[test]
+fn test_search_filters() {
+    let (temp, knowledge) = setup();
+    let index = load_or_build_index(&temp.path().join(".work"), &knowledge).unwrap();
+
+    let decisions = SearchFilter {
+        entry_type: Some(MemoryEntryType::Decision),
+        exclude_stage: Some("auth".to_string()),
+        ..Default::default()
+    };
+    let hits = index.search("redis tera", &decisions, 10);
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].document.stage_id.as_deref(), Some("billing"));
+
+    let future = SearchFilter {
+        since: Some(Utc::now() + Duration::days(1)),
+        ..Default::default()
+    };
+    assert!(index.search("redis", &future, 10).is_empty());
+    let past = SearchFilter {
+        until: Some(Utc::now() + Duration::days(1)),
+        source: Some(SourceKind::Handoff),
+        ..Default::default()
+    };
+    assert_eq!(index.search("redis", &past, 10).len(), 1);
+}
+
+#[test]
+fn test_index_is_rebuilt_when_sources_change() {
+    let (temp, knowledge) = setup();
+    let work_dir = temp.path().join(".work");
+    let first = load_or_build_index(&work_dir, &knowledge).unwrap();
+    assert!(index_path(&work_dir).exists());
+    assert!(first
+        .search("kafka", &SearchFilter::default(), 10)
+        .is_empty());
+
+    append_entry(
+        &work_dir,
+        "events",
+        &MemoryEntry::new(
+            MemoryEntryType::Decision,
+            "Publish events to kafka".to_string(),
+        ),
+    )
+    .unwrap();
+    let second = load_or_build_index(&work_dir, &knowledge).unwrap();
+
+    assert_eq!(second.len(), first.len() + 1);
+    let hits = relevant_decisions(&work_dir, &knowledge, "auth", "kafka events", 5).unwrap();
+    assert_eq!(hits[0].document.stage_id.as_deref(), Some("events"));
+}
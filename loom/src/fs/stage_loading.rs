@@ -4,7 +4,7 @@ use anyhow::{Context, Result};
 use std::path::Path;
 
 use crate::parser::frontmatter::parse_from_markdown;
-use crate::plan::schema::StageDefinition;
+use crate::plan::schema::{AcceptanceCriterion, StageDefinition};
 use crate::validation::validate_id;
 
 /// Stage frontmatter data extracted from .work/stages/*.md files
@@ -19,7 +19,9 @@ pub struct StageFrontmatter {
     #[serde(default)]
     pub parallel_group: Option<String>,
     #[serde(default)]
-    pub acceptance: Vec<String>,
+    pub acceptance: Vec<AcceptanceCriterion>,
+    #[serde(default)]
+    pub parallel: Option<bool>,
     #[serde(default)]
     pub setup: Vec<String>,
     #[serde(default)]
@@ -39,6 +41,8 @@ pub struct StageFrontmatter {
     #[serde(default)]
     pub dead_code_check: Option<crate::plan::schema::DeadCodeCheck>,
     #[serde(default)]
+    pub overlap: Option<crate::models::stage::OverlapPolicy>,
+    #[serde(default)]
     pub execution_mode: Option<crate::models::stage::ExecutionMode>,
 }
 
@@ -52,9 +56,12 @@ impl StageFrontmatter {
             dependencies: self.dependencies,
             parallel_group: self.parallel_group,
             acceptance: self.acceptance,
+            parallel: self.parallel,
             setup: self.setup,
             files: self.files,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: self.overlap,
             working_dir: self.working_dir.unwrap_or_else(|| ".".to_string()),
             stage_type: crate::plan::schema::StageType::default(),
             truths: self.truths,
@@ -66,6 +73,12 @@ impl StageFrontmatter {
             before_stage: vec![],
             after_stage: vec![],
             context_budget: None,
+            timeout: None,
+            budget: None,
+            model: None,
+            for_each: None,
+            when: None,
+            output_keys: vec![],
             sandbox: crate::plan::schema::StageSandboxConfig::default(),
             execution_mode: self.execution_mode,
             bug_fix: None,
@@ -107,7 +107,7 @@ pub fn list_verifications(work_dir: &Path) -> Result<Vec<VerificationRecord>> {
     }
 
     // Sort by timestamp descending
-    records.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+    records.sort_by_key(|r| std::cmp::Reverse(r.timestamp));
     Ok(records)
 }
 
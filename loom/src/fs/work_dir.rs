@@ -19,6 +19,38 @@ impl Config {
             .and_then(|v| v.as_str())
     }
 
+    /// Get a string value from the run section (e.g., "backend")
+    pub fn get_run_str(&self, key: &str) -> Option<&str> {
+        self.inner
+            .get("run")
+            .and_then(|r| r.get(key))
+            .and_then(|v| v.as_str())
+    }
+
+    /// Get a raw value from the run section (e.g., "max_parallel", "auto_merge")
+    pub fn get_run_value(&self, key: &str) -> Option<&toml::Value> {
+        self.inner.get("run").and_then(|r| r.get(key))
+    }
+
+    /// Get a raw top-level value (e.g., the "notifiers" array)
+    pub fn get(&self, key: &str) -> Option<&toml::Value> {
+        self.inner.get(key)
+    }
+
+    /// Set a value in the run section, creating the section if needed
+    pub fn set_run_value(&mut self, key: &str, value: toml::Value) -> Result<()> {
+        let root = self
+            .inner
+            .as_table_mut()
+            .context("config.toml root is not a table")?;
+        root.entry("run")
+            .or_insert_with(|| toml::Value::Table(toml::map::Map::new()))
+            .as_table_mut()
+            .context("[run] in config.toml is not a table")?
+            .insert(key.to_string(), value);
+        Ok(())
+    }
+
     /// Get the plan source path
     pub fn source_path(&self) -> Option<PathBuf> {
         self.get_plan_str("source_path").map(PathBuf::from)
@@ -7,6 +7,9 @@ use std::process::Command;
 use super::operations::delete_branch;
 
 /// Clean up loom branches that have been merged
+///
+/// Only branches merged with a merge commit or fast-forward show up here;
+/// squashed and rebased branches are force-deleted by per-stage cleanup.
 pub fn cleanup_merged_branches(target_branch: &str, repo_root: &Path) -> Result<Vec<String>> {
     // Get merged branches
     let output = Command::new("git")
@@ -1,6 +1,6 @@
 //! Core branch operations: create, delete, list, check existence
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use std::path::Path;
 
 use super::info::{parse_branch_list, BranchInfo};
@@ -91,6 +91,19 @@ pub fn resolve_target_branch(config_branch: &Option<String>, repo_root: &Path) -
         .unwrap_or_else(|| default_branch(repo_root).unwrap_or_else(|_| "main".to_string()))
 }
 
+/// Push a local branch to a branch on a remote, which may have another name
+pub fn push_branch(local: &str, remote: &str, remote_branch: &str, repo_root: &Path) -> Result<()> {
+    let refspec = format!("refs/heads/{local}:refs/heads/{remote_branch}");
+    let output = run_git(&["push", "--porcelain", remote, &refspec], repo_root)?;
+    if !output.status.success() {
+        bail!(
+            "Failed to push '{local}' to {remote}/{remote_branch}: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
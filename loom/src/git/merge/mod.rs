@@ -1,7 +1,9 @@
 //! Git merge operations for integrating worktree branches
 
 pub mod lock;
+mod predict;
 mod status;
+mod strategy;
 
 use anyhow::{bail, Result};
 use std::path::Path;
@@ -9,10 +11,15 @@ use std::time::Duration;
 
 use super::branch::{branch_exists, branch_name_for_stage, current_branch, is_ancestor_of};
 use crate::git::runner::{run_git, run_git_checked};
+use crate::models::stage::MergeStrategy;
 use lock::MergeLock;
 
 // Re-export status types for use by other modules
-pub use status::{build_merge_report, check_merge_state, MergeState, MergeStatusReport};
+pub use predict::merge_tree_changes;
+pub use status::{
+    build_merge_report, check_merge_state, merged_commit, MergeState, MergeStatusReport,
+};
+pub use strategy::MergeOptions;
 
 /// Result of a merge operation
 #[derive(Debug, Clone)]
@@ -25,11 +32,15 @@ pub enum MergeResult {
         insertions: u32,
         /// Number of deletions
         deletions: u32,
+        /// Strategy that integrated the branch
+        strategy: MergeStrategy,
     },
     /// Merge has conflicts that need resolution
     Conflict {
         /// List of files with conflicts
         conflicting_files: Vec<String>,
+        /// Strategy that ran into the conflicts
+        strategy: MergeStrategy,
     },
     /// Fast-forward merge (no actual merge commit needed)
     FastForward,
@@ -42,7 +53,7 @@ pub enum MergeResult {
 /// Steps:
 /// 1. Acquire merge lock to prevent concurrent merges
 /// 2. Checkout target branch
-/// 3. Merge stage branch (loom/{stage_id})
+/// 3. Merge stage branch (loom/{stage_id}) with the strategy in `options`
 /// 4. Return merge result
 ///
 /// The merge lock is held for the duration of the operation and automatically
@@ -52,6 +63,7 @@ pub fn merge_stage(
     target_branch: &str,
     repo_root: &Path,
     work_dir: &Path,
+    options: &MergeOptions,
 ) -> Result<MergeResult> {
     // Acquire merge lock to prevent concurrent merges
     let _lock = MergeLock::acquire(work_dir, Duration::from_secs(30)).map_err(|e| {
@@ -74,12 +86,38 @@ pub fn merge_stage(
     // Checkout target branch
     checkout_branch(target_branch, repo_root)?;
 
-    // Attempt merge
+    match options.strategy {
+        MergeStrategy::Merge => {
+            merge_commit(&branch_name, target_branch, repo_root, &original_branch)
+        }
+        MergeStrategy::Squash => {
+            let default_message = format!("Squash {branch_name} into {target_branch}");
+            let message = options
+                .squash_message
+                .as_deref()
+                .unwrap_or(&default_message);
+            strategy::squash(&branch_name, message, repo_root, &original_branch)
+        }
+        MergeStrategy::Rebase => {
+            strategy::rebase(&branch_name, target_branch, repo_root, &original_branch)
+        }
+        MergeStrategy::FfOnly => {
+            strategy::fast_forward_only(&branch_name, target_branch, repo_root, &original_branch)
+        }
+    }
+}
+
+/// Merge the branch into the checked-out target with a merge commit
+fn merge_commit(
+    branch_name: &str,
+    target_branch: &str,
+    repo_root: &Path,
+    original_branch: &str,
+) -> Result<MergeResult> {
     let msg = format!("Merge {branch_name} into {target_branch}");
-    let output = run_git(&["merge", "--no-ff", "-m", &msg, &branch_name], repo_root)?;
+    let output = run_git(&["merge", "--no-ff", "-m", &msg, branch_name], repo_root)?;
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let stderr = String::from_utf8_lossy(&output.stderr);
 
     if output.status.success() {
         // Parse merge output to determine result type
@@ -97,48 +135,28 @@ pub fn merge_stage(
             files_changed: stats.0,
             insertions: stats.1,
             deletions: stats.2,
+            strategy: MergeStrategy::Merge,
         });
     }
 
     // Check for conflicts
-    if stderr.contains("CONFLICT") || stdout.contains("CONFLICT") {
+    if strategy::has_conflicts(&output) {
         let conflicts = get_conflicting_files(repo_root)?;
 
         // Abort the merge to leave repo in clean state
         abort_merge(repo_root).ok(); // Ignore abort errors
 
         // Restore original branch
-        checkout_branch(&original_branch, repo_root).ok();
+        checkout_branch(original_branch, repo_root).ok();
 
         return Ok(MergeResult::Conflict {
             conflicting_files: conflicts,
+            strategy: MergeStrategy::Merge,
         });
     }
 
     // Some other error - include comprehensive diagnostics
-    let exit_code = output
-        .status
-        .code()
-        .map(|c| c.to_string())
-        .unwrap_or_else(|| "signal".to_string());
-
-    bail!(
-        "git merge failed (exit code {exit_code}):\n\
-         Directory: {}\n\
-         Stdout: {}\n\
-         Stderr: {}",
-        repo_root.display(),
-        if stdout.is_empty() {
-            "(empty)"
-        } else {
-            stdout.trim()
-        },
-        if stderr.is_empty() {
-            "(empty)"
-        } else {
-            stderr.trim()
-        }
-    );
+    Err(strategy::git_failure("git merge", &output, repo_root))
 }
 
 /// Parse merge statistics from git output
@@ -207,26 +225,9 @@ pub fn get_conflicting_files_from_status(
     // Checkout target branch
     checkout_branch(target_branch, repo_root)?;
 
-    // Try merge with --no-commit to test for conflicts
-    let output = run_git(
-        &["merge", "--no-commit", "--no-ff", source_branch],
-        repo_root,
-    )?;
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let stderr = String::from_utf8_lossy(&output.stderr);
-
-    let conflicts = if !output.status.success()
-        && (stderr.contains("CONFLICT") || stdout.contains("CONFLICT"))
-    {
-        // Get the conflicting files
-        get_conflicting_files(repo_root).unwrap_or_default()
-    } else {
-        Vec::new()
-    };
-
-    // Always abort the test merge and restore original branch
-    abort_merge(repo_root).ok();
+    // Try merge with --no-commit to test for conflicts; the test merge is
+    // always aborted
+    let conflicts = strategy::test_merge(source_branch, repo_root);
     checkout_branch(&original_branch, repo_root).ok();
 
     Ok(conflicts)
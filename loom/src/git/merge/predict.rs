@@ -0,0 +1,95 @@
+//! Dry-run merges of stage branches
+
+use anyhow::{anyhow, bail, Result};
+use std::path::Path;
+
+use crate::git::runner::{run_git, run_git_checked};
+
+/// Files merging `branch` into `target` would change, conflicted ones
+/// included.
+///
+/// Uses `git merge-tree`, which merges in memory: neither the index nor any
+/// worktree is touched, so it is safe while the branch's session runs.
+pub fn merge_tree_changes(branch: &str, target: &str, repo_root: &Path) -> Result<Vec<String>> {
+    let output = run_git(
+        &[
+            "merge-tree",
+            "--write-tree",
+            "--name-only",
+            "--no-messages",
+            target,
+            branch,
+        ],
+        repo_root,
+    )?;
+    // Exit code 1 means the merge has conflicts; the tree is written anyway
+    if !matches!(output.status.code(), Some(0 | 1)) {
+        bail!(
+            "git merge-tree {target} {branch} failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut lines = stdout.lines();
+    let tree = lines
+        .next()
+        .ok_or_else(|| anyhow!("git merge-tree printed no tree"))?;
+    let mut files: Vec<String> = lines
+        .take_while(|line| !line.is_empty())
+        .map(String::from)
+        .collect();
+    let diff = run_git_checked(&["diff", "--name-only", target, tree], repo_root)?;
+    files.extend(diff.lines().map(String::from));
+    files.sort();
+    files.dedup();
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn git(args: &[&str], dir: &Path) -> String {
+        run_git_checked(args, dir).unwrap()
+    }
+
+    fn commit(dir: &Path, file: &str, content: &str) {
+        std::fs::write(dir.join(file), content).unwrap();
+        git(&["add", file], dir);
+        git(&["commit", "-q", "-m", file], dir);
+    }
+
+    #[test]
+    fn test_merge_tree_changes() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        git(&["init", "-q"], dir);
+        git(&["checkout", "-q", "-b", "main"], dir);
+        git(&["config", "user.email", "test@example.com"], dir);
+        git(&["config", "user.name", "Test"], dir);
+        commit(dir, "shared.txt", "base\n");
+
+        git(&["checkout", "-q", "-b", "loom/s1"], dir);
+        commit(dir, "feature.txt", "one\n");
+        git(&["checkout", "-q", "main"], dir);
+        commit(dir, "other.txt", "main\n");
+
+        assert_eq!(
+            merge_tree_changes("loom/s1", "main", dir).unwrap(),
+            vec!["feature.txt"]
+        );
+
+        // Conflicted files are reported too
+        git(&["checkout", "-q", "loom/s1"], dir);
+        commit(dir, "shared.txt", "stage\n");
+        git(&["checkout", "-q", "main"], dir);
+        commit(dir, "shared.txt", "main\n");
+        assert_eq!(
+            merge_tree_changes("loom/s1", "main", dir).unwrap(),
+            vec!["feature.txt", "shared.txt"]
+        );
+        assert!(git(&["status", "--porcelain"], dir).is_empty());
+    }
+}
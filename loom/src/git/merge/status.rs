@@ -7,8 +7,8 @@
 use anyhow::Result;
 use std::path::Path;
 
-use crate::git::branch::{branch_exists, branch_name_for_stage, is_ancestor_of};
-use crate::models::stage::Stage;
+use crate::git::branch::{branch_exists, branch_name_for_stage, get_branch_head, is_ancestor_of};
+use crate::models::stage::{MergeStrategy, Stage};
 
 /// The merge state of a completed stage.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -37,6 +37,28 @@ impl std::fmt::Display for MergeState {
     }
 }
 
+/// The commit to record as a stage's `completed_commit` once `strategy`
+/// merged it into `target`
+///
+/// Merge commits and fast-forwards put the stage branch head on `target`, so
+/// it is recorded and verification still catches merges that never landed.
+/// Squash and rebase land new commits instead (a squash of changes already on
+/// `target` lands none), so `target`'s head, which carries the stage's work,
+/// is recorded for them.
+pub fn merged_commit(
+    stage_id: &str,
+    target: &str,
+    strategy: MergeStrategy,
+    repo_root: &Path,
+) -> Option<String> {
+    let branch = if strategy.rewrites_commits() {
+        target.to_string()
+    } else {
+        branch_name_for_stage(stage_id)
+    };
+    get_branch_head(&branch, repo_root).ok()
+}
+
 /// Check the merge state of a stage.
 ///
 /// Determines whether a stage's work has been merged to the merge point
@@ -44,7 +66,9 @@ impl std::fmt::Display for MergeState {
 ///
 /// IMPORTANT: This function always verifies via git ancestry when possible,
 /// rather than trusting the `merged` flag. This prevents "phantom merges"
-/// where the flag was set but code never landed.
+/// where the flag was set but code never landed. Merges record the commit
+/// from [`merged_commit`], so squash and rebase merges verify by the commit
+/// they created rather than by the stage branch.
 ///
 /// # Arguments
 /// * `stage` - The stage to check
@@ -204,6 +228,7 @@ mod tests {
             dependencies: vec![],
             parallel_group: None,
             acceptance: vec![],
+            parallel: None,
             setup: vec![],
             files: vec![],
             stage_type: StageType::default(),
@@ -222,6 +247,9 @@ mod tests {
             attempt_started_at: None,
             close_reason: None,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
+            overlap_hold: None,
             working_dir: Some(".".to_string()),
             retry_count: 0,
             max_retries: None,
@@ -234,8 +262,15 @@ mod tests {
             completed_commit: None,
             merged: false,
             merge_conflict: false,
+            condition_skipped: false,
             verification_status: Default::default(),
             context_budget: None,
+            timeout: None,
+            budget: None,
+            model: None,
+            model_escalation: 0,
+            for_each: None,
+            when: None,
             truths: Vec::new(),
             artifacts: Vec::new(),
             wiring: Vec::new(),
@@ -251,6 +286,7 @@ mod tests {
             review_reason: None,
             bug_fix: None,
             regression_test: None,
+            usage: Default::default(),
         }
     }
 
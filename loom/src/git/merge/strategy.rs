@@ -0,0 +1,383 @@
+//! Merge strategies other than a merge commit
+//!
+//! Each runs with the merge point checked out and the merge lock held. On
+//! conflicts the repository is cleaned up and the original branch restored,
+//! as after a conflicting `git merge`.
+
+use anyhow::{anyhow, bail, Result};
+use std::path::Path;
+use std::process::Output;
+
+use super::{abort_merge, checkout_branch, get_conflicting_files, parse_merge_stats, MergeResult};
+use crate::git::branch::is_ancestor_of;
+use crate::git::runner::{run_git, run_git_checked};
+use crate::models::stage::MergeStrategy;
+
+/// How `merge_stage` integrates a stage branch
+#[derive(Debug, Clone, Default)]
+pub struct MergeOptions {
+    pub strategy: MergeStrategy,
+    /// Commit message for `squash`; a generic one is used when unset
+    pub squash_message: Option<String>,
+}
+
+impl MergeOptions {
+    pub fn new(strategy: MergeStrategy) -> Self {
+        Self {
+            strategy,
+            squash_message: None,
+        }
+    }
+
+    pub fn with_squash_message(mut self, message: impl Into<String>) -> Self {
+        self.squash_message = Some(message.into());
+        self
+    }
+}
+
+/// Commit all of the branch's changes as one commit on the merge point
+pub(super) fn squash(
+    branch: &str,
+    message: &str,
+    repo_root: &Path,
+    original_branch: &str,
+) -> Result<MergeResult> {
+    let output = run_git(&["merge", "--squash", branch], repo_root)?;
+    if !output.status.success() {
+        if has_conflicts(&output) {
+            let conflicting_files = get_conflicting_files(repo_root)?;
+            // A squash leaves no MERGE_HEAD, so `merge --abort` does not apply
+            run_git(&["reset", "--merge"], repo_root).ok();
+            checkout_branch(original_branch, repo_root).ok();
+            return Ok(MergeResult::Conflict {
+                conflicting_files,
+                strategy: MergeStrategy::Squash,
+            });
+        }
+        return Err(git_failure("git merge --squash", &output, repo_root));
+    }
+
+    // Nothing staged: the branch's changes are already on the merge point
+    if run_git(&["diff", "--cached", "--quiet"], repo_root)?
+        .status
+        .success()
+    {
+        return Ok(MergeResult::AlreadyUpToDate);
+    }
+    run_git_checked(&["commit", "-q", "-m", message], repo_root)?;
+    landed("HEAD~1", MergeStrategy::Squash, repo_root)
+}
+
+/// Replay the branch's commits on top of the merge point, then fast-forward
+/// to them. The replay runs on a detached HEAD, so the stage branch itself
+/// (checked out in its worktree) is left as it was.
+pub(super) fn rebase(
+    branch: &str,
+    target_branch: &str,
+    repo_root: &Path,
+    original_branch: &str,
+) -> Result<MergeResult> {
+    if is_ancestor_of(branch, target_branch, repo_root)? {
+        return Ok(MergeResult::AlreadyUpToDate);
+    }
+    let target_head = run_git_checked(&["rev-parse", "HEAD"], repo_root)?;
+
+    run_git_checked(&["checkout", "-q", "--detach", branch], repo_root)?;
+    let output = run_git(&["rebase", target_branch], repo_root)?;
+    if !output.status.success() {
+        let conflicting_files = get_conflicting_files(repo_root).unwrap_or_default();
+        run_git(&["rebase", "--abort"], repo_root).ok();
+        checkout_branch(original_branch, repo_root).ok();
+        if has_conflicts(&output) {
+            return Ok(MergeResult::Conflict {
+                conflicting_files,
+                strategy: MergeStrategy::Rebase,
+            });
+        }
+        return Err(git_failure("git rebase", &output, repo_root));
+    }
+
+    let rebased = run_git_checked(&["rev-parse", "HEAD"], repo_root)?;
+    checkout_branch(target_branch, repo_root)?;
+    if rebased == target_head {
+        // Every commit was already on the merge point
+        return Ok(MergeResult::AlreadyUpToDate);
+    }
+    run_git_checked(&["merge", "-q", "--ff-only", &rebased], repo_root)?;
+    landed(&target_head, MergeStrategy::Rebase, repo_root)
+}
+
+/// Move the merge point to the branch if it has not moved on since the
+/// branch was created
+pub(super) fn fast_forward_only(
+    branch: &str,
+    target_branch: &str,
+    repo_root: &Path,
+    original_branch: &str,
+) -> Result<MergeResult> {
+    let output = run_git(&["merge", "--ff-only", branch], repo_root)?;
+    if output.status.success() {
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        if stdout.contains("Already up to date") || stdout.contains("Already up-to-date") {
+            return Ok(MergeResult::AlreadyUpToDate);
+        }
+        return Ok(MergeResult::FastForward);
+    }
+
+    // The merge point has diverged: report what merging would conflict on
+    let conflicting_files = test_merge(branch, repo_root);
+    checkout_branch(original_branch, repo_root).ok();
+    if conflicting_files.is_empty() {
+        bail!(
+            "Cannot fast-forward '{target_branch}' to '{branch}': the branches have diverged. \
+             Rebase the stage branch onto '{target_branch}' or use another merge_strategy."
+        );
+    }
+    Ok(MergeResult::Conflict {
+        conflicting_files,
+        strategy: MergeStrategy::FfOnly,
+    })
+}
+
+/// Files a merge of `branch` into the checked-out branch would conflict on.
+/// The test merge is always aborted.
+pub(super) fn test_merge(branch: &str, repo_root: &Path) -> Vec<String> {
+    let conflicts = match run_git(&["merge", "--no-commit", "--no-ff", branch], repo_root) {
+        Ok(output) if !output.status.success() && has_conflicts(&output) => {
+            get_conflicting_files(repo_root).unwrap_or_default()
+        }
+        _ => Vec::new(),
+    };
+    abort_merge(repo_root).ok();
+    conflicts
+}
+
+pub(super) fn has_conflicts(output: &Output) -> bool {
+    String::from_utf8_lossy(&output.stdout).contains("CONFLICT")
+        || String::from_utf8_lossy(&output.stderr).contains("CONFLICT")
+}
+
+/// Result for commits that landed on the merge point since `base`
+fn landed(base: &str, strategy: MergeStrategy, repo_root: &Path) -> Result<MergeResult> {
+    let stat = run_git_checked(&["diff", "--shortstat", base, "HEAD"], repo_root)?;
+    let (files_changed, insertions, deletions) = parse_merge_stats(&stat);
+    Ok(MergeResult::Success {
+        files_changed,
+        insertions,
+        deletions,
+        strategy,
+    })
+}
+
+pub(super) fn git_failure(command: &str, output: &Output, repo_root: &Path) -> anyhow::Error {
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let or_empty = |text: &str| {
+        if text.trim().is_empty() {
+            "(empty)".to_string()
+        } else {
+            text.trim().to_string()
+        }
+    };
+    let exit_code = output
+        .status
+        .code()
+        .map(|c| c.to_string())
+        .unwrap_or_else(|| "signal".to_string());
+    anyhow!(
+        "{command} failed (exit code {exit_code}):\n\
+         Directory: {}\n\
+         Stdout: {}\n\
+         Stderr: {}",
+        repo_root.display(),
+        or_empty(&stdout),
+        or_empty(&stderr)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::merge_stage;
+    use super::*;
+    use tempfile::TempDir;
+
+    fn git(args: &[&str], dir: &Path) -> String {
+        run_git_checked(args, dir).unwrap()
+    }
+
+    fn commit(dir: &Path, file: &str, content: &str, message: &str) {
+        std::fs::write(dir.join(file), content).unwrap();
+        git(&["add", file], dir);
+        git(&["commit", "-q", "-m", message], dir);
+    }
+
+    /// Repo whose `loom/s1` branch adds two commits while `main` moves on
+    /// with a change to `main_file`
+    fn diverged_repo(main_file: &str) -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        git(&["init", "-q"], dir);
+        git(&["checkout", "-q", "-b", "main"], dir);
+        git(&["config", "user.email", "test@example.com"], dir);
+        git(&["config", "user.name", "Test"], dir);
+        std::fs::create_dir(dir.join(".work")).unwrap();
+        std::fs::write(dir.join(".gitignore"), ".work/\n").unwrap();
+        git(&["add", ".gitignore"], dir);
+        commit(dir, "shared.txt", "base\n", "Initial commit");
+
+        git(&["checkout", "-q", "-b", "loom/s1"], dir);
+        commit(dir, "feature.txt", "one\n", "Add feature");
+        commit(dir, "shared.txt", "stage\n", "Change shared file");
+        git(&["checkout", "-q", "main"], dir);
+        commit(dir, main_file, "main\n", "Move main on");
+        temp_dir
+    }
+
+    fn merge(dir: &Path, options: &MergeOptions) -> MergeResult {
+        merge_stage("s1", "main", dir, &dir.join(".work"), options).unwrap()
+    }
+
+    fn log(dir: &Path) -> Vec<String> {
+        git(&["log", "--format=%s", "main"], dir)
+            .lines()
+            .map(String::from)
+            .collect()
+    }
+
+    #[test]
+    fn test_squash_merge() {
+        let repo = diverged_repo("other.txt");
+        let options = MergeOptions::new(MergeStrategy::Squash).with_squash_message("Stage s1");
+
+        let result = merge(repo.path(), &options);
+        assert!(matches!(
+            result,
+            MergeResult::Success {
+                files_changed: 2,
+                strategy: MergeStrategy::Squash,
+                ..
+            }
+        ));
+        assert_eq!(
+            log(repo.path()),
+            vec!["Stage s1", "Move main on", "Initial commit"]
+        );
+        assert!(matches!(
+            merge(repo.path(), &options),
+            MergeResult::AlreadyUpToDate
+        ));
+    }
+
+    #[test]
+    fn test_rebase_merge_keeps_history_linear() {
+        let repo = diverged_repo("other.txt");
+        let stage_head = git(&["rev-parse", "loom/s1"], repo.path());
+
+        let result = merge(repo.path(), &MergeOptions::new(MergeStrategy::Rebase));
+        assert!(matches!(
+            result,
+            MergeResult::Success {
+                strategy: MergeStrategy::Rebase,
+                ..
+            }
+        ));
+        assert_eq!(
+            log(repo.path()),
+            vec![
+                "Change shared file",
+                "Add feature",
+                "Move main on",
+                "Initial commit"
+            ]
+        );
+        // The stage branch itself is not rewritten
+        assert_eq!(git(&["rev-parse", "loom/s1"], repo.path()), stage_head);
+        assert_eq!(current_branch(repo.path()), "main");
+    }
+
+    #[test]
+    fn test_rewritten_merges_verify_by_merged_commit() {
+        use super::super::{check_merge_state, merged_commit, MergeState};
+        use crate::models::stage::Stage;
+
+        for strategy in [MergeStrategy::Squash, MergeStrategy::Rebase] {
+            let repo = diverged_repo("other.txt");
+            let dir = repo.path();
+            let options = MergeOptions::new(strategy);
+            assert!(matches!(merge(dir, &options), MergeResult::Success { .. }));
+            // Squash finds the changes already on main the second time
+            merge(dir, &options);
+
+            let stage = Stage {
+                id: "s1".to_string(),
+                completed_commit: merged_commit("s1", "main", strategy, dir),
+                ..Stage::default()
+            };
+            assert_eq!(
+                stage.completed_commit,
+                Some(git(&["rev-parse", "main"], dir))
+            );
+            let state = check_merge_state(&stage, "main", dir).unwrap();
+            assert_eq!(state, MergeState::Merged, "{strategy}");
+        }
+    }
+
+    #[test]
+    fn test_conflicts_are_detected_for_each_strategy() {
+        for strategy in [
+            MergeStrategy::Merge,
+            MergeStrategy::Squash,
+            MergeStrategy::Rebase,
+            MergeStrategy::FfOnly,
+        ] {
+            let repo = diverged_repo("shared.txt");
+            let main_head = git(&["rev-parse", "main"], repo.path());
+
+            match merge(repo.path(), &MergeOptions::new(strategy)) {
+                MergeResult::Conflict {
+                    conflicting_files,
+                    strategy: reported,
+                } => {
+                    assert_eq!(conflicting_files, vec!["shared.txt"], "{strategy}");
+                    assert_eq!(reported, strategy);
+                }
+                other => panic!("{strategy}: expected conflict, got {other:?}"),
+            }
+            // The repository is left clean, with main untouched
+            assert_eq!(git(&["rev-parse", "main"], repo.path()), main_head);
+            assert!(
+                git(&["status", "--porcelain"], repo.path()).is_empty(),
+                "{strategy}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_fast_forward_only() {
+        let repo = diverged_repo("other.txt");
+        let options = MergeOptions::new(MergeStrategy::FfOnly);
+        let err = merge_stage(
+            "s1",
+            "main",
+            repo.path(),
+            &repo.path().join(".work"),
+            &options,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("have diverged"));
+
+        git(&["reset", "-q", "--hard", "HEAD~1"], repo.path());
+        assert!(matches!(
+            merge(repo.path(), &options),
+            MergeResult::FastForward
+        ));
+        assert_eq!(
+            git(&["rev-parse", "main"], repo.path()),
+            git(&["rev-parse", "loom/s1"], repo.path())
+        );
+    }
+
+    fn current_branch(dir: &Path) -> String {
+        git(&["rev-parse", "--abbrev-ref", "HEAD"], dir)
+    }
+}
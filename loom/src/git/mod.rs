@@ -12,6 +12,7 @@ pub mod cleanup;
 pub mod hooks;
 pub mod merge;
 pub mod runner;
+pub mod tree;
 pub mod worktree;
 
 // Re-export commonly used types and functions
@@ -23,14 +24,14 @@ pub use worktree::{
 
 pub use merge::{
     abort_merge, build_merge_report, check_merge_state, checkout_branch,
-    conflict_resolution_instructions, get_conflicting_files, merge_stage, verify_merge_succeeded,
-    MergeResult, MergeState, MergeStatusReport,
+    conflict_resolution_instructions, get_conflicting_files, merge_stage, merged_commit,
+    verify_merge_succeeded, MergeOptions, MergeResult, MergeState, MergeStatusReport,
 };
 
 pub use branch::{
     branch_exists, branch_name_for_stage, cleanup_merged_branches, create_branch, current_branch,
     default_branch, delete_branch, get_branch_head, get_uncommitted_changes_summary,
-    has_uncommitted_changes, is_branch_merged, list_branches, list_loom_branches,
+    has_uncommitted_changes, is_branch_merged, list_branches, list_loom_branches, push_branch,
     stage_id_from_branch, BranchInfo,
 };
 
@@ -44,6 +45,8 @@ pub use hooks::{install_pre_commit_hook, is_pre_commit_hook_installed};
 
 pub use runner::{run_git, run_git_bool, run_git_checked};
 
+pub use tree::working_tree_hash;
+
 /// Initialize git module - check prerequisites
 pub fn init() -> anyhow::Result<()> {
     check_git_available()?;
@@ -0,0 +1,86 @@
+//! Content hashes of a worktree
+
+use anyhow::{bail, Context, Result};
+use std::path::Path;
+use std::process::Command;
+
+use super::runner::run_git_checked;
+
+/// Hash of the tree a commit of everything in `dir`'s worktree would have.
+///
+/// Covers uncommitted and untracked files but not ignored ones, so any
+/// change to the worktree's contents changes the hash. The real index is
+/// left untouched: files are staged into a temporary copy of it.
+pub fn working_tree_hash(dir: &Path) -> Result<String> {
+    let index = dir.join(run_git_checked(&["rev-parse", "--git-path", "index"], dir)?);
+    let temp_dir = tempfile::TempDir::new().context("Failed to create temporary index dir")?;
+    let temp_index = temp_dir.path().join("index");
+    if index.exists() {
+        std::fs::copy(&index, &temp_index).context("Failed to copy git index")?;
+    }
+
+    git_with_index(&["add", "--all"], dir, &temp_index)?;
+    git_with_index(&["write-tree"], dir, &temp_index)
+}
+
+fn git_with_index(args: &[&str], dir: &Path, index: &Path) -> Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .env("GIT_INDEX_FILE", index)
+        .current_dir(dir)
+        .output()
+        .with_context(|| format!("Failed to execute: git {}", args.join(" ")))?;
+    if !output.status.success() {
+        bail!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn git(args: &[&str], dir: &Path) {
+        run_git_checked(args, dir).unwrap();
+    }
+
+    #[test]
+    fn test_working_tree_hash_tracks_uncommitted_changes() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        git(&["init", "-q"], dir);
+        git(&["config", "user.email", "test@example.com"], dir);
+        git(&["config", "user.name", "Test"], dir);
+        std::fs::write(dir.join(".gitignore"), "target/\n").unwrap();
+        std::fs::write(dir.join("lib.rs"), "fn main() {}\n").unwrap();
+        git(&["add", "."], dir);
+        git(&["commit", "-q", "-m", "init"], dir);
+
+        let committed = working_tree_hash(dir).unwrap();
+        assert_eq!(
+            committed,
+            run_git_checked(&["rev-parse", "HEAD^{tree}"], dir).unwrap()
+        );
+
+        // Ignored files do not change the hash
+        std::fs::create_dir(dir.join("target")).unwrap();
+        std::fs::write(dir.join("target/out"), "build").unwrap();
+        assert_eq!(working_tree_hash(dir).unwrap(), committed);
+
+        // Untracked and modified files do, without touching the real index
+        std::fs::write(dir.join("new.rs"), "// new\n").unwrap();
+        let untracked = working_tree_hash(dir).unwrap();
+        assert_ne!(untracked, committed);
+        assert!(run_git_checked(&["diff", "--cached", "--name-only"], dir)
+            .unwrap()
+            .is_empty());
+
+        std::fs::remove_file(dir.join("new.rs")).unwrap();
+        assert_eq!(working_tree_hash(dir).unwrap(), committed);
+    }
+}
@@ -92,7 +92,7 @@ pub fn resolve_base_branch(
 
         tracing::debug!(dep, status = ?dep_node.status, is_completed, is_merged, "checking dependency");
 
-        if !is_completed || !is_merged {
+        if !dep_node.satisfies_dependents() {
             unmerged_deps.push((dep.as_str(), is_completed, is_merged));
         }
     }
@@ -238,10 +238,13 @@ mod tests {
                 dependencies: deps.into_iter().map(String::from).collect(),
                 description: Some(format!("Test stage {id}")),
                 acceptance: vec![],
+                parallel: None,
                 setup: vec![],
                 files: vec![],
                 parallel_group: None,
                 auto_merge: None,
+                merge_strategy: None,
+                overlap: None,
                 working_dir: ".".to_string(),
                 stage_type: crate::plan::schema::StageType::default(),
                 truths: vec![],
@@ -253,6 +256,12 @@ mod tests {
                 before_stage: vec![],
                 after_stage: vec![],
                 context_budget: None,
+                timeout: None,
+                budget: None,
+                model: None,
+                for_each: None,
+                when: None,
+                output_keys: vec![],
                 sandbox: StageSandboxConfig::default(),
                 execution_mode: None,
                 bug_fix: None,
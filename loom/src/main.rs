@@ -19,5 +19,5 @@ fn main() -> Result<()> {
         .ok();
 
     let cli = Cli::parse();
-    dispatch(cli.command)
+    dispatch(cli.command, cli.format)
 }
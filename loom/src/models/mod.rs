@@ -3,4 +3,5 @@ pub mod failure;
 pub mod keys;
 pub mod session;
 pub mod stage;
+pub mod usage;
 pub mod worktree;
@@ -23,6 +23,8 @@ impl Session {
             session_type: SessionType::default(),
             merge_source_branch: None,
             merge_target_branch: None,
+            model: None,
+            usage: None,
         }
     }
 
@@ -46,6 +48,13 @@ impl Session {
         session
     }
 
+    /// Create a new knowledge stage session
+    pub fn new_knowledge() -> Self {
+        let mut session = Self::new();
+        session.session_type = SessionType::Knowledge;
+        session
+    }
+
     /// Check if this is a merge resolution session
     pub fn is_merge_session(&self) -> bool {
         self.session_type == SessionType::Merge
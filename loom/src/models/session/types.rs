@@ -13,6 +13,8 @@ pub enum SessionType {
     Merge,
     /// Base branch conflict resolution session (pre-stage multi-dep merge)
     BaseConflict,
+    /// Knowledge stage session (runs in the main repository, not a worktree)
+    Knowledge,
 }
 
 impl std::fmt::Display for SessionType {
@@ -21,6 +23,7 @@ impl std::fmt::Display for SessionType {
             SessionType::Stage => write!(f, "stage"),
             SessionType::Merge => write!(f, "merge"),
             SessionType::BaseConflict => write!(f, "base_conflict"),
+            SessionType::Knowledge => write!(f, "knowledge"),
         }
     }
 }
@@ -71,4 +74,10 @@ pub struct Session {
     /// For merge sessions: the target branch to merge into
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub merge_target_branch: Option<String>,
+    /// Claude model the session was launched with (None = claude CLI default)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// Cumulative token usage reported by the session's heartbeats
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usage: Option<crate::models::usage::SessionUsage>,
 }
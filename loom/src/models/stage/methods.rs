@@ -2,6 +2,7 @@ use anyhow::Result;
 use chrono::{DateTime, Utc};
 
 use super::types::{Stage, StageOutput, StageStatus, StageType};
+use crate::models::usage::{SessionUsage, TokenUsage};
 
 impl Stage {
     pub fn new(name: String, description: Option<String>) -> Self {
@@ -16,6 +17,7 @@ impl Stage {
             dependencies: Vec::new(),
             parallel_group: None,
             acceptance: Vec::new(),
+            parallel: None,
             setup: Vec::new(),
             files: Vec::new(),
             stage_type: StageType::default(),
@@ -34,6 +36,9 @@ impl Stage {
             attempt_started_at: None,
             close_reason: None,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
+            overlap_hold: None,
             working_dir: Some(".".to_string()),
             retry_count: 0,
             max_retries: None,
@@ -46,8 +51,15 @@ impl Stage {
             completed_commit: None,
             merged: false,
             merge_conflict: false,
+            condition_skipped: false,
             verification_status: Default::default(),
             context_budget: None,
+            timeout: None,
+            budget: None,
+            model: None,
+            model_escalation: 0,
+            for_each: None,
+            when: None,
             truths: Vec::new(),
             artifacts: Vec::new(),
             wiring: Vec::new(),
@@ -63,6 +75,7 @@ impl Stage {
             review_reason: None,
             bug_fix: None,
             regression_test: None,
+            usage: Default::default(),
         }
     }
 
@@ -83,7 +96,7 @@ impl Stage {
     }
 
     pub fn add_acceptance_criterion(&mut self, criterion: String) {
-        self.acceptance.push(criterion);
+        self.acceptance.push(criterion.into());
         self.updated_at = Utc::now();
     }
 
@@ -207,6 +220,41 @@ impl Stage {
         Ok(())
     }
 
+    /// Skip the stage because its `when:` condition is false.
+    ///
+    /// Unlike a manual skip, the stage is flagged `condition_skipped`, so it
+    /// still satisfies its dependents.
+    ///
+    /// # Returns
+    /// `Ok(())` if the transition succeeded, `Err` if invalid
+    pub fn try_skip_unmet_condition(&mut self, reason: String) -> Result<()> {
+        self.try_skip(Some(reason))?;
+        self.condition_skipped = true;
+        Ok(())
+    }
+
+    /// Whether dependents of this stage may start: it completed and was
+    /// merged, or it was skipped because its condition was false
+    pub fn satisfies_dependents(&self) -> bool {
+        match self.status {
+            StageStatus::Completed => self.merged,
+            StageStatus::Skipped => self.condition_skipped,
+            _ => false,
+        }
+    }
+
+    /// Whether the stage's work has landed on the merge point: it was
+    /// merged, or it has nothing to merge because its condition was false
+    pub fn is_landed(&self) -> bool {
+        Self::landed(self.merged, self.condition_skipped)
+    }
+
+    /// [`Stage::is_landed`] over the raw flags, for callers that read stage
+    /// frontmatter without deserializing a whole stage
+    pub fn landed(merged: bool, condition_skipped: bool) -> bool {
+        merged || condition_skipped
+    }
+
     /// Mark the stage as having merge conflicts.
     ///
     /// This sets both the status to MergeConflict and the merge_conflict flag.
@@ -460,6 +508,45 @@ impl Stage {
             self.execution_secs = Some(current.saturating_add(elapsed));
         }
     }
+
+    /// Check whether the current execution attempt has exceeded the stage timeout.
+    ///
+    /// Only Executing stages with both a valid `timeout` and `attempt_started_at`
+    /// can time out.
+    ///
+    /// # Returns
+    /// `Some((elapsed_secs, timeout_secs))` if the timeout has been reached
+    pub fn attempt_timed_out(&self, now: DateTime<Utc>) -> Option<(u64, u64)> {
+        if self.status != StageStatus::Executing {
+            return None;
+        }
+        let timeout = crate::utils::parse_duration(self.timeout.as_deref()?).ok()?;
+        let start = self.attempt_started_at?;
+
+        let elapsed = now.signed_duration_since(start).num_seconds().max(0) as u64;
+        let timeout_secs = timeout.as_secs();
+        (timeout_secs > 0 && elapsed >= timeout_secs).then_some((elapsed, timeout_secs))
+    }
+
+    /// Record the latest cumulative usage reported by one of this stage's sessions.
+    ///
+    /// Usage is keyed by session so repeated heartbeats overwrite rather than
+    /// double-count, while handoff and retry sessions each keep their own entry.
+    ///
+    /// # Returns
+    /// `true` if the stored usage changed
+    pub fn record_session_usage(&mut self, session_id: &str, usage: SessionUsage) -> bool {
+        if self.usage.get(session_id) == Some(&usage) {
+            return false;
+        }
+        self.usage.insert(session_id.to_string(), usage);
+        true
+    }
+
+    /// Total token usage across every session that worked on this stage
+    pub fn total_usage(&self) -> TokenUsage {
+        self.usage.values().map(|u| &u.tokens).sum()
+    }
 }
 
 #[cfg(test)]
@@ -534,4 +621,49 @@ mod tests {
 
         assert_eq!(stage.execution_secs, Some(100)); // Unchanged
     }
+
+    #[test]
+    fn test_attempt_timed_out() {
+        let now = Utc::now();
+        let mut stage = Stage {
+            status: StageStatus::Executing,
+            timeout: Some("45m".to_string()),
+            attempt_started_at: Some(now - Duration::minutes(50)),
+            ..Stage::default()
+        };
+        assert_eq!(stage.attempt_timed_out(now), Some((3000, 2700)));
+
+        stage.attempt_started_at = Some(now - Duration::minutes(10));
+        assert_eq!(stage.attempt_timed_out(now), None);
+
+        stage.attempt_started_at = Some(now - Duration::minutes(50));
+        stage.status = StageStatus::WaitingForInput;
+        assert_eq!(stage.attempt_timed_out(now), None);
+
+        stage.status = StageStatus::Executing;
+        stage.timeout = None;
+        assert_eq!(stage.attempt_timed_out(now), None);
+    }
+
+    #[test]
+    fn test_record_session_usage_aggregates_across_sessions() {
+        let mut stage = Stage::default();
+        let usage = |input| SessionUsage {
+            model: Some("claude-sonnet-4".to_string()),
+            tokens: TokenUsage {
+                input_tokens: input,
+                output_tokens: 10,
+                ..TokenUsage::default()
+            },
+        };
+
+        assert!(stage.record_session_usage("session-1", usage(100)));
+        assert!(!stage.record_session_usage("session-1", usage(100)));
+        assert!(stage.record_session_usage("session-1", usage(150)));
+        assert!(stage.record_session_usage("session-2", usage(50)));
+
+        let total = stage.total_usage();
+        assert_eq!(total.input_tokens, 200);
+        assert_eq!(total.output_tokens, 20);
+    }
 }
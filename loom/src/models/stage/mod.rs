@@ -5,4 +5,7 @@ mod types;
 #[cfg(test)]
 mod tests;
 
-pub use types::{ExecutionMode, Stage, StageOutput, StageStatus, StageType, WiringCheck};
+pub use types::{
+    ExecutionMode, MergeStrategy, OverlapHold, OverlapPolicy, Stage, StageOutput, StageStatus,
+    StageType, VerificationStatus, WiringCheck,
+};
@@ -1,8 +1,10 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::BTreeMap;
 
 use crate::models::failure::FailureInfo;
+use crate::models::usage::{SessionUsage, StageBudget};
 
 /// Type of stage for specialized handling.
 ///
@@ -37,6 +39,62 @@ pub enum ExecutionMode {
     Team,
 }
 
+/// How a stage branch is integrated into the merge point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MergeStrategy {
+    /// Merge commit joining the stage branch (`git merge --no-ff`)
+    #[default]
+    Merge,
+    /// One commit with all of the stage's changes
+    Squash,
+    /// The stage's commits replayed on top of the merge point
+    Rebase,
+    /// Fast-forward only; fails if the merge point has moved on
+    FfOnly,
+}
+
+impl MergeStrategy {
+    /// Whether the stage's own commits do not land in the merge point, so
+    /// merges are verified against the commit the strategy created instead
+    pub fn rewrites_commits(self) -> bool {
+        matches!(self, Self::Squash | Self::Rebase)
+    }
+}
+
+impl std::fmt::Display for MergeStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Merge => "merge",
+            Self::Squash => "squash",
+            Self::Rebase => "rebase",
+            Self::FfOnly => "ff-only",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// What the scheduler does with a ready stage that overlaps stages still in
+/// flight (executing, or completed but not yet merged).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum OverlapPolicy {
+    /// Hold the stage until the overlapping stages have merged
+    Serialize,
+    /// Start the stage anyway and leave conflicts to progressive merge
+    #[default]
+    Allow,
+}
+
+/// Why a ready stage is being held back by the scheduler
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OverlapHold {
+    /// In-flight stages the stage overlaps
+    pub stages: Vec<String>,
+    /// Files and patterns they share
+    pub files: Vec<String>,
+}
+
 /// Wiring check to verify component connections.
 ///
 /// Used in goal-backward verification to ensure critical connections
@@ -102,7 +160,10 @@ pub struct Stage {
     pub status: StageStatus,
     pub dependencies: Vec<String>,
     pub parallel_group: Option<String>,
-    pub acceptance: Vec<String>,
+    pub acceptance: Vec<crate::plan::schema::AcceptanceCriterion>,
+    /// Run the acceptance criteria concurrently
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parallel: Option<bool>,
     #[serde(default)]
     pub setup: Vec<String>,
     pub files: Vec<String>,
@@ -140,6 +201,15 @@ pub struct Stage {
     pub close_reason: Option<String>,
     #[serde(default)]
     pub auto_merge: Option<bool>,
+    /// How the stage branch is merged (None = `merge`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merge_strategy: Option<MergeStrategy>,
+    /// Whether the stage waits for overlapping in-flight stages (None = `allow`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub overlap: Option<OverlapPolicy>,
+    /// Set while the scheduler holds the ready stage back for overlap
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub overlap_hold: Option<OverlapHold>,
     /// Working directory for acceptance criteria, relative to worktree root.
     /// If set, criteria run from this subdirectory instead of worktree root.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -190,12 +260,39 @@ pub struct Stage {
     /// Whether stage has unresolved merge conflicts
     #[serde(default)]
     pub merge_conflict: bool,
+    /// Whether the stage was skipped because its `when:` condition was false.
+    ///
+    /// Such a stage has nothing to merge but still satisfies its dependents,
+    /// unlike a manually skipped one.
+    #[serde(default)]
+    pub condition_skipped: bool,
     /// Goal-backward verification status
     #[serde(default)]
     pub verification_status: VerificationStatus,
     /// Stage-specific context budget (percentage)
     #[serde(default)]
     pub context_budget: Option<u32>,
+    /// Wall-clock timeout per execution attempt (e.g. "45m"), enforced by the monitor
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<String>,
+    /// Token/cost limits for this stage (own budget merged with plan defaults)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub budget: Option<StageBudget>,
+    /// Claude model requested by the plan for this stage's sessions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// Steps taken up the plan's model escalation list.
+    /// Incremented each time the stage is retried after completing with failures.
+    #[serde(default)]
+    pub model_escalation: u32,
+    /// Fan-out block: the orchestrator expands this stage into `child_stages`
+    /// and completes it once every child is completed and merged
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub for_each: Option<crate::plan::schema::ForEach>,
+    /// Condition over dependency outputs; when false the stage is skipped
+    /// and still satisfies its dependents
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub when: Option<String>,
     /// Observable behaviors that must work (shell commands return 0)
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub truths: Vec<String>,
@@ -241,6 +338,10 @@ pub struct Stage {
     /// Regression test requirement (required when bug_fix is true)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub regression_test: Option<crate::plan::schema::RegressionTest>,
+    /// Token usage of every session that worked on this stage, keyed by session ID.
+    /// Covers handoffs and retries; see `record_session_usage()` in methods.rs.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub usage: BTreeMap<String, SessionUsage>,
 }
 
 /// Status of a stage in the execution lifecycle.
@@ -478,6 +579,7 @@ impl Default for Stage {
             dependencies: Vec::new(),
             parallel_group: None,
             acceptance: Vec::new(),
+            parallel: None,
             setup: Vec::new(),
             files: Vec::new(),
             stage_type: StageType::default(),
@@ -496,6 +598,9 @@ impl Default for Stage {
             attempt_started_at: None,
             close_reason: None,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
+            overlap_hold: None,
             working_dir: Some(".".to_string()),
             retry_count: 0,
             max_retries: None,
@@ -508,8 +613,15 @@ impl Default for Stage {
             completed_commit: None,
             merged: false,
             merge_conflict: false,
+            condition_skipped: false,
             verification_status: Default::default(),
             context_budget: None,
+            timeout: None,
+            budget: None,
+            model: None,
+            model_escalation: 0,
+            for_each: None,
+            when: None,
             truths: Vec::new(),
             artifacts: Vec::new(),
             wiring: Vec::new(),
@@ -525,6 +637,7 @@ impl Default for Stage {
             review_reason: None,
             bug_fix: None,
             regression_test: None,
+            usage: Default::default(),
         }
     }
 }
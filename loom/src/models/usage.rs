@@ -0,0 +1,174 @@
+//! Token usage reported by Claude Code sessions and the budgets that limit it
+
+use serde::{Deserialize, Serialize};
+use std::ops::AddAssign;
+
+/// Cumulative token counts for one or more API calls
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenUsage {
+    /// Uncached input tokens
+    #[serde(default)]
+    pub input_tokens: u64,
+    /// Generated output tokens
+    #[serde(default)]
+    pub output_tokens: u64,
+    /// Input tokens written to the prompt cache
+    #[serde(default)]
+    pub cache_creation_tokens: u64,
+    /// Input tokens read from the prompt cache
+    #[serde(default)]
+    pub cache_read_tokens: u64,
+}
+
+impl TokenUsage {
+    /// Sum of all token counts
+    pub fn total(&self) -> u64 {
+        self.input_tokens
+            .saturating_add(self.output_tokens)
+            .saturating_add(self.cache_creation_tokens)
+            .saturating_add(self.cache_read_tokens)
+    }
+
+    /// Whether no tokens have been recorded
+    pub fn is_empty(&self) -> bool {
+        self.total() == 0
+    }
+}
+
+impl AddAssign for TokenUsage {
+    fn add_assign(&mut self, other: Self) {
+        self.input_tokens = self.input_tokens.saturating_add(other.input_tokens);
+        self.output_tokens = self.output_tokens.saturating_add(other.output_tokens);
+        self.cache_creation_tokens = self
+            .cache_creation_tokens
+            .saturating_add(other.cache_creation_tokens);
+        self.cache_read_tokens = self
+            .cache_read_tokens
+            .saturating_add(other.cache_read_tokens);
+    }
+}
+
+impl<'a> std::iter::Sum<&'a TokenUsage> for TokenUsage {
+    fn sum<I: Iterator<Item = &'a TokenUsage>>(iter: I) -> Self {
+        let mut total = TokenUsage::default();
+        for usage in iter {
+            total += *usage;
+        }
+        total
+    }
+}
+
+/// Cumulative usage of a single session, as last reported by its heartbeat
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionUsage {
+    /// Model the session ran on (e.g. "claude-sonnet-4-5-20250929")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    #[serde(flatten)]
+    pub tokens: TokenUsage,
+}
+
+/// Spending limits for a single stage, summed over all of its sessions
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct StageBudget {
+    /// Maximum total tokens (input, output and cache)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<u64>,
+    /// Maximum cost in USD
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_cost_usd: Option<f64>,
+}
+
+impl StageBudget {
+    /// Whether no limit is set
+    pub fn is_empty(&self) -> bool {
+        self.max_tokens.is_none() && self.max_cost_usd.is_none()
+    }
+
+    /// Describe the first limit reached by the given usage, if any.
+    ///
+    /// A cost limit is not evaluated when the cost is unknown.
+    pub fn exceeded_by(&self, tokens: u64, cost_usd: Option<f64>) -> Option<String> {
+        if let Some(max) = self.max_tokens.filter(|max| tokens >= *max) {
+            return Some(format!(
+                "token budget exhausted: {} of {} tokens used",
+                format_tokens(tokens),
+                format_tokens(max)
+            ));
+        }
+        match (self.max_cost_usd, cost_usd) {
+            (Some(max), Some(cost)) if cost >= max => Some(format!(
+                "cost budget exhausted: ${cost:.2} of ${max:.2} spent"
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// Format a token count compactly (e.g. `950`, `12.3k`, `1.20M`)
+pub fn format_tokens(count: u64) -> String {
+    if count >= 1_000_000 {
+        format!("{:.2}M", count as f64 / 1_000_000.0)
+    } else if count >= 1_000 {
+        format!("{:.1}k", count as f64 / 1_000.0)
+    } else {
+        count.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_usage_sum() {
+        let a = TokenUsage {
+            input_tokens: 10,
+            output_tokens: 5,
+            cache_creation_tokens: 100,
+            cache_read_tokens: 1000,
+        };
+        let total: TokenUsage = [a, a].iter().sum();
+        assert_eq!(total.input_tokens, 20);
+        assert_eq!(total.cache_read_tokens, 2000);
+        assert_eq!(total.total(), 2230);
+        assert!(TokenUsage::default().is_empty());
+    }
+
+    #[test]
+    fn test_session_usage_serde_flattens_tokens() {
+        let json = r#"{"model":"claude-sonnet-4","input_tokens":3,"output_tokens":4}"#;
+        let usage: SessionUsage = serde_json::from_str(json).unwrap();
+        assert_eq!(usage.model.as_deref(), Some("claude-sonnet-4"));
+        assert_eq!(usage.tokens.output_tokens, 4);
+        assert_eq!(usage.tokens.cache_read_tokens, 0);
+    }
+
+    #[test]
+    fn test_stage_budget_exceeded_by() {
+        let budget = StageBudget {
+            max_tokens: Some(1_000),
+            max_cost_usd: Some(2.0),
+        };
+        assert_eq!(budget.exceeded_by(999, Some(1.99)), None);
+        assert!(budget
+            .exceeded_by(1_000, None)
+            .unwrap()
+            .contains("1.0k of 1.0k tokens"));
+        assert!(budget
+            .exceeded_by(10, Some(2.5))
+            .unwrap()
+            .contains("$2.50 of $2.00"));
+        assert_eq!(
+            StageBudget::default().exceeded_by(u64::MAX, Some(1e9)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_format_tokens() {
+        assert_eq!(format_tokens(950), "950");
+        assert_eq!(format_tokens(12_345), "12.3k");
+        assert_eq!(format_tokens(1_200_000), "1.20M");
+    }
+}
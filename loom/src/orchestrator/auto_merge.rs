@@ -11,7 +11,9 @@ use crate::git::branch::branch_name_for_stage;
 use crate::git::cleanup::{cleanup_after_merge, CleanupConfig, CleanupResult};
 use crate::git::merge::{merge_stage, MergeResult};
 use crate::models::session::Session;
-use crate::models::stage::Stage;
+use crate::models::stage::{MergeStrategy, Stage};
+use crate::orchestrator::models::model_for_session;
+use crate::orchestrator::progressive_merge::merge_options;
 use crate::orchestrator::signals::generate_merge_signal;
 use crate::orchestrator::terminal::TerminalBackend;
 
@@ -23,6 +25,7 @@ pub enum AutoMergeResult {
         files_changed: u32,
         insertions: u32,
         deletions: u32,
+        strategy: MergeStrategy,
         cleanup: CleanupResult,
     },
     /// Fast-forward merge completed
@@ -31,7 +34,7 @@ pub enum AutoMergeResult {
     AlreadyUpToDate { cleanup: CleanupResult },
     /// Conflicts detected, spawned resolution session
     ConflictResolutionSpawned {
-        session: Session,
+        session: Box<Session>,
         conflicting_files: Vec<String>,
     },
     /// Stage has no worktree (nothing to merge)
@@ -59,7 +62,8 @@ pub fn is_auto_merge_enabled(
 ///
 /// This function:
 /// 1. Checks if the stage has a worktree
-/// 2. Attempts to merge the stage branch to the target branch
+/// 2. Attempts to merge the stage branch to the target branch with the
+///    stage's `merge_strategy`
 /// 3. On success: cleans up the worktree and branch
 /// 4. On conflict: spawns a Claude Code session for resolution
 ///
@@ -79,42 +83,54 @@ pub fn attempt_auto_merge(
     }
 
     // Attempt the merge
-    let merge_result =
-        merge_stage(&stage.id, target_branch, repo_root, work_dir).context("Auto-merge failed")?;
+    let options = merge_options(stage, target_branch, repo_root, work_dir);
+    let merge_result = merge_stage(&stage.id, target_branch, repo_root, work_dir, &options)
+        .context("Auto-merge failed")?;
+
+    // Squashed and rebased branches never show as merged to git
+    let cleanup_config = CleanupConfig {
+        force_branch_deletion: options.strategy.rewrites_commits(),
+        ..CleanupConfig::quiet()
+    };
 
     match merge_result {
         MergeResult::Success {
             files_changed,
             insertions,
             deletions,
+            strategy,
         } => {
             // Clean up worktree and branch
-            let cleanup = cleanup_after_merge(&stage.id, repo_root, &CleanupConfig::quiet())?;
+            let cleanup = cleanup_after_merge(&stage.id, repo_root, &cleanup_config)?;
 
             Ok(AutoMergeResult::Success {
                 files_changed,
                 insertions,
                 deletions,
+                strategy,
                 cleanup,
             })
         }
 
         MergeResult::FastForward => {
-            let cleanup = cleanup_after_merge(&stage.id, repo_root, &CleanupConfig::quiet())?;
+            let cleanup = cleanup_after_merge(&stage.id, repo_root, &cleanup_config)?;
 
             Ok(AutoMergeResult::FastForward { cleanup })
         }
 
         MergeResult::AlreadyUpToDate => {
-            let cleanup = cleanup_after_merge(&stage.id, repo_root, &CleanupConfig::quiet())?;
+            let cleanup = cleanup_after_merge(&stage.id, repo_root, &cleanup_config)?;
 
             Ok(AutoMergeResult::AlreadyUpToDate { cleanup })
         }
 
-        MergeResult::Conflict { conflicting_files } => {
+        MergeResult::Conflict {
+            conflicting_files, ..
+        } => {
             // Create a merge session to resolve conflicts
             let source_branch = branch_name_for_stage(&stage.id);
-            let session = Session::new_merge(source_branch.clone(), target_branch.to_string());
+            let mut session = Session::new_merge(source_branch.clone(), target_branch.to_string());
+            session.model = model_for_session(work_dir, repo_root, stage, &session.session_type);
 
             // Generate the merge signal file
             let signal_path = generate_merge_signal(
@@ -133,7 +149,7 @@ pub fn attempt_auto_merge(
                 .context("Failed to spawn merge resolution session")?;
 
             Ok(AutoMergeResult::ConflictResolutionSpawned {
-                session: spawned_session,
+                session: Box::new(spawned_session),
                 conflicting_files,
             })
         }
@@ -37,6 +37,8 @@ pub struct ContinuationConfig {
     pub backend_type: BackendType,
     /// Whether to automatically spawn a terminal session
     pub auto_spawn: bool,
+    /// Claude model for the continuation session (None = claude CLI default)
+    pub model: Option<String>,
 }
 
 impl Default for ContinuationConfig {
@@ -44,6 +46,7 @@ impl Default for ContinuationConfig {
         Self {
             backend_type: BackendType::Native,
             auto_spawn: true,
+            model: None,
         }
     }
 }
@@ -72,6 +75,7 @@ pub fn continue_session(
     validate_stage_for_continuation(stage)?;
 
     let mut session = Session::new();
+    session.model = config.model.clone();
     session.assign_to_stage(stage.id.clone());
     session.set_worktree_path(worktree.path.clone());
 
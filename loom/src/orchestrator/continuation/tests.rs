@@ -183,6 +183,7 @@ fn test_continue_session_with_handoff() {
     let config = ContinuationConfig {
         backend_type: BackendType::Native,
         auto_spawn: false,
+        model: None,
     };
 
     let session = continue_session(&stage, Some(&handoff_path), &worktree, &config, &work_dir)
@@ -212,6 +213,7 @@ fn test_continue_session_without_handoff() {
     let config = ContinuationConfig {
         backend_type: BackendType::Native,
         auto_spawn: false,
+        model: None,
     };
 
     let session = continue_session(&stage, None, &worktree, &config, &work_dir)
@@ -235,6 +237,7 @@ fn test_continue_session_invalid_status() {
     let config = ContinuationConfig {
         backend_type: BackendType::Native,
         auto_spawn: false,
+        model: None,
     };
 
     let result = continue_session(&stage, None, &worktree, &config, &work_dir);
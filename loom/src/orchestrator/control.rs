@@ -0,0 +1,233 @@
+//! Stage control requests executed inside the orchestrator loop
+//!
+//! The daemon receives stage commands (hold, retry, skip, ...) from CLI clients
+//! and queues them here. The orchestrator drains the queue between loop steps,
+//! so state changes never race with its own reads and writes of stage files.
+//! Each request carries a reply channel that receives the resulting status.
+//! Run setting changes from `loom config set` travel the same way but are
+//! fire-and-forget: the daemon has already persisted them. Plan updates from
+//! `loom init --update --apply` are queued too and reply with what changed.
+
+use anyhow::{anyhow, Result};
+use std::collections::VecDeque;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+
+use crate::models::stage::StageStatus;
+use crate::orchestrator::plan_update::AppliedUpdate;
+use crate::orchestrator::run_settings::ConfigChange;
+
+/// A state change requested for a single stage
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StageCommand {
+    /// Prevent the stage from auto-executing
+    Hold,
+    /// Allow a held stage to auto-execute again
+    Release,
+    /// Re-queue a blocked, completed-with-failures or merge-blocked stage
+    Retry { force: bool },
+    /// Skip the stage (dependents remain blocked)
+    Skip { reason: Option<String> },
+    /// Reset the stage to its initial state
+    Reset { hard: bool, kill_session: bool },
+    /// Kill the session currently running for the stage
+    KillSession,
+    /// Merge a completed, merge-conflict or merge-blocked stage now
+    Merge,
+}
+
+impl StageCommand {
+    /// Short name used in log messages
+    pub fn name(&self) -> &'static str {
+        match self {
+            StageCommand::Hold => "hold",
+            StageCommand::Release => "release",
+            StageCommand::Retry { .. } => "retry",
+            StageCommand::Skip { .. } => "skip",
+            StageCommand::Reset { .. } => "reset",
+            StageCommand::KillSession => "kill-session",
+            StageCommand::Merge => "merge",
+        }
+    }
+}
+
+/// Outcome of a control request: the stage's new status or an error message
+pub type ControlReply = std::result::Result<StageStatus, String>;
+
+/// A queued stage command waiting for the orchestrator
+#[derive(Debug)]
+pub struct ControlRequest {
+    pub stage_id: String,
+    pub command: StageCommand,
+    reply: Sender<ControlReply>,
+}
+
+impl ControlRequest {
+    /// Send the outcome back to the requester
+    ///
+    /// The requester may have timed out and gone away, so send errors are ignored.
+    pub fn respond(self, reply: ControlReply) {
+        let _ = self.reply.send(reply);
+    }
+}
+
+/// Outcome of a plan update request: the changed stages or an error message
+pub type PlanUpdateReply = std::result::Result<AppliedUpdate, String>;
+
+/// A queued plan update waiting for the orchestrator
+#[derive(Debug)]
+pub struct PlanUpdateRequest {
+    reply: Sender<PlanUpdateReply>,
+}
+
+impl PlanUpdateRequest {
+    /// Send the outcome back to the requester, ignoring one that went away
+    pub fn respond(self, reply: PlanUpdateReply) {
+        let _ = self.reply.send(reply);
+    }
+}
+
+/// Shared queue of control requests between daemon clients and the orchestrator
+#[derive(Debug, Clone, Default)]
+pub struct ControlQueue {
+    pending: Arc<Mutex<VecDeque<ControlRequest>>>,
+    config_changes: Arc<Mutex<Vec<ConfigChange>>>,
+    plan_updates: Arc<Mutex<Vec<PlanUpdateRequest>>>,
+}
+
+impl ControlQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue a command and return the channel its outcome will be sent on
+    pub fn submit(&self, stage_id: &str, command: StageCommand) -> Result<Receiver<ControlReply>> {
+        let (reply, receiver) = mpsc::channel();
+        self.pending
+            .lock()
+            .map_err(|_| anyhow!("Control queue lock poisoned"))?
+            .push_back(ControlRequest {
+                stage_id: stage_id.to_string(),
+                command,
+                reply,
+            });
+        Ok(receiver)
+    }
+
+    /// Take all pending requests in submission order
+    pub fn drain(&self) -> Vec<ControlRequest> {
+        match self.pending.lock() {
+            Ok(mut pending) => pending.drain(..).collect(),
+            Err(poisoned) => poisoned.into_inner().drain(..).collect(),
+        }
+    }
+
+    /// Queue a run setting change to apply on the next loop step
+    pub fn push_config(&self, change: ConfigChange) -> Result<()> {
+        self.config_changes
+            .lock()
+            .map_err(|_| anyhow!("Control queue lock poisoned"))?
+            .push(change);
+        Ok(())
+    }
+
+    /// Take all pending run setting changes in submission order
+    pub fn drain_config(&self) -> Vec<ConfigChange> {
+        match self.config_changes.lock() {
+            Ok(mut changes) => changes.drain(..).collect(),
+            Err(poisoned) => poisoned.into_inner().drain(..).collect(),
+        }
+    }
+
+    /// Queue a plan update and return the channel its outcome will be sent on
+    pub fn submit_plan_update(&self) -> Result<Receiver<PlanUpdateReply>> {
+        let (reply, receiver) = mpsc::channel();
+        self.plan_updates
+            .lock()
+            .map_err(|_| anyhow!("Control queue lock poisoned"))?
+            .push(PlanUpdateRequest { reply });
+        Ok(receiver)
+    }
+
+    /// Take all pending plan update requests
+    pub fn drain_plan_updates(&self) -> Vec<PlanUpdateRequest> {
+        match self.plan_updates.lock() {
+            Ok(mut updates) => updates.drain(..).collect(),
+            Err(poisoned) => poisoned.into_inner().drain(..).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_queue_preserves_order_and_replies() {
+        let queue = ControlQueue::new();
+        let first = queue.submit("stage-a", StageCommand::Hold).unwrap();
+        let second = queue
+            .submit("stage-b", StageCommand::Retry { force: true })
+            .unwrap();
+
+        let requests = queue.drain();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].stage_id, "stage-a");
+        assert_eq!(requests[1].command, StageCommand::Retry { force: true });
+        assert!(queue.drain().is_empty());
+
+        let mut requests = requests.into_iter();
+        requests
+            .next()
+            .unwrap()
+            .respond(Ok(StageStatus::WaitingForDeps));
+        requests.next().unwrap().respond(Err("boom".to_string()));
+
+        assert_eq!(first.recv().unwrap(), Ok(StageStatus::WaitingForDeps));
+        assert_eq!(second.recv().unwrap(), Err("boom".to_string()));
+    }
+
+    #[test]
+    fn test_respond_after_requester_dropped() {
+        let queue = ControlQueue::new();
+        drop(queue.submit("stage-a", StageCommand::Merge).unwrap());
+
+        for request in queue.drain() {
+            request.respond(Ok(StageStatus::Completed));
+        }
+    }
+
+    #[test]
+    fn test_config_changes_drained_separately() {
+        let queue = ControlQueue::new();
+        queue.push_config(ConfigChange::MaxParallel(6)).unwrap();
+        queue.push_config(ConfigChange::AutoMerge(false)).unwrap();
+
+        assert!(queue.drain().is_empty());
+        assert_eq!(
+            queue.drain_config(),
+            vec![ConfigChange::MaxParallel(6), ConfigChange::AutoMerge(false)]
+        );
+        assert!(queue.drain_config().is_empty());
+    }
+
+    #[test]
+    fn test_plan_updates_drained_separately() {
+        let queue = ControlQueue::new();
+        let receiver = queue.submit_plan_update().unwrap();
+
+        assert!(queue.drain().is_empty());
+        let updates = queue.drain_plan_updates();
+        assert_eq!(updates.len(), 1);
+        assert!(queue.drain_plan_updates().is_empty());
+
+        let applied = AppliedUpdate {
+            added: vec!["lint".to_string()],
+            ..AppliedUpdate::default()
+        };
+        for update in updates {
+            update.respond(Ok(applied.clone()));
+        }
+        assert_eq!(receiver.recv().unwrap(), Ok(applied));
+    }
+}
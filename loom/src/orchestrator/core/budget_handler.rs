@@ -0,0 +1,237 @@
+//! Plan and stage budget enforcement
+
+use anyhow::Result;
+use chrono::Utc;
+use colored::Colorize;
+
+use crate::models::stage::{Stage, StageStatus};
+use crate::orchestrator::plan_metadata::load_plan_metadata;
+use crate::plan::schema::BudgetConfig;
+use crate::utils::{format_elapsed, parse_duration};
+use crate::verify::transitions::list_all_stages;
+
+use super::persistence::Persistence;
+use super::{clear_status_line, Orchestrator, OrchestratorConfig};
+
+/// Tokens used and cost (if every model is priced) of a set of usage records
+pub(super) type Spend = (u64, Option<f64>);
+
+impl Orchestrator {
+    /// Tokens and cost spent so far by a stage
+    pub(super) fn stage_spend(&self, stage: &Stage) -> Spend {
+        (
+            stage.total_usage().total(),
+            self.prices.sessions_cost(stage.usage.values()),
+        )
+    }
+
+    /// Pause a stage whose usage just crossed its own budget.
+    ///
+    /// Only the crossing triggers a pause, so a stage a human approved to
+    /// continue past its budget is not paused again on the next heartbeat.
+    pub(super) fn enforce_stage_budget(&mut self, stage: &Stage, before: Spend) -> Result<()> {
+        let Some(limits) = stage.budget else {
+            return Ok(());
+        };
+        if limits.exceeded_by(before.0, before.1).is_some() {
+            return Ok(());
+        }
+        let (tokens, cost) = self.stage_spend(stage);
+        match limits.exceeded_by(tokens, cost) {
+            Some(reason) => self.pause_for_budget(&stage.id, &format!("Stage {reason}")),
+            None => Ok(()),
+        }
+    }
+
+    /// Check plan-wide limits; once one is reached, stop spawning stages and
+    /// move executing stages to human review.
+    pub(super) fn enforce_plan_budget(&mut self) -> Result<()> {
+        if self.budget_exhausted.is_some() {
+            return Ok(());
+        }
+        let Some(budget) = self.budget.clone() else {
+            return Ok(());
+        };
+
+        let stages = list_all_stages(&self.config.work_dir)?;
+        let Some(reason) = self.plan_budget_reason(&budget, &stages) else {
+            return Ok(());
+        };
+
+        clear_status_line();
+        eprintln!(
+            "{} Plan {reason}. No new stages will be started; raise the plan budget and restart with 'loom run' to continue.",
+            "BUDGET:".yellow().bold()
+        );
+        let review_reason = format!("Plan {reason}");
+        self.budget_exhausted = Some(review_reason.clone());
+
+        for stage in stages.iter().filter(|s| s.status == StageStatus::Executing) {
+            self.pause_for_budget(&stage.id, &review_reason)?;
+        }
+        Ok(())
+    }
+
+    /// Describe the first plan-wide limit reached by the given stages
+    fn plan_budget_reason(&self, budget: &BudgetConfig, stages: &[Stage]) -> Option<String> {
+        let tokens = stages.iter().map(|s| s.total_usage().total()).sum();
+        let cost = stages
+            .iter()
+            .map(|s| self.prices.sessions_cost(s.usage.values()))
+            .sum();
+        if let Some(reason) = budget.plan_limits().exceeded_by(tokens, cost) {
+            return Some(reason);
+        }
+
+        let max_duration = parse_duration(budget.max_duration.as_deref()?).ok()?;
+        let first_start = stages.iter().filter_map(|s| s.started_at).min()?;
+        let elapsed = Utc::now().signed_duration_since(first_start).num_seconds();
+        (elapsed >= max_duration.as_secs() as i64).then(|| {
+            format!(
+                "time budget exhausted: running for {} of {}",
+                format_elapsed(elapsed),
+                format_elapsed(max_duration.as_secs() as i64)
+            )
+        })
+    }
+
+    /// Stop a stage's session and hand the stage to a human for review
+    fn pause_for_budget(&mut self, stage_id: &str, reason: &str) -> Result<()> {
+        let mut stage = self.load_stage(stage_id)?;
+        if stage.status != StageStatus::Executing {
+            return Ok(());
+        }
+
+        clear_status_line();
+        eprintln!("Stage '{stage_id}' paused: {reason}");
+        self.stop_stage_session(stage_id, "over-budget");
+
+        stage.accumulate_attempt_time(Utc::now());
+        stage.try_request_human_review(reason.to_string())?;
+        self.save_stage(&stage)?;
+        self.graph
+            .mark_status(stage_id, StageStatus::NeedsHumanReview)?;
+        Ok(())
+    }
+}
+
+/// Read the plan's `budget` block from the plan file recorded in config.toml
+///
+/// A plan that cannot be loaded is reported and runs without a budget.
+pub(super) fn load_plan_budget(config: &OrchestratorConfig) -> Option<BudgetConfig> {
+    match load_plan_metadata(&config.work_dir, &config.repo_root) {
+        Ok(metadata) => metadata?.loom.budget,
+        Err(e) => {
+            eprintln!("Warning: {e:#}; running without a budget");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::usage::{SessionUsage, StageBudget, TokenUsage};
+    use crate::plan::graph::build_execution_graph;
+    use crate::verify::transitions::{load_stage, save_stage};
+    use std::path::Path;
+
+    use super::super::stage_executor::StageExecutor;
+
+    fn budget_test_orchestrator(work_dir: &Path) -> Orchestrator {
+        let graph = build_execution_graph(work_dir).unwrap();
+        Orchestrator::new(OrchestratorConfig::headless(work_dir), graph).unwrap()
+    }
+
+    fn heartbeat_usage(input_tokens: u64) -> SessionUsage {
+        SessionUsage {
+            model: Some("claude-sonnet-4-5".to_string()),
+            tokens: TokenUsage {
+                input_tokens,
+                ..Default::default()
+            },
+        }
+    }
+
+    fn executing_stage() -> Stage {
+        let mut stage = Stage::new("Stage 1".to_string(), None);
+        stage.id = "stage-1".to_string();
+        stage.status = StageStatus::Executing;
+        stage
+    }
+
+    #[test]
+    fn test_stage_budget_pauses_stage_once_when_crossed() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let work_dir = temp_dir.path().join(".work");
+        let mut stage = executing_stage();
+        stage.budget = Some(StageBudget {
+            max_tokens: Some(1_000),
+            max_cost_usd: None,
+        });
+        save_stage(&stage, &work_dir).unwrap();
+
+        let mut orchestrator = budget_test_orchestrator(&work_dir);
+        orchestrator
+            .record_heartbeat_usage("stage-1", "session-1", heartbeat_usage(600))
+            .unwrap();
+        assert_eq!(
+            load_stage("stage-1", &work_dir).unwrap().status,
+            StageStatus::Executing
+        );
+
+        orchestrator
+            .record_heartbeat_usage("stage-1", "session-2", heartbeat_usage(600))
+            .unwrap();
+        let mut stage = load_stage("stage-1", &work_dir).unwrap();
+        assert_eq!(stage.status, StageStatus::NeedsHumanReview);
+        assert!(stage
+            .review_reason
+            .as_deref()
+            .unwrap()
+            .contains("token budget"));
+
+        // A human approved continuing past the budget: no second pause
+        stage.try_approve_review().unwrap();
+        save_stage(&stage, &work_dir).unwrap();
+        orchestrator
+            .record_heartbeat_usage("stage-1", "session-3", heartbeat_usage(600))
+            .unwrap();
+        let stage = load_stage("stage-1", &work_dir).unwrap();
+        assert_eq!(stage.status, StageStatus::Executing);
+        assert_eq!(stage.total_usage().input_tokens, 1_800);
+    }
+
+    #[test]
+    fn test_plan_budget_stops_spawning_and_pauses_executing_stages() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let work_dir = temp_dir.path().join(".work");
+        let mut stage = executing_stage();
+        stage.record_session_usage("session-1", heartbeat_usage(2_000_000));
+        save_stage(&stage, &work_dir).unwrap();
+
+        let mut orchestrator = budget_test_orchestrator(&work_dir);
+        orchestrator.budget = Some(BudgetConfig {
+            max_cost_usd: Some(10.0),
+            ..BudgetConfig::default()
+        });
+        orchestrator.enforce_plan_budget().unwrap();
+        assert!(orchestrator.budget_exhausted.is_none());
+
+        orchestrator.budget = Some(BudgetConfig {
+            max_cost_usd: Some(10.0),
+            max_tokens: Some(1_000_000),
+            ..BudgetConfig::default()
+        });
+        orchestrator.enforce_plan_budget().unwrap();
+        assert!(orchestrator.budget_exhausted.is_some());
+
+        let stage = load_stage("stage-1", &work_dir).unwrap();
+        assert_eq!(stage.status, StageStatus::NeedsHumanReview);
+        assert!(stage
+            .review_reason
+            .unwrap()
+            .starts_with("Plan token budget"));
+        assert_eq!(orchestrator.start_ready_stages().unwrap(), 0);
+    }
+}
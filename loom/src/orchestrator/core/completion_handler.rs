@@ -3,7 +3,9 @@
 use anyhow::Result;
 use chrono::Utc;
 
+use crate::orchestrator::notify::NotifyEvent;
 use crate::orchestrator::signals::remove_signal;
+use crate::plan::schema::NotifyEventKind;
 
 use super::persistence::Persistence;
 use super::Orchestrator;
@@ -35,6 +37,12 @@ impl Orchestrator {
         // If merge failed with conflicts, stage will be in MergeConflict status instead
         if merge_succeeded {
             self.graph.mark_completed(stage_id)?;
+            self.promote_merged_decisions(stage_id);
+            self.notifier.notify(NotifyEvent::stage(
+                NotifyEventKind::StageCompleted,
+                stage_id,
+                format!("Stage '{stage_id}' completed"),
+            ));
         }
 
         Ok(())
@@ -0,0 +1,153 @@
+//! Stage `when:` conditions
+//!
+//! A stage's condition is evaluated when it is about to start, against the
+//! outputs its dependencies persisted. A false condition skips the stage
+//! without a session; the skipped stage still satisfies its dependents.
+
+use anyhow::Result;
+use chrono::Utc;
+use colored::Colorize;
+use serde_json::Value;
+
+use crate::models::failure::{FailureInfo, FailureType};
+use crate::models::stage::{Stage, StageStatus};
+use crate::plan::schema::Condition;
+
+use super::persistence::Persistence;
+use super::{clear_status_line, Orchestrator};
+
+impl Orchestrator {
+    /// Skip a Queued stage whose `when:` condition is false.
+    ///
+    /// Returns true when the stage must not be started (skipped, or blocked
+    /// because its condition no longer parses).
+    pub(super) fn skip_unmet_condition(&mut self, stage: &mut Stage) -> Result<bool> {
+        let Some(expr) = stage.when.clone() else {
+            return Ok(false);
+        };
+        let condition = match Condition::parse(&expr) {
+            Ok(condition) => condition,
+            Err(e) => {
+                self.block_unstarted_stage(stage, format!("Invalid when condition '{expr}': {e}"))?;
+                return Ok(true);
+            }
+        };
+
+        let mut outputs: Vec<(String, String, Value)> = Vec::new();
+        for (dep, key) in condition.references() {
+            let value = self
+                .load_stage(dep)
+                .ok()
+                .and_then(|s| s.outputs.into_iter().find(|o| o.key == key))
+                .map(|o| o.value);
+            if let Some(value) = value {
+                outputs.push((dep.to_string(), key.to_string(), value));
+            }
+        }
+        let lookup = |dep: &str, key: &str| {
+            outputs
+                .iter()
+                .find(|(d, k, _)| d == dep && k == key)
+                .map(|(_, _, v)| v.clone())
+        };
+        if condition.evaluate(&lookup) {
+            return Ok(false);
+        }
+
+        stage.try_skip_unmet_condition(format!("Condition not met: {expr}"))?;
+        self.save_stage(stage)?;
+        self.graph.set_node_condition_skipped(&stage.id, true);
+        self.graph.mark_status(&stage.id, StageStatus::Skipped)?;
+        self.graph.update_ready_status();
+
+        clear_status_line();
+        println!(
+            "{} Stage '{}' skipped: condition not met ({})",
+            "⊘".dimmed(),
+            stage.id,
+            expr.dimmed()
+        );
+        Ok(true)
+    }
+
+    /// Block a Queued stage that cannot be started, recording why
+    pub(super) fn block_unstarted_stage(
+        &mut self,
+        stage: &mut Stage,
+        reason: String,
+    ) -> Result<()> {
+        clear_status_line();
+        eprintln!("Stage '{}' blocked: {reason}", stage.id);
+        stage.try_mark_blocked()?;
+        stage.failure_info = Some(FailureInfo {
+            failure_type: FailureType::Unknown,
+            detected_at: Utc::now(),
+            evidence: vec![reason],
+        });
+        self.save_stage(stage)?;
+        self.graph.mark_status(&stage.id, StageStatus::Blocked)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::stage::StageOutput;
+    use crate::orchestrator::OrchestratorConfig;
+    use crate::plan::graph::build_execution_graph;
+    use crate::verify::transitions::{load_stage, save_stage};
+
+    fn stage(id: &str, deps: &[&str], status: StageStatus) -> Stage {
+        let mut stage = Stage::new(id.to_string(), None);
+        stage.id = id.to_string();
+        stage.dependencies = deps.iter().map(|d| d.to_string()).collect();
+        stage.status = status;
+        stage
+    }
+
+    #[test]
+    fn test_unmet_condition_skips_stage_and_unblocks_dependents() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let work_dir = temp_dir.path().join(".work");
+
+        let mut detect = stage("detect-db", &[], StageStatus::Completed);
+        detect.merged = true;
+        detect.outputs = vec![StageOutput {
+            key: "engine".to_string(),
+            value: Value::String("mysql".to_string()),
+            description: String::new(),
+        }];
+        save_stage(&detect, &work_dir).unwrap();
+        let mut postgres = stage("postgres", &["detect-db"], StageStatus::Queued);
+        postgres.when = Some(r#"outputs.detect-db.engine == "postgres""#.to_string());
+        save_stage(&postgres, &work_dir).unwrap();
+        let mut mysql = stage("mysql", &["detect-db"], StageStatus::Queued);
+        mysql.when = Some(r#"outputs.detect-db.engine == "mysql""#.to_string());
+        save_stage(&mysql, &work_dir).unwrap();
+        save_stage(
+            &stage("app", &["postgres"], StageStatus::WaitingForDeps),
+            &work_dir,
+        )
+        .unwrap();
+
+        let mut graph = build_execution_graph(work_dir.as_path()).unwrap();
+        graph.mark_executing("detect-db").unwrap();
+        graph.set_node_merged("detect-db", true);
+        graph.mark_completed("detect-db").unwrap();
+        let config = OrchestratorConfig::headless(&work_dir);
+        let mut orchestrator = Orchestrator::new(config, graph).unwrap();
+
+        assert!(!orchestrator.skip_unmet_condition(&mut mysql).unwrap());
+        assert!(orchestrator.skip_unmet_condition(&mut postgres).unwrap());
+
+        let skipped = load_stage("postgres", &work_dir).unwrap();
+        assert_eq!(skipped.status, StageStatus::Skipped);
+        assert!(skipped.satisfies_dependents());
+        assert!(skipped.condition_skipped && !skipped.merged);
+        assert!(skipped.close_reason.unwrap().contains("Condition not met"));
+        assert_eq!(
+            orchestrator.graph.get_node("app").unwrap().status,
+            StageStatus::Queued
+        );
+    }
+}
@@ -0,0 +1,222 @@
+//! Execution of stage commands and plan updates queued by daemon clients
+
+use anyhow::{bail, Context, Result};
+
+use crate::fs::worktree_files::remove_session_file;
+use crate::models::session::{Session, SessionType};
+use crate::models::stage::StageStatus;
+use crate::orchestrator::control::StageCommand;
+use crate::orchestrator::models::load_plan_models;
+use crate::orchestrator::plan_update::{apply_plan_update, load_plan_update, AppliedUpdate};
+use crate::orchestrator::reset::reset_stage;
+use crate::orchestrator::retry::retry_stage;
+use crate::orchestrator::signals::remove_signal;
+use crate::orchestrator::skip::skip_stage;
+use crate::plan::graph::build_execution_graph;
+
+use super::budget_handler::load_plan_budget;
+use super::persistence::Persistence;
+use super::recovery::Recovery;
+use super::{clear_status_line, Orchestrator};
+
+impl Orchestrator {
+    /// Apply pending run setting changes, execute all pending control
+    /// requests and reply with each stage's new status, then apply pending
+    /// plan updates
+    pub(super) fn process_control_requests(&mut self) {
+        let Some(queue) = self.config.control_queue.clone() else {
+            return;
+        };
+
+        for change in queue.drain_config() {
+            self.config.apply(change);
+            clear_status_line();
+            eprintln!("Run setting changed: {change}");
+        }
+
+        for request in queue.drain() {
+            let result = self.execute_stage_command(&request.stage_id, &request.command);
+            if let Err(ref e) = result {
+                clear_status_line();
+                eprintln!(
+                    "Stage command '{}' failed for '{}': {e:#}",
+                    request.command.name(),
+                    request.stage_id
+                );
+            }
+            request.respond(result.map_err(|e| format!("{e:#}")));
+        }
+
+        for request in queue.drain_plan_updates() {
+            let result = self.apply_plan_update();
+            clear_status_line();
+            match &result {
+                Ok(applied) => eprintln!(
+                    "Plan update applied: {} added, {} updated, {} removed",
+                    applied.added.len(),
+                    applied.updated.len(),
+                    applied.dropped.len()
+                ),
+                Err(e) => eprintln!("Plan update failed: {e:#}"),
+            }
+            request.respond(result.map_err(|e| format!("{e:#}")));
+        }
+    }
+
+    /// Reconcile the plan with the stage files, then rebuild the execution
+    /// graph from them and reload the plan's budget and models
+    fn apply_plan_update(&mut self) -> Result<AppliedUpdate> {
+        let work_dir = self.config.work_dir.clone();
+        let update = load_plan_update(&work_dir)?;
+        apply_plan_update(&update, &work_dir)?;
+
+        self.graph = build_execution_graph(work_dir.as_path())
+            .context("Failed to rebuild graph after plan update")?;
+        self.sync_graph_with_stage_files()
+            .context("Failed to sync graph after plan update")?;
+        self.sync_queued_status_to_files()
+            .context("Failed to sync queued status after plan update")?;
+        self.budget = load_plan_budget(&self.config);
+        self.models = load_plan_models(&work_dir, &self.config.repo_root);
+        Ok(update.applied())
+    }
+
+    /// Apply a single stage command and return the stage's resulting status
+    fn execute_stage_command(
+        &mut self,
+        stage_id: &str,
+        command: &StageCommand,
+    ) -> Result<StageStatus> {
+        let work_dir = self.config.work_dir.clone();
+
+        match command {
+            StageCommand::Hold => {
+                let mut stage = self.load_stage(stage_id)?;
+                stage.hold();
+                self.save_stage(&stage)?;
+            }
+            StageCommand::Release => {
+                let mut stage = self.load_stage(stage_id)?;
+                stage.release();
+                self.save_stage(&stage)?;
+            }
+            StageCommand::Retry { force } => {
+                if self.active_sessions.contains_key(stage_id) {
+                    if !force {
+                        bail!("Stage '{stage_id}' has an active session. Use --force to override.");
+                    }
+                    self.stop_active_session(stage_id)?;
+                }
+                retry_stage(stage_id, *force, &work_dir)?;
+            }
+            StageCommand::Skip { reason } => {
+                skip_stage(stage_id, reason.clone(), &work_dir)?;
+            }
+            StageCommand::Reset { hard, kill_session } => {
+                if self.active_sessions.contains_key(stage_id) {
+                    if !kill_session {
+                        bail!(
+                            "Stage '{stage_id}' has an active session. Use --kill-session to stop it first."
+                        );
+                    }
+                    self.stop_active_session(stage_id)?;
+                }
+                reset_stage(stage_id, *hard, &work_dir)?;
+            }
+            StageCommand::KillSession => self.kill_stage_session(stage_id)?,
+            StageCommand::Merge => self.merge_stage_on_request(stage_id)?,
+        }
+
+        self.sync_graph_with_stage_files()
+            .context("Failed to sync graph after stage command")?;
+        Ok(self.load_stage(stage_id)?.status)
+    }
+
+    /// Kill the active session for a stage and stop tracking it
+    ///
+    /// The session file is removed so the monitor does not report the kill as
+    /// a crash. Merge signals are kept as a guard against respawning a
+    /// resolution session on the next poll.
+    fn stop_active_session(&mut self, stage_id: &str) -> Result<Option<Session>> {
+        let Some(session) = self.active_sessions.remove(stage_id) else {
+            return Ok(None);
+        };
+        self.reported_crashes.insert(session.id.clone());
+
+        self.backend
+            .kill_session(&session)
+            .with_context(|| format!("Failed to kill session '{}'", session.id))?;
+        remove_session_file(&session.id, &self.config.work_dir)?;
+        if matches!(
+            session.session_type,
+            SessionType::Stage | SessionType::Knowledge
+        ) {
+            remove_signal(&session.id, &self.config.work_dir)?;
+        }
+
+        clear_status_line();
+        eprintln!("Killed session '{}' for stage '{stage_id}'", session.id);
+        Ok(Some(session))
+    }
+
+    /// Kill a stage's running session
+    ///
+    /// The stage keeps its status, as when the session is killed without a
+    /// daemon; use a reset request to run it again.
+    fn kill_stage_session(&mut self, stage_id: &str) -> Result<()> {
+        if self.stop_active_session(stage_id)?.is_none() {
+            bail!("No active session for stage '{stage_id}'");
+        }
+        Ok(())
+    }
+
+    /// Merge a stage immediately instead of waiting for a resolution session
+    fn merge_stage_on_request(&mut self, stage_id: &str) -> Result<()> {
+        if self.active_sessions.contains_key(stage_id) {
+            bail!(
+                "Stage '{stage_id}' has an active session. Wait for it to finish or kill it first."
+            );
+        }
+
+        let mut stage = self.load_stage(stage_id)?;
+        match stage.status {
+            StageStatus::Completed if stage.merged => {
+                bail!("Stage '{stage_id}' is already merged")
+            }
+            StageStatus::Completed => {}
+            StageStatus::MergeConflict | StageStatus::MergeBlocked => {
+                if stage.is_at_fix_limit() {
+                    bail!(
+                        "Stage '{stage_id}' has reached the fix attempt limit ({}). Request human review or skip it.",
+                        stage.get_effective_max_fix_attempts()
+                    );
+                }
+                stage.increment_fix_attempts();
+                self.save_stage(&stage)?;
+            }
+            other => bail!(
+                "Cannot merge stage in status: {other}. Only completed, merge-conflict or merge-blocked stages can be merged."
+            ),
+        }
+
+        if !self.try_auto_merge(stage_id) {
+            return Ok(());
+        }
+
+        let mut stage = self.load_stage(stage_id)?;
+        if stage.status != StageStatus::Completed {
+            // MergeBlocked has no direct transition to Completed; the merge just
+            // succeeded, so force the status as try_auto_merge does for conflicts
+            if let Err(e) = stage.try_complete_merge() {
+                eprintln!("Warning: Failed to transition stage to Completed: {e}");
+                stage.status = StageStatus::Completed;
+                stage.merge_conflict = false;
+                stage.merged = true;
+            }
+            self.save_stage(&stage)?;
+        }
+        self.graph.set_node_merged(stage_id, true);
+        self.graph.mark_completed(stage_id)?;
+        Ok(())
+    }
+}
@@ -6,6 +6,8 @@ use std::path::PathBuf;
 
 use crate::models::stage::StageStatus;
 use crate::orchestrator::monitor::MonitorEvent;
+use crate::orchestrator::notify::NotifyEvent;
+use crate::plan::schema::NotifyEventKind;
 
 use super::clear_status_line;
 use super::persistence::Persistence;
@@ -54,6 +56,14 @@ impl EventHandler for Orchestrator {
                     clear_status_line();
                     eprintln!("Stage '{stage_id}' blocked: {reason}");
                     self.graph.mark_status(&stage_id, StageStatus::Blocked)?;
+                    self.notifier.notify(
+                        NotifyEvent::stage(
+                            NotifyEventKind::StageBlocked,
+                            &stage_id,
+                            format!("Stage '{stage_id}' blocked: {reason}"),
+                        )
+                        .with_details(serde_json::json!({ "reason": reason })),
+                    );
                 }
                 MonitorEvent::SessionContextWarning {
                     session_id,
@@ -123,12 +133,18 @@ impl EventHandler for Orchestrator {
                     );
                 }
                 MonitorEvent::HeartbeatReceived {
-                    stage_id: _,
-                    session_id: _,
+                    stage_id,
+                    session_id,
                     context_percent: _,
                     last_tool: _,
+                    usage,
                 } => {
                     // Heartbeat events are silent - just used for internal tracking
+                    if let Some(usage) = usage {
+                        if let Err(e) = self.record_heartbeat_usage(&stage_id, &session_id, usage) {
+                            eprintln!("Warning: Failed to record usage for '{session_id}': {e}");
+                        }
+                    }
                 }
                 MonitorEvent::BudgetExceeded {
                     session_id,
@@ -138,6 +154,14 @@ impl EventHandler for Orchestrator {
                 } => {
                     self.on_budget_exceeded(&session_id, &stage_id, usage_percent, budget_percent)?;
                 }
+                MonitorEvent::StageTimedOut {
+                    stage_id,
+                    session_id: _,
+                    elapsed_secs,
+                    timeout_secs,
+                } => {
+                    self.handle_stage_timed_out(&stage_id, elapsed_secs, timeout_secs)?;
+                }
                 MonitorEvent::StageNeedsHumanReview {
                     stage_id,
                     review_reason,
@@ -154,6 +178,14 @@ impl EventHandler for Orchestrator {
                         &stage_id,
                         review_reason.as_deref(),
                     );
+                    self.notifier.notify(
+                        NotifyEvent::stage(
+                            NotifyEventKind::NeedsHumanReview,
+                            &stage_id,
+                            format!("Stage '{stage_id}' needs human review: {reason_str}"),
+                        )
+                        .with_details(serde_json::json!({ "review_reason": review_reason })),
+                    );
                 }
             }
         }
@@ -184,6 +216,15 @@ impl EventHandler for Orchestrator {
         stage.try_mark_needs_handoff()?;
         self.save_stage(&stage)?;
 
+        self.notifier.notify(
+            NotifyEvent::stage(
+                NotifyEventKind::Handoff,
+                stage_id,
+                format!("Stage '{stage_id}' needs a handoff to a new session"),
+            )
+            .with_details(serde_json::json!({ "session_id": session_id })),
+        );
+
         Ok(())
     }
 
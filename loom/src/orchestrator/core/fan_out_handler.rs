@@ -0,0 +1,138 @@
+//! Fan-out (`for_each`) parent stages
+//!
+//! A fan-out parent never runs a session. The first time it is scheduled it
+//! expands into child stages (unless `loom init` already did); once every
+//! child is completed and merged it is scheduled again and completes.
+
+use anyhow::{Context, Result};
+
+use crate::models::stage::Stage;
+use crate::plan::fan_out::{attach_children, expand_stage, resolve_output_items};
+use crate::plan::StageNode;
+
+use super::persistence::Persistence;
+use super::{clear_status_line, Orchestrator};
+
+impl Orchestrator {
+    /// Expand or complete a Queued fan-out parent
+    pub(super) fn start_fan_out_stage(&mut self, mut stage: Stage) -> Result<()> {
+        if !stage.child_stages.is_empty() {
+            // Queued again: every child is completed and merged
+            return self.complete_fan_out_parent(stage);
+        }
+
+        let items = match self.fan_out_items(&stage) {
+            Ok(items) => items,
+            Err(e) => {
+                return self.block_unstarted_stage(&mut stage, format!("Cannot fan out: {e:#}"));
+            }
+        };
+        if items.is_empty() {
+            return self.complete_fan_out_parent(stage);
+        }
+
+        // Runtime items can give invalid or clashing child IDs; that blocks
+        // this stage, not the run
+        let expanded = expand_stage(&stage, &items).and_then(|children| {
+            let nodes = children.iter().map(StageNode::from).collect();
+            self.graph.add_fan_out(&stage.id, nodes)?;
+            Ok(children)
+        });
+        let children = match expanded {
+            Ok(children) => children,
+            Err(e) => {
+                return self.block_unstarted_stage(&mut stage, format!("Cannot fan out: {e:#}"));
+            }
+        };
+        for child in &children {
+            self.save_stage(child)?;
+        }
+        attach_children(&mut stage, &children);
+        self.save_stage(&stage)?;
+
+        clear_status_line();
+        println!(
+            "Stage '{}' fanned out into {} stages",
+            stage.id,
+            children.len()
+        );
+        Ok(())
+    }
+
+    /// Items of a fan-out parent: the plan's static list or a dependency output
+    fn fan_out_items(&self, stage: &Stage) -> Result<Vec<String>> {
+        let for_each = stage.for_each.as_ref().context("Stage has no for_each")?;
+        match for_each.output_source() {
+            Some((dep, _)) => resolve_output_items(stage, &self.load_stage(dep)?),
+            None => Ok(for_each.items.clone()),
+        }
+    }
+
+    /// Complete a parent without a session; it has no branch of its own to merge
+    fn complete_fan_out_parent(&mut self, mut stage: Stage) -> Result<()> {
+        stage.try_mark_executing()?;
+        stage.try_complete(Some(format!(
+            "Fan-out of {} stages completed",
+            stage.child_stages.len()
+        )))?;
+        stage.merged = true;
+        self.save_stage(&stage)?;
+
+        self.graph.mark_executing(&stage.id)?;
+        self.graph.set_node_merged(&stage.id, true);
+        self.graph.mark_completed(&stage.id)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::stage_executor::StageExecutor;
+    use super::*;
+    use crate::models::stage::StageStatus;
+    use crate::orchestrator::OrchestratorConfig;
+    use crate::plan::graph::build_execution_graph;
+    use crate::plan::schema::ForEach;
+    use crate::verify::transitions::{load_stage, save_stage};
+
+    fn fan_out(id: &str, items: Vec<String>) -> Stage {
+        let mut stage = Stage::new(id.to_string(), None);
+        stage.id = id.to_string();
+        stage.status = StageStatus::Queued;
+        stage.for_each = Some(ForEach {
+            items,
+            from_output: None,
+        });
+        stage
+    }
+
+    #[test]
+    fn test_invalid_child_ids_block_the_parent_only() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let work_dir = temp_dir.path().join(".work");
+        save_stage(&fan_out("api", vec!["x".repeat(130)]), &work_dir).unwrap();
+        save_stage(&fan_out("web", vec!["a".into(), "b".into()]), &work_dir).unwrap();
+        let mut existing = Stage::new("web-b".to_string(), None);
+        existing.id = "web-b".to_string();
+        existing.status = StageStatus::Completed;
+        existing.merged = true;
+        save_stage(&existing, &work_dir).unwrap();
+
+        let graph = build_execution_graph(work_dir.as_path()).unwrap();
+        let config = OrchestratorConfig::headless(&work_dir);
+        let mut orchestrator = Orchestrator::new(config, graph).unwrap();
+        orchestrator.start_ready_stages().unwrap();
+
+        for id in ["api", "web"] {
+            let parent = load_stage(id, &work_dir).unwrap();
+            assert_eq!(parent.status, StageStatus::Blocked, "{id}");
+            let evidence = parent.failure_info.unwrap().evidence.join("\n");
+            assert!(evidence.starts_with("Cannot fan out"), "{evidence}");
+            assert!(parent.child_stages.is_empty());
+            let node = orchestrator.graph.get_node(id).unwrap();
+            assert_eq!(node.status, StageStatus::Blocked);
+        }
+        // A clash on a later child leaves no earlier child behind
+        assert!(orchestrator.graph.get_node("web-a").is_none());
+    }
+}
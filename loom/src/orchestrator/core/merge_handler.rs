@@ -4,14 +4,17 @@ use anyhow::{Context, Result};
 
 use crate::git::branch::branch_name_for_stage;
 use crate::git::merge::{check_merge_state, MergeState};
-use crate::git::merge::{get_conflicting_files_from_status, verify_merge_succeeded};
+use crate::git::merge::{get_conflicting_files_from_status, merged_commit, verify_merge_succeeded};
 use crate::models::session::Session;
 use crate::models::stage::StageStatus;
 use crate::orchestrator::auto_merge::{attempt_auto_merge, is_auto_merge_enabled, AutoMergeResult};
+use crate::orchestrator::models::select_model;
+use crate::orchestrator::notify::NotifyEvent;
 use crate::orchestrator::signals::{
     generate_merge_signal, list_signals, read_merge_signal, remove_signal,
 };
 use crate::parser::frontmatter::parse_from_markdown;
+use crate::plan::schema::NotifyEventKind;
 use crate::process::is_process_alive;
 use crate::verify::transitions::load_stage;
 
@@ -123,6 +126,7 @@ impl Orchestrator {
         if let Err(e) = self.graph.mark_completed(stage_id) {
             eprintln!("Warning: Failed to mark stage as completed in graph: {e}");
         }
+        self.promote_merged_decisions(stage_id);
 
         if let Err(e) = remove_signal(session_id, &self.config.work_dir) {
             eprintln!("Warning: Failed to remove merge signal: {e}");
@@ -133,6 +137,19 @@ impl Orchestrator {
         eprintln!("Stage '{stage_id}' {log_message}");
     }
 
+    /// Record the commit a successful merge left on the target for verification.
+    ///
+    /// Cleanup may already have deleted a merged stage branch; the commit
+    /// recorded at completion is its head, so it is kept then.
+    fn record_merged_commit(&self, stage: &mut crate::models::stage::Stage, target_branch: &str) {
+        let strategy = stage.merge_strategy.unwrap_or_default();
+        if let Some(commit) =
+            merged_commit(&stage.id, target_branch, strategy, &self.config.repo_root)
+        {
+            stage.completed_commit = Some(commit);
+        }
+    }
+
     /// Verify merge succeeded and update stage state accordingly.
     ///
     /// This helper encapsulates the common pattern of verifying a merge via git ancestry
@@ -261,18 +278,21 @@ impl Orchestrator {
                 files_changed,
                 insertions,
                 deletions,
+                strategy,
                 ..
             }) => {
+                self.record_merged_commit(&mut stage, &target_branch);
                 let success = self.verify_and_finalize_merge(&mut stage, stage_id, &target_branch);
                 if success {
                     clear_status_line();
                     eprintln!(
-                        "Stage '{stage_id}' merged: {files_changed} files, +{insertions} -{deletions}"
+                        "Stage '{stage_id}' merged ({strategy}): {files_changed} files, +{insertions} -{deletions}"
                     );
                 }
                 success
             }
             Ok(AutoMergeResult::FastForward { .. }) => {
+                self.record_merged_commit(&mut stage, &target_branch);
                 let success = self.verify_and_finalize_merge(&mut stage, stage_id, &target_branch);
                 if success {
                     clear_status_line();
@@ -281,6 +301,7 @@ impl Orchestrator {
                 success
             }
             Ok(AutoMergeResult::AlreadyUpToDate { .. }) => {
+                self.record_merged_commit(&mut stage, &target_branch);
                 let success = self.verify_and_finalize_merge(&mut stage, stage_id, &target_branch);
                 if success {
                     clear_status_line();
@@ -309,10 +330,22 @@ impl Orchestrator {
                     eprintln!("Warning: Failed to mark stage as merge conflict in graph: {e}");
                 }
 
+                self.notifier.notify(
+                    NotifyEvent::stage(
+                        NotifyEventKind::MergeConflict,
+                        stage_id,
+                        format!("Stage '{stage_id}' has merge conflicts with '{target_branch}'"),
+                    )
+                    .with_details(serde_json::json!({
+                        "target_branch": target_branch,
+                        "conflicting_files": conflicting_files,
+                    })),
+                );
+
                 // Track the merge session so the monitor can detect its lifecycle
                 let session_id = session.id.clone();
                 self.active_sessions
-                    .insert(stage_id.to_string(), session.clone());
+                    .insert(stage_id.to_string(), (*session).clone());
                 if let Err(e) = self.save_session(&session) {
                     eprintln!("Warning: Failed to save merge session: {e}");
                     // Remove from active_sessions to avoid tracking a session
@@ -533,7 +566,8 @@ impl Orchestrator {
         .unwrap_or_default();
 
         // Create a merge session
-        let session = Session::new_merge(source_branch.clone(), target_branch.clone());
+        let mut session = Session::new_merge(source_branch.clone(), target_branch.clone());
+        session.model = select_model(self.models.as_ref(), stage, &session.session_type);
 
         // Generate merge signal
         let signal_path = generate_merge_signal(
@@ -4,19 +4,28 @@
 //! - Creates worktrees for ready stages
 //! - Spawns Claude sessions in terminal windows
 //! - Monitors stage completion and session health
-//! - Handles crashes and context exhaustion
+//! - Handles crashes, timeouts and context exhaustion
+//! - Enforces plan and stage budgets
 //! - Manages the execution graph
 
 use std::io::{self, Write};
 
+mod budget_handler;
 mod completion_handler;
+mod condition_handler;
+mod control_handler;
 mod crash_handler;
 mod event_handler;
+mod fan_out_handler;
 mod merge_handler;
 mod orchestrator;
+mod overlap_handler;
 mod persistence;
+mod promotion_handler;
 mod recovery;
 mod stage_executor;
+mod timeout_handler;
+mod usage_handler;
 
 pub use orchestrator::{Orchestrator, OrchestratorConfig, OrchestratorResult};
 
@@ -28,6 +37,20 @@ pub(super) fn clear_status_line() {
     let _ = io::stdout().flush();
 }
 
+#[cfg(test)]
+impl OrchestratorConfig {
+    /// Headless config for handler tests, with the repo root at the parent of `work_dir`
+    fn headless(work_dir: &std::path::Path) -> Self {
+        Self {
+            work_dir: work_dir.to_path_buf(),
+            repo_root: work_dir.parent().unwrap().to_path_buf(),
+            backend_type: crate::orchestrator::terminal::BackendType::Headless,
+            enable_skill_routing: false,
+            ..Self::default()
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -54,6 +77,7 @@ mod tests {
             max_skill_recommendations: 5,
             sandbox_config: SandboxConfig::default(),
             shutdown_flag: None,
+            control_queue: None,
         }
     }
 
@@ -65,9 +89,12 @@ mod tests {
             dependencies: vec![],
             parallel_group: None,
             acceptance: vec![],
+            parallel: None,
             setup: vec![],
             files: vec![],
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             working_dir: ".".to_string(),
             stage_type: crate::plan::schema::StageType::default(),
             truths: vec![],
@@ -79,6 +106,12 @@ mod tests {
             before_stage: vec![],
             after_stage: vec![],
             context_budget: None,
+            timeout: None,
+            budget: None,
+            model: None,
+            for_each: None,
+            when: None,
+            output_keys: vec![],
             sandbox: StageSandboxConfig::default(),
             execution_mode: None,
             bug_fix: None,
@@ -150,6 +183,137 @@ mod tests {
         assert_eq!(orchestrator.running_session_count(), 0);
     }
 
+    #[test]
+    fn test_control_requests_apply_stage_commands() {
+        use crate::models::stage::{Stage, StageStatus};
+        use crate::orchestrator::control::{ControlQueue, StageCommand};
+        use crate::orchestrator::run_settings::ConfigChange;
+        use crate::verify::transitions::{load_stage, save_stage};
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let work_dir = temp_dir.path().join(".work");
+        let queue = ControlQueue::new();
+        let config = OrchestratorConfig {
+            control_queue: Some(queue.clone()),
+            ..OrchestratorConfig::headless(&work_dir)
+        };
+
+        let mut stage = Stage::new("Stage 1".to_string(), None);
+        stage.id = "stage-1".to_string();
+        stage.status = StageStatus::Queued;
+        save_stage(&stage, &work_dir).unwrap();
+
+        let mut orchestrator = Orchestrator::new(config, create_simple_graph()).unwrap();
+
+        let held = queue.submit("stage-1", StageCommand::Hold).unwrap();
+        let retried = queue
+            .submit("stage-1", StageCommand::Retry { force: false })
+            .unwrap();
+        let killed = queue.submit("stage-1", StageCommand::KillSession).unwrap();
+        queue.push_config(ConfigChange::MaxParallel(6)).unwrap();
+        queue.push_config(ConfigChange::AutoMerge(false)).unwrap();
+        orchestrator.process_control_requests();
+
+        assert_eq!(orchestrator.config.max_parallel_sessions, 6);
+        assert!(!orchestrator.config.auto_merge);
+
+        assert_eq!(held.recv().unwrap(), Ok(StageStatus::Queued));
+        assert!(load_stage("stage-1", &work_dir).unwrap().held);
+        assert!(retried
+            .recv()
+            .unwrap()
+            .unwrap_err()
+            .contains("Cannot retry"));
+        assert!(killed
+            .recv()
+            .unwrap()
+            .unwrap_err()
+            .contains("No active session"));
+    }
+
+    #[test]
+    fn test_kill_session_request_keeps_stage_status() {
+        use crate::models::session::Session;
+        use crate::models::stage::{Stage, StageStatus};
+        use crate::orchestrator::control::{ControlQueue, StageCommand};
+        use crate::verify::transitions::{load_stage, save_stage};
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let work_dir = temp_dir.path().join(".work");
+        let queue = ControlQueue::new();
+        let config = OrchestratorConfig {
+            control_queue: Some(queue.clone()),
+            ..OrchestratorConfig::headless(&work_dir)
+        };
+
+        let mut stage = Stage::new("Stage 1".to_string(), None);
+        stage.id = "stage-1".to_string();
+        stage.status = StageStatus::Executing;
+        save_stage(&stage, &work_dir).unwrap();
+
+        let mut orchestrator = Orchestrator::new(config, create_simple_graph()).unwrap();
+        let mut session = Session::new();
+        session.assign_to_stage("stage-1".to_string());
+        session.set_pid(999_999_999);
+        orchestrator
+            .active_sessions
+            .insert("stage-1".to_string(), session);
+
+        let killed = queue.submit("stage-1", StageCommand::KillSession).unwrap();
+        orchestrator.process_control_requests();
+
+        assert_eq!(killed.recv().unwrap(), Ok(StageStatus::Executing));
+        assert_eq!(orchestrator.running_session_count(), 0);
+        assert_eq!(
+            load_stage("stage-1", &work_dir).unwrap().status,
+            StageStatus::Executing
+        );
+    }
+
+    #[test]
+    fn test_stage_timeout_blocks_stage_with_timeout_failure() {
+        use crate::models::failure::FailureType;
+        use crate::models::stage::{Stage, StageStatus};
+        use crate::verify::transitions::{load_stage, save_stage};
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let work_dir = temp_dir.path().join(".work");
+        let config = OrchestratorConfig::headless(&work_dir);
+        std::fs::create_dir_all(&work_dir).unwrap();
+        let notifiers = "[[notifiers]]\ntype = \"jsonl\"\npath = \"events.jsonl\"\n";
+        std::fs::write(work_dir.join("config.toml"), notifiers).unwrap();
+
+        let mut stage = Stage::new("Stage 1".to_string(), None);
+        stage.id = "stage-1".to_string();
+        stage.status = StageStatus::Executing;
+        stage.timeout = Some("10m".to_string());
+        stage.attempt_started_at = Some(chrono::Utc::now() - chrono::Duration::minutes(11));
+        save_stage(&stage, &work_dir).unwrap();
+
+        let mut orchestrator = Orchestrator::new(config, create_simple_graph()).unwrap();
+        orchestrator
+            .handle_stage_timed_out("stage-1", 660, 600)
+            .unwrap();
+
+        let stage = load_stage("stage-1", &work_dir).unwrap();
+        assert_eq!(stage.status, StageStatus::Blocked);
+        assert_eq!(stage.retry_count, 1);
+        assert!(stage.attempt_started_at.is_none());
+        let info = stage.failure_info.unwrap();
+        assert_eq!(info.failure_type, FailureType::Timeout);
+        assert!(info.evidence.iter().any(|e| e.contains("660s")));
+
+        orchestrator.notifier.flush();
+        let events = std::fs::read_to_string(temp_dir.path().join("events.jsonl")).unwrap();
+        let event: serde_json::Value = serde_json::from_str(events.trim()).unwrap();
+        assert_eq!(event["event"], "stage_blocked");
+        assert_eq!(event["stage_id"], "stage-1");
+        assert!(event["details"]["evidence"][1]
+            .as_str()
+            .unwrap()
+            .contains("660s"));
+    }
+
     #[test]
     fn test_extract_yaml_frontmatter() {
         let content = r#"---
@@ -14,15 +14,21 @@ use crate::models::session::Session;
 use crate::models::stage::StageStatus;
 use crate::models::worktree::Worktree;
 use crate::orchestrator::monitor::{Monitor, MonitorConfig};
-use crate::plan::schema::SandboxConfig;
+use crate::plan::schema::{BudgetConfig, ModelConfig, SandboxConfig};
 use crate::plan::ExecutionGraph;
 use crate::skills::SkillIndex;
 use crate::utils::{cleanup_terminal, install_terminal_panic_hook};
 
+use super::budget_handler::load_plan_budget;
 use super::event_handler::EventHandler;
 use super::persistence::Persistence;
 use super::recovery::Recovery;
 use super::stage_executor::StageExecutor;
+use crate::orchestrator::control::ControlQueue;
+use crate::orchestrator::models::load_plan_models;
+use crate::orchestrator::notify::{Notifier, NotifyEvent};
+use crate::orchestrator::pricing::PriceTable;
+use crate::orchestrator::run_settings::ConfigChange;
 use crate::orchestrator::terminal::{create_backend, BackendType, TerminalBackend};
 
 /// Configuration for the orchestrator
@@ -53,6 +59,18 @@ pub struct OrchestratorConfig {
     pub sandbox_config: SandboxConfig,
     /// Shutdown flag for graceful termination (used by daemon)
     pub shutdown_flag: Option<Arc<AtomicBool>>,
+    /// Stage commands queued by daemon clients, executed between loop steps (used by daemon)
+    pub control_queue: Option<ControlQueue>,
+}
+
+impl OrchestratorConfig {
+    /// Apply a run setting changed while the orchestrator is running
+    pub fn apply(&mut self, change: ConfigChange) {
+        match change {
+            ConfigChange::MaxParallel(n) => self.max_parallel_sessions = n,
+            ConfigChange::AutoMerge(enabled) => self.auto_merge = enabled,
+        }
+    }
 }
 
 impl Default for OrchestratorConfig {
@@ -73,6 +91,7 @@ impl Default for OrchestratorConfig {
             max_skill_recommendations: 5,
             sandbox_config: SandboxConfig::default(),
             shutdown_flag: None,
+            control_queue: None,
         }
     }
 }
@@ -92,6 +111,16 @@ pub struct Orchestrator {
     pub(super) skill_index: Option<SkillIndex>,
     /// Detected project languages for signal skill injection
     pub(super) detected_languages: Vec<DetectedLanguage>,
+    /// Webhook, command and JSONL notifiers for orchestrator events
+    pub(super) notifier: Notifier,
+    /// Plan-level budget limits, if the plan declares any
+    pub(super) budget: Option<BudgetConfig>,
+    /// Model prices used to turn token usage into cost
+    pub(super) prices: PriceTable,
+    /// Set once a plan-wide budget is exhausted; no new stages start afterwards
+    pub(super) budget_exhausted: Option<String>,
+    /// Plan-level Claude model defaults, if the plan declares any
+    pub(super) models: Option<ModelConfig>,
 }
 
 impl Orchestrator {
@@ -119,6 +148,14 @@ impl Orchestrator {
         // Detect project languages for skill recommendations
         let detected_languages = detect_project_languages(&config.repo_root);
 
+        let notifier = Notifier::load(&config.work_dir, &config.repo_root);
+        let budget = load_plan_budget(&config);
+        let models = load_plan_models(&config.work_dir, &config.repo_root);
+        let prices = PriceTable::load(&config.work_dir).unwrap_or_else(|e| {
+            eprintln!("Warning: {e:#}; using default model prices");
+            PriceTable::default()
+        });
+
         Ok(Self {
             config,
             graph,
@@ -129,6 +166,11 @@ impl Orchestrator {
             backend,
             skill_index,
             detected_languages,
+            notifier,
+            budget,
+            prices,
+            budget_exhausted: None,
+            models,
         })
     }
 
@@ -202,16 +244,21 @@ impl Orchestrator {
         let mut needs_handoff = Vec::new();
         let mut last_status_update = Instant::now();
         let mut printed_view_instructions = false;
+        let mut shutdown_requested = false;
 
         loop {
             // Check shutdown flag at start of each iteration
             if let Some(ref flag) = self.config.shutdown_flag {
                 if flag.load(Ordering::Relaxed) {
                     println!("Orchestrator shutdown requested");
+                    shutdown_requested = true;
                     break;
                 }
             }
 
+            // Apply stage commands sent by daemon clients before anything else reads state
+            self.process_control_requests();
+
             // Re-sync with stage files to pick up external changes
             // (e.g., stages verified via `loom verify` command)
             self.sync_graph_with_stage_files()
@@ -227,6 +274,9 @@ impl Orchestrator {
                 .context("Failed to spawn merge resolution sessions")?;
             total_sessions_spawned += merge_sessions_spawned;
 
+            self.enforce_plan_budget()
+                .context("Failed to check plan budget")?;
+
             let started = self
                 .start_ready_stages()
                 .context("Failed to start ready stages")?;
@@ -257,20 +307,14 @@ impl Orchestrator {
                 for stage_id in &stage_ids {
                     if let Ok(stage) = self.load_stage(stage_id) {
                         match stage.status {
-                            StageStatus::Completed => {
-                                if !completed_stages.contains(&stage_id.clone()) {
-                                    completed_stages.push(stage_id.clone());
-                                }
+                            StageStatus::Completed if !completed_stages.contains(stage_id) => {
+                                completed_stages.push(stage_id.clone());
                             }
-                            StageStatus::Blocked => {
-                                if !failed_stages.contains(&stage_id.clone()) {
-                                    failed_stages.push(stage_id.clone());
-                                }
+                            StageStatus::Blocked if !failed_stages.contains(stage_id) => {
+                                failed_stages.push(stage_id.clone());
                             }
-                            StageStatus::NeedsHandoff => {
-                                if !needs_handoff.contains(&stage_id.clone()) {
-                                    needs_handoff.push(stage_id.clone());
-                                }
+                            StageStatus::NeedsHandoff if !needs_handoff.contains(stage_id) => {
+                                needs_handoff.push(stage_id.clone());
                             }
                             _ => {}
                         }
@@ -317,6 +361,8 @@ impl Orchestrator {
                         break;
                     }
                 }
+                // Keep control requests responsive while waiting for the next poll
+                self.process_control_requests();
                 std::thread::sleep(check_interval);
                 elapsed += check_interval;
             }
@@ -325,14 +371,22 @@ impl Orchestrator {
         // Restore terminal state before returning (clears \r-based status line)
         cleanup_terminal();
 
-        Ok(OrchestratorResult {
+        let result = OrchestratorResult {
             completed_stages,
             failed_stages,
             needs_handoff,
             total_sessions_spawned,
             started_at,
             completed_at: Utc::now(),
-        })
+        };
+
+        // A stopped orchestrator has not finished the plan; manual mode only starts a batch
+        if !shutdown_requested && !self.config.manual_mode {
+            self.notifier.notify(result.to_notify_event());
+        }
+        self.notifier.flush();
+
+        Ok(result)
     }
 
     /// Count currently running sessions
@@ -358,4 +412,26 @@ impl OrchestratorResult {
     pub fn is_success(&self) -> bool {
         self.failed_stages.is_empty() && self.needs_handoff.is_empty()
     }
+
+    /// Build the `orchestration_complete` notifier event for this result
+    pub fn to_notify_event(&self) -> NotifyEvent {
+        let message = format!(
+            "Orchestration complete: {} completed, {} failed, {} need handoff",
+            self.completed_stages.len(),
+            self.failed_stages.len(),
+            self.needs_handoff.len()
+        );
+        NotifyEvent::orchestration_complete(
+            message,
+            serde_json::json!({
+                "completed_stages": self.completed_stages,
+                "failed_stages": self.failed_stages,
+                "needs_handoff": self.needs_handoff,
+                "total_sessions_spawned": self.total_sessions_spawned,
+                "started_at": self.started_at,
+                "completed_at": self.completed_at,
+                "success": self.is_success(),
+            }),
+        )
+    }
 }
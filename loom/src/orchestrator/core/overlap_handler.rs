@@ -0,0 +1,93 @@
+//! Overlap between ready stages and stages still in flight
+//!
+//! Before starting ready stages the orchestrator estimates which of them
+//! overlap in-flight stages, from declared `files` and a dry-run merge of
+//! each in-flight branch, and holds those back until the overlapping stages
+//! merge rather than leaving the conflict to progressive merge. The hold is
+//! recorded on the stage so `loom status` can show it.
+
+use anyhow::Result;
+use colored::Colorize;
+use std::collections::HashMap;
+
+use crate::git::branch::{branch_name_for_stage, resolve_target_branch};
+use crate::git::merge::merge_tree_changes;
+use crate::models::stage::{OverlapHold, OverlapPolicy};
+use crate::plan::graph::admit_ready;
+use crate::plan::StageNode;
+
+use super::persistence::Persistence;
+use super::{clear_status_line, Orchestrator};
+
+impl Orchestrator {
+    /// IDs of up to `slots` ready stages to start now; the ready stages held
+    /// back for overlap get the hold recorded, the others have it cleared
+    pub(super) fn admit_ready_stages(&mut self, slots: usize) -> Result<Vec<String>> {
+        // Nothing can start, so leave the holds as they are and skip the git work
+        if slots == 0 {
+            return Ok(Vec::new());
+        }
+        let ready = self.graph.ready_stages();
+        let in_flight: Vec<&StageNode> = self
+            .graph
+            .all_nodes()
+            .into_iter()
+            .filter(|node| node.is_in_flight())
+            .collect();
+        let changed = self.in_flight_changes(&ready, &in_flight);
+        let admission = admit_ready(&ready, &in_flight, &changed, slots);
+
+        let ready_ids: Vec<String> = ready.iter().map(|node| node.id.clone()).collect();
+        let mut held: HashMap<String, OverlapHold> = admission.held.into_iter().collect();
+        for stage_id in ready_ids {
+            let hold = held.remove(&stage_id);
+            self.set_overlap_hold(&stage_id, hold)?;
+        }
+        Ok(admission.start)
+    }
+
+    /// Files each in-flight branch would change on the merge point. Skipped
+    /// when no ready stage could be held back, since it runs git per branch.
+    fn in_flight_changes(
+        &self,
+        ready: &[&StageNode],
+        in_flight: &[&StageNode],
+    ) -> HashMap<String, Vec<String>> {
+        let may_hold = ready.iter().any(|node| {
+            !node.files.is_empty() && node.overlap.unwrap_or_default() == OverlapPolicy::Serialize
+        });
+        if !may_hold {
+            return HashMap::new();
+        }
+
+        let merge_point = resolve_target_branch(&self.config.base_branch, &self.config.repo_root);
+        in_flight
+            .iter()
+            .filter_map(|node| {
+                let branch = branch_name_for_stage(&node.id);
+                merge_tree_changes(&branch, &merge_point, &self.config.repo_root)
+                    .ok()
+                    .map(|files| (node.id.clone(), files))
+            })
+            .collect()
+    }
+
+    fn set_overlap_hold(&mut self, stage_id: &str, hold: Option<OverlapHold>) -> Result<()> {
+        let mut stage = self.load_stage(stage_id)?;
+        if stage.overlap_hold == hold {
+            return Ok(());
+        }
+        if let Some(hold) = &hold {
+            clear_status_line();
+            println!(
+                "{} Stage '{}' waiting: overlaps {} ({})",
+                "⏸".yellow(),
+                stage_id,
+                hold.stages.join(", "),
+                hold.files.join(", ").dimmed()
+            );
+        }
+        stage.overlap_hold = hold;
+        self.save_stage(&stage)
+    }
+}
@@ -0,0 +1,75 @@
+//! Promotion of a merged stage's memory decisions into knowledge
+//!
+//! With `promote_decisions: <knowledge file>` in the plan, every decision a
+//! stage recorded with `loom memory decision` is promoted once the stage is
+//! merged, so it outlives `.work/` being cleaned.
+
+use colored::Colorize;
+
+use crate::fs::knowledge::{KnowledgeDir, KnowledgeFile};
+use crate::fs::memory::{promote_entries, MemoryEntryType, PromoteFilter};
+use crate::orchestrator::plan_metadata::load_plan_metadata;
+
+use super::{clear_status_line, Orchestrator};
+
+impl Orchestrator {
+    /// Promote the decisions of a merged stage, if the plan asks for it.
+    /// Failures are reported but never block the merge.
+    pub(super) fn promote_merged_decisions(&self, stage_id: &str) {
+        let Some(target) = self.promote_decisions_target() else {
+            return;
+        };
+        let knowledge = KnowledgeDir::new(&self.config.repo_root);
+        let filter = PromoteFilter {
+            entry_type: Some(MemoryEntryType::Decision),
+            search: None,
+        };
+
+        let promoted = (|| {
+            if !knowledge.exists() {
+                knowledge.initialize()?;
+            }
+            promote_entries(
+                &self.config.work_dir,
+                &knowledge,
+                stage_id,
+                &filter,
+                &target,
+            )
+        })();
+        match promoted {
+            Ok(0) => {}
+            Ok(count) => {
+                clear_status_line();
+                println!(
+                    "{} Promoted {} decisions from '{}' to {}",
+                    "✓".green(),
+                    count,
+                    stage_id,
+                    target.filename()
+                );
+            }
+            Err(e) => {
+                clear_status_line();
+                eprintln!("Warning: Failed to promote decisions of stage '{stage_id}': {e}");
+            }
+        }
+    }
+
+    /// The plan's `promote_decisions` knowledge file, read from the plan source
+    fn promote_decisions_target(&self) -> Option<KnowledgeFile> {
+        let target = load_plan_metadata(&self.config.work_dir, &self.config.repo_root).and_then(
+            |metadata| {
+                metadata
+                    .and_then(|m| m.loom.promote_decisions)
+                    .map(|target| KnowledgeDir::new(&self.config.repo_root).parse_file(&target))
+                    .transpose()
+            },
+        );
+        target.unwrap_or_else(|e| {
+            clear_status_line();
+            eprintln!("Warning: Cannot promote decisions: {e:#}");
+            None
+        })
+    }
+}
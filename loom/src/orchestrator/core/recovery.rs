@@ -255,6 +255,9 @@ impl Recovery for Orchestrator {
                         }
                     }
                     StageStatus::Skipped => {
+                        // Condition-skipped stages satisfy dependents
+                        self.graph
+                            .set_node_condition_skipped(&stage.id, stage.condition_skipped);
                         if let Err(e) = self.graph.mark_status(&stage.id, StageStatus::Skipped) {
                             tracing::warn!(
                                 "Failed to sync graph status for stage {}: {}",
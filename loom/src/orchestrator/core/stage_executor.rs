@@ -9,9 +9,11 @@ use crate::hooks::{find_hooks_dir, setup_hooks_for_worktree, HooksConfig};
 use crate::models::failure::{FailureInfo, FailureType};
 use crate::models::session::Session;
 use crate::models::stage::{Stage, StageStatus, StageType};
+use crate::orchestrator::models::select_model;
 use crate::orchestrator::signals::{
     generate_knowledge_signal, generate_signal_with_skills, DependencyStatus,
 };
+use crate::verify::outputs::interpolate_check_outputs;
 
 use super::persistence::Persistence;
 use super::Orchestrator;
@@ -30,18 +32,18 @@ pub(super) trait StageExecutor: Persistence {
 
 impl StageExecutor for Orchestrator {
     fn start_ready_stages(&mut self) -> Result<usize> {
-        let ready_stages = self.graph.ready_stages();
+        // An exhausted plan budget stops all new work until the plan is restarted
+        if self.budget_exhausted.is_some() {
+            return Ok(0);
+        }
+
         let available_slots = self
             .config
             .max_parallel_sessions
             .saturating_sub(self.active_sessions.len());
 
-        // Collect stage IDs first to avoid borrow checker issues
-        let stage_ids: Vec<String> = ready_stages
-            .iter()
-            .take(available_slots)
-            .map(|node| node.id.clone())
-            .collect();
+        // Ready stages overlapping in-flight ones wait for them to merge
+        let stage_ids = self.admit_ready_stages(available_slots)?;
 
         let mut started = 0;
         for stage_id in stage_ids {
@@ -75,6 +77,16 @@ impl StageExecutor for Orchestrator {
             self.save_stage(&stage)?;
         }
 
+        // Stages whose `when:` condition is false are skipped without a session
+        if self.skip_unmet_condition(&mut stage)? {
+            return Ok(());
+        }
+
+        // Fan-out parents expand into child stages instead of running a session
+        if stage.for_each.is_some() {
+            return self.start_fan_out_stage(stage);
+        }
+
         // Knowledge stages run in main repo without a worktree - mark executing immediately
         if stage.stage_type == StageType::Knowledge {
             stage.try_mark_executing()?;
@@ -157,11 +169,18 @@ impl StageExecutor for Orchestrator {
                 _ => worktree.path.clone(),
             };
 
-            println!("  Running before-stage checks for '{stage_id}'...");
-            match crate::verify::before_after::run_before_stage_checks(
-                &stage.before_stage,
-                &check_dir,
+            let mut checks = stage.before_stage.clone();
+            if let Err(e) = interpolate_check_outputs(
+                stage_id,
+                &stage.outputs,
+                &mut checks,
+                &self.config.work_dir,
             ) {
+                return self.block_unstarted_stage(&mut stage, format!("{e:#}"));
+            }
+
+            println!("  Running before-stage checks for '{stage_id}'...");
+            match crate::verify::before_after::run_before_stage_checks(&checks, &check_dir) {
                 Ok(gaps) if !gaps.is_empty() => {
                     for gap in &gaps {
                         eprintln!("  ✗ Before-stage: {}", gap.description);
@@ -210,7 +229,8 @@ impl StageExecutor for Orchestrator {
             // Continue anyway - sandbox is optional enhancement
         }
 
-        let session = Session::new();
+        let mut session = Session::new();
+        session.model = select_model(self.models.as_ref(), &stage, &session.session_type);
 
         // Set up Claude Code hooks for this session
         if let Some(hooks_dir) = find_hooks_dir() {
@@ -306,7 +326,8 @@ impl StageExecutor for Orchestrator {
             // Continue anyway - sandbox is optional enhancement
         }
 
-        let session = Session::new();
+        let mut session = Session::new_knowledge();
+        session.model = select_model(self.models.as_ref(), &stage, &session.session_type);
 
         // Set up Claude Code hooks for this session in the main repo
         if let Some(hooks_dir) = find_hooks_dir() {
@@ -0,0 +1,111 @@
+//! Stage timeout enforcement
+
+use anyhow::Result;
+use chrono::Utc;
+
+use crate::models::failure::{FailureInfo, FailureType};
+use crate::models::stage::StageStatus;
+use crate::orchestrator::notify::NotifyEvent;
+use crate::orchestrator::retry::{calculate_backoff, should_auto_retry};
+use crate::plan::schema::NotifyEventKind;
+use crate::utils::format_elapsed;
+
+use super::persistence::Persistence;
+use super::{clear_status_line, Orchestrator};
+
+impl Orchestrator {
+    /// Kill the session of a stage that exceeded its timeout and block the stage.
+    ///
+    /// The stage records a `FailureType::Timeout` failure, so the regular
+    /// retry/backoff path in `recovery` re-queues it while retries remain.
+    pub(super) fn handle_stage_timed_out(
+        &mut self,
+        stage_id: &str,
+        elapsed_secs: u64,
+        timeout_secs: u64,
+    ) -> Result<()> {
+        let mut stage = self.load_stage(stage_id)?;
+
+        // The stage may have finished between the monitor poll and now
+        if stage.status != StageStatus::Executing {
+            return Ok(());
+        }
+
+        let timeout = format_elapsed(timeout_secs as i64);
+        let elapsed = format_elapsed(elapsed_secs as i64);
+        clear_status_line();
+        eprintln!("Stage '{stage_id}' timed out after {elapsed} (timeout: {timeout})");
+
+        self.stop_stage_session(stage_id, "timed out");
+
+        let reason = format!("Stage timeout: exceeded {timeout} wall-clock limit");
+        let now = Utc::now();
+        stage.accumulate_attempt_time(now);
+        stage.failure_info = Some(FailureInfo {
+            failure_type: FailureType::Timeout,
+            detected_at: now,
+            evidence: vec![
+                reason.clone(),
+                format!("Attempt ran for {elapsed_secs}s (timeout: {timeout_secs}s)"),
+            ],
+        });
+        stage.last_failure_at = Some(now);
+        stage.retry_count += 1;
+        stage.close_reason = Some(reason.clone());
+
+        let max = stage.max_retries.unwrap_or(3);
+        if should_auto_retry(&FailureType::Timeout, stage.retry_count, max) {
+            let backoff = calculate_backoff(stage.retry_count, 30, 300);
+            eprintln!(
+                "Stage '{stage_id}' timed out (attempt {}/{max}). Will retry in {}s...",
+                stage.retry_count,
+                backoff.as_secs()
+            );
+        } else {
+            eprintln!(
+                "Stage '{stage_id}' failed after {} attempts. Run `loom diagnose {stage_id}` for help.",
+                stage.retry_count
+            );
+        }
+
+        stage.try_mark_blocked()?;
+        self.save_stage(&stage)?;
+        self.graph.mark_status(stage_id, StageStatus::Blocked)?;
+        let evidence = stage
+            .failure_info
+            .map(|info| info.evidence)
+            .unwrap_or_default();
+        self.notifier.notify(
+            NotifyEvent::stage(
+                NotifyEventKind::StageBlocked,
+                stage_id,
+                format!("Stage '{stage_id}' blocked: {reason}"),
+            )
+            .with_details(serde_json::json!({ "reason": reason, "evidence": evidence })),
+        );
+
+        Ok(())
+    }
+
+    /// Kill the active session of a stage that the orchestrator is stopping.
+    ///
+    /// The session is recorded as crashed but also marked as reported, so the
+    /// monitor's crash handling does not count it as a second failure.
+    pub(super) fn stop_stage_session(&mut self, stage_id: &str, why: &str) {
+        if let Some(mut session) = self.active_sessions.remove(stage_id) {
+            if let Err(e) = self.backend.kill_session(&session) {
+                eprintln!(
+                    "Warning: Failed to kill {why} session '{}': {e}",
+                    session.id
+                );
+            }
+            self.reported_crashes.insert(session.id.clone());
+            if session.try_mark_crashed().is_ok() {
+                if let Err(e) = self.save_session(&session) {
+                    eprintln!("Warning: Failed to save {why} session: {e}");
+                }
+            }
+        }
+        self.active_worktrees.remove(stage_id);
+    }
+}
@@ -0,0 +1,42 @@
+//! Token usage recording from session heartbeats
+
+use anyhow::Result;
+
+use crate::models::usage::SessionUsage;
+
+use super::persistence::Persistence;
+use super::Orchestrator;
+
+impl Orchestrator {
+    /// Record the cumulative usage reported by a session's heartbeat.
+    ///
+    /// The usage is stored on the session and, keyed by session, on the
+    /// stage so totals survive handoffs and retries. Files are only
+    /// rewritten when the reported usage actually changed. A stage whose usage
+    /// crosses its budget is paused for human review.
+    pub(super) fn record_heartbeat_usage(
+        &mut self,
+        stage_id: &str,
+        session_id: &str,
+        usage: SessionUsage,
+    ) -> Result<()> {
+        let session_to_save = match self.active_sessions.get_mut(stage_id) {
+            Some(session) if session.id == session_id && session.usage.as_ref() != Some(&usage) => {
+                session.usage = Some(usage.clone());
+                Some(session.clone())
+            }
+            _ => None,
+        };
+        if let Some(session) = session_to_save {
+            self.save_session(&session)?;
+        }
+
+        let mut stage = self.load_stage(stage_id)?;
+        let before = self.stage_spend(&stage);
+        if stage.record_session_usage(session_id, usage) {
+            self.save_stage(&stage)?;
+            self.enforce_stage_budget(&stage, before)?;
+        }
+        Ok(())
+    }
+}
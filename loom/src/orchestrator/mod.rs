@@ -1,10 +1,18 @@
 pub mod auto_merge;
 pub mod continuation;
+pub mod control;
 pub mod core;
+pub mod models;
 pub mod monitor;
 pub mod notify;
+pub mod plan_metadata;
+pub mod plan_update;
+pub mod pricing;
 pub mod progressive_merge;
+pub mod publish;
+pub mod reset;
 pub mod retry;
+pub mod run_settings;
 pub mod signals;
 pub mod skip;
 pub mod spawner;
@@ -15,6 +23,7 @@ pub use continuation::{
     continue_session, load_handoff_content, prepare_continuation, ContinuationConfig,
     ContinuationContext,
 };
+pub use control::{ControlQueue, ControlReply, ControlRequest, StageCommand};
 pub use core::{Orchestrator, OrchestratorConfig, OrchestratorResult};
 pub use monitor::{
     build_failure_info, context_health, context_usage_percent, failure_state_path, heartbeat_path,
@@ -23,7 +32,7 @@ pub use monitor::{
     MonitorEvent, StageFailureState, DEFAULT_HEARTBEAT_POLL_SECS, DEFAULT_HUNG_TIMEOUT_SECS,
     DEFAULT_MAX_FAILURES,
 };
-pub use notify::{notify_needs_human_review, send_desktop_notification};
+pub use notify::{notify_needs_human_review, send_desktop_notification, Notifier, NotifyEvent};
 pub use progressive_merge::{
     get_merge_point, merge_completed_stage, merge_completed_stage_with_timeout, MergeLock,
     ProgressiveMergeResult,
@@ -34,6 +43,7 @@ pub use signals::{
     RecoverySignalContent, SignalContent, SignalUpdates,
 };
 // Re-export crash reporting from spawner (until migrated to separate module)
+pub use run_settings::{ConfigChange, RunSettings};
 pub use spawner::{generate_crash_report, CrashReport};
 // Re-export terminal functions (replaces legacy spawner exports)
 pub use terminal::native::NativeBackend;
@@ -0,0 +1,115 @@
+//! Claude model selection for spawned sessions
+//!
+//! A stage session runs on the stage's own `model`, else the plan default for
+//! its stage type, moved up the plan's escalation list once per retry after
+//! failed acceptance. Merge and base conflict sessions use their plan defaults.
+
+use std::path::Path;
+
+use crate::models::session::SessionType;
+use crate::models::stage::Stage;
+use crate::orchestrator::plan_metadata::load_plan_metadata;
+use crate::plan::schema::ModelConfig;
+
+/// Read the plan's `models` block.
+///
+/// Returns None when the plan declares none or cannot be read; sessions then
+/// fall back to the stage's own model or the claude CLI default.
+pub fn load_plan_models(work_dir: &Path, repo_root: &Path) -> Option<ModelConfig> {
+    load_plan_metadata(work_dir, repo_root).ok()??.loom.models
+}
+
+/// Choose the model for a session of `session_type` working on `stage`
+pub fn select_model(
+    models: Option<&ModelConfig>,
+    stage: &Stage,
+    session_type: &SessionType,
+) -> Option<String> {
+    let fallback = ModelConfig::default();
+    let models = models.unwrap_or(&fallback);
+    let plan_default = models.default_for(session_type, stage.stage_type);
+
+    match session_type {
+        SessionType::Stage | SessionType::Knowledge => models.escalate(
+            stage.model.as_deref().or(plan_default),
+            stage.model_escalation,
+        ),
+        SessionType::Merge | SessionType::BaseConflict => plan_default.map(String::from),
+    }
+}
+
+/// Load the plan's model defaults and choose the model for one session
+pub fn model_for_session(
+    work_dir: &Path,
+    repo_root: &Path,
+    stage: &Stage,
+    session_type: &SessionType,
+) -> Option<String> {
+    select_model(
+        load_plan_models(work_dir, repo_root).as_ref(),
+        stage,
+        session_type,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::stage::StageType;
+
+    fn models() -> ModelConfig {
+        ModelConfig {
+            stage: Some("claude-haiku-4-5".to_string()),
+            knowledge: Some("claude-sonnet-4-5".to_string()),
+            merge: Some("claude-sonnet-4-5".to_string()),
+            base_conflict: None,
+            escalation: vec![
+                "claude-haiku-4-5".to_string(),
+                "claude-sonnet-4-5".to_string(),
+                "claude-opus-4-1".to_string(),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_stage_model_overrides_plan_default_and_escalates() {
+        let models = models();
+        let mut stage = Stage::new("Build".to_string(), None);
+        assert_eq!(
+            select_model(Some(&models), &stage, &SessionType::Stage).as_deref(),
+            Some("claude-haiku-4-5")
+        );
+
+        stage.model = Some("claude-sonnet-4-5".to_string());
+        assert_eq!(
+            select_model(Some(&models), &stage, &SessionType::Stage).as_deref(),
+            Some("claude-sonnet-4-5")
+        );
+
+        stage.model_escalation = 1;
+        assert_eq!(
+            select_model(Some(&models), &stage, &SessionType::Stage).as_deref(),
+            Some("claude-opus-4-1")
+        );
+        // Escalation only applies to the stage's own sessions
+        assert_eq!(
+            select_model(Some(&models), &stage, &SessionType::Merge).as_deref(),
+            Some("claude-sonnet-4-5")
+        );
+        assert_eq!(
+            select_model(Some(&models), &stage, &SessionType::BaseConflict),
+            None
+        );
+    }
+
+    #[test]
+    fn test_knowledge_stage_uses_knowledge_default() {
+        let mut stage = Stage::new("Learn".to_string(), None);
+        stage.stage_type = StageType::Knowledge;
+        assert_eq!(
+            select_model(Some(&models()), &stage, &SessionType::Stage).as_deref(),
+            Some("claude-sonnet-4-5")
+        );
+        assert_eq!(select_model(None, &stage, &SessionType::Stage), None);
+    }
+}
@@ -1,6 +1,7 @@
 //! Core Monitor implementation
 
 use anyhow::{Context, Result};
+use chrono::Utc;
 
 use crate::models::session::Session;
 use crate::models::stage::Stage;
@@ -39,6 +40,7 @@ impl Monitor {
         let sessions = self.load_sessions()?;
 
         events.extend(self.detection.detect_stage_changes(&stages));
+        events.extend(self.detection.detect_stage_timeouts(&stages, Utc::now()));
         events.extend(
             self.detection
                 .detect_session_changes(&sessions, &stages, &self.handlers),
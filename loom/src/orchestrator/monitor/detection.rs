@@ -19,6 +19,8 @@ pub struct Detection {
     pub last_context_levels: HashMap<String, ContextHealth>,
     /// Track sessions that have been reported as hung to avoid duplicate events
     pub reported_hung_sessions: HashSet<String>,
+    /// Track attempts that have been reported as timed out to avoid duplicate events
+    pub reported_timeouts: HashSet<String>,
 }
 
 impl Detection {
@@ -28,6 +30,7 @@ impl Detection {
             last_session_states: HashMap::new(),
             last_context_levels: HashMap::new(),
             reported_hung_sessions: HashSet::new(),
+            reported_timeouts: HashSet::new(),
         }
     }
 
@@ -308,6 +311,7 @@ impl Detection {
                     session_id: update.heartbeat.session_id.clone(),
                     context_percent: update.heartbeat.context_percent,
                     last_tool: update.heartbeat.last_tool.clone(),
+                    usage: update.heartbeat.session_usage(),
                 });
 
                 // If we previously reported this session as hung, clear that flag
@@ -2,6 +2,8 @@
 
 use std::path::PathBuf;
 
+use crate::models::usage::SessionUsage;
+
 /// Events detected by the monitor
 #[derive(Debug, Clone, PartialEq)]
 pub enum MonitorEvent {
@@ -58,6 +60,8 @@ pub enum MonitorEvent {
         session_id: String,
         context_percent: Option<f32>,
         last_tool: Option<String>,
+        /// Cumulative token usage of the session, if reported
+        usage: Option<SessionUsage>,
     },
     /// Context budget has been exceeded - forced handoff required
     BudgetExceeded {
@@ -66,6 +70,13 @@ pub enum MonitorEvent {
         usage_percent: f32,
         budget_percent: f32,
     },
+    /// Stage attempt ran longer than its configured wall-clock timeout
+    StageTimedOut {
+        stage_id: String,
+        session_id: Option<String>,
+        elapsed_secs: u64,
+        timeout_secs: u64,
+    },
     /// Stage needs human review - agent flagged something for human judgment
     StageNeedsHumanReview {
         stage_id: String,
@@ -5,6 +5,7 @@
 //! - Timestamp of last activity
 //! - Context usage percentage
 //! - Last tool used
+//! - Model and cumulative token usage of the session (from its transcript)
 //!
 //! The orchestrator polls these files to detect:
 //! - Crashed sessions (PID dead)
@@ -17,6 +18,8 @@ use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 
+use crate::models::usage::{SessionUsage, TokenUsage};
+
 /// Default timeout for considering a session hung (5 minutes)
 pub const DEFAULT_HUNG_TIMEOUT_SECS: u64 = 300;
 
@@ -41,6 +44,12 @@ pub struct Heartbeat {
     /// Optional message about current activity
     #[serde(default)]
     pub activity: Option<String>,
+    /// Model the session is running on
+    #[serde(default)]
+    pub model: Option<String>,
+    /// Cumulative token usage of the session so far
+    #[serde(default)]
+    pub usage: Option<TokenUsage>,
 }
 
 impl Heartbeat {
@@ -53,6 +62,8 @@ impl Heartbeat {
             context_percent: None,
             last_tool: None,
             activity: None,
+            model: None,
+            usage: None,
         }
     }
 
@@ -74,6 +85,21 @@ impl Heartbeat {
         self
     }
 
+    /// Create heartbeat with the session's model and cumulative token usage
+    pub fn with_usage(mut self, model: Option<String>, usage: TokenUsage) -> Self {
+        self.model = model;
+        self.usage = Some(usage);
+        self
+    }
+
+    /// Session usage carried by this heartbeat, if the hook reported any
+    pub fn session_usage(&self) -> Option<SessionUsage> {
+        self.usage.map(|tokens| SessionUsage {
+            model: self.model.clone(),
+            tokens,
+        })
+    }
+
     /// Check if heartbeat is stale (older than timeout)
     pub fn is_stale(&self, timeout: Duration) -> bool {
         let age = Utc::now().signed_duration_since(self.timestamp);
@@ -302,6 +328,22 @@ mod tests {
         assert_eq!(hb.activity, Some("Running tests".to_string()));
     }
 
+    #[test]
+    fn test_heartbeat_usage_from_hook_json() {
+        // Heartbeats written by older hooks have no model/usage fields
+        let json = r#"{"stage_id":"s","session_id":"x","timestamp":"2024-01-01T00:00:00Z"}"#;
+        let hb: Heartbeat = serde_json::from_str(json).unwrap();
+        assert!(hb.session_usage().is_none());
+
+        let json = r#"{"stage_id":"s","session_id":"x","timestamp":"2024-01-01T00:00:00Z",
+            "model":"claude-sonnet-4-5","usage":{"input_tokens":15,"output_tokens":70,
+            "cache_creation_tokens":100,"cache_read_tokens":300}}"#;
+        let hb: Heartbeat = serde_json::from_str(json).unwrap();
+        let usage = hb.session_usage().unwrap();
+        assert_eq!(usage.model.as_deref(), Some("claude-sonnet-4-5"));
+        assert_eq!(usage.tokens.total(), 485);
+    }
+
     #[test]
     fn test_heartbeat_staleness() {
         let hb = Heartbeat::new("stage-1".to_string(), "session-abc".to_string());
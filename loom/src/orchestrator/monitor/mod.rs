@@ -9,6 +9,9 @@
 //! The monitor polls these files to detect:
 //! - Crashed sessions (PID dead)
 //! - Hung sessions (PID alive but no heartbeat update for threshold duration)
+//!
+//! Stages with a `timeout` are also checked on every poll: an attempt that
+//! stays Executing past its wall-clock limit produces `StageTimedOut`.
 
 mod config;
 mod context;
@@ -18,6 +21,7 @@ pub mod events;
 pub mod failure_tracking;
 pub(crate) mod handlers;
 pub mod heartbeat;
+mod timeout;
 
 #[cfg(test)]
 mod tests;
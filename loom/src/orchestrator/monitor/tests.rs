@@ -293,6 +293,37 @@ fn test_stage_blocked_event() {
     }
 }
 
+#[test]
+fn test_stage_timed_out_event_reported_once_per_attempt() {
+    let mut detection = Detection::new();
+    let now = chrono::Utc::now();
+
+    let mut stage = Stage::new("test".to_string(), None);
+    stage.id = "stage-1".to_string();
+    stage.status = StageStatus::Executing;
+    stage.session = Some("session-1".to_string());
+    stage.timeout = Some("30m".to_string());
+    stage.attempt_started_at = Some(now - chrono::Duration::minutes(31));
+
+    let events = detection.detect_stage_timeouts(&[stage.clone()], now);
+    assert_eq!(
+        events,
+        vec![MonitorEvent::StageTimedOut {
+            stage_id: "stage-1".to_string(),
+            session_id: Some("session-1".to_string()),
+            elapsed_secs: 31 * 60,
+            timeout_secs: 30 * 60,
+        }]
+    );
+    assert!(detection
+        .detect_stage_timeouts(&[stage.clone()], now)
+        .is_empty());
+
+    // A retried attempt has a new start time and can time out again
+    stage.attempt_started_at = Some(now - chrono::Duration::minutes(45));
+    assert_eq!(detection.detect_stage_timeouts(&[stage], now).len(), 1);
+}
+
 #[test]
 fn test_session_needs_handoff_event() {
     let mut detection = Detection::new();
@@ -0,0 +1,43 @@
+//! Wall-clock timeout detection for executing stages
+
+use chrono::{DateTime, Utc};
+
+use crate::models::stage::Stage;
+
+use super::detection::Detection;
+use super::events::MonitorEvent;
+
+impl Detection {
+    /// Detect Executing stages whose current attempt exceeded the stage timeout
+    ///
+    /// Each attempt is reported once, keyed by stage and attempt start time,
+    /// so a retried stage can time out again.
+    pub fn detect_stage_timeouts(
+        &mut self,
+        stages: &[Stage],
+        now: DateTime<Utc>,
+    ) -> Vec<MonitorEvent> {
+        let mut events = Vec::new();
+
+        for stage in stages {
+            let Some((elapsed_secs, timeout_secs)) = stage.attempt_timed_out(now) else {
+                continue;
+            };
+            let Some(started) = stage.attempt_started_at else {
+                continue;
+            };
+
+            let attempt_key = format!("{}@{}", stage.id, started.to_rfc3339());
+            if self.reported_timeouts.insert(attempt_key) {
+                events.push(MonitorEvent::StageTimedOut {
+                    stage_id: stage.id.clone(),
+                    session_id: stage.session.clone(),
+                    elapsed_secs,
+                    timeout_secs,
+                });
+            }
+        }
+
+        events
+    }
+}
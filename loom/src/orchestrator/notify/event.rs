@@ -0,0 +1,54 @@
+//! Event payload delivered to notifiers
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::plan::schema::NotifyEventKind;
+
+/// An orchestrator event as delivered to webhooks, commands and JSONL files
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NotifyEvent {
+    pub event: NotifyEventKind,
+    pub timestamp: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plan_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stage_id: Option<String>,
+    pub message: String,
+    /// Event-specific fields (e.g. block reason, orchestration counts)
+    #[serde(default, skip_serializing_if = "Value::is_null")]
+    pub details: Value,
+}
+
+impl NotifyEvent {
+    /// Create an event for a single stage
+    pub fn stage(kind: NotifyEventKind, stage_id: &str, message: impl Into<String>) -> Self {
+        Self {
+            event: kind,
+            timestamp: Utc::now(),
+            plan_id: None,
+            stage_id: Some(stage_id.to_string()),
+            message: message.into(),
+            details: Value::Null,
+        }
+    }
+
+    /// Create the event sent when the orchestrator finishes
+    pub fn orchestration_complete(message: impl Into<String>, details: Value) -> Self {
+        Self {
+            event: NotifyEventKind::OrchestrationComplete,
+            timestamp: Utc::now(),
+            plan_id: None,
+            stage_id: None,
+            message: message.into(),
+            details,
+        }
+    }
+
+    /// Attach event-specific details
+    pub fn with_details(mut self, details: Value) -> Self {
+        self.details = details;
+        self
+    }
+}
@@ -1,7 +1,16 @@
-//! Desktop notification support for orchestrator events.
+//! Notification support for orchestrator events.
 //!
 //! Sends desktop notifications for events that need human attention,
-//! using notify-send on Linux and osascript on macOS.
+//! using notify-send on Linux and osascript on macOS, and delivers events to
+//! the webhook, command and JSONL notifiers configured in the plan or
+//! `.work/config.toml` (see [`Notifier`]).
+
+mod event;
+mod notifier;
+mod sinks;
+
+pub use event::NotifyEvent;
+pub use notifier::Notifier;
 
 use crate::utils::truncate;
 use std::process::Command;
@@ -0,0 +1,266 @@
+//! Background dispatch of orchestrator events to configured notifiers
+
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Sender};
+use std::thread::{self, JoinHandle};
+
+use super::event::NotifyEvent;
+use super::sinks::deliver_with_retry;
+use crate::fs::load_config;
+use crate::orchestrator::plan_metadata::load_plan_metadata;
+use crate::plan::schema::NotifierConfig;
+
+/// Delivers events to webhooks, commands and JSONL files without blocking the caller
+///
+/// Events are queued on a channel and delivered in order by a worker thread,
+/// so slow webhooks and retries never stall the orchestrator loop. Delivery
+/// failures are logged and dropped.
+pub struct Notifier {
+    plan_id: Option<String>,
+    sender: Option<Sender<NotifyEvent>>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl Notifier {
+    /// Create a notifier; no worker is started when `configs` is empty
+    pub fn new(configs: Vec<NotifierConfig>, repo_root: PathBuf, plan_id: Option<String>) -> Self {
+        if configs.is_empty() {
+            return Self::disabled();
+        }
+
+        let (sender, receiver) = mpsc::channel::<NotifyEvent>();
+        let worker = thread::spawn(move || {
+            for event in receiver {
+                dispatch(&configs, &event, &repo_root);
+            }
+        });
+
+        Self {
+            plan_id,
+            sender: Some(sender),
+            worker: Some(worker),
+        }
+    }
+
+    /// A notifier that discards all events
+    pub fn disabled() -> Self {
+        Self {
+            plan_id: None,
+            sender: None,
+            worker: None,
+        }
+    }
+
+    /// Build a notifier from `[[notifiers]]` in config.toml and `notifiers` in the plan
+    ///
+    /// Configuration errors are logged and result in a disabled notifier.
+    pub fn load(work_dir: &Path, repo_root: &Path) -> Self {
+        match load_notifier_configs(work_dir, repo_root) {
+            Ok((configs, plan_id)) => Self::new(configs, repo_root.to_path_buf(), plan_id),
+            Err(e) => {
+                eprintln!("Warning: Notifiers disabled: {e:#}");
+                Self::disabled()
+            }
+        }
+    }
+
+    /// Queue an event for delivery
+    pub fn notify(&self, mut event: NotifyEvent) {
+        let Some(sender) = &self.sender else {
+            return;
+        };
+        if event.plan_id.is_none() {
+            event.plan_id = self.plan_id.clone();
+        }
+        // The worker only exits after the sender is dropped, so a send error cannot happen
+        let _ = sender.send(event);
+    }
+
+    /// Wait until every queued event has been delivered (or has failed)
+    pub fn flush(&mut self) {
+        self.sender = None;
+        if let Some(worker) = self.worker.take() {
+            if worker.join().is_err() {
+                eprintln!("Warning: Notifier worker panicked");
+            }
+        }
+    }
+}
+
+impl Drop for Notifier {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+/// Send one event to every notifier subscribed to it
+fn dispatch(configs: &[NotifierConfig], event: &NotifyEvent, repo_root: &Path) {
+    let payload = match serde_json::to_string(event) {
+        Ok(payload) => payload,
+        Err(e) => {
+            eprintln!("Warning: Failed to serialize {} event: {e}", event.event);
+            return;
+        }
+    };
+
+    for config in configs.iter().filter(|c| c.accepts(event.event)) {
+        if let Err(e) = deliver_with_retry(config, &payload, repo_root) {
+            eprintln!("Warning: {e:#}");
+        }
+    }
+}
+
+/// Collect notifier definitions and the plan ID from config.toml and plan metadata
+fn load_notifier_configs(
+    work_dir: &Path,
+    repo_root: &Path,
+) -> Result<(Vec<NotifierConfig>, Option<String>)> {
+    let Some(config) = load_config(work_dir)? else {
+        return Ok((Vec::new(), None));
+    };
+
+    let mut configs: Vec<NotifierConfig> = match config.get("notifiers") {
+        Some(value) => value
+            .clone()
+            .try_into()
+            .context("Invalid [[notifiers]] in config.toml")?,
+        None => Vec::new(),
+    };
+
+    // A plan that cannot be loaded only loses its own notifiers
+    match load_plan_metadata(work_dir, repo_root) {
+        Ok(metadata) => configs.extend(metadata.into_iter().flat_map(|m| m.loom.notifiers)),
+        Err(e) => eprintln!("Warning: Plan notifiers disabled: {e:#}"),
+    }
+
+    Ok((configs, config.plan_id().map(String::from)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plan::schema::{NotifierSink, NotifyEventKind};
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn jsonl_notifier(path: &str, events: Vec<NotifyEventKind>) -> NotifierConfig {
+        NotifierConfig {
+            sink: NotifierSink::Jsonl {
+                path: PathBuf::from(path),
+            },
+            events,
+            retries: 0,
+            retry_delay_ms: 1,
+        }
+    }
+
+    fn read_events(path: &Path) -> Vec<NotifyEvent> {
+        fs::read_to_string(path)
+            .unwrap_or_default()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_notify_filters_events_and_stamps_plan_id() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut notifier = Notifier::new(
+            vec![
+                jsonl_notifier("all.jsonl", Vec::new()),
+                jsonl_notifier("blocked.jsonl", vec![NotifyEventKind::StageBlocked]),
+            ],
+            temp_dir.path().to_path_buf(),
+            Some("plan-42".to_string()),
+        );
+
+        notifier.notify(NotifyEvent::stage(
+            NotifyEventKind::StageCompleted,
+            "build",
+            "Stage 'build' completed",
+        ));
+        notifier.notify(NotifyEvent::stage(
+            NotifyEventKind::StageBlocked,
+            "test",
+            "Stage 'test' blocked",
+        ));
+        notifier.flush();
+
+        let all = read_events(&temp_dir.path().join("all.jsonl"));
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[0].event, NotifyEventKind::StageCompleted);
+        assert_eq!(all[0].plan_id.as_deref(), Some("plan-42"));
+        assert_eq!(all[1].stage_id.as_deref(), Some("test"));
+
+        let blocked = read_events(&temp_dir.path().join("blocked.jsonl"));
+        assert_eq!(blocked.len(), 1);
+        assert_eq!(blocked[0].event, NotifyEventKind::StageBlocked);
+    }
+
+    #[test]
+    fn test_load_reads_config_toml_notifiers() {
+        let temp_dir = TempDir::new().unwrap();
+        let work_dir = temp_dir.path().join(".work");
+        fs::create_dir_all(&work_dir).unwrap();
+        fs::write(
+            work_dir.join("config.toml"),
+            r#"
+[plan]
+plan_id = "demo"
+
+[[notifiers]]
+type = "jsonl"
+path = "events.jsonl"
+events = ["orchestration_complete"]
+"#,
+        )
+        .unwrap();
+
+        let (configs, plan_id) = load_notifier_configs(&work_dir, temp_dir.path()).unwrap();
+        assert_eq!(plan_id.as_deref(), Some("demo"));
+        assert_eq!(configs.len(), 1);
+        assert_eq!(
+            configs[0].sink,
+            NotifierSink::Jsonl {
+                path: PathBuf::from("events.jsonl")
+            }
+        );
+        assert!(configs[0].accepts(NotifyEventKind::OrchestrationComplete));
+        assert!(!configs[0].accepts(NotifyEventKind::StageCompleted));
+    }
+
+    #[test]
+    fn test_load_keeps_config_toml_notifiers_on_invalid_plan() {
+        let temp_dir = TempDir::new().unwrap();
+        let work_dir = temp_dir.path().join(".work");
+        fs::create_dir_all(&work_dir).unwrap();
+        fs::write(
+            work_dir.join("config.toml"),
+            r#"
+[plan]
+source_path = "plan.md"
+
+[[notifiers]]
+type = "jsonl"
+path = "events.jsonl"
+"#,
+        )
+        .unwrap();
+        fs::write(temp_dir.path().join("plan.md"), "# Plan without metadata\n").unwrap();
+
+        let (configs, _) = load_notifier_configs(&work_dir, temp_dir.path()).unwrap();
+        assert_eq!(configs.len(), 1);
+    }
+
+    #[test]
+    fn test_disabled_notifier_discards_events() {
+        let mut notifier = Notifier::disabled();
+        notifier.notify(NotifyEvent::stage(
+            NotifyEventKind::Handoff,
+            "build",
+            "handoff",
+        ));
+        notifier.flush();
+    }
+}
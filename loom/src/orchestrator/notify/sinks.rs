@@ -0,0 +1,203 @@
+//! Delivery of serialized events to webhook, command and JSONL sinks
+
+use anyhow::{bail, Context, Result};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::Duration;
+use wait_timeout::ChildExt;
+
+use crate::plan::schema::{NotifierConfig, NotifierSink};
+
+/// Maximum time for a webhook request (connection + response)
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Maximum time a notifier command may run before it is killed
+const COMMAND_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Deliver a payload to a notifier, retrying with exponential backoff
+///
+/// # Arguments
+/// * `notifier` - The notifier configuration (sink and retry policy)
+/// * `payload` - The event serialized as a single line of JSON
+/// * `repo_root` - Directory commands run in and JSONL paths are relative to
+pub(super) fn deliver_with_retry(
+    notifier: &NotifierConfig,
+    payload: &str,
+    repo_root: &Path,
+) -> Result<()> {
+    let mut delay = Duration::from_millis(notifier.retry_delay_ms);
+    let mut attempt = 0;
+
+    loop {
+        match deliver(&notifier.sink, payload, repo_root) {
+            Ok(()) => return Ok(()),
+            Err(e) if attempt >= notifier.retries => {
+                return Err(e).with_context(|| {
+                    format!(
+                        "Notifier {} failed after {} attempt(s)",
+                        notifier.sink,
+                        attempt + 1
+                    )
+                });
+            }
+            Err(_) => {
+                attempt += 1;
+                thread::sleep(delay);
+                delay = delay.saturating_mul(2);
+            }
+        }
+    }
+}
+
+/// Deliver a payload to a sink once
+fn deliver(sink: &NotifierSink, payload: &str, repo_root: &Path) -> Result<()> {
+    match sink {
+        NotifierSink::Webhook { url, headers } => post_webhook(url, headers, payload),
+        NotifierSink::Command { command, args } => run_command(command, args, payload, repo_root),
+        NotifierSink::Jsonl { path } => append_jsonl(&repo_root.join(path), payload),
+    }
+}
+
+fn post_webhook(
+    url: &str,
+    headers: &std::collections::BTreeMap<String, String>,
+    payload: &str,
+) -> Result<()> {
+    let client = reqwest::blocking::Client::builder()
+        .timeout(WEBHOOK_TIMEOUT)
+        .user_agent("loom-notifier")
+        .build()
+        .context("Failed to create HTTP client")?;
+
+    let mut request = client
+        .post(url)
+        .header("Content-Type", "application/json")
+        .body(payload.to_string());
+    for (name, value) in headers {
+        request = request.header(name, value);
+    }
+
+    let response = request
+        .send()
+        .with_context(|| format!("Failed to POST to {url}"))?;
+    if !response.status().is_success() {
+        bail!("Webhook {url} returned HTTP {}", response.status().as_u16());
+    }
+    Ok(())
+}
+
+fn run_command(command: &str, args: &[String], payload: &str, repo_root: &Path) -> Result<()> {
+    let mut child = Command::new(command)
+        .args(args)
+        .current_dir(repo_root)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .with_context(|| format!("Failed to run notifier command: {command}"))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // A command that ignores stdin may exit before reading it; that is not a failure
+        let _ = writeln!(stdin, "{payload}");
+    }
+
+    match child
+        .wait_timeout(COMMAND_TIMEOUT)
+        .with_context(|| format!("Failed to wait for notifier command: {command}"))?
+    {
+        Some(status) if status.success() => Ok(()),
+        Some(status) => bail!("Notifier command {command} exited with {status}"),
+        None => {
+            let _ = child.kill();
+            let _ = child.wait();
+            bail!(
+                "Notifier command {command} timed out after {}s",
+                COMMAND_TIMEOUT.as_secs()
+            )
+        }
+    }
+}
+
+fn append_jsonl(path: &Path, payload: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory {}", parent.display()))?;
+    }
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("Failed to open {}", path.display()))?;
+    writeln!(file, "{payload}").with_context(|| format!("Failed to write {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    fn notifier(sink: NotifierSink, retries: u32) -> NotifierConfig {
+        NotifierConfig {
+            sink,
+            events: Vec::new(),
+            retries,
+            retry_delay_ms: 1,
+        }
+    }
+
+    #[test]
+    fn test_jsonl_appends_lines() {
+        let temp_dir = TempDir::new().unwrap();
+        let sink = NotifierSink::Jsonl {
+            path: PathBuf::from("logs/events.jsonl"),
+        };
+        let notifier = notifier(sink, 0);
+
+        deliver_with_retry(&notifier, r#"{"n":1}"#, temp_dir.path()).unwrap();
+        deliver_with_retry(&notifier, r#"{"n":2}"#, temp_dir.path()).unwrap();
+
+        let content = fs::read_to_string(temp_dir.path().join("logs/events.jsonl")).unwrap();
+        assert_eq!(content, "{\"n\":1}\n{\"n\":2}\n");
+    }
+
+    #[test]
+    fn test_command_receives_payload_on_stdin() {
+        let temp_dir = TempDir::new().unwrap();
+        let sink = NotifierSink::Command {
+            command: "sh".to_string(),
+            args: vec!["-c".to_string(), "cat > received.json".to_string()],
+        };
+
+        deliver_with_retry(
+            &notifier(sink, 0),
+            r#"{"event":"handoff"}"#,
+            temp_dir.path(),
+        )
+        .unwrap();
+
+        let received = fs::read_to_string(temp_dir.path().join("received.json")).unwrap();
+        assert_eq!(received.trim(), r#"{"event":"handoff"}"#);
+    }
+
+    #[test]
+    fn test_command_retried_until_success() {
+        let temp_dir = TempDir::new().unwrap();
+        // Fails on the first two runs, succeeds on the third
+        let script = "echo x >> attempts; [ $(wc -l < attempts) -ge 3 ]";
+        let sink = NotifierSink::Command {
+            command: "sh".to_string(),
+            args: vec!["-c".to_string(), script.to_string()],
+        };
+
+        assert!(deliver_with_retry(&notifier(sink.clone(), 1), "{}", temp_dir.path()).is_err());
+        fs::remove_file(temp_dir.path().join("attempts")).unwrap();
+        deliver_with_retry(&notifier(sink, 3), "{}", temp_dir.path()).unwrap();
+
+        let attempts = fs::read_to_string(temp_dir.path().join("attempts")).unwrap();
+        assert_eq!(attempts.lines().count(), 3);
+    }
+}
@@ -0,0 +1,95 @@
+//! Metadata of the running plan, read from the plan file recorded in config.toml
+//!
+//! Model defaults, budgets, notifiers, publishing and decision promotion all
+//! read settings from the plan's YAML block. The parsed block is cached and
+//! only re-read when the plan file's size or modification time changes.
+
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, Mutex, PoisonError};
+use std::time::SystemTime;
+
+use crate::fs::load_config;
+use crate::plan::parser::{extract_yaml_metadata, parse_and_validate};
+use crate::plan::schema::LoomMetadata;
+
+/// A parsed plan file and the file state it was parsed from
+struct CachedPlan {
+    path: PathBuf,
+    len: u64,
+    modified: SystemTime,
+    metadata: LoomMetadata,
+}
+
+static CACHE: LazyLock<Mutex<Option<CachedPlan>>> = LazyLock::new(|| Mutex::new(None));
+
+/// Load the metadata of the plan recorded in config.toml
+///
+/// Returns `Ok(None)` when there is no active plan or its file is missing,
+/// and an error when the plan file cannot be read, parsed or validated.
+pub fn load_plan_metadata(work_dir: &Path, repo_root: &Path) -> Result<Option<LoomMetadata>> {
+    let Some(source_path) = load_config(work_dir)?.and_then(|c| c.source_path()) else {
+        return Ok(None);
+    };
+    let path = repo_root.join(source_path);
+    let Ok(file) = fs::metadata(&path) else {
+        return Ok(None);
+    };
+    let modified = file.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+
+    let mut cache = CACHE.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(cached) = cache
+        .as_ref()
+        .filter(|c| c.path == path && c.len == file.len() && c.modified == modified)
+    {
+        return Ok(Some(cached.metadata.clone()));
+    }
+
+    let content = fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read plan file: {}", path.display()))?;
+    let metadata = extract_yaml_metadata(&content)
+        .and_then(|yaml| parse_and_validate(&yaml))
+        .with_context(|| format!("Invalid plan metadata in {}", path.display()))?;
+    *cache = Some(CachedPlan {
+        path,
+        len: file.len(),
+        modified,
+        metadata: metadata.clone(),
+    });
+    Ok(Some(metadata))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const PLAN: &str = "# Plan\n\n<!-- loom METADATA -->\n\n```yaml\nloom:\n  version: 1\n  stages:\n    - id: stage-1\n      name: Stage 1\n      stage_type: knowledge\n      working_dir: \".\"\n```\n\n<!-- END loom METADATA -->\n";
+
+    #[test]
+    fn test_load_plan_metadata() {
+        let temp = TempDir::new().unwrap();
+        let work_dir = temp.path().join(".work");
+        fs::create_dir_all(&work_dir).unwrap();
+        assert!(load_plan_metadata(&work_dir, temp.path())
+            .unwrap()
+            .is_none());
+
+        fs::write(
+            work_dir.join("config.toml"),
+            "[plan]\nsource_path = \"plan.md\"\n",
+        )
+        .unwrap();
+        assert!(load_plan_metadata(&work_dir, temp.path())
+            .unwrap()
+            .is_none());
+
+        fs::write(temp.path().join("plan.md"), PLAN).unwrap();
+        let metadata = load_plan_metadata(&work_dir, temp.path()).unwrap().unwrap();
+        assert_eq!(metadata.loom.stages[0].id, "stage-1");
+
+        fs::write(temp.path().join("plan.md"), "```yaml\nloom: [\n```\n").unwrap();
+        assert!(load_plan_metadata(&work_dir, temp.path()).is_err());
+    }
+}
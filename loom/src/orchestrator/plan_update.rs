@@ -0,0 +1,287 @@
+//! Reconciling an edited plan with existing stage files
+//!
+//! The plan is re-parsed and every stage definition is diffed against its
+//! stage file. New stages are added, and stages that have not started take
+//! the plan's definition while keeping their run state. Stages that have
+//! started keep their current definition; their changes are only reported.
+//!
+//! `loom init --update` applies an update directly when no orchestrator is
+//! running. Otherwise it asks the daemon, and the orchestrator applies it
+//! between loop steps and reloads its execution graph.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::fs::load_config_required;
+use crate::fs::stage_files::find_stage_file;
+use crate::models::stage::{Stage, StageStatus};
+use crate::plan::graph::detect_cycles;
+use crate::plan::parser::{parse_plan, ParsedPlan};
+use crate::plan::stages::{expand_init_fan_out, stage_for_plan};
+use crate::plan::StageNode;
+use crate::verify::transitions::{list_all_stages, save_stage};
+
+/// A changed plan-defined field: name, stage file value, plan value
+pub type FieldChange = (&'static str, Value, Value);
+
+/// Differences between the plan and the stage files
+#[derive(Debug, Default)]
+pub struct PlanUpdate {
+    /// New stages, including statically expanded fan-out children
+    pub added: Vec<Stage>,
+    /// Not-yet-started stages with the plan's definition applied
+    pub updated: Vec<(Stage, Vec<FieldChange>)>,
+    /// Started stages whose definition changed; left as they are
+    pub locked: Vec<(Stage, Vec<FieldChange>)>,
+    /// Stages no longer in the plan; left as they are
+    pub removed: Vec<String>,
+    /// Unstarted fan-out children an updated parent no longer expands into;
+    /// their stage files are deleted
+    pub dropped: Vec<String>,
+}
+
+impl PlanUpdate {
+    /// Whether applying the update writes or deletes any stage file
+    pub fn has_writes(&self) -> bool {
+        !self.added.is_empty() || !self.updated.is_empty() || !self.dropped.is_empty()
+    }
+
+    /// The stage IDs the update writes or deletes
+    pub fn applied(&self) -> AppliedUpdate {
+        AppliedUpdate {
+            added: self.added.iter().map(|s| s.id.clone()).collect(),
+            updated: self.updated.iter().map(|(s, _)| s.id.clone()).collect(),
+            dropped: self.dropped.clone(),
+        }
+    }
+}
+
+/// Stage IDs changed by an applied plan update
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AppliedUpdate {
+    pub added: Vec<String>,
+    pub updated: Vec<String>,
+    #[serde(default)]
+    pub dropped: Vec<String>,
+}
+
+/// Generate the diff and copy functions over the plan-defined stage fields
+macro_rules! plan_fields {
+    ($($field:ident),+ $(,)?) => {
+        /// Plan-defined fields whose value differs between `current` and `planned`
+        fn changed_fields(current: &Stage, planned: &Stage) -> Vec<FieldChange> {
+            let mut changes = Vec::new();
+            $(
+                let old = serde_json::to_value(&current.$field).unwrap_or(Value::Null);
+                let new = serde_json::to_value(&planned.$field).unwrap_or(Value::Null);
+                if old != new {
+                    changes.push((stringify!($field), old, new));
+                }
+            )+
+            changes
+        }
+
+        /// Copy the plan-defined fields onto a stage, keeping its run state
+        fn copy_plan_fields(stage: &mut Stage, planned: &Stage) {
+            $(stage.$field = planned.$field.clone();)+
+        }
+    };
+}
+
+plan_fields!(
+    name,
+    description,
+    dependencies,
+    parallel_group,
+    acceptance,
+    parallel,
+    setup,
+    files,
+    stage_type,
+    auto_merge,
+    merge_strategy,
+    overlap,
+    working_dir,
+    context_budget,
+    timeout,
+    budget,
+    model,
+    for_each,
+    when,
+    truths,
+    artifacts,
+    wiring,
+    truth_checks,
+    wiring_tests,
+    dead_code_check,
+    before_stage,
+    after_stage,
+    sandbox,
+    execution_mode,
+    bug_fix,
+    regression_test,
+);
+
+/// Diff the plan recorded in config.toml against the stage files
+pub fn load_plan_update(work_dir: &Path) -> Result<PlanUpdate> {
+    let config = load_config_required(work_dir)?;
+    let plan_path = config
+        .source_path()
+        .context("No 'plan.source_path' found in config.toml")?;
+    let plan = parse_plan(&plan_path)
+        .with_context(|| format!("Failed to parse plan file: {}", plan_path.display()))?;
+    let existing = list_all_stages(work_dir)?;
+    let update = reconcile(&plan, &existing)?;
+    check_merged_graph(&existing, &update)?;
+    Ok(update)
+}
+
+/// Write the added and updated stage files and delete dropped children
+pub fn apply_plan_update(update: &PlanUpdate, work_dir: &Path) -> Result<()> {
+    for stage in update
+        .added
+        .iter()
+        .chain(update.updated.iter().map(|(s, _)| s))
+    {
+        save_stage(stage, work_dir)?;
+    }
+    let stages_dir = work_dir.join("stages");
+    for id in &update.dropped {
+        if let Some(path) = find_stage_file(&stages_dir, id)? {
+            fs::remove_file(&path)
+                .with_context(|| format!("Failed to remove stage file: {}", path.display()))?;
+        }
+    }
+    Ok(())
+}
+
+/// Diff the plan's stage definitions against the existing stages
+pub fn reconcile(plan: &ParsedPlan, existing: &[Stage]) -> Result<PlanUpdate> {
+    let satisfied: HashSet<&str> = existing
+        .iter()
+        .filter(|s| s.satisfies_dependents())
+        .map(|s| s.id.as_str())
+        .collect();
+    let mut update = PlanUpdate::default();
+
+    for stage_def in &plan.stages {
+        let planned = stage_for_plan(stage_def, plan);
+        let Some(current) = existing.iter().find(|s| s.id == stage_def.id) else {
+            let mut stage = planned;
+            stage.status = ready_status(&stage, &satisfied);
+            update.added.extend(expand_init_fan_out(&mut stage)?);
+            update.added.push(stage);
+            continue;
+        };
+
+        // A fan-out parent also depends on its children, which the plan omits
+        let mut definition = current.clone();
+        definition
+            .dependencies
+            .retain(|d| !current.child_stages.contains(d));
+        let changes = changed_fields(&definition, &planned);
+        if changes.is_empty() {
+            continue;
+        }
+        if has_started(current, existing) {
+            update.locked.push((current.clone(), changes));
+            continue;
+        }
+
+        let mut stage = current.clone();
+        copy_plan_fields(&mut stage, &planned);
+        stage.child_stages.clear();
+        stage.status = ready_status(&stage, &satisfied);
+        stage.updated_at = chrono::Utc::now();
+        let children = expand_init_fan_out(&mut stage)?;
+        update.dropped.extend(
+            current
+                .child_stages
+                .iter()
+                .filter(|id| !stage.child_stages.contains(id))
+                .cloned(),
+        );
+        for child in children {
+            match existing.iter().find(|s| s.id == child.id) {
+                Some(old) => {
+                    let child_changes = changed_fields(old, &child);
+                    if !child_changes.is_empty() {
+                        update.updated.push((child, child_changes));
+                    }
+                }
+                None => update.added.push(child),
+            }
+        }
+        update.updated.push((stage, changes));
+    }
+
+    let planned_ids: HashSet<&str> = plan.stages.iter().map(|s| s.id.as_str()).collect();
+    update.removed = existing
+        .iter()
+        .filter(|s| s.parent_stage.is_none() && !planned_ids.contains(s.id.as_str()))
+        .map(|s| s.id.clone())
+        .collect();
+    Ok(update)
+}
+
+/// Whether a stage is past the point where its definition may change.
+///
+/// A fan-out parent waits on its children, so it counts as started once
+/// any of them has.
+fn has_started(stage: &Stage, existing: &[Stage]) -> bool {
+    let started =
+        |s: &Stage| !matches!(s.status, StageStatus::WaitingForDeps | StageStatus::Queued);
+    started(stage)
+        || existing
+            .iter()
+            .any(|s| s.parent_stage.as_deref() == Some(stage.id.as_str()) && started(s))
+}
+
+/// Queued when every dependency already satisfies its dependents
+fn ready_status(stage: &Stage, satisfied: &HashSet<&str>) -> StageStatus {
+    if stage
+        .dependencies
+        .iter()
+        .all(|d| satisfied.contains(d.as_str()))
+    {
+        StageStatus::Queued
+    } else {
+        StageStatus::WaitingForDeps
+    }
+}
+
+/// Reject updates whose merged graph has unknown dependencies or cycles
+pub fn check_merged_graph(existing: &[Stage], update: &PlanUpdate) -> Result<()> {
+    let mut stages: Vec<&Stage> = update
+        .added
+        .iter()
+        .chain(update.updated.iter().map(|(s, _)| s))
+        .collect();
+    for stage in existing {
+        if !update.dropped.contains(&stage.id) && !stages.iter().any(|s| s.id == stage.id) {
+            stages.push(stage);
+        }
+    }
+
+    let ids: HashSet<&str> = stages.iter().map(|s| s.id.as_str()).collect();
+    for stage in &stages {
+        if let Some(dep) = stage
+            .dependencies
+            .iter()
+            .find(|d| !ids.contains(d.as_str()))
+        {
+            bail!("Stage '{}' depends on unknown stage '{dep}'", stage.id);
+        }
+    }
+
+    let nodes = stages
+        .iter()
+        .map(|s| (s.id.clone(), StageNode::from(*s)))
+        .collect();
+    detect_cycles(&nodes).context("Plan update would create a dependency cycle")
+}
@@ -0,0 +1,207 @@
+//! Per-model token prices for cost reporting
+//!
+//! Prices are in USD per million tokens. Built-in defaults cover the current
+//! Claude model families and can be overridden or extended in
+//! `.work/config.toml`:
+//!
+//! ```toml
+//! [pricing."claude-sonnet-4"]
+//! input = 3.0
+//! output = 15.0
+//! cache_write = 3.75
+//! cache_read = 0.3
+//! ```
+//!
+//! Models are matched by the longest configured prefix, so
+//! `claude-sonnet-4-5-20250929` uses the `claude-sonnet-4` entry.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::fs::load_config;
+use crate::models::usage::{SessionUsage, TokenUsage};
+
+/// Price of one model in USD per million tokens
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ModelPrice {
+    pub input: f64,
+    pub output: f64,
+    #[serde(default)]
+    pub cache_write: f64,
+    #[serde(default)]
+    pub cache_read: f64,
+}
+
+impl ModelPrice {
+    const fn new(input: f64, output: f64, cache_write: f64, cache_read: f64) -> Self {
+        Self {
+            input,
+            output,
+            cache_write,
+            cache_read,
+        }
+    }
+
+    /// Cost in USD of the given token counts
+    pub fn cost(&self, tokens: &TokenUsage) -> f64 {
+        let per_token = |count: u64, price: f64| count as f64 * price / 1_000_000.0;
+        per_token(tokens.input_tokens, self.input)
+            + per_token(tokens.output_tokens, self.output)
+            + per_token(tokens.cache_creation_tokens, self.cache_write)
+            + per_token(tokens.cache_read_tokens, self.cache_read)
+    }
+}
+
+const DEFAULT_PRICES: &[(&str, ModelPrice)] = &[
+    ("claude-opus-4", ModelPrice::new(15.0, 75.0, 18.75, 1.5)),
+    ("claude-opus-4-5", ModelPrice::new(5.0, 25.0, 6.25, 0.5)),
+    ("claude-sonnet-4", ModelPrice::new(3.0, 15.0, 3.75, 0.3)),
+    ("claude-haiku-4", ModelPrice::new(1.0, 5.0, 1.25, 0.1)),
+    ("claude-3-5-haiku", ModelPrice::new(0.8, 4.0, 1.0, 0.08)),
+];
+
+/// Price table keyed by model-name prefix
+#[derive(Debug, Clone, PartialEq)]
+pub struct PriceTable {
+    prices: BTreeMap<String, ModelPrice>,
+}
+
+impl Default for PriceTable {
+    fn default() -> Self {
+        Self {
+            prices: DEFAULT_PRICES
+                .iter()
+                .map(|(model, price)| (model.to_string(), *price))
+                .collect(),
+        }
+    }
+}
+
+impl PriceTable {
+    /// Load the built-in prices merged with `[pricing]` overrides from config.toml
+    pub fn load(work_dir: &Path) -> Result<Self> {
+        let mut table = Self::default();
+        let Some(config) = load_config(work_dir)? else {
+            return Ok(table);
+        };
+        if let Some(pricing) = config.get("pricing") {
+            let overrides: BTreeMap<String, ModelPrice> = pricing
+                .clone()
+                .try_into()
+                .context("Invalid [pricing] section in config.toml")?;
+            table.prices.extend(overrides);
+        }
+        Ok(table)
+    }
+
+    /// Price for a model, matched by the longest known prefix
+    pub fn price_for(&self, model: &str) -> Option<&ModelPrice> {
+        self.prices
+            .iter()
+            .filter(|(prefix, _)| model.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, price)| price)
+    }
+
+    /// Cost in USD of the given tokens on a model, if the model is priced
+    pub fn cost(&self, model: Option<&str>, tokens: &TokenUsage) -> Option<f64> {
+        if tokens.is_empty() {
+            return Some(0.0);
+        }
+        self.price_for(model?).map(|price| price.cost(tokens))
+    }
+
+    /// Total cost of a set of sessions, or `None` if any of them is unpriced
+    pub fn sessions_cost<'a>(
+        &self,
+        sessions: impl IntoIterator<Item = &'a SessionUsage>,
+    ) -> Option<f64> {
+        sessions
+            .into_iter()
+            .map(|usage| self.cost(usage.model.as_deref(), &usage.tokens))
+            .sum()
+    }
+}
+
+/// Format a USD amount for display (e.g. `$1.23`, `$0.0042`)
+pub fn format_cost(cost: f64) -> String {
+    if cost > 0.0 && cost < 0.01 {
+        format!("${cost:.4}")
+    } else {
+        format!("${cost:.2}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn tokens(input: u64, output: u64) -> TokenUsage {
+        TokenUsage {
+            input_tokens: input,
+            output_tokens: output,
+            ..TokenUsage::default()
+        }
+    }
+
+    #[test]
+    fn test_longest_prefix_wins() {
+        let table = PriceTable::default();
+        let opus_45 = table.price_for("claude-opus-4-5-20251101").unwrap();
+        assert_eq!(opus_45.input, 5.0);
+        let opus_41 = table.price_for("claude-opus-4-1-20250805").unwrap();
+        assert_eq!(opus_41.input, 15.0);
+        assert!(table.price_for("gpt-4").is_none());
+    }
+
+    #[test]
+    fn test_cost_calculation() {
+        let table = PriceTable::default();
+        let cost = table
+            .cost(Some("claude-sonnet-4-5"), &tokens(1_000_000, 100_000))
+            .unwrap();
+        assert!((cost - 4.5).abs() < 1e-9);
+        assert_eq!(table.cost(None, &TokenUsage::default()), Some(0.0));
+        assert_eq!(table.cost(None, &tokens(1, 1)), None);
+
+        let priced = SessionUsage {
+            model: Some("claude-haiku-4-5".to_string()),
+            tokens: tokens(1_000_000, 0),
+        };
+        let unpriced = SessionUsage {
+            model: Some("custom-model".to_string()),
+            tokens: tokens(1, 0),
+        };
+        assert_eq!(table.sessions_cost([&priced]), Some(1.0));
+        assert_eq!(table.sessions_cost([&priced, &unpriced]), None);
+    }
+
+    #[test]
+    fn test_load_applies_config_overrides() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("config.toml"),
+            "[pricing.\"custom-model\"]\ninput = 2.0\noutput = 8.0\n\n\
+             [pricing.\"claude-sonnet-4\"]\ninput = 1.0\noutput = 1.0\n",
+        )
+        .unwrap();
+
+        let table = PriceTable::load(temp.path()).unwrap();
+        assert_eq!(table.price_for("custom-model-v2").unwrap().output, 8.0);
+        assert_eq!(table.price_for("claude-sonnet-4-5").unwrap().input, 1.0);
+        assert_eq!(
+            table.price_for("claude-sonnet-4-5").unwrap().cache_read,
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_format_cost() {
+        assert_eq!(format_cost(1.234), "$1.23");
+        assert_eq!(format_cost(0.0042), "$0.0042");
+        assert_eq!(format_cost(0.0), "$0.00");
+    }
+}
@@ -8,14 +8,15 @@ use crate::git::branch::{branch_exists, branch_name_for_stage};
 use crate::git::merge::{merge_stage, MergeResult};
 use crate::models::stage::Stage;
 
-use super::ProgressiveMergeResult;
+use super::{merge_options, ProgressiveMergeResult};
 
 /// Attempt to merge a just-completed stage into the merge point.
 ///
 /// Called immediately after verification passes. This function:
 /// 1. Acquires a file-based lock to prevent concurrent merges
 /// 2. Checks if the stage's branch exists
-/// 3. Attempts to merge the branch into the merge point
+/// 3. Attempts to merge the branch into the merge point with the stage's
+///    `merge_strategy`
 /// 4. Returns the result (success, conflict, or no-op)
 ///
 /// # Arguments
@@ -62,19 +63,29 @@ pub fn merge_completed_stage_with_timeout(
     }
 
     // Attempt the merge (merge_stage will acquire the lock internally)
-    let result = merge_stage(&stage.id, merge_point, repo_root, &work_dir)
+    let options = merge_options(stage, merge_point, repo_root, &work_dir);
+    let result = merge_stage(&stage.id, merge_point, repo_root, &work_dir, &options)
         .with_context(|| format!("Failed to merge stage {} into {}", stage.id, merge_point))?;
 
     // Convert git::merge::MergeResult to ProgressiveMergeResult
     let progressive_result = match result {
-        MergeResult::Success { files_changed, .. } => {
-            ProgressiveMergeResult::Success { files_changed }
-        }
+        MergeResult::Success {
+            files_changed,
+            strategy,
+            ..
+        } => ProgressiveMergeResult::Success {
+            files_changed,
+            strategy,
+        },
         MergeResult::FastForward => ProgressiveMergeResult::FastForward,
         MergeResult::AlreadyUpToDate => ProgressiveMergeResult::AlreadyMerged,
-        MergeResult::Conflict { conflicting_files } => {
-            ProgressiveMergeResult::Conflict { conflicting_files }
-        }
+        MergeResult::Conflict {
+            conflicting_files,
+            strategy,
+        } => ProgressiveMergeResult::Conflict {
+            conflicting_files,
+            strategy,
+        },
     };
 
     // Lock is automatically released when _lock goes out of scope
@@ -0,0 +1,119 @@
+//! Merge options for a stage, including the squash commit message
+
+use std::path::Path;
+
+use crate::git::branch::branch_name_for_stage;
+use crate::git::merge::MergeOptions;
+use crate::git::run_git_checked;
+use crate::handoff::{find_latest_handoff, load_handoff_v2, CommitRef};
+use crate::models::stage::{MergeStrategy, Stage};
+
+/// Merge options for a stage's strategy; squash merges get a message built
+/// from the stage and its commits
+pub fn merge_options(
+    stage: &Stage,
+    target_branch: &str,
+    repo_root: &Path,
+    work_dir: &Path,
+) -> MergeOptions {
+    let strategy = stage.merge_strategy.unwrap_or_default();
+    let options = MergeOptions::new(strategy);
+    if strategy != MergeStrategy::Squash {
+        return options;
+    }
+    let commits = stage_commits(stage, target_branch, repo_root, work_dir);
+    options.with_squash_message(squash_message(stage, &commits))
+}
+
+/// Squash commit message: the stage name as subject, then its description
+/// and the commits being squashed
+pub fn squash_message(stage: &Stage, commits: &[CommitRef]) -> String {
+    let mut message = format!("{} ({})\n", stage.name, stage.id);
+    if let Some(description) = stage.description.as_deref().map(str::trim) {
+        if !description.is_empty() {
+            message.push_str(&format!("\n{description}\n"));
+        }
+    }
+    if !commits.is_empty() {
+        message.push_str(&format!(
+            "\nSquashed commits from {}:\n",
+            branch_name_for_stage(&stage.id)
+        ));
+        for commit in commits {
+            message.push_str(&format!("- {} {}\n", commit.hash, commit.message));
+        }
+    }
+    message
+}
+
+/// Commits listed in the stage's latest handoff, followed by any later
+/// commits on the stage branch
+fn stage_commits(
+    stage: &Stage,
+    target_branch: &str,
+    repo_root: &Path,
+    work_dir: &Path,
+) -> Vec<CommitRef> {
+    let mut commits: Vec<CommitRef> = find_latest_handoff(&stage.id, work_dir)
+        .ok()
+        .flatten()
+        .and_then(|path| load_handoff_v2(&path).ok().flatten())
+        .map(|handoff| handoff.commits)
+        .unwrap_or_default();
+
+    let range = format!("{target_branch}..{}", branch_name_for_stage(&stage.id));
+    let log = run_git_checked(&["log", "--reverse", "--format=%h %s", &range], repo_root)
+        .unwrap_or_default();
+    for line in log.lines() {
+        let Some((hash, subject)) = line.split_once(' ') else {
+            continue;
+        };
+        let listed = commits
+            .iter()
+            .any(|c| c.hash.starts_with(hash) || hash.starts_with(&c.hash));
+        if !listed {
+            commits.push(CommitRef::new(hash, subject));
+        }
+    }
+    commits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_squash_message() {
+        let stage = Stage {
+            id: "auth".to_string(),
+            name: "Add login".to_string(),
+            description: Some("Session-based login for the API.".to_string()),
+            ..Stage::default()
+        };
+        let commits = vec![
+            CommitRef::new("abc1234", "Add session store"),
+            CommitRef::new("def5678", "Add login endpoint"),
+        ];
+
+        assert_eq!(
+            squash_message(&stage, &commits),
+            "Add login (auth)\n\
+             \n\
+             Session-based login for the API.\n\
+             \n\
+             Squashed commits from loom/auth:\n\
+             - abc1234 Add session store\n\
+             - def5678 Add login endpoint\n"
+        );
+        assert_eq!(
+            squash_message(
+                &Stage {
+                    description: None,
+                    ..stage
+                },
+                &[]
+            ),
+            "Add login (auth)\n"
+        );
+    }
+}
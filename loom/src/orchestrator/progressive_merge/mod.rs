@@ -8,22 +8,32 @@
 //! stages completing simultaneously.
 
 pub mod execution;
+pub mod message;
 
 pub use crate::fs::get_merge_point;
 pub use crate::git::merge::lock::MergeLock;
 pub use execution::{merge_completed_stage, merge_completed_stage_with_timeout};
+pub use message::{merge_options, squash_message};
+
+use crate::models::stage::MergeStrategy;
 
 /// Result of a progressive merge attempt
 #[derive(Debug, Clone)]
 pub enum ProgressiveMergeResult {
     /// Merge completed successfully with changes
-    Success { files_changed: u32 },
+    Success {
+        files_changed: u32,
+        strategy: MergeStrategy,
+    },
     /// Fast-forward merge completed (no merge commit needed)
     FastForward,
     /// Branch was already merged or up to date
     AlreadyMerged,
     /// Conflicts detected that need resolution
-    Conflict { conflicting_files: Vec<String> },
+    Conflict {
+        conflicting_files: Vec<String>,
+        strategy: MergeStrategy,
+    },
     /// Branch doesn't exist (already cleaned up)
     NoBranch,
 }
@@ -43,7 +53,9 @@ impl ProgressiveMergeResult {
     /// Returns the conflicting files if there was a conflict
     pub fn conflicting_files(&self) -> Option<&[String]> {
         match self {
-            ProgressiveMergeResult::Conflict { conflicting_files } => Some(conflicting_files),
+            ProgressiveMergeResult::Conflict {
+                conflicting_files, ..
+            } => Some(conflicting_files),
             _ => None,
         }
     }
@@ -68,12 +80,17 @@ mod tests {
 
     #[test]
     fn test_progressive_merge_result_is_success() {
-        assert!(ProgressiveMergeResult::Success { files_changed: 5 }.is_success());
+        assert!(ProgressiveMergeResult::Success {
+            files_changed: 5,
+            strategy: MergeStrategy::Squash
+        }
+        .is_success());
         assert!(ProgressiveMergeResult::FastForward.is_success());
         assert!(ProgressiveMergeResult::AlreadyMerged.is_success());
         assert!(ProgressiveMergeResult::NoBranch.is_success());
         assert!(!ProgressiveMergeResult::Conflict {
-            conflicting_files: vec!["file.rs".to_string()]
+            conflicting_files: vec!["file.rs".to_string()],
+            strategy: MergeStrategy::Merge,
         }
         .is_success());
     }
@@ -82,15 +99,19 @@ mod tests {
     fn test_progressive_merge_result_conflicting_files() {
         let conflict = ProgressiveMergeResult::Conflict {
             conflicting_files: vec!["a.rs".to_string(), "b.rs".to_string()],
+            strategy: MergeStrategy::Rebase,
         };
         assert_eq!(
             conflict.conflicting_files(),
             Some(&["a.rs".to_string(), "b.rs".to_string()][..])
         );
 
-        assert!(ProgressiveMergeResult::Success { files_changed: 1 }
-            .conflicting_files()
-            .is_none());
+        assert!(ProgressiveMergeResult::Success {
+            files_changed: 1,
+            strategy: MergeStrategy::Merge
+        }
+        .conflicting_files()
+        .is_none());
     }
 
     #[test]
@@ -0,0 +1,173 @@
+//! PR body of a published plan
+
+use serde_json::Value;
+use std::fmt::Write;
+use std::path::Path;
+
+use crate::fs::criteria_history::{load_history, RunKind};
+use crate::fs::verifications::load_verification;
+use crate::models::stage::{Stage, VerificationStatus};
+
+/// Render the PR body: the plan overview, then each stage's summary,
+/// outputs and verification results
+pub fn render_body(
+    overview: Option<&str>,
+    stages: &[Stage],
+    merge_point: &str,
+    commit: &str,
+    work_dir: &Path,
+) -> String {
+    let mut body = String::new();
+    if let Some(overview) = overview {
+        let _ = writeln!(body, "{}\n", overview.trim());
+    }
+
+    let merged = stages.iter().filter(|s| s.merged).count();
+    let _ = writeln!(body, "## Stages\n");
+    let _ = writeln!(
+        body,
+        "{merged} of {} stages merged into `{merge_point}` at `{commit}`.\n",
+        stages.len()
+    );
+    for stage in stages {
+        render_stage(&mut body, stage, work_dir);
+    }
+    body.trim_end().to_string() + "\n"
+}
+
+fn render_stage(body: &mut String, stage: &Stage, work_dir: &Path) {
+    let _ = writeln!(body, "### {} (`{}`)\n", stage.name, stage.id);
+    if let Some(description) = stage.description.as_deref().map(str::trim) {
+        if !description.is_empty() {
+            let _ = writeln!(body, "{description}\n");
+        }
+    }
+
+    let mut status = stage.status.to_string();
+    if stage.merged {
+        status.push_str(", merged");
+    }
+    if let Some(reason) = stage.close_reason.as_deref().filter(|_| !stage.merged) {
+        let _ = write!(status, " ({reason})");
+    }
+    let _ = writeln!(body, "- Status: {status}");
+    if let Some(acceptance) = acceptance_summary(stage, work_dir) {
+        let _ = writeln!(body, "- Acceptance: {acceptance}");
+    }
+    if let Some(verification) = verification_summary(stage, work_dir) {
+        let _ = writeln!(body, "- Verification: {verification}");
+    }
+    if !stage.outputs.is_empty() {
+        let _ = writeln!(body, "- Outputs:");
+        for output in &stage.outputs {
+            let value = match &output.value {
+                Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+            let _ = writeln!(
+                body,
+                "  - `{}`: `{value}` ({})",
+                output.key, output.description
+            );
+        }
+    }
+    body.push('\n');
+}
+
+/// Passed criteria of the stage's latest recorded acceptance run
+fn acceptance_summary(stage: &Stage, work_dir: &Path) -> Option<String> {
+    let records = load_history(&stage.id, work_dir).ok()?;
+    let last = records
+        .iter()
+        .filter(|r| r.kind == RunKind::Acceptance)
+        .map(|r| r.attempt)
+        .max()?;
+    let run: Vec<_> = records
+        .iter()
+        .filter(|r| r.kind == RunKind::Acceptance && r.attempt == last)
+        .collect();
+    let passed = run.iter().filter(|r| r.success).count();
+    Some(format!("{passed}/{} criteria passed", run.len()))
+}
+
+/// Goal-backward verification result, from its stored record or the stage
+fn verification_summary(stage: &Stage, work_dir: &Path) -> Option<String> {
+    if let Ok(Some(record)) = load_verification(&stage.id, work_dir) {
+        return Some(if record.passed {
+            "passed".to_string()
+        } else {
+            format!("{} gap(s) found", record.gaps.len())
+        });
+    }
+    match &stage.verification_status {
+        VerificationStatus::NotRun => None,
+        VerificationStatus::Passed => Some("passed".to_string()),
+        VerificationStatus::GapsFound { gap_count } => Some(format!("{gap_count} gap(s) found")),
+        VerificationStatus::HumanNeeded => Some("needs human review".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::stage::{StageOutput, StageStatus};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_render_body() {
+        let temp_dir = TempDir::new().unwrap();
+        let stages = vec![
+            Stage {
+                id: "auth".to_string(),
+                name: "Add login".to_string(),
+                description: Some("Session-based login.".to_string()),
+                status: StageStatus::Completed,
+                merged: true,
+                verification_status: VerificationStatus::Passed,
+                outputs: vec![StageOutput {
+                    key: "endpoint".to_string(),
+                    value: Value::String("/login".to_string()),
+                    description: "Login route".to_string(),
+                }],
+                ..Stage::default()
+            },
+            Stage {
+                id: "docs".to_string(),
+                name: "Docs".to_string(),
+                status: StageStatus::Skipped,
+                condition_skipped: true,
+                close_reason: Some("Condition not met".to_string()),
+                ..Stage::default()
+            },
+        ];
+
+        let body = render_body(
+            Some("Add login to the API.\n"),
+            &stages,
+            "main",
+            "abc1234",
+            temp_dir.path(),
+        );
+        assert_eq!(
+            body,
+            "Add login to the API.\n\
+             \n\
+             ## Stages\n\
+             \n\
+             1 of 2 stages merged into `main` at `abc1234`.\n\
+             \n\
+             ### Add login (`auth`)\n\
+             \n\
+             Session-based login.\n\
+             \n\
+             - Status: Completed, merged\n\
+             - Verification: passed\n\
+             - Outputs:\n  \
+               - `endpoint`: `/login` (Login route)\n\
+             \n\
+             ### Docs (`docs`)\n\
+             \n\
+             - Status: Skipped (Condition not met)\n"
+        );
+    }
+}
@@ -0,0 +1,272 @@
+//! Publishing a finished plan
+//!
+//! `loom publish` pushes the merge point (the branch stages were merged into)
+//! to a branch on a remote, renders a PR body from the plan overview and each
+//! stage's summary, outputs and verification results, writes it to a file
+//! and hands it to an optional command such as a forge CLI. With
+//! `publish.auto` set, the run does this itself once every stage is merged.
+
+mod body;
+#[cfg(test)]
+mod tests;
+
+pub use body::render_body;
+
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use crate::fs::plan_lifecycle::all_stages_merged;
+use crate::fs::work_dir::{Config, WorkDir};
+use crate::git::branch::{get_branch_head, push_branch, resolve_target_branch};
+use crate::models::stage::Stage;
+use crate::orchestrator::plan_metadata::load_plan_metadata;
+use crate::plan::parser::extract_plan_overview;
+use crate::plan::schema::PublishConfig;
+use crate::verify::transitions::list_all_stages;
+
+/// Overrides of the configured publish settings
+#[derive(Debug, Clone, Default)]
+pub struct PublishOptions {
+    pub remote: Option<String>,
+    pub branch: Option<String>,
+    pub body_file: Option<PathBuf>,
+    /// Render the body without pushing, writing it or running the command
+    pub dry_run: bool,
+    /// Publish even when some stages are not merged
+    pub allow_incomplete: bool,
+}
+
+/// What was published, or would be in a dry run
+#[derive(Debug, Clone)]
+pub struct Publication {
+    pub remote: String,
+    pub branch: String,
+    pub merge_point: String,
+    pub commit: String,
+    pub title: String,
+    pub body: String,
+    pub body_file: PathBuf,
+    /// The configured publish command, if one ran
+    pub command: Option<String>,
+}
+
+/// The parts of the plan document a publication is built from
+struct PlanInfo {
+    id: String,
+    title: String,
+    overview: Option<String>,
+    stage_order: Vec<String>,
+    publish: PublishConfig,
+}
+
+/// Push the merge point, write the PR body and run the publish command
+pub fn publish_plan(
+    work_dir: &WorkDir,
+    repo_root: &Path,
+    options: &PublishOptions,
+) -> Result<Publication> {
+    let config = work_dir.load_config_required()?;
+    let plan = load_plan_info(&config, work_dir.root(), repo_root)?;
+    let mut publication = prepare_publication(work_dir, repo_root, &config, &plan, options)?;
+    if options.dry_run {
+        return Ok(publication);
+    }
+
+    push_branch(
+        &publication.merge_point,
+        &publication.remote,
+        &publication.branch,
+        repo_root,
+    )?;
+    write_body_file(&publication)?;
+    if let Some(command) = &plan.publish.command {
+        run_publish_command(command, &plan.publish.args, &publication, repo_root)?;
+        publication.command = Some(command.clone());
+    }
+    Ok(publication)
+}
+
+/// Resolve where to publish and render the body, refusing unmerged stages
+/// unless `allow_incomplete` is set
+fn prepare_publication(
+    work_dir: &WorkDir,
+    repo_root: &Path,
+    config: &Config,
+    plan: &PlanInfo,
+    options: &PublishOptions,
+) -> Result<Publication> {
+    let stages = ordered_stages(work_dir.root(), &plan.stage_order)?;
+    if stages.is_empty() {
+        bail!("No stages to publish");
+    }
+    let unmerged: Vec<&str> = stages
+        .iter()
+        .filter(|s| !s.is_landed())
+        .map(|s| s.id.as_str())
+        .collect();
+    if !unmerged.is_empty() && !options.allow_incomplete {
+        bail!(
+            "Stages not merged yet: {}. Use --allow-incomplete to publish anyway",
+            unmerged.join(", ")
+        );
+    }
+
+    let merge_point = resolve_target_branch(&config.base_branch(), repo_root);
+    let commit = get_branch_head(&merge_point, repo_root)
+        .with_context(|| format!("Merge point '{merge_point}' not found"))?;
+    let body = render_body(
+        plan.overview.as_deref(),
+        &stages,
+        &merge_point,
+        &commit[..commit.len().min(7)],
+        work_dir.root(),
+    );
+
+    let publish = &plan.publish;
+    Ok(Publication {
+        remote: options.remote.clone().unwrap_or(publish.remote.clone()),
+        branch: options
+            .branch
+            .clone()
+            .or_else(|| publish.branch.clone())
+            .unwrap_or_else(|| format!("plan/{}", plan.id)),
+        merge_point,
+        commit,
+        title: plan.title.clone(),
+        body,
+        body_file: options
+            .body_file
+            .clone()
+            .or_else(|| publish.body_file.clone())
+            .map(|path| repo_root.join(path))
+            .unwrap_or_else(|| work_dir.root().join("publish").join("PR.md")),
+        command: None,
+    })
+}
+
+/// Publish at the end of a run when the plan sets `publish.auto` and every
+/// stage is merged. Failures are reported, not returned: the plan itself
+/// finished.
+pub fn auto_publish(work_dir: &WorkDir, repo_root: &Path) {
+    let enabled = work_dir
+        .load_config()
+        .ok()
+        .flatten()
+        .and_then(|config| load_plan_info(&config, work_dir.root(), repo_root).ok())
+        .is_some_and(|plan| plan.publish.auto);
+    if !enabled || !all_stages_merged(work_dir).unwrap_or(false) {
+        return;
+    }
+
+    match publish_plan(work_dir, repo_root, &PublishOptions::default()) {
+        Ok(publication) => println!(
+            "  {} Published {} to {}/{}",
+            "✓".green().bold(),
+            publication.merge_point,
+            publication.remote,
+            publication.branch
+        ),
+        Err(e) => eprintln!("  {} Publish failed: {e:#}", "⚠".yellow().bold()),
+    }
+}
+
+/// Title, overview, stage order and publish settings of the active plan.
+/// `[publish]` in config.toml takes precedence over the plan's `publish:`.
+fn load_plan_info(config: &Config, work_dir: &Path, repo_root: &Path) -> Result<PlanInfo> {
+    let id = config.plan_id().unwrap_or("plan").to_string();
+    let title = match config.get_plan_str("plan_name") {
+        Some(name) if !name.is_empty() => name.to_string(),
+        _ => id.clone(),
+    };
+    // A missing plan file is not fatal: stages can still be published from .work state
+    let metadata = load_plan_metadata(work_dir, repo_root)?;
+    let mut info = PlanInfo {
+        id,
+        title,
+        overview: None,
+        stage_order: Vec::new(),
+        publish: PublishConfig::default(),
+    };
+    if let Some(metadata) = metadata {
+        info.stage_order = metadata.loom.stages.into_iter().map(|s| s.id).collect();
+        info.publish = metadata.loom.publish.unwrap_or_default();
+        info.overview = config
+            .source_path()
+            .and_then(|path| fs::read_to_string(repo_root.join(path)).ok())
+            .and_then(|content| extract_plan_overview(&content));
+    }
+    if let Some(value) = config.get("publish") {
+        info.publish = value
+            .clone()
+            .try_into()
+            .context("Invalid [publish] in config.toml")?;
+    }
+    Ok(info)
+}
+
+/// Stages in plan order, followed by any the plan does not list, by ID
+fn ordered_stages(work_dir: &Path, order: &[String]) -> Result<Vec<Stage>> {
+    let mut stages = list_all_stages(work_dir)?;
+    stages.sort_by(|a, b| {
+        let position = |stage: &Stage| order.iter().position(|id| *id == stage.id);
+        match (position(a), position(b)) {
+            (Some(x), Some(y)) => x.cmp(&y),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => a.id.cmp(&b.id),
+        }
+    });
+    Ok(stages)
+}
+
+fn write_body_file(publication: &Publication) -> Result<()> {
+    let path = &publication.body_file;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    fs::write(path, &publication.body)
+        .with_context(|| format!("Failed to write PR body to {}", path.display()))
+}
+
+/// Run the publish command with the placeholders in its arguments filled
+/// in and the PR body on stdin
+fn run_publish_command(
+    command: &str,
+    args: &[String],
+    publication: &Publication,
+    repo_root: &Path,
+) -> Result<()> {
+    let body_file = publication.body_file.to_string_lossy();
+    let args: Vec<String> = args
+        .iter()
+        .map(|arg| {
+            arg.replace("{remote}", &publication.remote)
+                .replace("{branch}", &publication.branch)
+                .replace("{title}", &publication.title)
+                .replace("{body_file}", &body_file)
+        })
+        .collect();
+
+    let mut child = Command::new(command)
+        .args(&args)
+        .current_dir(repo_root)
+        .stdin(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Failed to run publish command: {command}"))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        // A command that ignores stdin may exit before reading it; that is not a failure
+        let _ = stdin.write_all(publication.body.as_bytes());
+    }
+    let status = child
+        .wait()
+        .with_context(|| format!("Failed to wait for publish command: {command}"))?;
+    if !status.success() {
+        bail!("Publish command {command} exited with {status}");
+    }
+    Ok(())
+}
@@ -0,0 +1,146 @@
+use super::*;
+use crate::models::stage::StageStatus;
+use crate::verify::transitions::save_stage;
+use tempfile::TempDir;
+
+const PLAN: &str = r#"# PLAN: Add Login
+
+Session-based login for the API.
+
+---
+
+<!-- loom METADATA -->
+
+```yaml
+loom:
+  version: 1
+  stages:
+    - id: api
+      name: "API"
+      working_dir: "."
+      truths: ["true"]
+    - id: auth
+      name: "Auth"
+      working_dir: "."
+      truths: ["true"]
+```
+
+<!-- END loom METADATA -->
+"#;
+
+fn git(args: &[&str], dir: &Path) -> String {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "git {args:?} failed");
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+/// A repository with a `main` commit, an `origin` bare remote and a `.work`
+/// dir whose stages are merged unless listed in `unmerged`
+fn setup(unmerged: &[&str], publish_toml: &str) -> (TempDir, TempDir, WorkDir) {
+    let repo = TempDir::new().unwrap();
+    let remote = TempDir::new().unwrap();
+    let root = repo.path();
+    git(&["init", "-b", "main"], root);
+    git(&["config", "user.email", "test@example.com"], root);
+    git(&["config", "user.name", "Test"], root);
+    fs::create_dir_all(root.join("doc/plans")).unwrap();
+    fs::write(root.join("doc/plans/PLAN-login.md"), PLAN).unwrap();
+    git(&["add", "."], root);
+    git(&["commit", "-m", "Initial"], root);
+    git(&["init", "--bare"], remote.path());
+    let remote_path = remote.path().to_string_lossy().to_string();
+    git(&["remote", "add", "origin", &remote_path], root);
+
+    let work_dir = WorkDir::new(root).unwrap();
+    work_dir.initialize().unwrap();
+    let config = format!(
+        "[plan]\nsource_path = \"doc/plans/PLAN-login.md\"\nplan_id = \"login\"\n\
+         plan_name = \"Add Login\"\nbase_branch = \"main\"\n{publish_toml}"
+    );
+    fs::write(work_dir.root().join("config.toml"), config).unwrap();
+    for id in ["auth", "api"] {
+        let stage = Stage {
+            id: id.to_string(),
+            name: id.to_uppercase(),
+            status: StageStatus::Completed,
+            merged: !unmerged.contains(&id),
+            ..Stage::default()
+        };
+        save_stage(&stage, work_dir.root()).unwrap();
+    }
+    (repo, remote, work_dir)
+}
+
+#[test]
+fn test_publish_pushes_merge_point_and_runs_command() {
+    let publish = "[publish]\ncommand = \"cp\"\nargs = [\"{body_file}\", \"{remote}.md\"]\n";
+    let (repo, remote, work_dir) = setup(&[], publish);
+    let root = repo.path();
+
+    let publication = publish_plan(&work_dir, root, &PublishOptions::default()).unwrap();
+
+    assert_eq!(publication.branch, "plan/login");
+    assert_eq!(publication.title, "Add Login");
+    assert_eq!(publication.command.as_deref(), Some("cp"));
+    let pushed = git(&["rev-parse", "refs/heads/plan/login"], remote.path());
+    assert_eq!(pushed, git(&["rev-parse", "main"], root));
+    assert_eq!(publication.commit, pushed);
+
+    let body = fs::read_to_string(work_dir.root().join("publish/PR.md")).unwrap();
+    assert!(body.starts_with("Session-based login for the API.\n"));
+    assert!(body.find("(`api`)").unwrap() < body.find("(`auth`)").unwrap());
+    assert_eq!(fs::read_to_string(root.join("origin.md")).unwrap(), body);
+}
+
+#[test]
+fn test_publish_refuses_unmerged_stages() {
+    let (repo, remote, work_dir) = setup(&["auth"], "");
+
+    let err = publish_plan(&work_dir, repo.path(), &PublishOptions::default()).unwrap_err();
+    assert!(err.to_string().contains("auth"));
+
+    let options = PublishOptions {
+        branch: Some("feature/login".to_string()),
+        body_file: Some(PathBuf::from("PR.md")),
+        allow_incomplete: true,
+        ..PublishOptions::default()
+    };
+    publish_plan(&work_dir, repo.path(), &options).unwrap();
+    git(&["rev-parse", "refs/heads/feature/login"], remote.path());
+    assert!(repo.path().join("PR.md").exists());
+}
+
+#[test]
+fn test_publish_dry_run_has_no_side_effects() {
+    let (repo, remote, work_dir) = setup(&[], "");
+    let options = PublishOptions {
+        dry_run: true,
+        ..PublishOptions::default()
+    };
+
+    let publication = publish_plan(&work_dir, repo.path(), &options).unwrap();
+
+    assert!(publication
+        .body
+        .contains("2 of 2 stages merged into `main`"));
+    assert!(!publication.body_file.exists());
+    let refs = git(&["for-each-ref"], remote.path());
+    assert!(refs.is_empty());
+}
+
+#[test]
+fn test_publish_treats_condition_skipped_stages_as_landed() {
+    let (repo, remote, work_dir) = setup(&["auth"], "");
+    let mut auth = crate::verify::transitions::load_stage("auth", work_dir.root()).unwrap();
+    auth.status = StageStatus::Skipped;
+    auth.condition_skipped = true;
+    save_stage(&auth, work_dir.root()).unwrap();
+    assert!(all_stages_merged(&work_dir).unwrap());
+
+    publish_plan(&work_dir, repo.path(), &PublishOptions::default()).unwrap();
+    git(&["rev-parse", "refs/heads/plan/login"], remote.path());
+}
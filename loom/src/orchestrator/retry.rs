@@ -1,7 +1,12 @@
-use crate::models::failure::FailureType;
+use anyhow::{bail, Result};
 use chrono::{DateTime, Utc};
+use std::path::Path;
 use std::time::Duration;
 
+use crate::models::failure::FailureType;
+use crate::models::stage::{Stage, StageStatus};
+use crate::verify::transitions::{load_stage, save_stage};
+
 /// Determines if a stage failure should trigger an automatic retry.
 ///
 /// Only transient failures (SessionCrash, Timeout) are eligible for auto-retry,
@@ -153,6 +158,62 @@ pub fn classify_failure(close_reason: &str) -> FailureType {
     FailureType::Unknown
 }
 
+/// Manually re-queue a stage that is blocked, completed with failures, or merge-blocked.
+///
+/// Non-forced retries count against the stage's retry limit. A forced retry
+/// ignores the limit and resets the retry count and failure info. Retrying a
+/// stage that completed with failures moves it one step up the plan's model
+/// escalation list.
+///
+/// # Arguments
+/// * `stage_id` - The ID of the stage to retry
+/// * `force` - Ignore the retry limit and reset the retry count
+/// * `work_dir` - Path to the `.work` directory
+///
+/// # Returns
+/// The stage as saved after being queued
+pub fn retry_stage(stage_id: &str, force: bool, work_dir: &Path) -> Result<Stage> {
+    let mut stage = load_stage(stage_id, work_dir)?;
+
+    let retryable = matches!(
+        stage.status,
+        StageStatus::Blocked | StageStatus::CompletedWithFailures | StageStatus::MergeBlocked
+    );
+    if !retryable {
+        bail!(
+            "Cannot retry stage in status: {}. Only blocked, completed-with-failures, or merge-blocked stages can be retried.",
+            stage.status
+        );
+    }
+
+    let max = stage.max_retries.unwrap_or(3);
+    if !force && stage.retry_count >= max {
+        bail!(
+            "Stage '{}' has exceeded retry limit ({}/{}). Use --force to override.",
+            stage_id,
+            stage.retry_count,
+            max
+        );
+    }
+
+    if force {
+        stage.retry_count = 0;
+        stage.failure_info = None;
+    } else {
+        // Manual retries count against the limit just like automatic ones
+        stage.retry_count += 1;
+    }
+    // Failing acceptance suggests the model was not up to the task
+    if stage.status == StageStatus::CompletedWithFailures {
+        stage.model_escalation += 1;
+    }
+    stage.last_failure_at = None;
+    stage.try_mark_queued()?;
+
+    save_stage(&stage, work_dir)?;
+    Ok(stage)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -320,4 +381,27 @@ mod tests {
         );
         assert_eq!(classify_failure("BUILD FAILED"), FailureType::BuildFailure);
     }
+
+    #[test]
+    fn test_retry_after_failed_acceptance_escalates_model() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let work_dir = temp_dir.path();
+
+        let mut stage = Stage::new("Stage One".to_string(), None);
+        stage.id = "stage-1".to_string();
+        stage.status = StageStatus::CompletedWithFailures;
+        save_stage(&stage, work_dir).unwrap();
+
+        let retried = retry_stage("stage-1", false, work_dir).unwrap();
+        assert_eq!(retried.status, StageStatus::Queued);
+        assert_eq!(retried.retry_count, 1);
+        assert_eq!(retried.model_escalation, 1);
+
+        // Blocked stages are retried on the same model
+        let mut blocked = load_stage("stage-1", work_dir).unwrap();
+        blocked.status = StageStatus::Blocked;
+        save_stage(&blocked, work_dir).unwrap();
+        let retried = retry_stage("stage-1", true, work_dir).unwrap();
+        assert_eq!(retried.model_escalation, 1);
+    }
 }
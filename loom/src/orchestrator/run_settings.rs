@@ -0,0 +1,166 @@
+//! Run settings that can be changed while the orchestrator is running
+//!
+//! `loom config set` persists these to the `[run]` section of
+//! `.work/config.toml`. A running daemon also applies them live; otherwise
+//! they take effect on the next `loom run` (CLI flags still take precedence).
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use crate::fs::load_config_required;
+
+/// Setting keys accepted by `loom config set`
+pub const SETTING_KEYS: &[&str] = &["max-parallel", "auto-merge"];
+
+/// A single change to a live run setting
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConfigChange {
+    /// Maximum number of concurrent stage sessions
+    MaxParallel(usize),
+    /// Whether completed stages are merged automatically
+    AutoMerge(bool),
+}
+
+impl ConfigChange {
+    /// Parse a `loom config set <key> <value>` pair
+    pub fn parse(key: &str, value: &str) -> Result<Self> {
+        match key {
+            "max-parallel" => {
+                let n: usize = value.parse().with_context(|| {
+                    format!("Invalid max-parallel '{value}': expected a number")
+                })?;
+                if n == 0 {
+                    bail!("max-parallel must be at least 1");
+                }
+                Ok(ConfigChange::MaxParallel(n))
+            }
+            "auto-merge" => match value {
+                "true" | "on" | "yes" => Ok(ConfigChange::AutoMerge(true)),
+                "false" | "off" | "no" => Ok(ConfigChange::AutoMerge(false)),
+                _ => bail!("Invalid auto-merge '{value}': expected true or false"),
+            },
+            _ => bail!(
+                "Unknown setting '{key}'. Valid settings: {}",
+                SETTING_KEYS.join(", ")
+            ),
+        }
+    }
+
+    /// Key under `[run]` in config.toml
+    fn toml_key(&self) -> &'static str {
+        match self {
+            ConfigChange::MaxParallel(_) => "max_parallel",
+            ConfigChange::AutoMerge(_) => "auto_merge",
+        }
+    }
+
+    fn toml_value(&self) -> toml::Value {
+        match *self {
+            ConfigChange::MaxParallel(n) => toml::Value::Integer(n as i64),
+            ConfigChange::AutoMerge(enabled) => toml::Value::Boolean(enabled),
+        }
+    }
+
+    /// Write the change to the `[run]` section of `.work/config.toml`
+    pub fn persist(&self, work_dir: &Path) -> Result<()> {
+        let mut config = load_config_required(work_dir)?;
+        config.set_run_value(self.toml_key(), self.toml_value())?;
+        fs::write(work_dir.join("config.toml"), config.to_toml_string()?)
+            .context("Failed to write config.toml")
+    }
+}
+
+impl fmt::Display for ConfigChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigChange::MaxParallel(n) => write!(f, "max-parallel = {n}"),
+            ConfigChange::AutoMerge(enabled) => write!(f, "auto-merge = {enabled}"),
+        }
+    }
+}
+
+/// Run settings persisted in `.work/config.toml`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RunSettings {
+    pub max_parallel: Option<usize>,
+    pub auto_merge: Option<bool>,
+}
+
+impl RunSettings {
+    /// Load persisted run settings; missing config or keys yield `None` fields
+    pub fn load(work_dir: &Path) -> Self {
+        let Ok(Some(config)) = crate::fs::load_config(work_dir) else {
+            return Self::default();
+        };
+
+        Self {
+            max_parallel: config
+                .get_run_value("max_parallel")
+                .and_then(|v| v.as_integer())
+                .and_then(|n| usize::try_from(n).ok())
+                .filter(|n| *n > 0),
+            auto_merge: config.get_run_value("auto_merge").and_then(|v| v.as_bool()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_parse_settings() {
+        assert_eq!(
+            ConfigChange::parse("max-parallel", "6").unwrap(),
+            ConfigChange::MaxParallel(6)
+        );
+        assert_eq!(
+            ConfigChange::parse("auto-merge", "off").unwrap(),
+            ConfigChange::AutoMerge(false)
+        );
+        assert!(ConfigChange::parse("max-parallel", "0").is_err());
+        assert!(ConfigChange::parse("max-parallel", "many").is_err());
+        assert!(ConfigChange::parse("auto-merge", "maybe").is_err());
+
+        let err = ConfigChange::parse("manual", "true").unwrap_err();
+        assert!(err.to_string().contains("max-parallel, auto-merge"));
+    }
+
+    #[test]
+    fn test_persist_and_load_settings() {
+        let temp_dir = TempDir::new().unwrap();
+        let work_dir = temp_dir.path();
+        fs::write(
+            work_dir.join("config.toml"),
+            "[plan]\nsource_path = \"doc/plans/PLAN-1.md\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(RunSettings::load(work_dir), RunSettings::default());
+
+        ConfigChange::MaxParallel(6).persist(work_dir).unwrap();
+        ConfigChange::AutoMerge(false).persist(work_dir).unwrap();
+
+        let settings = RunSettings::load(work_dir);
+        assert_eq!(settings.max_parallel, Some(6));
+        assert_eq!(settings.auto_merge, Some(false));
+
+        let config = crate::fs::load_config_required(work_dir).unwrap();
+        assert_eq!(
+            config.get_plan_str("source_path"),
+            Some("doc/plans/PLAN-1.md")
+        );
+    }
+
+    #[test]
+    fn test_persist_requires_config() {
+        let temp_dir = TempDir::new().unwrap();
+        assert!(ConfigChange::MaxParallel(2)
+            .persist(temp_dir.path())
+            .is_err());
+    }
+}
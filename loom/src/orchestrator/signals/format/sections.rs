@@ -29,6 +29,13 @@ pub(super) fn format_semi_stable_section(
         content.push_str("loom knowledge show patterns     # Architectural patterns\n");
         content.push_str("loom knowledge show conventions  # Coding conventions\n");
         content.push_str("loom knowledge show mistakes     # Lessons learned\n");
+        for file_type in &embedded_context.custom_knowledge_files {
+            content.push_str(&format!(
+                "loom knowledge show {:<12} # {}\n",
+                file_type.name(),
+                file_type.description()
+            ));
+        }
         content.push_str("```\n\n");
     }
 
@@ -439,6 +446,18 @@ pub(super) fn format_dynamic_section(
         content.push('\n');
     }
 
+    // Decisions other stages made about the same area (from `loom search`'s index)
+    if !embedded_context.past_decisions.is_empty() {
+        content.push_str("## Relevant Past Decisions\n\n");
+        content.push_str("Decisions recorded by other stages that relate to this one:\n\n");
+        for hit in &embedded_context.past_decisions {
+            let stage = hit.document.stage_id.as_deref().unwrap_or("unknown");
+            let text = hit.document.text.split_whitespace().collect::<Vec<_>>();
+            content.push_str(&format!("- **{stage}**: {}\n", text.join(" ")));
+        }
+        content.push('\n');
+    }
+
     // Acceptance Criteria (stage-specific but part of dynamic for ordering)
     content.push_str("## Acceptance Criteria\n\n");
 
@@ -458,6 +477,25 @@ pub(super) fn format_dynamic_section(
     }
     content.push('\n');
 
+    if !embedded_context.flaky_criteria.is_empty() {
+        content.push_str(
+            "**Flaky:** these commands have both passed and failed on the same code. \
+             Re-run a failure before debugging it:\n\n",
+        );
+        for command in &embedded_context.flaky_criteria {
+            content.push_str(&format!("- `{command}`\n"));
+        }
+        content.push('\n');
+    }
+
+    if !embedded_context.failed_tests.is_empty() {
+        content.push_str("**Failing tests** from the last acceptance run:\n\n");
+        for test in &embedded_context.failed_tests {
+            content.push_str(&format!("- {test}\n"));
+        }
+        content.push('\n');
+    }
+
     // Goal-backward verification criteria (if defined)
     if stage.has_any_goal_checks() {
         content.push_str("\n## Goal-Backward Verification\n\n");
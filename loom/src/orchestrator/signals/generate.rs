@@ -2,8 +2,10 @@ use anyhow::Result;
 use std::fs;
 use std::path::{Path, PathBuf};
 
-use crate::fs::knowledge::KnowledgeDir;
+use crate::fs::criteria_history::{last_failed_tests, stage_flaky_commands};
+use crate::fs::knowledge::{KnowledgeDir, KnowledgeFile};
 use crate::fs::memory::format_memory_for_signal;
+use crate::fs::search::{relevant_decisions, SearchHit};
 use crate::handoff::git_handoff::GitHistory;
 use crate::handoff::schema::ParsedHandoff;
 use crate::language::DetectedLanguage;
@@ -24,6 +26,9 @@ pub const DEFAULT_MAX_SKILL_RECOMMENDATIONS: usize = 5;
 /// language-detected skills appear prominently in recommendations.
 const LANGUAGE_DETECTION_SCORE: f32 = 10.0;
 
+/// Maximum number of past decisions from other stages to include in signals
+const MAX_PAST_DECISIONS: usize = 5;
+
 pub fn generate_signal(
     session: &Session,
     stage: &Stage,
@@ -171,6 +176,12 @@ pub fn build_embedded_context_with_stage_and_session(
     let project_root = work_dir.parent().unwrap_or(work_dir);
     let knowledge = KnowledgeDir::new(project_root);
     context.knowledge_has_content = knowledge.has_content();
+    context.custom_knowledge_files = knowledge
+        .file_types()
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|file_type| matches!(file_type, KnowledgeFile::Custom(_)))
+        .collect();
 
     // Read recent memory entries for recitation (Manus pattern - last 10 entries)
     // This keeps important stage context in the attention window
@@ -330,9 +341,32 @@ fn build_signal_context(
     // Populate sandbox summary from stage config
     embedded_context.sandbox_summary = Some(build_sandbox_summary(stage));
 
+    embedded_context.flaky_criteria = stage_flaky_commands(&stage.id, work_dir);
+    embedded_context.failed_tests = last_failed_tests(&stage.id, work_dir);
+    embedded_context.past_decisions = find_past_decisions(stage, work_dir);
+
     embedded_context
 }
 
+/// Decisions of other stages matching the stage's name, description and files.
+/// Search failures only cost the signal this section.
+fn find_past_decisions(stage: &Stage, work_dir: &Path) -> Vec<SearchHit> {
+    let mut text = stage.name.clone();
+    for part in stage.description.iter().chain(&stage.files) {
+        text.push(' ');
+        text.push_str(part);
+    }
+    let project_root = work_dir.parent().unwrap_or(work_dir);
+    relevant_decisions(
+        work_dir,
+        &KnowledgeDir::new(project_root),
+        &stage.id,
+        &text,
+        MAX_PAST_DECISIONS,
+    )
+    .unwrap_or_default()
+}
+
 /// Build sandbox summary from stage configuration
 fn build_sandbox_summary(stage: &Stage) -> SandboxSummary {
     // For now, use stage.sandbox directly
@@ -146,7 +146,7 @@ fn extract_tasks_from_stage(stage: &Stage) -> Vec<String> {
 
     if tasks.is_empty() && !stage.acceptance.is_empty() {
         for criterion in &stage.acceptance {
-            tasks.push(criterion.clone());
+            tasks.push(criterion.command.clone());
         }
     }
 
@@ -165,8 +165,8 @@ mod tests {
             description: Some("Explore the codebase and document findings.".to_string()),
             status: StageStatus::Queued,
             acceptance: vec![
-                "grep -q '## ' doc/loom/knowledge/entry-points.md".to_string(),
-                "grep -q '## ' doc/loom/knowledge/patterns.md".to_string(),
+                "grep -q '## ' doc/loom/knowledge/entry-points.md".into(),
+                "grep -q '## ' doc/loom/knowledge/patterns.md".into(),
             ],
             files: vec!["src/**/*.rs".to_string()],
             stage_type: crate::models::stage::StageType::Knowledge,
@@ -3,7 +3,7 @@ use std::fs;
 use std::path::{Path, PathBuf};
 
 use crate::models::session::Session;
-use crate::models::stage::Stage;
+use crate::models::stage::{MergeStrategy, Stage};
 
 use super::helpers;
 use super::types::MergeSignalContent;
@@ -90,20 +90,17 @@ pub(super) fn format_merge_signal_content(
 
     // Task instructions
     content.push_str("## Your Task\n\n");
-    content.push_str(&format!(
-        "1. Run: `git merge {source_branch}` (if not already in merge state)\n"
-    ));
-    content.push_str("2. Resolve conflicts in the files listed above\n");
-    content.push_str("3. Stage resolved files: `git add <resolved-files>`\n");
-    content.push_str("4. Review changes and complete the merge: `git commit`\n");
-    content.push_str(&format!(
-        "5. Run: `loom stage merge-complete {}`\n",
-        stage.id
-    ));
-    content.push_str(&format!(
-        "6. Clean up worktree and branch: `loom worktree remove {}`\n\n",
+    let strategy = stage.merge_strategy.unwrap_or_default();
+    let mut steps = merge_steps(strategy, source_branch, target_branch);
+    steps.push(format!("Run: `loom stage merge-complete {}`", stage.id));
+    steps.push(format!(
+        "Clean up worktree and branch: `loom worktree remove {}`",
         stage.id
     ));
+    for (i, step) in steps.iter().enumerate() {
+        content.push_str(&format!("{}. {step}\n", i + 1));
+    }
+    content.push('\n');
 
     // Important notes
     content.push_str("## Important\n\n");
@@ -118,6 +115,36 @@ pub(super) fn format_merge_signal_content(
     content
 }
 
+/// Steps that integrate the source branch the way its merge strategy does
+fn merge_steps(strategy: MergeStrategy, source_branch: &str, target_branch: &str) -> Vec<String> {
+    let resolve = "Resolve conflicts in the files listed above".to_string();
+    let add = "Stage resolved files: `git add <resolved-files>`".to_string();
+    match strategy {
+        MergeStrategy::Merge => vec![
+            format!("Run: `git merge {source_branch}` (if not already in merge state)"),
+            resolve,
+            add,
+            "Review changes and complete the merge: `git commit`".to_string(),
+        ],
+        MergeStrategy::Squash => vec![
+            format!("Run: `git merge --squash {source_branch}` (if not already in merge state)"),
+            resolve,
+            add,
+            "Review changes and commit them as one commit: `git commit`".to_string(),
+        ],
+        // Keep history linear: replay the branch on a detached HEAD, since
+        // the branch itself is checked out in the stage worktree
+        MergeStrategy::Rebase | MergeStrategy::FfOnly => vec![
+            format!("Run: `git checkout --detach {source_branch} && git rebase {target_branch}`"),
+            resolve,
+            format!("{add}, then `git rebase --continue`; repeat until the rebase finishes"),
+            format!(
+                "Fast-forward the target: `git checkout {target_branch} && git merge --ff-only HEAD@{{1}}`"
+            ),
+        ],
+    }
+}
+
 pub(super) fn parse_merge_signal_content(
     session_id: &str,
     content: &str,
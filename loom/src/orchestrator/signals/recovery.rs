@@ -56,7 +56,7 @@ mod tests {
             name: "Test Stage".to_string(),
             description: Some("Test description".to_string()),
             status: crate::models::stage::StageStatus::Executing,
-            acceptance: vec!["cargo test".to_string()],
+            acceptance: vec!["cargo test".into()],
             files: vec!["src/lib.rs".to_string()],
             plan_id: Some("test-plan".to_string()),
             worktree: Some(".worktrees/test-stage".to_string()),
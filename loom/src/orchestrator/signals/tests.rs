@@ -2,6 +2,7 @@ use std::fs;
 use std::path::PathBuf;
 use tempfile::TempDir;
 
+use crate::fs::memory::{append_entry, MemoryEntry, MemoryEntryType};
 use crate::models::session::Session;
 use crate::models::stage::{Stage, StageStatus};
 use crate::models::worktree::Worktree;
@@ -166,11 +167,15 @@ fn test_format_signal_content_with_embedded_context() {
         parsed_handoff: None,
         plan_overview: Some("# Plan Title\n\n## Overview\nThis plan does X.".to_string()),
         knowledge_has_content: false,
+        custom_knowledge_files: Vec::new(),
         memory_content: None,
         skill_recommendations: Vec::new(),
         context_budget: None,
         context_usage: None,
         sandbox_summary: None,
+        flaky_criteria: Vec::new(),
+        failed_tests: Vec::new(),
+        past_decisions: Vec::new(),
     };
 
     let content = format_signal_content(
@@ -195,6 +200,126 @@ fn test_format_signal_content_with_embedded_context() {
     assert!(content.contains("</handoff>"));
 }
 
+#[test]
+fn test_format_signal_content_flags_flaky_criteria() {
+    let session = create_test_session();
+    let stage = create_test_stage();
+    let worktree = create_test_worktree();
+    let embedded_context = EmbeddedContext {
+        flaky_criteria: vec!["cargo test --test e2e".to_string()],
+        ..Default::default()
+    };
+
+    let content = format_signal_content(
+        &session,
+        &stage,
+        &worktree,
+        &[],
+        None,
+        None,
+        &embedded_context,
+    );
+
+    assert!(content.contains("**Flaky:**"));
+    assert!(content.contains("- `cargo test --test e2e`"));
+}
+
+#[test]
+fn test_format_signal_content_lists_failed_tests() {
+    let session = create_test_session();
+    let stage = create_test_stage();
+    let worktree = create_test_worktree();
+    let embedded_context = EmbeddedContext {
+        failed_tests: vec!["config::tests::loads: assertion failed".to_string()],
+        ..Default::default()
+    };
+
+    let content = format_signal_content(
+        &session,
+        &stage,
+        &worktree,
+        &[],
+        None,
+        None,
+        &embedded_context,
+    );
+
+    assert!(content.contains("**Failing tests** from the last acceptance run"));
+    assert!(content.contains("- config::tests::loads: assertion failed"));
+}
+
+#[test]
+fn test_generate_signal_includes_past_decisions_of_other_stages() {
+    let temp_dir = TempDir::new().unwrap();
+    let work_dir = temp_dir.path().join(".work");
+    let decision = |text: &str| MemoryEntry::new(MemoryEntryType::Decision, text.to_string());
+    append_entry(
+        &work_dir,
+        "stage-0",
+        &decision("Signal files are written atomically"),
+    )
+    .unwrap();
+    append_entry(&work_dir, "stage-0", &decision("Use postgres for billing")).unwrap();
+    append_entry(&work_dir, "stage-1", &decision("Own signal decision")).unwrap();
+
+    let stage = create_test_stage();
+    let path = generate_signal(
+        &create_test_session(),
+        &stage,
+        &create_test_worktree(),
+        &[],
+        None,
+        None,
+        &work_dir,
+    )
+    .unwrap();
+
+    let content = fs::read_to_string(path).unwrap();
+    assert!(content.contains("## Relevant Past Decisions"));
+    assert!(content.contains("- **stage-0**: Signal files are written atomically"));
+    assert!(!content.contains("postgres"));
+    let past = content.split("## Relevant Past Decisions").nth(1).unwrap();
+    assert!(!past
+        .split("## ")
+        .next()
+        .unwrap()
+        .contains("Own signal decision"));
+}
+
+#[test]
+fn test_generate_signal_lists_custom_knowledge_files() {
+    let temp_dir = TempDir::new().unwrap();
+    let work_dir = temp_dir.path().join(".work");
+    let knowledge = temp_dir.path().join("doc/loom/knowledge");
+    fs::create_dir_all(&work_dir).unwrap();
+    fs::create_dir_all(&knowledge).unwrap();
+    fs::write(
+        knowledge.join("index.toml"),
+        "[[files]]\nname = \"runbooks\"\ndescription = \"Operational runbooks\"\n",
+    )
+    .unwrap();
+    fs::write(
+        knowledge.join("runbooks.md"),
+        "# Runbooks\n\n## Rotate keys\n",
+    )
+    .unwrap();
+
+    let path = generate_signal(
+        &create_test_session(),
+        &create_test_stage(),
+        &create_test_worktree(),
+        &[],
+        None,
+        None,
+        &work_dir,
+    )
+    .unwrap();
+
+    let content = fs::read_to_string(path).unwrap();
+    assert!(content.contains("## Knowledge Base"));
+    assert!(content.contains("loom knowledge show runbooks     # Operational runbooks"));
+}
+
 #[test]
 fn test_extract_plan_overview() {
     let plan_content = r#"# PLAN: Test Feature
@@ -9,6 +9,7 @@ use super::super::merge::{
     read_merge_signal,
 };
 use super::{create_test_session, create_test_stage, create_test_worktree};
+use crate::models::stage::MergeStrategy;
 
 #[test]
 fn test_generate_merge_signal_basic() {
@@ -91,6 +92,25 @@ fn test_format_merge_signal_content_sections() {
     assert!(content.contains("loom worktree remove stage-1"));
 }
 
+#[test]
+fn test_format_merge_signal_content_follows_merge_strategy() {
+    let session = create_test_session();
+    let mut stage = create_test_stage();
+    let files = vec!["src/test.rs".to_string()];
+
+    stage.merge_strategy = Some(MergeStrategy::Squash);
+    let content = format_merge_signal_content(&session, &stage, "loom/stage-1", "main", &files);
+    assert!(content.contains("git merge --squash loom/stage-1"));
+
+    stage.merge_strategy = Some(MergeStrategy::Rebase);
+    let content = format_merge_signal_content(&session, &stage, "loom/stage-1", "main", &files);
+    assert!(content.contains("git checkout --detach loom/stage-1 && git rebase main"));
+    assert!(content.contains("git rebase --continue"));
+    assert!(content.contains("git merge --ff-only HEAD@{1}"));
+    assert!(!content.contains("git merge loom/stage-1"));
+    assert!(content.contains("6. Clean up worktree and branch"));
+}
+
 #[test]
 fn test_read_merge_signal() {
     let temp_dir = TempDir::new().unwrap();
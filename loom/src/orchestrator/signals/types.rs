@@ -1,3 +1,5 @@
+use crate::fs::knowledge::KnowledgeFile;
+use crate::fs::search::SearchHit;
 use crate::handoff::git_handoff::GitHistory;
 use crate::handoff::schema::HandoffV2;
 use crate::models::stage::StageOutput;
@@ -31,6 +33,8 @@ pub struct EmbeddedContext {
     pub plan_overview: Option<String>,
     /// Whether the knowledge directory has meaningful content
     pub knowledge_has_content: bool,
+    /// Knowledge files declared in the knowledge manifest
+    pub custom_knowledge_files: Vec<KnowledgeFile>,
     /// Recent memory entries for recitation (Manus pattern - keeps context in attention)
     pub memory_content: Option<String>,
     /// Skill recommendations based on stage description matching
@@ -41,6 +45,12 @@ pub struct EmbeddedContext {
     pub context_usage: Option<f32>,
     /// Merged sandbox configuration summary for display in signal
     pub sandbox_summary: Option<SandboxSummary>,
+    /// Acceptance commands that passed and failed on the same code
+    pub flaky_criteria: Vec<String>,
+    /// Tests that failed in the stage's latest acceptance run
+    pub failed_tests: Vec<String>,
+    /// Decisions of other stages most relevant to this stage's description and files
+    pub past_decisions: Vec<SearchHit>,
 }
 
 #[derive(Debug, Clone)]
@@ -2,6 +2,7 @@
 //!
 //! This module provides functionality to skip stages that are blocked or waiting.
 //! Skipped stages are marked as such and do not satisfy dependencies for downstream stages.
+//! (Stages skipped by an unmet `when:` condition are the exception; see `Stage::condition_skipped`.)
 
 use anyhow::{bail, Result};
 use std::path::Path;
@@ -0,0 +1,262 @@
+//! Headless terminal backend
+//!
+//! Runs sessions as detached child processes without a terminal emulator,
+//! so `loom run` works on CI runners, SSH boxes and containers. Claude is
+//! started in print mode and each session's output is captured to
+//! `.work/logs/<session-id>.log`.
+
+mod process;
+
+use anyhow::{Context, Result};
+use shell_escape::escape;
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+
+use super::native::{
+    claude_command, cleanup_stage_files, create_wrapper_script, find_claude_path, read_pid_file,
+};
+use super::{prompts, BackendType, TerminalBackend};
+use crate::models::session::{Session, SessionType};
+use crate::models::stage::Stage;
+use crate::models::worktree::Worktree;
+
+pub use process::{kill_process_group, log_file_path, logs_dir, spawn_detached};
+
+/// Headless backend - spawns sessions as background processes with captured logs
+pub struct HeadlessBackend {
+    /// The .work directory path for PID tracking and logs
+    work_dir: PathBuf,
+}
+
+impl HeadlessBackend {
+    /// Create a new headless backend
+    pub fn new(work_dir: PathBuf) -> Result<Self> {
+        std::fs::create_dir_all(logs_dir(&work_dir)).with_context(|| {
+            format!(
+                "Failed to create logs directory: {}",
+                logs_dir(&work_dir).display()
+            )
+        })?;
+        Ok(Self { work_dir })
+    }
+
+    /// Launch claude for a session and return the PID of its process group
+    ///
+    /// # Arguments
+    /// * `tracking_id` - Identifier for the wrapper script and PID file
+    /// * `session` - The session being spawned (provides the log file name)
+    /// * `prompt` - Initial prompt for Claude
+    /// * `workdir` - Directory the session runs in
+    fn launch(
+        &self,
+        tracking_id: &str,
+        session: &Session,
+        prompt: &str,
+        workdir: &Path,
+    ) -> Result<u32> {
+        let escaped_prompt = escape(Cow::Borrowed(prompt));
+        let claude_path = find_claude_path()?;
+        // Print mode: there is no TTY to drive an interactive session
+        let claude_cmd = claude_command(
+            &claude_path,
+            session.model.as_deref(),
+            &format!("-p {escaped_prompt}"),
+        );
+
+        let wrapper_path = create_wrapper_script(
+            &self.work_dir,
+            tracking_id,
+            &session.id,
+            &claude_cmd,
+            Some(workdir),
+        )?;
+        let wrapper_path_abs = wrapper_path.canonicalize().unwrap_or(wrapper_path);
+
+        let log_path = log_file_path(&self.work_dir, &session.id);
+        spawn_detached(&wrapper_path_abs, workdir, &log_path)
+    }
+}
+
+/// Get the tracking ID (wrapper/PID file name) used for a session
+fn tracking_id(session: &Session) -> Option<String> {
+    let stage_id = session.stage_id.as_ref()?;
+    Some(match session.session_type {
+        SessionType::Stage => stage_id.clone(),
+        SessionType::Merge => format!("merge-{stage_id}"),
+        SessionType::BaseConflict => format!("base-conflict-{stage_id}"),
+        SessionType::Knowledge => format!("knowledge-{stage_id}"),
+    })
+}
+
+impl TerminalBackend for HeadlessBackend {
+    fn spawn_session(
+        &self,
+        stage: &Stage,
+        worktree: &Worktree,
+        session: Session,
+        signal_path: &Path,
+    ) -> Result<Session> {
+        let prompt = prompts::stage_prompt(signal_path);
+        let pid = self.launch(&stage.id, &session, &prompt, &worktree.path)?;
+
+        let mut session = session;
+        session.set_worktree_path(worktree.path.clone());
+        session.assign_to_stage(stage.id.clone());
+        session.set_pid(pid);
+        session.try_mark_running()?;
+
+        Ok(session)
+    }
+
+    fn spawn_merge_session(
+        &self,
+        stage: &Stage,
+        session: Session,
+        signal_path: &Path,
+        repo_root: &Path,
+    ) -> Result<Session> {
+        let prompt = prompts::merge_prompt(signal_path);
+        let tracking_id = format!("merge-{}", stage.id);
+        let pid = self.launch(&tracking_id, &session, &prompt, repo_root)?;
+
+        let mut session = session;
+        session.assign_to_stage(stage.id.clone());
+        session.set_pid(pid);
+        session.try_mark_running()?;
+
+        Ok(session)
+    }
+
+    fn spawn_base_conflict_session(
+        &self,
+        stage: &Stage,
+        session: Session,
+        signal_path: &Path,
+        repo_root: &Path,
+    ) -> Result<Session> {
+        let prompt = prompts::base_conflict_prompt(signal_path, &stage.id);
+        let tracking_id = format!("base-conflict-{}", stage.id);
+        let pid = self.launch(&tracking_id, &session, &prompt, repo_root)?;
+
+        let mut session = session;
+        session.assign_to_stage(stage.id.clone());
+        session.set_pid(pid);
+        session.try_mark_running()?;
+
+        Ok(session)
+    }
+
+    fn spawn_knowledge_session(
+        &self,
+        stage: &Stage,
+        session: Session,
+        signal_path: &Path,
+        repo_root: &Path,
+    ) -> Result<Session> {
+        let prompt = prompts::knowledge_prompt(signal_path);
+        let tracking_id = format!("knowledge-{}", stage.id);
+        let pid = self.launch(&tracking_id, &session, &prompt, repo_root)?;
+
+        let mut session = session;
+        session.assign_to_stage(stage.id.clone());
+        session.set_pid(pid);
+        session.try_mark_running()?;
+
+        Ok(session)
+    }
+
+    fn kill_session(&self, session: &Session) -> Result<()> {
+        // The wrapper script execs claude, so the recorded PID leads the process
+        // group that also contains any tools or subagents claude started.
+        let pid = session
+            .pid
+            .or_else(|| tracking_id(session).and_then(|id| read_pid_file(&self.work_dir, &id)));
+
+        if let Some(pid) = pid {
+            kill_process_group(pid)?;
+        }
+
+        if let Some(id) = tracking_id(session) {
+            cleanup_stage_files(&self.work_dir, &id);
+        }
+        Ok(())
+    }
+
+    fn is_session_alive(&self, session: &Session) -> Result<bool> {
+        if let Some(pid) = session.pid {
+            if crate::process::is_process_alive(pid) {
+                return Ok(true);
+            }
+        }
+
+        if let Some(id) = tracking_id(session) {
+            if let Some(pid) = read_pid_file(&self.work_dir, &id) {
+                if crate::process::is_process_alive(pid) {
+                    return Ok(true);
+                }
+                cleanup_stage_files(&self.work_dir, &id);
+            }
+        }
+
+        Ok(false)
+    }
+
+    fn backend_type(&self) -> BackendType {
+        BackendType::Headless
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_headless_backend_creation() {
+        let temp_dir = TempDir::new().unwrap();
+        let backend = HeadlessBackend::new(temp_dir.path().to_path_buf()).unwrap();
+
+        assert_eq!(backend.backend_type(), BackendType::Headless);
+        assert!(logs_dir(temp_dir.path()).is_dir());
+    }
+
+    #[test]
+    fn test_tracking_id_by_session_type() {
+        let mut session = Session::new();
+        assert_eq!(tracking_id(&session), None);
+
+        session.assign_to_stage("stage-a".to_string());
+        assert_eq!(tracking_id(&session), Some("stage-a".to_string()));
+
+        let mut merge = Session::new_merge("loom/stage-a".to_string(), "main".to_string());
+        merge.assign_to_stage("stage-a".to_string());
+        assert_eq!(tracking_id(&merge), Some("merge-stage-a".to_string()));
+
+        let mut base = Session::new_base_conflict("loom/_base/stage-a".to_string());
+        base.assign_to_stage("stage-a".to_string());
+        assert_eq!(
+            tracking_id(&base),
+            Some("base-conflict-stage-a".to_string())
+        );
+
+        let mut knowledge = Session::new_knowledge();
+        knowledge.assign_to_stage("stage-a".to_string());
+        assert_eq!(
+            tracking_id(&knowledge),
+            Some("knowledge-stage-a".to_string())
+        );
+    }
+
+    #[test]
+    fn test_dead_session_is_not_alive() {
+        let temp_dir = TempDir::new().unwrap();
+        let backend = HeadlessBackend::new(temp_dir.path().to_path_buf()).unwrap();
+
+        let mut session = Session::new();
+        session.assign_to_stage("stage-a".to_string());
+        session.set_pid(999_999_999);
+
+        assert!(!backend.is_session_alive(&session).unwrap());
+        assert!(backend.kill_session(&session).is_ok());
+    }
+}
@@ -0,0 +1,157 @@
+//! Detached process management for headless sessions
+//!
+//! Launches wrapper scripts as the leader of a new process group with
+//! stdout/stderr redirected to a per-session log file, and signals the whole
+//! group when a session is killed.
+
+use anyhow::{bail, Context, Result};
+use nix::errno::Errno;
+use nix::sys::signal::{killpg, Signal};
+use nix::unistd::Pid;
+use std::fs::{self, OpenOptions};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+
+/// Get the path to the logs directory
+pub fn logs_dir(work_dir: &Path) -> PathBuf {
+    work_dir.join("logs")
+}
+
+/// Get the path to the log file for a session
+pub fn log_file_path(work_dir: &Path, session_id: &str) -> PathBuf {
+    logs_dir(work_dir).join(format!("{session_id}.log"))
+}
+
+/// Spawn a command detached from the controlling terminal
+///
+/// The child becomes the leader of its own process group so that it survives
+/// the daemon being stopped and so that `kill_process_group` reaches any
+/// subprocesses Claude starts. Output is appended to `log_path`.
+///
+/// # Returns
+/// The PID of the spawned process (which is also its process group ID)
+pub fn spawn_detached(program: &Path, workdir: &Path, log_path: &Path) -> Result<u32> {
+    if let Some(parent) = log_path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create logs directory: {}", parent.display()))?;
+    }
+
+    let log = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(log_path)
+        .with_context(|| format!("Failed to open session log: {}", log_path.display()))?;
+    let log_err = log
+        .try_clone()
+        .context("Failed to duplicate session log handle")?;
+
+    let mut command = Command::new(program);
+    command
+        .current_dir(workdir)
+        .stdin(Stdio::null())
+        .stdout(Stdio::from(log))
+        .stderr(Stdio::from(log_err));
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        command.process_group(0);
+    }
+
+    let mut child = command
+        .spawn()
+        .with_context(|| format!("Failed to spawn headless session: {}", program.display()))?;
+    let pid = child.id();
+
+    // Reap the child when it exits to avoid leaving zombies behind
+    thread::spawn(move || {
+        let _ = child.wait();
+    });
+
+    Ok(pid)
+}
+
+/// Send SIGTERM to every process in the group led by `pid`
+///
+/// A group that no longer exists is not treated as an error.
+pub fn kill_process_group(pid: u32) -> Result<()> {
+    let pgid = i32::try_from(pid).with_context(|| format!("Invalid process group ID: {pid}"))?;
+
+    match killpg(Pid::from_raw(pgid), Signal::SIGTERM) {
+        Ok(()) | Err(Errno::ESRCH) => Ok(()),
+        Err(e) => bail!("Failed to kill process group {pid}: {e}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+    use tempfile::TempDir;
+
+    fn write_script(dir: &Path, body: &str) -> PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = dir.join("script.sh");
+        fs::write(&path, format!("#!/bin/bash\n{body}\n")).unwrap();
+        let mut perms = fs::metadata(&path).unwrap().permissions();
+        perms.set_mode(0o700);
+        fs::set_permissions(&path, perms).unwrap();
+        path
+    }
+
+    fn wait_until(timeout: Duration, mut check: impl FnMut() -> bool) -> bool {
+        let deadline = Instant::now() + timeout;
+        while Instant::now() < deadline {
+            if check() {
+                return true;
+            }
+            thread::sleep(Duration::from_millis(50));
+        }
+        false
+    }
+
+    #[test]
+    fn test_log_file_path() {
+        let path = log_file_path(Path::new("/tmp/.work"), "session-abc");
+        assert_eq!(path, PathBuf::from("/tmp/.work/logs/session-abc.log"));
+    }
+
+    #[test]
+    fn test_spawn_detached_captures_output() {
+        let temp_dir = TempDir::new().unwrap();
+        let script = write_script(temp_dir.path(), "echo out; echo err >&2");
+        let log_path = log_file_path(temp_dir.path(), "session-1");
+
+        spawn_detached(&script, temp_dir.path(), &log_path).unwrap();
+
+        assert!(wait_until(Duration::from_secs(5), || {
+            fs::read_to_string(&log_path)
+                .map(|c| c.contains("out") && c.contains("err"))
+                .unwrap_or(false)
+        }));
+    }
+
+    #[test]
+    fn test_kill_process_group_terminates_session() {
+        let temp_dir = TempDir::new().unwrap();
+        let script = write_script(temp_dir.path(), "sleep 30");
+        let log_path = log_file_path(temp_dir.path(), "session-2");
+
+        let pid = spawn_detached(&script, temp_dir.path(), &log_path).unwrap();
+        assert!(crate::process::is_process_alive(pid));
+
+        kill_process_group(pid).unwrap();
+
+        assert!(wait_until(Duration::from_secs(5), || {
+            !crate::process::is_process_alive(pid)
+        }));
+    }
+
+    #[test]
+    fn test_kill_process_group_missing_is_ok() {
+        // Above the kernel's maximum PID, so the group can never exist
+        assert!(kill_process_group(999_999_999).is_ok());
+    }
+}
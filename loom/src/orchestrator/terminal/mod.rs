@@ -1,7 +1,7 @@
 //! Terminal backend abstraction for session management
 //!
 //! Provides a unified interface for spawning and managing Claude Code sessions
-//! in native terminal windows.
+//! in native terminal windows, tmux windows, or as headless background processes.
 //!
 //! Supports three session types:
 //! - Stage sessions: run in isolated worktrees for parallel stage execution
@@ -9,9 +9,13 @@
 //! - Knowledge sessions: run in main repository for knowledge gathering (no worktree)
 
 pub mod emulator;
+pub mod headless;
 pub mod native;
+pub mod prompts;
+pub mod tmux;
 
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
 use std::path::Path;
 
 use crate::models::session::Session;
@@ -19,17 +23,24 @@ use crate::models::stage::Stage;
 use crate::models::worktree::Worktree;
 
 /// Backend type selection
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum BackendType {
     /// Native terminal windows - each session in its own terminal
     #[default]
     Native,
+    /// Detached background processes with output captured to .work/logs/
+    Headless,
+    /// One window per session inside a per-plan tmux session
+    Tmux,
 }
 
 impl std::fmt::Display for BackendType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             BackendType::Native => write!(f, "native"),
+            BackendType::Headless => write!(f, "headless"),
+            BackendType::Tmux => write!(f, "tmux"),
         }
     }
 }
@@ -40,7 +51,11 @@ impl std::str::FromStr for BackendType {
     fn from_str(s: &str) -> Result<Self> {
         match s.to_lowercase().as_str() {
             "native" => Ok(BackendType::Native),
-            _ => anyhow::bail!("Unknown backend type: {s}. Expected 'native'"),
+            "headless" => Ok(BackendType::Headless),
+            "tmux" => Ok(BackendType::Tmux),
+            _ => {
+                anyhow::bail!("Unknown backend type: {s}. Expected 'native', 'headless' or 'tmux'")
+            }
         }
     }
 }
@@ -142,6 +157,34 @@ pub fn create_backend(
             let backend = native::NativeBackend::new(work_dir.to_path_buf())?;
             Ok(Box::new(backend))
         }
+        BackendType::Headless => {
+            let backend = headless::HeadlessBackend::new(work_dir.to_path_buf())?;
+            Ok(Box::new(backend))
+        }
+        BackendType::Tmux => {
+            let backend = tmux::TmuxBackend::new(work_dir.to_path_buf())?;
+            Ok(Box::new(backend))
+        }
+    }
+}
+
+/// Read the backend type configured in `.work/config.toml` (`[run] backend`)
+///
+/// Falls back to the default backend when the config is missing, has no
+/// backend set, or names an unknown backend (with a warning).
+pub fn configured_backend_type(work_dir: &Path) -> BackendType {
+    let config = match crate::fs::load_config(work_dir) {
+        Ok(Some(config)) => config,
+        _ => return BackendType::default(),
+    };
+
+    match config.get_run_str("backend").map(str::parse::<BackendType>) {
+        Some(Ok(backend_type)) => backend_type,
+        Some(Err(e)) => {
+            eprintln!("Warning: Ignoring backend in config.toml: {e}");
+            BackendType::default()
+        }
+        None => BackendType::default(),
     }
 }
 
@@ -155,6 +198,8 @@ mod tests {
     #[test]
     fn test_backend_type_display() {
         assert_eq!(BackendType::Native.to_string(), "native");
+        assert_eq!(BackendType::Headless.to_string(), "headless");
+        assert_eq!(BackendType::Tmux.to_string(), "tmux");
     }
 
     #[test]
@@ -167,6 +212,11 @@ mod tests {
             "NATIVE".parse::<BackendType>().unwrap(),
             BackendType::Native
         );
+        assert_eq!(
+            "headless".parse::<BackendType>().unwrap(),
+            BackendType::Headless
+        );
+        assert_eq!("tmux".parse::<BackendType>().unwrap(), BackendType::Tmux);
         assert!("invalid".parse::<BackendType>().is_err());
     }
 
@@ -174,4 +224,23 @@ mod tests {
     fn test_backend_type_default() {
         assert_eq!(BackendType::default(), BackendType::Native);
     }
+
+    #[test]
+    fn test_configured_backend_type() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let work_dir = temp_dir.path();
+
+        // No config.toml - default backend
+        assert_eq!(configured_backend_type(work_dir), BackendType::Native);
+
+        std::fs::write(
+            work_dir.join("config.toml"),
+            "[plan]\nsource_path = \"plan.md\"\n\n[run]\nbackend = \"headless\"\n",
+        )
+        .unwrap();
+        assert_eq!(configured_backend_type(work_dir), BackendType::Headless);
+
+        std::fs::write(work_dir.join("config.toml"), "[run]\nbackend = \"bogus\"\n").unwrap();
+        assert_eq!(configured_backend_type(work_dir), BackendType::Native);
+    }
 }
@@ -14,13 +14,13 @@ use std::borrow::Cow;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
-use super::{BackendType, TerminalBackend};
+use super::{prompts, BackendType, TerminalBackend};
 use crate::models::session::Session;
 use crate::models::stage::Stage;
 use crate::models::worktree::Worktree;
 
 pub use detection::detect_terminal;
-pub use pid_tracking::{cleanup_stage_files, read_pid_file};
+pub use pid_tracking::{cleanup_stage_files, create_wrapper_script, read_pid_file};
 pub use spawner::spawn_in_terminal;
 pub use window_ops::{close_window_by_title, window_exists_by_title};
 #[cfg(target_os = "macos")]
@@ -56,7 +56,7 @@ fn window_exists_for_terminal(title: &str, terminal: &super::emulator::TerminalE
 ///
 /// On macOS, spawned terminals don't inherit the parent's PATH, so we need
 /// to resolve claude's path at script generation time.
-fn find_claude_path() -> Result<PathBuf> {
+pub(crate) fn find_claude_path() -> Result<PathBuf> {
     // First try which::which (uses current PATH)
     if let Ok(path) = which::which("claude") {
         return Ok(path);
@@ -81,6 +81,21 @@ fn find_claude_path() -> Result<PathBuf> {
     bail!("claude binary not found in PATH or common locations. Checked: ~/.claude/local/claude, ~/.local/bin/claude, /usr/local/bin/claude")
 }
 
+/// Build the command line that launches claude
+///
+/// `args` must already be shell-escaped. The session's model, if any, is
+/// passed with `--model`.
+pub(crate) fn claude_command(claude_path: &Path, model: Option<&str>, args: &str) -> String {
+    match model {
+        Some(model) => format!(
+            "{} --model {} {args}",
+            claude_path.display(),
+            escape(Cow::Borrowed(model))
+        ),
+        None => format!("{} {args}", claude_path.display()),
+    }
+}
+
 /// Native terminal backend - spawns sessions in native terminal windows
 pub struct NativeBackend {
     /// The terminal emulator to use
@@ -123,19 +138,14 @@ impl TerminalBackend for NativeBackend {
         let title = format!("loom-{}", stage.id);
 
         // Build the initial prompt for Claude
-        let signal_path_str = signal_path.to_string_lossy();
-        let initial_prompt = format!(
-            "Read the signal file at {signal_path_str} and execute the assigned stage work. \
-             This file contains your assignment, tasks, acceptance criteria, \
-             and context files to read."
-        );
+        let initial_prompt = prompts::stage_prompt(signal_path);
 
         // Escape the prompt for shell
         let escaped_prompt = escape(Cow::Borrowed(&initial_prompt));
 
         // Find claude's absolute path (needed for macOS where terminals don't inherit PATH)
         let claude_path = find_claude_path()?;
-        let claude_cmd = format!("{} {escaped_prompt}", claude_path.display());
+        let claude_cmd = claude_command(&claude_path, session.model.as_deref(), &escaped_prompt);
 
         // Create wrapper script that writes PID before exec'ing claude
         // Pass the worktree path so the script can cd there (important for macOS)
@@ -191,18 +201,14 @@ impl TerminalBackend for NativeBackend {
         let title = format!("loom-merge-{}", stage.id);
 
         // Build the initial prompt for Claude merge session
-        let signal_path_str = signal_path.to_string_lossy();
-        let initial_prompt = format!(
-            "Read the merge signal file at {signal_path_str} and resolve the merge conflicts. \
-             This file contains the conflicting files, merge context, and resolution instructions."
-        );
+        let initial_prompt = prompts::merge_prompt(signal_path);
 
         // Escape the prompt for shell
         let escaped_prompt = escape(Cow::Borrowed(&initial_prompt));
 
         // Find claude's absolute path (needed for macOS where terminals don't inherit PATH)
         let claude_path = find_claude_path()?;
-        let claude_cmd = format!("{} {escaped_prompt}", claude_path.display());
+        let claude_cmd = claude_command(&claude_path, session.model.as_deref(), &escaped_prompt);
 
         // Create wrapper script for merge session
         // Pass repo root so the script can cd there (important for macOS)
@@ -257,20 +263,14 @@ impl TerminalBackend for NativeBackend {
         let title = format!("loom-base-conflict-{}", stage.id);
 
         // Build the initial prompt for Claude base conflict resolution session
-        let signal_path_str = signal_path.to_string_lossy();
-        let initial_prompt = format!(
-            "Read the base conflict signal file at {signal_path_str} and resolve the merge conflicts. \
-             This file contains the conflicting files from merging dependency branches, \
-             and instructions for resolution. After resolving, tell the user to run `loom retry {}`.",
-            stage.id
-        );
+        let initial_prompt = prompts::base_conflict_prompt(signal_path, &stage.id);
 
         // Escape the prompt for shell
         let escaped_prompt = escape(Cow::Borrowed(&initial_prompt));
 
         // Find claude's absolute path (needed for macOS where terminals don't inherit PATH)
         let claude_path = find_claude_path()?;
-        let claude_cmd = format!("{} {escaped_prompt}", claude_path.display());
+        let claude_cmd = claude_command(&claude_path, session.model.as_deref(), &escaped_prompt);
 
         // Create wrapper script for base conflict session
         // Pass repo root so the script can cd there (important for macOS)
@@ -325,19 +325,14 @@ impl TerminalBackend for NativeBackend {
         let title = format!("loom-knowledge-{}", stage.id);
 
         // Build the initial prompt for Claude knowledge gathering session
-        let signal_path_str = signal_path.to_string_lossy();
-        let initial_prompt = format!(
-            "Read the signal file at {signal_path_str} and execute the assigned knowledge gathering work. \
-             This file contains your assignment, tasks, acceptance criteria, \
-             and instructions for populating the knowledge base."
-        );
+        let initial_prompt = prompts::knowledge_prompt(signal_path);
 
         // Escape the prompt for shell
         let escaped_prompt = escape(Cow::Borrowed(&initial_prompt));
 
         // Find claude's absolute path (needed for macOS where terminals don't inherit PATH)
         let claude_path = find_claude_path()?;
-        let claude_cmd = format!("{} {escaped_prompt}", claude_path.display());
+        let claude_cmd = claude_command(&claude_path, session.model.as_deref(), &escaped_prompt);
 
         // Create wrapper script for knowledge session
         // Pass repo root so the script can cd there (important for macOS)
@@ -479,4 +474,17 @@ mod tests {
             assert_eq!(backend.backend_type(), BackendType::Native);
         }
     }
+
+    #[test]
+    fn test_claude_command_passes_model() {
+        let path = Path::new("/usr/bin/claude");
+        assert_eq!(
+            claude_command(path, None, "'do it'"),
+            "/usr/bin/claude 'do it'"
+        );
+        assert_eq!(
+            claude_command(path, Some("claude-haiku-4-5"), "-p 'do it'"),
+            "/usr/bin/claude --model claude-haiku-4-5 -p 'do it'"
+        );
+    }
 }
@@ -0,0 +1,45 @@
+//! Initial prompts passed to Claude Code when a session is spawned
+//!
+//! Shared by all terminal backends so every session type receives the same
+//! instructions regardless of how the process is hosted.
+
+use std::path::Path;
+
+/// Prompt for a regular stage session running in a worktree
+pub fn stage_prompt(signal_path: &Path) -> String {
+    let signal_path_str = signal_path.to_string_lossy();
+    format!(
+        "Read the signal file at {signal_path_str} and execute the assigned stage work. \
+         This file contains your assignment, tasks, acceptance criteria, \
+         and context files to read."
+    )
+}
+
+/// Prompt for a merge conflict resolution session
+pub fn merge_prompt(signal_path: &Path) -> String {
+    let signal_path_str = signal_path.to_string_lossy();
+    format!(
+        "Read the merge signal file at {signal_path_str} and resolve the merge conflicts. \
+         This file contains the conflicting files, merge context, and resolution instructions."
+    )
+}
+
+/// Prompt for a base branch conflict resolution session
+pub fn base_conflict_prompt(signal_path: &Path, stage_id: &str) -> String {
+    let signal_path_str = signal_path.to_string_lossy();
+    format!(
+        "Read the base conflict signal file at {signal_path_str} and resolve the merge conflicts. \
+         This file contains the conflicting files from merging dependency branches, \
+         and instructions for resolution. After resolving, tell the user to run `loom retry {stage_id}`."
+    )
+}
+
+/// Prompt for a knowledge gathering session
+pub fn knowledge_prompt(signal_path: &Path) -> String {
+    let signal_path_str = signal_path.to_string_lossy();
+    format!(
+        "Read the signal file at {signal_path_str} and execute the assigned knowledge gathering work. \
+         This file contains your assignment, tasks, acceptance criteria, \
+         and instructions for populating the knowledge base."
+    )
+}
@@ -0,0 +1,156 @@
+//! Thin wrappers around the tmux CLI
+//!
+//! All targets use tmux's `=` prefix so session and window names are matched
+//! exactly rather than by prefix or pattern.
+
+use anyhow::{bail, Context, Result};
+use std::path::Path;
+use std::process::Command;
+
+/// Build an exact-match target for a window in a session
+pub fn window_target(session_name: &str, window_name: &str) -> String {
+    format!("={session_name}:={window_name}")
+}
+
+/// Check if the tmux binary is available
+pub fn tmux_available() -> bool {
+    which::which("tmux").is_ok()
+}
+
+/// Check if a tmux session exists
+pub fn has_session(session_name: &str) -> bool {
+    Command::new("tmux")
+        .args(["has-session", "-t", &format!("={session_name}")])
+        .output()
+        .map(|o| o.status.success())
+        .unwrap_or(false)
+}
+
+/// Check if a named window exists in a tmux session
+pub fn window_exists(session_name: &str, window_name: &str) -> bool {
+    let output = Command::new("tmux")
+        .args([
+            "list-windows",
+            "-t",
+            &format!("={session_name}"),
+            "-F",
+            "#{window_name}",
+        ])
+        .output();
+
+    match output {
+        Ok(out) if out.status.success() => String::from_utf8_lossy(&out.stdout)
+            .lines()
+            .any(|name| name == window_name),
+        _ => false,
+    }
+}
+
+/// Open a new window running `cmd`, creating the session if needed
+///
+/// The window is created detached so it never steals focus from an attached
+/// client. Automatic renaming is disabled so the window keeps its stage name.
+///
+/// # Returns
+/// The PID of the process running in the window's pane (`pane_pid`)
+pub fn open_window(
+    session_name: &str,
+    window_name: &str,
+    workdir: &Path,
+    cmd: &str,
+) -> Result<u32> {
+    let workdir_str = workdir.to_string_lossy();
+    let session_target = format!("={session_name}:");
+
+    let mut command = Command::new("tmux");
+    if has_session(session_name) {
+        command.args(["new-window", "-d", "-t", &session_target]);
+    } else {
+        command.args(["new-session", "-d", "-s", session_name]);
+    }
+    command.args([
+        "-n",
+        window_name,
+        "-c",
+        &workdir_str,
+        "-P",
+        "-F",
+        "#{pane_pid}",
+        cmd,
+    ]);
+
+    let output = command
+        .output()
+        .context("Failed to run tmux. Is it installed?")?;
+    if !output.status.success() {
+        bail!(
+            "tmux failed to open window '{window_name}': {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    let target = window_target(session_name, window_name);
+    let _ = Command::new("tmux")
+        .args(["set-option", "-w", "-t", &target, "automatic-rename", "off"])
+        .output();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    stdout
+        .trim()
+        .parse()
+        .with_context(|| format!("Unexpected pane_pid output from tmux: '{}'", stdout.trim()))
+}
+
+/// Kill a named window
+///
+/// Returns `true` if tmux reported the window was killed.
+pub fn kill_window(session_name: &str, window_name: &str) -> bool {
+    Command::new("tmux")
+        .args([
+            "kill-window",
+            "-t",
+            &window_target(session_name, window_name),
+        ])
+        .output()
+        .map(|o| o.status.success())
+        .unwrap_or(false)
+}
+
+/// Focus a window and attach to (or switch the current client to) its session
+pub fn attach_window(session_name: &str, window_name: &str) -> Result<()> {
+    let target = window_target(session_name, window_name);
+
+    let status = Command::new("tmux")
+        .args(["select-window", "-t", &target])
+        .status()
+        .context("Failed to run tmux select-window")?;
+    if !status.success() {
+        bail!("tmux window '{window_name}' not found in session '{session_name}'");
+    }
+
+    // Inside tmux, attaching would nest sessions - switch the client instead
+    let subcommand = if std::env::var_os("TMUX").is_some() {
+        "switch-client"
+    } else {
+        "attach-session"
+    };
+
+    let status = Command::new("tmux")
+        .args([subcommand, "-t", &format!("={session_name}")])
+        .status()
+        .with_context(|| format!("Failed to run tmux {subcommand}"))?;
+    if !status.success() {
+        bail!("tmux {subcommand} failed for session '{session_name}'");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_target_uses_exact_match() {
+        assert_eq!(window_target("loom-plan", "stage-1"), "=loom-plan:=stage-1");
+    }
+}
@@ -0,0 +1,295 @@
+//! tmux terminal backend
+//!
+//! Runs every session of a plan inside a single detached tmux session named
+//! `loom-<plan-id>`, with one named window per stage, merge, base conflict or
+//! knowledge session. Works over SSH where no desktop terminal is available;
+//! `loom attach <stage-id>` jumps to a stage's window.
+
+mod commands;
+
+use anyhow::{bail, Result};
+use shell_escape::escape;
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+
+use super::native::{claude_command, cleanup_stage_files, create_wrapper_script, find_claude_path};
+use super::{prompts, BackendType, TerminalBackend};
+use crate::models::session::{Session, SessionType};
+use crate::models::stage::Stage;
+use crate::models::worktree::Worktree;
+
+pub use commands::{attach_window, has_session, kill_window, tmux_available, window_exists};
+
+/// tmux backend - spawns sessions as windows in a per-plan tmux session
+pub struct TmuxBackend {
+    /// Name of the tmux session holding all windows for this plan
+    session_name: String,
+    /// The .work directory path for wrapper scripts and PID files
+    work_dir: PathBuf,
+}
+
+impl TmuxBackend {
+    /// Create a new tmux backend for the plan in `work_dir`
+    pub fn new(work_dir: PathBuf) -> Result<Self> {
+        if !tmux_available() {
+            bail!("tmux backend selected but tmux was not found in PATH");
+        }
+        let session_name = session_name_for_work_dir(&work_dir);
+        Ok(Self {
+            session_name,
+            work_dir,
+        })
+    }
+
+    /// Get the tmux session name used for this plan
+    pub fn session_name(&self) -> &str {
+        &self.session_name
+    }
+
+    /// Open a window running claude and return its `pane_pid`
+    ///
+    /// # Arguments
+    /// * `window_name` - Name of the tmux window
+    /// * `tracking_id` - Identifier for the wrapper script (sets LOOM_STAGE_ID)
+    /// * `session` - The session being spawned
+    /// * `prompt` - Initial prompt for Claude
+    /// * `workdir` - Directory the session runs in
+    fn launch(
+        &self,
+        window_name: &str,
+        tracking_id: &str,
+        session: &Session,
+        prompt: &str,
+        workdir: &Path,
+    ) -> Result<u32> {
+        let escaped_prompt = escape(Cow::Borrowed(prompt));
+        let claude_path = find_claude_path()?;
+        let claude_cmd = claude_command(&claude_path, session.model.as_deref(), &escaped_prompt);
+
+        let wrapper_path = create_wrapper_script(
+            &self.work_dir,
+            tracking_id,
+            &session.id,
+            &claude_cmd,
+            Some(workdir),
+        )?;
+        let wrapper_path_abs = wrapper_path.canonicalize().unwrap_or(wrapper_path);
+        let wrapper_cmd = escape(wrapper_path_abs.to_string_lossy()).to_string();
+
+        // A window left over from a previous attempt would shadow the new one
+        if window_exists(&self.session_name, window_name) {
+            kill_window(&self.session_name, window_name);
+        }
+
+        // The wrapper execs claude, so the pane's PID is claude's PID
+        commands::open_window(&self.session_name, window_name, workdir, &wrapper_cmd)
+    }
+}
+
+/// Derive the tmux session name (`loom-<plan-id>`) from config.toml
+///
+/// Characters tmux treats specially in targets (`.` and `:`) are replaced.
+pub fn session_name_for_work_dir(work_dir: &Path) -> String {
+    let plan_id = crate::fs::load_config(work_dir)
+        .ok()
+        .flatten()
+        .and_then(|c| c.plan_id().map(String::from));
+
+    match plan_id {
+        Some(id) if !id.is_empty() => {
+            let sanitized: String = id
+                .chars()
+                .map(|c| {
+                    if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                        c
+                    } else {
+                        '-'
+                    }
+                })
+                .collect();
+            format!("loom-{sanitized}")
+        }
+        _ => "loom".to_string(),
+    }
+}
+
+/// Get the window name used for a session
+///
+/// Stage sessions use the stage ID so `loom attach <stage-id>` finds them
+/// directly; merge, base conflict and knowledge sessions get a prefix.
+pub fn window_name(session: &Session) -> Option<String> {
+    let stage_id = session.stage_id.as_ref()?;
+    Some(match session.session_type {
+        SessionType::Stage => stage_id.clone(),
+        SessionType::Merge => format!("merge-{stage_id}"),
+        SessionType::BaseConflict => format!("base-conflict-{stage_id}"),
+        SessionType::Knowledge => format!("knowledge-{stage_id}"),
+    })
+}
+
+impl TerminalBackend for TmuxBackend {
+    fn spawn_session(
+        &self,
+        stage: &Stage,
+        worktree: &Worktree,
+        session: Session,
+        signal_path: &Path,
+    ) -> Result<Session> {
+        let prompt = prompts::stage_prompt(signal_path);
+        let pid = self.launch(&stage.id, &stage.id, &session, &prompt, &worktree.path)?;
+
+        let mut session = session;
+        session.set_worktree_path(worktree.path.clone());
+        session.assign_to_stage(stage.id.clone());
+        session.set_pid(pid);
+        session.try_mark_running()?;
+
+        Ok(session)
+    }
+
+    fn spawn_merge_session(
+        &self,
+        stage: &Stage,
+        session: Session,
+        signal_path: &Path,
+        repo_root: &Path,
+    ) -> Result<Session> {
+        let prompt = prompts::merge_prompt(signal_path);
+        let name = format!("merge-{}", stage.id);
+        let pid = self.launch(&name, &name, &session, &prompt, repo_root)?;
+
+        let mut session = session;
+        session.assign_to_stage(stage.id.clone());
+        session.set_pid(pid);
+        session.try_mark_running()?;
+
+        Ok(session)
+    }
+
+    fn spawn_base_conflict_session(
+        &self,
+        stage: &Stage,
+        session: Session,
+        signal_path: &Path,
+        repo_root: &Path,
+    ) -> Result<Session> {
+        let prompt = prompts::base_conflict_prompt(signal_path, &stage.id);
+        let name = format!("base-conflict-{}", stage.id);
+        let pid = self.launch(&name, &name, &session, &prompt, repo_root)?;
+
+        let mut session = session;
+        session.assign_to_stage(stage.id.clone());
+        session.set_pid(pid);
+        session.try_mark_running()?;
+
+        Ok(session)
+    }
+
+    fn spawn_knowledge_session(
+        &self,
+        stage: &Stage,
+        session: Session,
+        signal_path: &Path,
+        repo_root: &Path,
+    ) -> Result<Session> {
+        let prompt = prompts::knowledge_prompt(signal_path);
+        let name = format!("knowledge-{}", stage.id);
+        let pid = self.launch(&name, &name, &session, &prompt, repo_root)?;
+
+        let mut session = session;
+        session.assign_to_stage(stage.id.clone());
+        session.set_pid(pid);
+        session.try_mark_running()?;
+
+        Ok(session)
+    }
+
+    fn kill_session(&self, session: &Session) -> Result<()> {
+        if let Some(name) = window_name(session) {
+            // Killing the window sends SIGHUP to the pane's process
+            kill_window(&self.session_name, &name);
+            cleanup_stage_files(&self.work_dir, &name);
+        }
+        Ok(())
+    }
+
+    fn is_session_alive(&self, session: &Session) -> Result<bool> {
+        // Windows close when their command exits, so the window's existence
+        // is the session's lifecycle
+        Ok(window_name(session)
+            .map(|name| window_exists(&self.session_name, &name))
+            .unwrap_or(false))
+    }
+
+    fn backend_type(&self) -> BackendType {
+        BackendType::Tmux
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_session_name_from_plan_id() {
+        let temp_dir = TempDir::new().unwrap();
+        assert_eq!(session_name_for_work_dir(temp_dir.path()), "loom");
+
+        std::fs::write(
+            temp_dir.path().join("config.toml"),
+            "[plan]\nplan_id = \"0042.auth:v2\"\n",
+        )
+        .unwrap();
+        assert_eq!(
+            session_name_for_work_dir(temp_dir.path()),
+            "loom-0042-auth-v2"
+        );
+    }
+
+    #[test]
+    fn test_window_name_by_session_type() {
+        let mut session = Session::new();
+        assert_eq!(window_name(&session), None);
+
+        session.assign_to_stage("stage-a".to_string());
+        assert_eq!(window_name(&session), Some("stage-a".to_string()));
+
+        let mut merge = Session::new_merge("loom/stage-a".to_string(), "main".to_string());
+        merge.assign_to_stage("stage-a".to_string());
+        assert_eq!(window_name(&merge), Some("merge-stage-a".to_string()));
+
+        let mut knowledge = Session::new_knowledge();
+        knowledge.assign_to_stage("stage-a".to_string());
+        assert_eq!(
+            window_name(&knowledge),
+            Some("knowledge-stage-a".to_string())
+        );
+    }
+
+    #[test]
+    fn test_window_lifecycle() {
+        if !tmux_available() {
+            return;
+        }
+        let temp_dir = TempDir::new().unwrap();
+        let session_name = format!("loom-test-{}", std::process::id());
+
+        let pid =
+            commands::open_window(&session_name, "stage-a", temp_dir.path(), "sleep 30").unwrap();
+        assert!(pid > 0);
+        assert!(has_session(&session_name));
+        assert!(window_exists(&session_name, "stage-a"));
+        assert!(!window_exists(&session_name, "stage-b"));
+
+        commands::open_window(&session_name, "stage-b", temp_dir.path(), "sleep 30").unwrap();
+        assert!(window_exists(&session_name, "stage-b"));
+
+        assert!(kill_window(&session_name, "stage-a"));
+        assert!(!window_exists(&session_name, "stage-a"));
+
+        let _ = std::process::Command::new("tmux")
+            .args(["kill-session", "-t", &format!("={session_name}")])
+            .output();
+    }
+}
@@ -0,0 +1,187 @@
+//! Stage fan-out expansion
+//!
+//! A stage with a `for_each` block becomes a parent that never runs a session
+//! itself: it is expanded into one child stage per item, each with its own
+//! worktree and signal. The parent depends on its children, so it is only
+//! scheduled again (and completed) once every child is completed and merged.
+
+use anyhow::{bail, Context, Result};
+use chrono::Utc;
+
+use crate::models::stage::{Stage, StageStatus};
+use crate::plan::schema::{child_stage_ids, AcceptanceCriterion};
+use crate::validation::validate_id;
+
+/// Placeholder replaced with each child's item
+pub const ITEM_PLACEHOLDER: &str = "${item}";
+
+/// Build the child stages of `parent`, one per item.
+///
+/// Children inherit the parent's definition with `${item}` substituted in its
+/// text fields, and its dependencies unchanged (plan validation rejects
+/// `${item}` there). They start in the parent's current status and carry no
+/// run state.
+pub fn expand_stage(parent: &Stage, items: &[String]) -> Result<Vec<Stage>> {
+    let ids = child_stage_ids(&parent.id, items);
+    let now = Utc::now();
+    let mut children = Vec::with_capacity(items.len());
+
+    for (id, item) in ids.into_iter().zip(items) {
+        validate_id(&id).with_context(|| format!("Invalid fan-out stage ID '{id}'"))?;
+        let sub = |text: &str| text.replace(ITEM_PLACEHOLDER, item);
+        let sub_all = |texts: &[String]| texts.iter().map(|t| sub(t)).collect::<Vec<_>>();
+
+        let mut child = parent.clone();
+        child.id = id;
+        child.name = if parent.name.contains(ITEM_PLACEHOLDER) {
+            sub(&parent.name)
+        } else {
+            format!("{} ({item})", parent.name)
+        };
+        child.description = parent.description.as_deref().map(sub);
+        child.acceptance = parent
+            .acceptance
+            .iter()
+            .map(|criterion| AcceptanceCriterion {
+                command: sub(&criterion.command),
+                report: criterion.report.as_deref().map(sub),
+            })
+            .collect();
+        child.setup = sub_all(&parent.setup);
+        child.files = sub_all(&parent.files);
+        child.truths = sub_all(&parent.truths);
+        child.artifacts = sub_all(&parent.artifacts);
+        child.working_dir = parent.working_dir.as_deref().map(sub);
+        child.parent_stage = Some(parent.id.clone());
+        child.child_stages = Vec::new();
+        child.for_each = None;
+        child.worktree = None;
+        child.session = None;
+        child.outputs = Vec::new();
+        child.usage = Default::default();
+        child.created_at = now;
+        child.updated_at = now;
+        children.push(child);
+    }
+
+    Ok(children)
+}
+
+/// Turn `parent` into a fan-out parent waiting on `children`.
+///
+/// The parent keeps its own dependencies and additionally depends on every
+/// child. This bypasses the state machine: a Queued parent that just fanned
+/// out goes back to waiting.
+pub fn attach_children(parent: &mut Stage, children: &[Stage]) {
+    for child in children {
+        parent.child_stages.push(child.id.clone());
+        parent.dependencies.push(child.id.clone());
+    }
+    if !children.is_empty() {
+        parent.status = StageStatus::WaitingForDeps;
+        parent.updated_at = Utc::now();
+    }
+}
+
+/// Resolve a runtime `for_each.from_output` list from the dependency's outputs.
+///
+/// The output must be a JSON array; string elements are used as-is and other
+/// values by their JSON text.
+pub fn resolve_output_items(parent: &Stage, dependency: &Stage) -> Result<Vec<String>> {
+    let Some((_, key)) = parent.for_each.as_ref().and_then(|f| f.output_source()) else {
+        bail!("Stage '{}' has no for_each.from_output", parent.id);
+    };
+    let output = dependency
+        .outputs
+        .iter()
+        .find(|o| o.key == key)
+        .with_context(|| format!("Stage '{}' has no output '{key}'", dependency.id))?;
+    let Some(values) = output.value.as_array() else {
+        bail!(
+            "Output '{}.{key}' must be a JSON array to fan out '{}'",
+            dependency.id,
+            parent.id
+        );
+    };
+    Ok(values
+        .iter()
+        .map(|v| match v.as_str() {
+            Some(s) => s.to_string(),
+            None => v.to_string(),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::stage::StageOutput;
+    use crate::plan::schema::ForEach;
+    use serde_json::json;
+
+    fn parent() -> Stage {
+        let mut stage = Stage::new("Test ${item}".to_string(), None);
+        stage.id = "test".to_string();
+        stage.dependencies = vec!["discover".to_string()];
+        stage.status = StageStatus::Queued;
+        stage.acceptance = vec![AcceptanceCriterion::with_report(
+            "cargo test -p ${item}",
+            "junit:target/${item}.xml",
+        )];
+        stage.working_dir = Some(".".to_string());
+        stage.for_each = Some(ForEach {
+            items: vec![],
+            from_output: Some("discover.crates".to_string()),
+        });
+        stage
+    }
+
+    #[test]
+    fn test_expand_stage_substitutes_item_and_links_parent() {
+        let mut parent = parent();
+        let items = vec!["core".to_string(), "cli".to_string()];
+        let children = expand_stage(&parent, &items).unwrap();
+
+        assert_eq!(children.len(), 2);
+        assert_eq!(children[0].id, "test-core");
+        assert_eq!(children[0].name, "Test core");
+        assert_eq!(children[1].acceptance, vec!["cargo test -p cli"]);
+        assert_eq!(
+            children[1].acceptance[0].report.as_deref(),
+            Some("junit:target/cli.xml")
+        );
+        assert_eq!(children[1].dependencies, vec!["discover"]);
+        assert_eq!(children[1].parent_stage.as_deref(), Some("test"));
+        assert_eq!(children[1].status, StageStatus::Queued);
+        assert!(children[1].for_each.is_none());
+
+        attach_children(&mut parent, &children);
+        assert_eq!(parent.child_stages, vec!["test-core", "test-cli"]);
+        assert_eq!(
+            parent.dependencies,
+            vec!["discover", "test-core", "test-cli"]
+        );
+        assert_eq!(parent.status, StageStatus::WaitingForDeps);
+    }
+
+    #[test]
+    fn test_resolve_output_items_requires_array() {
+        let parent = parent();
+        let mut discover = Stage::new("Discover".to_string(), None);
+        discover.id = "discover".to_string();
+        assert!(resolve_output_items(&parent, &discover).is_err());
+
+        discover.outputs = vec![StageOutput {
+            key: "crates".to_string(),
+            value: json!(["core", 7]),
+            description: String::new(),
+        }];
+        assert_eq!(
+            resolve_output_items(&parent, &discover).unwrap(),
+            vec!["core", "7"]
+        );
+
+        discover.outputs[0].value = json!("core");
+        assert!(resolve_output_items(&parent, &discover).is_err());
+    }
+}
@@ -4,6 +4,7 @@ mod cycle;
 pub mod levels;
 mod loader;
 mod nodes;
+mod overlap;
 mod scheduling;
 
 #[cfg(test)]
@@ -17,8 +18,10 @@ use crate::models::stage::StageStatus;
 
 use super::schema::StageDefinition;
 
+pub use cycle::detect_cycles;
 pub use loader::build_execution_graph;
 pub use nodes::StageNode;
+pub use scheduling::{admit_ready, Admission};
 
 /// Execution graph representing stages and their dependencies
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -40,20 +43,7 @@ impl ExecutionGraph {
 
         // First pass: create all nodes
         for stage in &stages {
-            let node = StageNode {
-                id: stage.id.clone(),
-                name: stage.name.clone(),
-                dependencies: stage.dependencies.clone(),
-                parallel_group: stage.parallel_group.clone(),
-                status: StageStatus::WaitingForDeps,
-                description: stage.description.clone(),
-                acceptance: stage.acceptance.clone(),
-                setup: stage.setup.clone(),
-                files: stage.files.clone(),
-                auto_merge: stage.auto_merge,
-                outputs: Vec::new(),
-                merged: false,
-            };
+            let node = StageNode::from(stage);
             nodes.insert(stage.id.clone(), node);
 
             // Add to parallel group if specified
@@ -251,6 +241,59 @@ impl ExecutionGraph {
         Ok(())
     }
 
+    /// Add the child stages of a fan-out parent and make the parent wait on them.
+    ///
+    /// Children are inserted waiting on their own dependencies; the parent gains
+    /// a dependency on every child and goes back to `WaitingForDeps`.
+    ///
+    /// # Returns
+    ///
+    /// The child stage IDs that became ready (transitioned to `Queued`).
+    pub fn add_fan_out(
+        &mut self,
+        parent_id: &str,
+        children: Vec<StageNode>,
+    ) -> Result<Vec<String>> {
+        if !self.nodes.contains_key(parent_id) {
+            bail!("Stage not found: {parent_id}");
+        }
+
+        if let Some(child) = children.iter().find(|c| self.nodes.contains_key(&c.id)) {
+            bail!("Stage already exists: {}", child.id);
+        }
+
+        let mut child_ids = Vec::with_capacity(children.len());
+        for mut child in children {
+            for dep in &child.dependencies {
+                self.edges
+                    .entry(dep.clone())
+                    .or_default()
+                    .push(child.id.clone());
+            }
+            self.edges
+                .entry(child.id.clone())
+                .or_default()
+                .push(parent_id.to_string());
+            if let Some(group) = &child.parallel_group {
+                self.parallel_groups
+                    .entry(group.clone())
+                    .or_default()
+                    .push(child.id.clone());
+            }
+            child.status = StageStatus::WaitingForDeps;
+            child_ids.push(child.id.clone());
+            self.nodes.insert(child.id.clone(), child);
+        }
+
+        if let Some(parent) = self.nodes.get_mut(parent_id) {
+            parent.dependencies.extend(child_ids);
+            parent.status = StageStatus::WaitingForDeps;
+        }
+
+        cycle::detect_cycles(&self.nodes)?;
+        Ok(self.update_ready_status())
+    }
+
     /// Get a topologically sorted list of stages
     pub fn topological_sort(&self) -> Result<Vec<String>> {
         scheduling::topological_sort(&self.nodes, &self.edges)
@@ -343,4 +386,11 @@ impl ExecutionGraph {
             node.merged = merged;
         }
     }
+
+    /// Set whether a node was skipped by an unmet `when:` condition
+    pub fn set_node_condition_skipped(&mut self, stage_id: &str, condition_skipped: bool) {
+        if let Some(node) = self.nodes.get_mut(stage_id) {
+            node.condition_skipped = condition_skipped;
+        }
+    }
 }
@@ -1,8 +1,10 @@
 //! Graph node types for the execution graph
 
-use crate::models::stage::{StageOutput, StageStatus};
+use crate::models::stage::{OverlapPolicy, Stage, StageOutput, StageStatus};
 use serde::{Deserialize, Serialize};
 
+use crate::plan::schema::{AcceptanceCriterion, StageDefinition};
+
 /// A node in the execution graph
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StageNode {
@@ -16,7 +18,7 @@ pub struct StageNode {
     pub description: Option<String>,
     /// Acceptance criteria - commands to verify stage completion
     #[serde(default)]
-    pub acceptance: Vec<String>,
+    pub acceptance: Vec<AcceptanceCriterion>,
     /// Setup commands to run before stage execution
     #[serde(default)]
     pub setup: Vec<String>,
@@ -26,6 +28,9 @@ pub struct StageNode {
     /// Whether to auto-merge after completion
     #[serde(default)]
     pub auto_merge: Option<bool>,
+    /// Whether the stage waits for overlapping in-flight stages
+    #[serde(default)]
+    pub overlap: Option<OverlapPolicy>,
     /// Structured outputs from this stage for dependent stages
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub outputs: Vec<StageOutput>,
@@ -36,4 +41,78 @@ pub struct StageNode {
     /// scheduled until `merged: true` because they need the merged changes as their base.
     #[serde(default)]
     pub merged: bool,
+    /// Whether the stage was skipped by an unmet `when:` condition
+    #[serde(default)]
+    pub condition_skipped: bool,
+}
+
+impl StageNode {
+    /// Whether dependents may start: completed and merged, or skipped by an
+    /// unmet condition
+    pub fn satisfies_dependents(&self) -> bool {
+        match self.status {
+            StageStatus::Completed => self.merged,
+            StageStatus::Skipped => self.condition_skipped,
+            _ => false,
+        }
+    }
+
+    /// Whether the stage has started and its branch is not merged yet, so
+    /// its changes may still conflict with stages starting now
+    pub fn is_in_flight(&self) -> bool {
+        match self.status {
+            StageStatus::Executing
+            | StageStatus::WaitingForInput
+            | StageStatus::NeedsHandoff
+            | StageStatus::CompletedWithFailures
+            | StageStatus::NeedsHumanReview
+            | StageStatus::MergeConflict
+            | StageStatus::MergeBlocked => true,
+            StageStatus::Completed => !self.merged,
+            _ => false,
+        }
+    }
+}
+
+impl From<&Stage> for StageNode {
+    fn from(stage: &Stage) -> Self {
+        Self {
+            id: stage.id.clone(),
+            name: stage.name.clone(),
+            dependencies: stage.dependencies.clone(),
+            parallel_group: stage.parallel_group.clone(),
+            status: stage.status.clone(),
+            description: stage.description.clone(),
+            acceptance: stage.acceptance.clone(),
+            setup: stage.setup.clone(),
+            files: stage.files.clone(),
+            auto_merge: stage.auto_merge,
+            overlap: stage.overlap,
+            outputs: stage.outputs.clone(),
+            merged: stage.merged,
+            condition_skipped: stage.condition_skipped,
+        }
+    }
+}
+
+impl From<&StageDefinition> for StageNode {
+    /// A node for a stage that has not run yet
+    fn from(stage: &StageDefinition) -> Self {
+        Self {
+            id: stage.id.clone(),
+            name: stage.name.clone(),
+            dependencies: stage.dependencies.clone(),
+            parallel_group: stage.parallel_group.clone(),
+            status: StageStatus::WaitingForDeps,
+            description: stage.description.clone(),
+            acceptance: stage.acceptance.clone(),
+            setup: stage.setup.clone(),
+            files: stage.files.clone(),
+            auto_merge: stage.auto_merge,
+            overlap: stage.overlap,
+            outputs: Vec::new(),
+            merged: false,
+            condition_skipped: false,
+        }
+    }
 }
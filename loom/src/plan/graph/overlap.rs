@@ -0,0 +1,172 @@
+//! Overlap between the files of stages that could run at the same time
+//!
+//! Declared `files` entries are paths or glob patterns. Two patterns overlap
+//! when some path could match both; the check is conservative, so patterns
+//! it cannot tell apart are treated as overlapping.
+
+use glob::{MatchOptions, Pattern};
+
+use super::nodes::StageNode;
+
+const GLOB_CHARS: &[char] = &['*', '?', '['];
+
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+/// Files and patterns a ready stage shares with an in-flight stage: its
+/// patterns that overlap the in-flight stage's, and the files the in-flight
+/// branch changes (`changed`) that its patterns cover. Sorted, without
+/// duplicates.
+pub fn stage_overlap(stage: &StageNode, in_flight: &StageNode, changed: &[String]) -> Vec<String> {
+    let mut shared: Vec<String> = stage
+        .files
+        .iter()
+        .filter(|pattern| {
+            in_flight
+                .files
+                .iter()
+                .any(|other| patterns_overlap(pattern, other))
+        })
+        .cloned()
+        .collect();
+    shared.extend(
+        changed
+            .iter()
+            .filter(|file| stage.files.iter().any(|pattern| covers(pattern, file)))
+            .cloned(),
+    );
+    shared.sort();
+    shared.dedup();
+    shared
+}
+
+/// Whether some path could match both patterns
+pub fn patterns_overlap(a: &str, b: &str) -> bool {
+    let (a, b) = (as_pattern(a), as_pattern(b));
+    match (is_glob(&a), is_glob(&b)) {
+        (false, _) => covers(&b, &a),
+        (_, false) => covers(&a, &b),
+        _ => {
+            let (dir_a, dir_b) = (literal_dir(&a), literal_dir(&b));
+            let (end_a, end_b) = (literal_suffix(&a), literal_suffix(&b));
+            (is_ancestor(dir_a, dir_b) || is_ancestor(dir_b, dir_a))
+                && (end_a.ends_with(end_b) || end_b.ends_with(end_a))
+        }
+    }
+}
+
+/// A declared path as a pattern: a path without an extension is taken to be
+/// a directory and matches everything below it
+fn as_pattern(path: &str) -> String {
+    let path = normalize(path);
+    let name = path.rsplit('/').next().unwrap_or(path);
+    if is_glob(path) || name.contains('.') {
+        path.to_string()
+    } else {
+        format!("{path}/**")
+    }
+}
+
+/// Whether a pattern matches the file or one of its parent directories
+fn covers(pattern: &str, file: &str) -> bool {
+    let pattern = normalize(pattern);
+    let file = normalize(file);
+    let Ok(compiled) = Pattern::new(pattern) else {
+        return is_ancestor(pattern, file);
+    };
+    let mut path = Some(file);
+    while let Some(current) = path {
+        if compiled.matches_with(current, MATCH_OPTIONS) {
+            return true;
+        }
+        path = current.rsplit_once('/').map(|(parent, _)| parent);
+    }
+    false
+}
+
+fn normalize(path: &str) -> &str {
+    path.trim_start_matches("./").trim_end_matches('/')
+}
+
+fn is_glob(pattern: &str) -> bool {
+    pattern.contains(GLOB_CHARS)
+}
+
+/// Directory part of the pattern before its first glob character
+fn literal_dir(pattern: &str) -> &str {
+    let literal = &pattern[..pattern.find(GLOB_CHARS).unwrap_or(pattern.len())];
+    literal.rsplit_once('/').map_or("", |(dir, _)| dir)
+}
+
+/// Text after the pattern's last glob character, which every match ends with
+fn literal_suffix(pattern: &str) -> &str {
+    pattern
+        .rfind(['*', '?', ']'])
+        .map_or(pattern, |i| &pattern[i + 1..])
+}
+
+/// Whether `dir` is `path` or one of its parent directories
+fn is_ancestor(dir: &str, path: &str) -> bool {
+    dir.is_empty()
+        || path == dir
+        || path
+            .strip_prefix(dir)
+            .is_some_and(|rest| rest.starts_with('/'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_patterns_overlap() {
+        assert!(patterns_overlap("src/api/mod.rs", "src/api/mod.rs"));
+        assert!(patterns_overlap("src/api", "src/api/routes.rs"));
+        assert!(patterns_overlap("src/**/*.rs", "src/api/mod.rs"));
+        assert!(patterns_overlap("src/**", "src/api/*.rs"));
+        assert!(patterns_overlap("./src/api/", "src/*/routes.rs"));
+        assert!(patterns_overlap("src/*.rs", "src/lib.rs"));
+
+        assert!(!patterns_overlap("src/api/mod.rs", "src/db/mod.rs"));
+        assert!(!patterns_overlap("src/api/**", "src/db/**"));
+        assert!(!patterns_overlap("src/**/*.rs", "src/**/*.md"));
+        assert!(!patterns_overlap("src/*.rs", "src/api/mod.rs"));
+        assert!(!patterns_overlap("docs/*", "README.md"));
+    }
+
+    #[test]
+    fn test_stage_overlap() {
+        let node = |files: &[&str]| StageNode {
+            id: "s".to_string(),
+            name: "s".to_string(),
+            dependencies: vec![],
+            parallel_group: None,
+            status: crate::models::stage::StageStatus::Queued,
+            description: None,
+            acceptance: vec![],
+            setup: vec![],
+            files: files.iter().map(|f| f.to_string()).collect(),
+            auto_merge: None,
+            overlap: None,
+            outputs: vec![],
+            merged: false,
+            condition_skipped: false,
+        };
+        let ready = node(&["src/api/**", "README.md"]);
+        let in_flight = node(&["src/db/**"]);
+
+        assert!(stage_overlap(&ready, &in_flight, &[]).is_empty());
+        let changed = vec!["src/api/routes.rs".to_string(), "Cargo.toml".to_string()];
+        assert_eq!(
+            stage_overlap(&ready, &in_flight, &changed),
+            vec!["src/api/routes.rs"]
+        );
+        assert_eq!(
+            stage_overlap(&ready, &node(&["README.md", "src/**/*.rs"]), &changed),
+            vec!["README.md", "src/api/**", "src/api/routes.rs"]
+        );
+    }
+}
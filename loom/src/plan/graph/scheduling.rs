@@ -1,16 +1,28 @@
-//! Scheduling algorithms: topological sort, ready status updates
+//! Scheduling algorithms: topological sort, ready status updates, overlap admission
 
 use anyhow::{bail, Result};
 use std::collections::{HashMap, HashSet, VecDeque};
 
 use super::nodes::StageNode;
-use crate::models::stage::StageStatus;
+use super::overlap::stage_overlap;
+use crate::models::stage::{OverlapHold, OverlapPolicy, StageStatus};
+
+/// Ready stages split into those to start now and those held back because
+/// they overlap stages still in flight
+#[derive(Debug, Default, PartialEq)]
+pub struct Admission {
+    /// Stages to start, in order
+    pub start: Vec<String>,
+    /// Stages held back, with the in-flight stages and files they overlap
+    pub held: Vec<(String, OverlapHold)>,
+}
 
 /// Update which stages are ready (all deps satisfied AND merged).
 ///
 /// A stage transitions from `WaitingForDeps` to `Queued` when:
 /// - It has no dependencies, OR
 /// - All dependencies have BOTH `status == Completed` AND `merged == true`
+///   (or were skipped because their `when:` condition was false)
 ///
 /// This ensures dependent stages can use the merge point (main) as their base,
 /// which contains all dependency work.
@@ -30,7 +42,7 @@ pub fn update_ready_status(nodes: &mut HashMap<String, StageNode>) -> Vec<String
     // Collect stages that are completed AND merged - only these satisfy dependencies
     let completed_and_merged: HashSet<_> = nodes
         .values()
-        .filter(|n| n.status == StageStatus::Completed && n.merged)
+        .filter(|n| n.satisfies_dependents())
         .map(|n| n.id.clone())
         .collect();
 
@@ -51,6 +63,55 @@ pub fn update_ready_status(nodes: &mut HashMap<String, StageNode>) -> Vec<String
     newly_ready
 }
 
+/// Choose up to `slots` ready stages to start, holding back those that
+/// overlap in-flight stages.
+///
+/// A ready stage overlaps an in-flight stage when their declared `files` can
+/// match the same path, or when the in-flight branch already changes a file
+/// the stage declares (`changed`, keyed by stage ID, from a dry-run merge of
+/// the branch into the merge point). Stages started by this pass count as in
+/// flight for the ones after them. Only stages with the `serialize` overlap
+/// policy are held back; the default `allow` starts them anyway.
+pub fn admit_ready(
+    ready: &[&StageNode],
+    in_flight: &[&StageNode],
+    changed: &HashMap<String, Vec<String>>,
+    slots: usize,
+) -> Admission {
+    let mut ready = ready.to_vec();
+    ready.sort_by(|a, b| a.id.cmp(&b.id));
+    let mut in_flight = in_flight.to_vec();
+    let mut admission = Admission::default();
+
+    for stage in ready {
+        if stage.overlap.unwrap_or_default() == OverlapPolicy::Serialize {
+            let mut hold = OverlapHold {
+                stages: Vec::new(),
+                files: Vec::new(),
+            };
+            for other in &in_flight {
+                let other_changed = changed.get(&other.id).map_or(&[][..], Vec::as_slice);
+                let shared = stage_overlap(stage, other, other_changed);
+                if !shared.is_empty() {
+                    hold.stages.push(other.id.clone());
+                    hold.files.extend(shared);
+                }
+            }
+            if !hold.stages.is_empty() {
+                hold.files.sort();
+                hold.files.dedup();
+                admission.held.push((stage.id.clone(), hold));
+                continue;
+            }
+        }
+        if admission.start.len() < slots {
+            admission.start.push(stage.id.clone());
+            in_flight.push(stage);
+        }
+    }
+    admission
+}
+
 /// Get a topologically sorted list of stages
 pub fn topological_sort(
     nodes: &HashMap<String, StageNode>,
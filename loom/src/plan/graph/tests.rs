@@ -11,9 +11,12 @@ fn make_stage(id: &str, deps: Vec<&str>, group: Option<&str>) -> StageDefinition
         dependencies: deps.into_iter().map(String::from).collect(),
         parallel_group: group.map(String::from),
         acceptance: vec![],
+        parallel: None,
         setup: vec![],
         files: vec![],
         auto_merge: None,
+        merge_strategy: None,
+        overlap: None,
         working_dir: ".".to_string(),
         stage_type: crate::plan::schema::StageType::default(),
         truths: vec![],
@@ -25,6 +28,12 @@ fn make_stage(id: &str, deps: Vec<&str>, group: Option<&str>) -> StageDefinition
         before_stage: vec![],
         after_stage: vec![],
         context_budget: None,
+        timeout: None,
+        budget: None,
+        model: None,
+        for_each: None,
+        when: None,
+        output_keys: vec![],
         sandbox: StageSandboxConfig::default(),
         execution_mode: None,
         bug_fix: None,
@@ -236,3 +245,104 @@ fn test_leaf_stages_all_independent() {
     assert!(leaves.contains(&"b"));
     assert!(leaves.contains(&"c"));
 }
+
+#[test]
+fn test_add_fan_out_queues_children_and_holds_parent() {
+    let stages = vec![
+        make_stage("build", vec![], None),
+        make_stage("test", vec!["build"], None),
+        make_stage("deploy", vec!["test"], None),
+    ];
+    let mut graph = ExecutionGraph::build(stages).unwrap();
+    graph.mark_executing("build").unwrap();
+    graph.set_node_merged("build", true);
+    assert_eq!(graph.mark_completed("build").unwrap(), vec!["test"]);
+
+    let child = |id: &str| StageNode {
+        id: id.to_string(),
+        name: id.to_string(),
+        dependencies: vec!["build".to_string()],
+        parallel_group: None,
+        status: StageStatus::Queued,
+        description: None,
+        acceptance: vec![],
+        setup: vec![],
+        files: vec![],
+        auto_merge: None,
+        overlap: None,
+        outputs: vec![],
+        merged: false,
+        condition_skipped: false,
+    };
+    let mut ready = graph
+        .add_fan_out("test", vec![child("test-a"), child("test-b")])
+        .unwrap();
+    ready.sort();
+    assert_eq!(ready, vec!["test-a", "test-b"]);
+
+    let parent = graph.get_node("test").unwrap();
+    assert_eq!(parent.status, StageStatus::WaitingForDeps);
+    assert_eq!(parent.dependencies, vec!["build", "test-a", "test-b"]);
+    assert!(graph.add_fan_out("test", vec![child("test-a")]).is_err());
+
+    for id in ["test-a", "test-b"] {
+        graph.mark_executing(id).unwrap();
+        graph.set_node_merged(id, true);
+        graph.mark_completed(id).unwrap();
+    }
+    assert_eq!(graph.get_node("test").unwrap().status, StageStatus::Queued);
+}
+
+#[test]
+fn test_admit_ready_holds_overlapping_stages() {
+    let with_files = |id: &str, files: &[&str]| StageDefinition {
+        files: files.iter().map(|f| f.to_string()).collect(),
+        overlap: Some(crate::models::stage::OverlapPolicy::Serialize),
+        ..make_stage(id, vec![], None)
+    };
+    let stages = vec![
+        with_files("api", &["src/api/**"]),
+        with_files("db", &["src/db/**"]),
+        with_files("docs", &["docs/**"]),
+        with_files("routes", &["src/api/routes.rs"]),
+        StageDefinition {
+            files: vec!["src/db/schema.rs".to_string()],
+            ..make_stage("schema", vec![], None)
+        },
+    ];
+    let mut graph = ExecutionGraph::build(stages).unwrap();
+    graph.mark_executing("db").unwrap();
+    let in_flight = vec![graph.get_node("db").unwrap()];
+    let changed = HashMap::from([("db".to_string(), vec!["docs/db.md".to_string()])]);
+
+    let admission = admit_ready(&graph.ready_stages(), &in_flight, &changed, 5);
+    // `routes` overlaps `api`, started before it in this pass; `docs`
+    // overlaps what the `db` branch already changes; `schema` keeps the
+    // default `allow` policy
+    assert_eq!(admission.start, vec!["api", "schema"]);
+    let held: Vec<_> = admission
+        .held
+        .iter()
+        .map(|(id, hold)| (id.as_str(), hold.stages.clone(), hold.files.clone()))
+        .collect();
+    assert_eq!(
+        held,
+        vec![
+            (
+                "docs",
+                vec!["db".to_string()],
+                vec!["docs/db.md".to_string()]
+            ),
+            (
+                "routes",
+                vec!["api".to_string()],
+                vec!["src/api/routes.rs".to_string()]
+            ),
+        ]
+    );
+
+    // Slots limit the stages started, not the ones held
+    let admission = admit_ready(&graph.ready_stages(), &in_flight, &changed, 1);
+    assert_eq!(admission.start, vec!["api"]);
+    assert_eq!(admission.held.len(), 2);
+}
@@ -5,9 +5,11 @@
 //! - Validating stage definitions
 //! - Building execution graphs
 
+pub mod fan_out;
 pub mod graph;
 pub mod parser;
 pub mod schema;
+pub mod stages;
 
 // Re-export commonly used types
 pub use graph::{ExecutionGraph, StageNode};
@@ -16,6 +16,32 @@ pub fn extract_plan_name(content: &str) -> Result<String> {
     bail!("No H1 header found in plan document")
 }
 
+/// Extract the plan's overview: the body of an `## Overview` (or `## Summary`)
+/// section if there is one, otherwise the text between the H1 header and the
+/// next heading, `---` rule or metadata block
+pub fn extract_plan_overview(content: &str) -> Option<String> {
+    let lines: Vec<&str> = content.lines().collect();
+    let is_overview_heading = |line: &&str| {
+        let heading = line.trim().trim_start_matches("## ").trim().to_lowercase();
+        line.trim().starts_with("## ") && (heading == "overview" || heading == "summary")
+    };
+    let start = match lines.iter().position(is_overview_heading) {
+        Some(i) => i + 1,
+        None => lines.iter().position(|l| l.trim().starts_with("# "))? + 1,
+    };
+
+    let body: Vec<&str> = lines[start..]
+        .iter()
+        .take_while(|line| {
+            let trimmed = line.trim();
+            !(trimmed.starts_with('#') || trimmed == "---" || trimmed.starts_with("<!--"))
+        })
+        .copied()
+        .collect();
+    let overview = body.join("\n").trim().to_string();
+    (!overview.is_empty()).then_some(overview)
+}
+
 /// Extract YAML content from metadata block
 pub fn extract_yaml_metadata(content: &str) -> Result<String> {
     // Find the metadata markers
@@ -103,6 +129,21 @@ mod tests {
         assert!(extract_plan_name(content).is_err());
     }
 
+    #[test]
+    fn test_extract_plan_overview() {
+        let content = "# PLAN: Auth\n\nAdd login.\nWith sessions.\n\n## Stages\n\nDetails\n";
+        assert_eq!(
+            extract_plan_overview(content).unwrap(),
+            "Add login.\nWith sessions."
+        );
+
+        let content = "# PLAN: Auth\n\nIntro\n\n## Overview\n\nSession login.\n\n---\n";
+        assert_eq!(extract_plan_overview(content).unwrap(), "Session login.");
+
+        let content = "# PLAN: Auth\n\n<!-- loom METADATA -->\n";
+        assert!(extract_plan_overview(content).is_none());
+    }
+
     #[test]
     fn test_extract_yaml_metadata() {
         let content = r#"
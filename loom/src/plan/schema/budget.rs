@@ -0,0 +1,179 @@
+//! Plan budget schema definitions and validation
+
+use serde::{Deserialize, Serialize};
+
+use super::types::{StageDefinition, ValidationError};
+use crate::models::usage::StageBudget;
+use crate::utils::parse_duration;
+
+/// Plan-level spending limits.
+///
+/// When a plan-wide limit is reached the orchestrator stops spawning stages
+/// and moves executing stages to human review. The `max_stage_*` limits are
+/// defaults for stages that do not set their own `budget`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BudgetConfig {
+    /// Maximum tokens across all stages
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<u64>,
+    /// Maximum cost in USD across all stages
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_cost_usd: Option<f64>,
+    /// Default maximum tokens per stage
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_stage_tokens: Option<u64>,
+    /// Default maximum cost in USD per stage
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_stage_cost_usd: Option<f64>,
+    /// Maximum wall-clock time since the first stage started (e.g. "8h")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_duration: Option<String>,
+}
+
+impl BudgetConfig {
+    /// Plan-wide token and cost limits
+    pub fn plan_limits(&self) -> StageBudget {
+        StageBudget {
+            max_tokens: self.max_tokens,
+            max_cost_usd: self.max_cost_usd,
+        }
+    }
+
+    /// Effective limits for a stage: its own budget, falling back to the plan defaults
+    pub fn stage_limits(&self, stage: Option<&StageBudget>) -> StageBudget {
+        let stage = stage.copied().unwrap_or_default();
+        StageBudget {
+            max_tokens: stage.max_tokens.or(self.max_stage_tokens),
+            max_cost_usd: stage.max_cost_usd.or(self.max_stage_cost_usd),
+        }
+    }
+}
+
+/// Validate plan and per-stage budget limits
+pub fn validate_budget(
+    budget: Option<&BudgetConfig>,
+    stages: &[StageDefinition],
+    errors: &mut Vec<ValidationError>,
+) {
+    let plan = budget.cloned().unwrap_or_default();
+    let mut push = |message: String, stage_id: Option<&str>| {
+        errors.push(ValidationError {
+            message,
+            stage_id: stage_id.map(String::from),
+        })
+    };
+
+    for message in check_limits(&plan.plan_limits(), "budget")
+        .into_iter()
+        .chain(check_limits(
+            &StageBudget {
+                max_tokens: plan.max_stage_tokens,
+                max_cost_usd: plan.max_stage_cost_usd,
+            },
+            "budget.max_stage",
+        ))
+    {
+        push(message, None);
+    }
+
+    if let Some(duration) = &plan.max_duration {
+        match parse_duration(duration) {
+            Ok(d) if d.is_zero() => {
+                push("budget.max_duration must be greater than zero".into(), None)
+            }
+            Ok(_) => {}
+            Err(e) => push(format!("Invalid budget.max_duration: {e}"), None),
+        }
+    }
+
+    let plan_limits = plan.plan_limits();
+    for stage in stages {
+        if let Some(own) = &stage.budget {
+            for message in check_limits(own, "budget") {
+                push(message, Some(&stage.id));
+            }
+        }
+        let effective = plan.stage_limits(stage.budget.as_ref());
+        for message in check_against_plan(&effective, &plan_limits) {
+            push(message, Some(&stage.id));
+        }
+    }
+}
+
+/// Check that each set limit is positive and finite
+fn check_limits(limits: &StageBudget, field: &str) -> Vec<String> {
+    let mut messages = Vec::new();
+    if limits.max_tokens == Some(0) {
+        messages.push(format!("{field} max_tokens must be greater than zero"));
+    }
+    if let Some(cost) = limits.max_cost_usd {
+        if !cost.is_finite() || cost <= 0.0 {
+            messages.push(format!(
+                "{field} max_cost_usd must be a positive amount, got {cost}"
+            ));
+        }
+    }
+    messages
+}
+
+/// Check that a stage's effective limits fit within the plan-wide limits
+fn check_against_plan(stage: &StageBudget, plan: &StageBudget) -> Vec<String> {
+    let mut messages = Vec::new();
+    if let (Some(stage_max), Some(plan_max)) = (stage.max_tokens, plan.max_tokens) {
+        if stage_max > plan_max {
+            messages.push(format!(
+                "stage token budget ({stage_max}) exceeds the plan budget ({plan_max})"
+            ));
+        }
+    }
+    if let (Some(stage_max), Some(plan_max)) = (
+        stage.max_cost_usd,
+        plan.max_cost_usd.filter(|max| *max > 0.0),
+    ) {
+        if stage_max > plan_max {
+            messages.push(format!(
+                "stage cost budget (${stage_max}) exceeds the plan budget (${plan_max})"
+            ));
+        }
+    }
+    messages
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stage_limits_fall_back_to_plan_defaults() {
+        let plan = BudgetConfig {
+            max_stage_tokens: Some(500_000),
+            max_stage_cost_usd: Some(5.0),
+            ..BudgetConfig::default()
+        };
+        let own = StageBudget {
+            max_tokens: Some(100_000),
+            max_cost_usd: None,
+        };
+
+        let limits = plan.stage_limits(Some(&own));
+        assert_eq!(limits.max_tokens, Some(100_000));
+        assert_eq!(limits.max_cost_usd, Some(5.0));
+        assert_eq!(plan.stage_limits(None).max_tokens, Some(500_000));
+    }
+
+    #[test]
+    fn test_parse_budget_from_yaml() {
+        let yaml = r#"
+max_tokens: 20000000
+max_cost_usd: 50
+max_stage_cost_usd: 10.5
+max_duration: 8h
+"#;
+        let budget: BudgetConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(budget.max_tokens, Some(20_000_000));
+        assert_eq!(budget.max_cost_usd, Some(50.0));
+        assert_eq!(budget.max_stage_cost_usd, Some(10.5));
+        assert_eq!(budget.max_duration.as_deref(), Some("8h"));
+        assert_eq!(budget.max_stage_tokens, None);
+    }
+}
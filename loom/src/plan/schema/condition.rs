@@ -0,0 +1,403 @@
+//! Stage `when:` conditions over dependency outputs
+//!
+//! Grammar (loosest binding first):
+//!
+//! ```text
+//! or      := and ("||" and)*
+//! and     := unary ("&&" unary)*
+//! unary   := "!" unary | primary
+//! primary := "(" or ")" | operand (("==" | "!=") operand)?
+//! operand := outputs.<stage-id>.<key> | "string" | number | true | false | null
+//! ```
+
+use std::collections::HashSet;
+
+use serde_json::Value;
+
+use super::types::{StageDefinition, ValidationError};
+
+/// A parsed `when:` expression
+#[derive(Debug, Clone, PartialEq)]
+pub enum Condition {
+    /// The operand is truthy (not null, false, 0, "" or empty)
+    Truthy(Operand),
+    Not(Box<Condition>),
+    Equals(Operand, Operand),
+    NotEquals(Operand, Operand),
+    And(Box<Condition>, Box<Condition>),
+    Or(Box<Condition>, Box<Condition>),
+}
+
+/// A value in a condition
+#[derive(Debug, Clone, PartialEq)]
+pub enum Operand {
+    /// `outputs.<stage>.<key>`: a dependency's output value
+    Output {
+        stage: String,
+        key: String,
+    },
+    Literal(Value),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Str(String),
+    Eq,
+    Ne,
+    Not,
+    And,
+    Or,
+    Open,
+    Close,
+}
+
+impl Operand {
+    /// Parse an `outputs.<stage>.<key>` reference
+    pub fn parse_output_ref(word: &str) -> Option<Self> {
+        let mut parts = word.splitn(3, '.');
+        let (Some("outputs"), Some(stage), Some(key)) = (parts.next(), parts.next(), parts.next())
+        else {
+            return None;
+        };
+        (!stage.is_empty() && !key.is_empty()).then(|| Operand::Output {
+            stage: stage.to_string(),
+            key: key.to_string(),
+        })
+    }
+
+    /// The `(stage, key)` of an output reference
+    pub fn output_ref(&self) -> Option<(&str, &str)> {
+        match self {
+            Operand::Output { stage, key } => Some((stage, key)),
+            Operand::Literal(_) => None,
+        }
+    }
+
+    fn from_word(word: &str) -> Result<Self, String> {
+        if word.starts_with("outputs.") {
+            return Self::parse_output_ref(word)
+                .ok_or_else(|| format!("'{word}' must have the form outputs.<stage-id>.<key>"));
+        }
+        match serde_json::from_str::<Value>(word) {
+            Ok(value) if !value.is_array() && !value.is_object() => Ok(Operand::Literal(value)),
+            _ => Err(format!("unexpected '{word}'")),
+        }
+    }
+
+    fn resolve(&self, lookup: &dyn Fn(&str, &str) -> Option<Value>) -> Value {
+        match self {
+            Operand::Output { stage, key } => lookup(stage, key).unwrap_or(Value::Null),
+            Operand::Literal(value) => value.clone(),
+        }
+    }
+}
+
+impl Condition {
+    /// Parse a `when:` expression
+    pub fn parse(expr: &str) -> Result<Self, String> {
+        let tokens = tokenize(expr)?;
+        let mut parser = Parser { tokens, pos: 0 };
+        let condition = parser.or()?;
+        match parser.tokens.get(parser.pos) {
+            None => Ok(condition),
+            Some(token) => Err(format!("unexpected {token:?} after expression")),
+        }
+    }
+
+    /// Every `(stage, key)` output the condition reads
+    pub fn references(&self) -> Vec<(&str, &str)> {
+        match self {
+            Condition::Truthy(op) => op.output_ref().into_iter().collect(),
+            Condition::Not(inner) => inner.references(),
+            Condition::Equals(a, b) | Condition::NotEquals(a, b) => {
+                a.output_ref().into_iter().chain(b.output_ref()).collect()
+            }
+            Condition::And(a, b) | Condition::Or(a, b) => [a.references(), b.references()].concat(),
+        }
+    }
+
+    /// Evaluate against output values; missing outputs read as null
+    pub fn evaluate(&self, lookup: &dyn Fn(&str, &str) -> Option<Value>) -> bool {
+        match self {
+            Condition::Truthy(op) => is_truthy(&op.resolve(lookup)),
+            Condition::Not(inner) => !inner.evaluate(lookup),
+            Condition::Equals(a, b) => values_equal(&a.resolve(lookup), &b.resolve(lookup)),
+            Condition::NotEquals(a, b) => !values_equal(&a.resolve(lookup), &b.resolve(lookup)),
+            Condition::And(a, b) => a.evaluate(lookup) && b.evaluate(lookup),
+            Condition::Or(a, b) => a.evaluate(lookup) || b.evaluate(lookup),
+        }
+    }
+}
+
+fn is_truthy(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::Bool(b) => *b,
+        Value::Number(n) => n.as_f64().is_some_and(|n| n != 0.0),
+        Value::String(s) => !s.is_empty(),
+        Value::Array(a) => !a.is_empty(),
+        Value::Object(o) => !o.is_empty(),
+    }
+}
+
+/// JSON equality, comparing numbers by value (so `1 == 1.0`)
+fn values_equal(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Number(x), Value::Number(y)) => x.as_f64() == y.as_f64(),
+        _ => a == b,
+    }
+}
+
+fn tokenize(expr: &str) -> Result<Vec<Token>, String> {
+    let chars: Vec<char> = expr.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        let (token, len) = match (c, next) {
+            _ if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            ('=', Some('=')) => (Token::Eq, 2),
+            ('!', Some('=')) => (Token::Ne, 2),
+            ('&', Some('&')) => (Token::And, 2),
+            ('|', Some('|')) => (Token::Or, 2),
+            ('!', _) => (Token::Not, 1),
+            ('(', _) => (Token::Open, 1),
+            (')', _) => (Token::Close, 1),
+            ('"', _) => string_token(&chars[i..])?,
+            _ if is_word_char(c) => {
+                let len = chars[i..].iter().take_while(|c| is_word_char(**c)).count();
+                (Token::Word(chars[i..i + len].iter().collect()), len)
+            }
+            _ => return Err(format!("unexpected character '{c}'")),
+        };
+        tokens.push(token);
+        i += len;
+    }
+    Ok(tokens)
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '+')
+}
+
+/// Read a double-quoted JSON string starting at `chars[0]`
+fn string_token(chars: &[char]) -> Result<(Token, usize), String> {
+    let mut escaped = false;
+    for (i, c) in chars.iter().enumerate().skip(1) {
+        match c {
+            '\\' if !escaped => escaped = true,
+            '"' if !escaped => {
+                let raw: String = chars[..=i].iter().collect();
+                let value: String = serde_json::from_str(&raw).map_err(|e| e.to_string())?;
+                return Ok((Token::Str(value), i + 1));
+            }
+            _ => escaped = false,
+        }
+    }
+    Err("unterminated string".to_string())
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn eat(&mut self, token: &Token) -> bool {
+        let matched = self.tokens.get(self.pos) == Some(token);
+        if matched {
+            self.pos += 1;
+        }
+        matched
+    }
+
+    fn or(&mut self) -> Result<Condition, String> {
+        let mut left = self.and()?;
+        while self.eat(&Token::Or) {
+            left = Condition::Or(Box::new(left), Box::new(self.and()?));
+        }
+        Ok(left)
+    }
+
+    fn and(&mut self) -> Result<Condition, String> {
+        let mut left = self.unary()?;
+        while self.eat(&Token::And) {
+            left = Condition::And(Box::new(left), Box::new(self.unary()?));
+        }
+        Ok(left)
+    }
+
+    fn unary(&mut self) -> Result<Condition, String> {
+        if self.eat(&Token::Not) {
+            return Ok(Condition::Not(Box::new(self.unary()?)));
+        }
+        if self.eat(&Token::Open) {
+            let inner = self.or()?;
+            if !self.eat(&Token::Close) {
+                return Err("missing ')'".to_string());
+            }
+            return Ok(inner);
+        }
+        let left = self.operand()?;
+        if self.eat(&Token::Eq) {
+            Ok(Condition::Equals(left, self.operand()?))
+        } else if self.eat(&Token::Ne) {
+            Ok(Condition::NotEquals(left, self.operand()?))
+        } else {
+            Ok(Condition::Truthy(left))
+        }
+    }
+
+    fn operand(&mut self) -> Result<Operand, String> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        match token {
+            Some(Token::Word(word)) => Operand::from_word(&word),
+            Some(Token::Str(s)) => Ok(Operand::Literal(Value::String(s))),
+            Some(token) => Err(format!("expected a value, found {token:?}")),
+            None => Err("expected a value, found end of expression".to_string()),
+        }
+    }
+}
+
+/// Validate `when:` conditions: they must parse, and every output they read
+/// must be declared in `output_keys` by one of the stage's dependencies
+pub fn validate_conditions(stages: &[StageDefinition], errors: &mut Vec<ValidationError>) {
+    let stage_ids: HashSet<&str> = stages.iter().map(|s| s.id.as_str()).collect();
+
+    for stage in stages {
+        let Some(expr) = &stage.when else {
+            continue;
+        };
+        let mut push = |message: String| {
+            errors.push(ValidationError {
+                message,
+                stage_id: Some(stage.id.clone()),
+            })
+        };
+        let condition = match Condition::parse(expr) {
+            Ok(condition) => condition,
+            Err(e) => {
+                push(format!("Invalid when condition '{expr}': {e}"));
+                continue;
+            }
+        };
+        for (dep, key) in condition.references() {
+            if !stage_ids.contains(dep) {
+                push(format!("when condition references unknown stage '{dep}'"));
+            } else if !stage.dependencies.iter().any(|d| d == dep) {
+                push(format!(
+                    "when condition reads outputs of '{dep}', which is not a dependency"
+                ));
+            } else if !stages
+                .iter()
+                .any(|s| s.id == dep && s.output_keys.iter().any(|k| k == key))
+            {
+                push(format!(
+                    "when condition references output '{key}' not declared in output_keys of '{dep}'"
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn lookup(stage: &str, key: &str) -> Option<Value> {
+        match (stage, key) {
+            ("detect-db", "engine") => Some(json!("postgres")),
+            ("detect-db", "replicas") => Some(json!(2)),
+            ("detect-db", "tls") => Some(json!(false)),
+            _ => None,
+        }
+    }
+
+    fn eval(expr: &str) -> bool {
+        Condition::parse(expr).unwrap().evaluate(&lookup)
+    }
+
+    #[test]
+    fn test_evaluate_conditions() {
+        assert!(eval(r#"outputs.detect-db.engine == "postgres""#));
+        assert!(!eval(r#"outputs.detect-db.engine != "postgres""#));
+        assert!(eval("outputs.detect-db.replicas == 2.0"));
+        assert!(!eval("outputs.detect-db.tls"));
+        assert!(eval("!outputs.detect-db.tls && outputs.detect-db.replicas"));
+        assert!(eval(
+            r#"(outputs.detect-db.engine == "mysql" || outputs.detect-db.replicas == 2) && true"#
+        ));
+        // Missing outputs read as null
+        assert!(eval("outputs.detect-db.missing == null"));
+        assert!(!eval("outputs.other.engine"));
+    }
+
+    #[test]
+    fn test_validate_conditions() {
+        let stages: Vec<StageDefinition> = serde_yaml::from_str(
+            r#"
+- id: detect-db
+  name: Detect DB
+  working_dir: "."
+  output_keys: [engine]
+- id: other
+  name: Other
+  working_dir: "."
+  output_keys: [engine]
+- id: postgres
+  name: Postgres
+  working_dir: "."
+  dependencies: [detect-db]
+  when: outputs.detect-db.engine == "postgres"
+- id: broken
+  name: Broken
+  working_dir: "."
+  dependencies: [detect-db]
+  when: >-
+    outputs.detect-db.version == 1 || outputs.other.engine ||
+    outputs.missing.engine || outputs.detect-db.engine ==
+"#,
+        )
+        .unwrap();
+        let mut errors = Vec::new();
+        validate_conditions(&stages[..3], &mut errors);
+        assert!(errors.is_empty(), "{errors:?}");
+
+        validate_conditions(&stages, &mut errors);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].message.contains("Invalid when condition"));
+
+        let mut broken = stages[3].clone();
+        broken.when = Some(
+            "outputs.detect-db.version == 1 || outputs.other.engine || outputs.missing.engine"
+                .to_string(),
+        );
+        let mut errors = Vec::new();
+        validate_conditions(&[stages[0].clone(), stages[1].clone(), broken], &mut errors);
+        let messages: Vec<&str> = errors.iter().map(|e| e.message.as_str()).collect();
+        assert_eq!(messages.len(), 3, "{messages:?}");
+        assert!(messages[0].contains("output 'version' not declared"));
+        assert!(messages[1].contains("'other', which is not a dependency"));
+        assert!(messages[2].contains("unknown stage 'missing'"));
+    }
+
+    #[test]
+    fn test_parse_errors_and_references() {
+        assert!(Condition::parse("outputs.detect-db == 1").is_err());
+        assert!(Condition::parse(r#"outputs.a.b == "x"#).is_err());
+        assert!(Condition::parse("outputs.a.b ==").is_err());
+        assert!(Condition::parse("(outputs.a.b").is_err());
+        assert!(Condition::parse("outputs.a.b outputs.c.d").is_err());
+        assert!(Condition::parse("postgres").is_err());
+
+        let condition = Condition::parse(r#"outputs.a.b == "x" || !outputs.c.d.e"#).unwrap();
+        assert_eq!(condition.references(), vec![("a", "b"), ("c", "d.e")]);
+    }
+}
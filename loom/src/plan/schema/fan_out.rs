@@ -0,0 +1,231 @@
+//! Stage fan-out (`for_each` / `matrix`) schema and validation
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use super::types::{StageDefinition, ValidationError};
+use crate::plan::fan_out::ITEM_PLACEHOLDER;
+use crate::validation::validate_id;
+
+/// Expand one stage into a child stage per item.
+///
+/// Items are either listed in the plan (expanded by `loom init`) or read at
+/// runtime from a dependency's output (`from_output: "<stage-id>.<key>"`,
+/// which must hold a JSON array). `${item}` in the stage's text fields is
+/// replaced with each child's item; dependencies are shared by every child
+/// and cannot use it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ForEach {
+    /// Static items, one child stage each
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub items: Vec<String>,
+    /// Dependency output holding the items, as `<stage-id>.<key>`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_output: Option<String>,
+}
+
+impl ForEach {
+    /// Split `from_output` into its stage ID and output key
+    pub fn output_source(&self) -> Option<(&str, &str)> {
+        self.from_output.as_deref()?.split_once('.')
+    }
+}
+
+/// IDs of the child stages `parent` fans out into, one per item.
+///
+/// Each ID is the parent ID plus a slug of the item; items that slug to
+/// nothing or to an ID already taken fall back to their 1-based index.
+pub fn child_stage_ids(parent: &str, items: &[String]) -> Vec<String> {
+    let mut ids: Vec<String> = Vec::with_capacity(items.len());
+    for (index, item) in items.iter().enumerate() {
+        let slug = slugify(item);
+        let mut id = format!("{parent}-{slug}");
+        if slug.is_empty() || ids.contains(&id) {
+            id = format!("{parent}-{}", index + 1);
+        }
+        ids.push(id);
+    }
+    ids
+}
+
+/// Lowercase an item, keeping alphanumerics and joining other runs with '-'
+fn slugify(item: &str) -> String {
+    let mapped: String = item
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    mapped
+        .split('-')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Validate every stage's `for_each` block
+pub fn validate_fan_out(stages: &[StageDefinition], errors: &mut Vec<ValidationError>) {
+    let stage_ids: HashSet<&str> = stages.iter().map(|s| s.id.as_str()).collect();
+
+    for stage in stages {
+        let Some(for_each) = &stage.for_each else {
+            continue;
+        };
+        let mut push = |message: String| {
+            errors.push(ValidationError {
+                message,
+                stage_id: Some(stage.id.clone()),
+            })
+        };
+
+        for dep in &stage.dependencies {
+            if dep.contains(ITEM_PLACEHOLDER) {
+                push(format!(
+                    "for_each dependency '{dep}' cannot use {ITEM_PLACEHOLDER}: every child shares the stage's dependencies"
+                ));
+            }
+        }
+
+        match (&for_each.items[..], &for_each.from_output) {
+            ([], None) => push("for_each needs either items or from_output".to_string()),
+            ([_, ..], Some(_)) => {
+                push("for_each cannot set both items and from_output".to_string())
+            }
+            (items, None) => {
+                let unique: HashSet<&String> = items.iter().collect();
+                if unique.len() != items.len() {
+                    push("for_each items must be unique".to_string());
+                }
+                for id in child_stage_ids(&stage.id, items) {
+                    if let Err(e) = validate_id(&id) {
+                        push(format!("for_each child stage ID '{id}' is invalid: {e}"));
+                    } else if stage_ids.contains(id.as_str()) {
+                        push(format!(
+                            "for_each child stage ID '{id}' clashes with an existing stage"
+                        ));
+                    }
+                }
+            }
+            ([], Some(source)) => match for_each.output_source() {
+                Some((dep, key)) if !dep.is_empty() && !key.is_empty() => {
+                    if !stage.dependencies.iter().any(|d| d == dep) {
+                        push(format!(
+                            "for_each.from_output stage '{dep}' must be a dependency"
+                        ));
+                    }
+                }
+                _ => push(format!(
+                    "for_each.from_output '{source}' must have the form <stage-id>.<key>"
+                )),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_child_stage_ids_slug_items_and_fall_back_to_index() {
+        let items = vec![
+            "crates/Core".to_string(),
+            "api service".to_string(),
+            "***".to_string(),
+            "crates-core".to_string(),
+        ];
+        assert_eq!(
+            child_stage_ids("build", &items),
+            vec![
+                "build-crates-core",
+                "build-api-service",
+                "build-3",
+                "build-4"
+            ]
+        );
+    }
+
+    fn fan_out_errors(yaml: &str) -> Vec<String> {
+        let stages: Vec<StageDefinition> = serde_yaml::from_str(yaml).unwrap();
+        let mut errors = Vec::new();
+        validate_fan_out(&stages, &mut errors);
+        errors.into_iter().map(|e| e.message).collect()
+    }
+
+    #[test]
+    fn test_validate_fan_out() {
+        let valid = r#"
+- id: discover
+  name: Discover
+  working_dir: "."
+- id: build
+  name: Build
+  working_dir: "."
+  matrix:
+    items: [core, cli]
+- id: test
+  name: Test
+  working_dir: "."
+  dependencies: [discover]
+  for_each:
+    from_output: discover.crates
+"#;
+        assert!(fan_out_errors(valid).is_empty());
+
+        let invalid = r#"
+- id: build
+  name: Build
+  working_dir: "."
+  for_each:
+    items: [core, core]
+- id: build-cli
+  name: Build CLI
+  working_dir: "."
+  for_each:
+    items: [x]
+    from_output: build.crates
+- id: test
+  name: Test
+  working_dir: "."
+  for_each:
+    from_output: discover.crates
+- id: lint
+  name: Lint
+  working_dir: "."
+  for_each:
+    from_output: discover
+- id: docs
+  name: Docs
+  working_dir: "."
+  for_each: {}
+- id: bench
+  name: Bench
+  working_dir: "."
+  dependencies: ["build-${item}"]
+  for_each:
+    items: [core]
+"#;
+        let errors = fan_out_errors(invalid);
+        assert_eq!(errors.len(), 6, "{errors:?}");
+        assert!(errors[0].contains("must be unique"));
+        assert!(errors[1].contains("cannot set both"));
+        assert!(errors[2].contains("must be a dependency"));
+        assert!(errors[3].contains("<stage-id>.<key>"));
+        assert!(errors[4].contains("needs either"));
+        assert!(errors[5].contains("'build-${item}' cannot use ${item}"));
+    }
+
+    #[test]
+    fn test_output_source_splits_on_first_dot() {
+        let for_each = ForEach {
+            items: vec![],
+            from_output: Some("discover.crates.list".to_string()),
+        };
+        assert_eq!(for_each.output_source(), Some(("discover", "crates.list")));
+    }
+}
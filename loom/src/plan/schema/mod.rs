@@ -1,16 +1,31 @@
 //! Plan YAML schema definitions and validation
 
+mod budget;
+mod condition;
+mod fan_out;
+mod models;
+mod notify;
+mod publish;
+mod report;
 mod types;
 mod validation;
 
 #[cfg(test)]
 mod tests;
 
+pub use crate::models::usage::StageBudget;
+pub use budget::{validate_budget, BudgetConfig};
+pub use condition::{validate_conditions, Condition, Operand};
+pub use fan_out::{child_stage_ids, validate_fan_out, ForEach};
+pub use models::{validate_models, ModelConfig};
+pub use notify::{validate_notifiers, NotifierConfig, NotifierSink, NotifyEventKind};
+pub use publish::{validate_publish, PublishConfig};
+pub use report::{validate_reports, AcceptanceCriterion, ReportFormat};
 pub use types::{
     ChangeImpactConfig, ChangeImpactPolicy, DeadCodeCheck, FilesystemConfig, LinuxConfig,
-    LoomConfig, LoomMetadata, NetworkConfig, RegressionTest, SandboxConfig, StageDefinition,
-    StageSandboxConfig, StageType, SuccessCriteria, TruthCheck, ValidationError, WiringCheck,
-    WiringTest,
+    LoomConfig, LoomMetadata, MergeStrategy, NetworkConfig, OverlapPolicy, RegressionTest,
+    SandboxConfig, StageDefinition, StageSandboxConfig, StageType, SuccessCriteria, TruthCheck,
+    ValidationError, WiringCheck, WiringTest,
 };
 pub use validation::{
     check_knowledge_recommendations, check_sandbox_recommendations, validate,
@@ -0,0 +1,196 @@
+//! Plan model selection schema and validation
+
+use serde::{Deserialize, Serialize};
+
+use super::types::{StageDefinition, StageType, ValidationError};
+use crate::models::session::SessionType;
+
+/// Plan-level Claude model defaults.
+///
+/// Each default applies to one kind of session; unset defaults leave the
+/// choice to the claude CLI. `escalation` lists models from cheapest to
+/// strongest: a stage retried after failing acceptance moves one step up the
+/// list per retry.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModelConfig {
+    /// Default model for stage sessions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stage: Option<String>,
+    /// Default model for knowledge stage sessions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub knowledge: Option<String>,
+    /// Default model for merge conflict resolution sessions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merge: Option<String>,
+    /// Default model for base branch conflict resolution sessions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_conflict: Option<String>,
+    /// Models to step through on retries, cheapest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub escalation: Vec<String>,
+}
+
+impl ModelConfig {
+    /// Plan default for a session type (knowledge stages have their own default)
+    pub fn default_for(&self, session_type: &SessionType, stage_type: StageType) -> Option<&str> {
+        let model = match (session_type, stage_type) {
+            (SessionType::Knowledge, _) | (SessionType::Stage, StageType::Knowledge) => {
+                &self.knowledge
+            }
+            (SessionType::Stage, _) => &self.stage,
+            (SessionType::Merge, _) => &self.merge,
+            (SessionType::BaseConflict, _) => &self.base_conflict,
+        };
+        model.as_deref()
+    }
+
+    /// Move `steps` entries up the escalation list from `model`.
+    ///
+    /// A model that is not in the list (or no model at all) escalates to the
+    /// first entry on the first step. The last entry is the ceiling.
+    pub fn escalate(&self, model: Option<&str>, steps: u32) -> Option<String> {
+        let last = self.escalation.len().checked_sub(1);
+        let (Some(last), true) = (last, steps > 0) else {
+            return model.map(String::from);
+        };
+        let steps = steps as usize;
+        let index = match model.and_then(|m| self.escalation.iter().position(|e| e == m)) {
+            Some(current) => current.saturating_add(steps),
+            None => steps - 1,
+        };
+        Some(self.escalation[index.min(last)].clone())
+    }
+}
+
+/// Validate plan-level model defaults and per-stage model overrides
+pub fn validate_models(
+    models: Option<&ModelConfig>,
+    stages: &[StageDefinition],
+    errors: &mut Vec<ValidationError>,
+) {
+    let mut push = |message: String, stage_id: Option<&str>| {
+        errors.push(ValidationError {
+            message,
+            stage_id: stage_id.map(String::from),
+        })
+    };
+
+    if let Some(models) = models {
+        let defaults = [
+            ("models.stage", &models.stage),
+            ("models.knowledge", &models.knowledge),
+            ("models.merge", &models.merge),
+            ("models.base_conflict", &models.base_conflict),
+        ];
+        for (field, model) in defaults {
+            if let Some(message) = model.as_deref().and_then(|m| check_model_name(m, field)) {
+                push(message, None);
+            }
+        }
+        for (i, model) in models.escalation.iter().enumerate() {
+            if let Some(message) = check_model_name(model, "models.escalation") {
+                push(message, None);
+            } else if models.escalation[..i].contains(model) {
+                push(
+                    format!("models.escalation lists '{model}' more than once"),
+                    None,
+                );
+            }
+        }
+    }
+
+    for stage in stages {
+        if let Some(message) = stage
+            .model
+            .as_deref()
+            .and_then(|m| check_model_name(m, "model"))
+        {
+            push(message, Some(&stage.id));
+        }
+    }
+}
+
+/// Check that a model name is a single non-empty token
+fn check_model_name(model: &str, field: &str) -> Option<String> {
+    if model.trim().is_empty() {
+        Some(format!("{field} must not be empty"))
+    } else if model.chars().any(char::is_whitespace) {
+        Some(format!("{field} '{model}' must not contain whitespace"))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ladder() -> ModelConfig {
+        ModelConfig {
+            escalation: vec![
+                "claude-haiku-4-5".to_string(),
+                "claude-sonnet-4-5".to_string(),
+                "claude-opus-4-1".to_string(),
+            ],
+            ..ModelConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_escalate_steps_up_and_stops_at_strongest() {
+        let models = ladder();
+        assert_eq!(
+            models.escalate(Some("claude-haiku-4-5"), 0).as_deref(),
+            Some("claude-haiku-4-5")
+        );
+        assert_eq!(
+            models.escalate(Some("claude-haiku-4-5"), 1).as_deref(),
+            Some("claude-sonnet-4-5")
+        );
+        assert_eq!(
+            models.escalate(Some("claude-sonnet-4-5"), 5).as_deref(),
+            Some("claude-opus-4-1")
+        );
+        assert_eq!(
+            models.escalate(None, 1).as_deref(),
+            Some("claude-haiku-4-5")
+        );
+        assert_eq!(models.escalate(None, 0), None);
+        assert_eq!(
+            ModelConfig::default()
+                .escalate(Some("claude-haiku-4-5"), 3)
+                .as_deref(),
+            Some("claude-haiku-4-5")
+        );
+    }
+
+    #[test]
+    fn test_default_for_session_type() {
+        let models = ModelConfig {
+            stage: Some("sonnet".to_string()),
+            knowledge: Some("haiku".to_string()),
+            merge: Some("opus".to_string()),
+            ..ModelConfig::default()
+        };
+        assert_eq!(
+            models.default_for(&SessionType::Stage, StageType::Standard),
+            Some("sonnet")
+        );
+        assert_eq!(
+            models.default_for(&SessionType::Stage, StageType::Knowledge),
+            Some("haiku")
+        );
+        assert_eq!(
+            models.default_for(&SessionType::Knowledge, StageType::Knowledge),
+            Some("haiku")
+        );
+        assert_eq!(
+            models.default_for(&SessionType::Merge, StageType::Standard),
+            Some("opus")
+        );
+        assert_eq!(
+            models.default_for(&SessionType::BaseConflict, StageType::Standard),
+            None
+        );
+    }
+}
@@ -0,0 +1,219 @@
+//! Notifier configuration shared by plan metadata and `.work/config.toml`
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::PathBuf;
+
+use super::types::ValidationError;
+
+/// Orchestrator events that notifiers can subscribe to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotifyEventKind {
+    StageCompleted,
+    StageBlocked,
+    MergeConflict,
+    NeedsHumanReview,
+    Handoff,
+    OrchestrationComplete,
+}
+
+impl fmt::Display for NotifyEventKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            NotifyEventKind::StageCompleted => "stage_completed",
+            NotifyEventKind::StageBlocked => "stage_blocked",
+            NotifyEventKind::MergeConflict => "merge_conflict",
+            NotifyEventKind::NeedsHumanReview => "needs_human_review",
+            NotifyEventKind::Handoff => "handoff",
+            NotifyEventKind::OrchestrationComplete => "orchestration_complete",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// A notification sink and the events it receives
+///
+/// ```yaml
+/// notifiers:
+///   - type: webhook
+///     url: https://hooks.example.com/loom
+///     events: [stage_blocked, merge_conflict]
+///   - type: jsonl
+///     path: .work/events.jsonl
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NotifierConfig {
+    #[serde(flatten)]
+    pub sink: NotifierSink,
+    /// Events to deliver (default: all events)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub events: Vec<NotifyEventKind>,
+    /// Extra delivery attempts after a failure (default: 3)
+    #[serde(default = "default_retries")]
+    pub retries: u32,
+    /// Delay before the first retry in milliseconds, doubled on each retry (default: 1000)
+    #[serde(default = "default_retry_delay_ms")]
+    pub retry_delay_ms: u64,
+}
+
+fn default_retries() -> u32 {
+    3
+}
+
+fn default_retry_delay_ms() -> u64 {
+    1000
+}
+
+impl NotifierConfig {
+    /// Whether this notifier subscribes to the given event
+    pub fn accepts(&self, kind: NotifyEventKind) -> bool {
+        self.events.is_empty() || self.events.contains(&kind)
+    }
+}
+
+/// Where a notifier delivers events
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum NotifierSink {
+    /// POST the event as JSON to a URL
+    Webhook {
+        url: String,
+        #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+        headers: BTreeMap<String, String>,
+    },
+    /// Run a local command with the event JSON on stdin
+    Command {
+        command: String,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        args: Vec<String>,
+    },
+    /// Append the event as one JSON line to a file (relative to the project root)
+    Jsonl { path: PathBuf },
+}
+
+impl fmt::Display for NotifierSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NotifierSink::Webhook { url, .. } => write!(f, "webhook {url}"),
+            NotifierSink::Command { command, .. } => write!(f, "command {command}"),
+            NotifierSink::Jsonl { path } => write!(f, "jsonl {}", path.display()),
+        }
+    }
+}
+
+/// Validate notifier definitions, appending any problems to `errors`
+pub fn validate_notifiers(notifiers: &[NotifierConfig], errors: &mut Vec<ValidationError>) {
+    for notifier in notifiers {
+        let problem = match &notifier.sink {
+            NotifierSink::Webhook { url, .. }
+                if !(url.starts_with("http://") || url.starts_with("https://")) =>
+            {
+                Some(format!(
+                    "Notifier webhook URL must start with http:// or https://: '{url}'"
+                ))
+            }
+            NotifierSink::Command { command, .. } if command.trim().is_empty() => {
+                Some("Notifier command cannot be empty".to_string())
+            }
+            NotifierSink::Jsonl { path } if path.as_os_str().is_empty() => {
+                Some("Notifier jsonl path cannot be empty".to_string())
+            }
+            _ => None,
+        };
+
+        if let Some(message) = problem {
+            errors.push(ValidationError {
+                message,
+                stage_id: None,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_notifiers_from_yaml() {
+        let yaml = r#"
+- type: webhook
+  url: https://hooks.example.com/loom
+  headers:
+    Authorization: Bearer token
+  events: [stage_blocked, merge_conflict]
+  retries: 5
+- type: command
+  command: ./notify.sh
+- type: jsonl
+  path: .work/events.jsonl
+"#;
+        let notifiers: Vec<NotifierConfig> = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(notifiers.len(), 3);
+
+        assert_eq!(notifiers[0].retries, 5);
+        assert!(notifiers[0].accepts(NotifyEventKind::MergeConflict));
+        assert!(!notifiers[0].accepts(NotifyEventKind::StageCompleted));
+        assert!(matches!(
+            &notifiers[0].sink,
+            NotifierSink::Webhook { headers, .. } if headers["Authorization"] == "Bearer token"
+        ));
+
+        assert_eq!(notifiers[1].retries, 3);
+        assert!(notifiers[1].accepts(NotifyEventKind::OrchestrationComplete));
+        assert_eq!(
+            notifiers[2].sink,
+            NotifierSink::Jsonl {
+                path: PathBuf::from(".work/events.jsonl")
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_notifiers_from_toml() {
+        #[derive(Deserialize)]
+        struct Config {
+            notifiers: Vec<NotifierConfig>,
+        }
+
+        let toml_str = r#"
+[[notifiers]]
+type = "command"
+command = "notify-team"
+args = ["--channel", "loom"]
+events = ["handoff"]
+"#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(
+            config.notifiers[0].sink,
+            NotifierSink::Command {
+                command: "notify-team".to_string(),
+                args: vec!["--channel".to_string(), "loom".to_string()],
+            }
+        );
+        assert_eq!(config.notifiers[0].events, vec![NotifyEventKind::Handoff]);
+    }
+
+    #[test]
+    fn test_validate_notifiers() {
+        let notifiers: Vec<NotifierConfig> = serde_yaml::from_str(
+            r#"
+- type: webhook
+  url: ftp://example.com
+- type: command
+  command: " "
+- type: jsonl
+  path: events.jsonl
+"#,
+        )
+        .unwrap();
+
+        let mut errors = Vec::new();
+        validate_notifiers(&notifiers, &mut errors);
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].message.contains("http://"));
+        assert!(errors[1].message.contains("command cannot be empty"));
+    }
+}
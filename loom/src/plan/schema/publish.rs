@@ -0,0 +1,142 @@
+//! Publish configuration shared by plan metadata and `.work/config.toml`
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use super::types::ValidationError;
+
+/// Where `loom publish` pushes the merge point and what it does with the
+/// rendered PR body
+///
+/// ```yaml
+/// publish:
+///   remote: origin
+///   branch: feature/auth
+///   command: gh
+///   args: [pr, create, --head, "{branch}", --title, "{title}", --body-file, "{body_file}"]
+///   auto: true
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PublishConfig {
+    /// Remote to push to (default: origin)
+    #[serde(default = "default_remote")]
+    pub remote: String,
+    /// Branch to push the merge point to (default: `plan/<plan-id>`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    /// Command run after the push with the PR body on stdin, e.g. a forge CLI
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+    /// Arguments of `command`; `{remote}`, `{branch}`, `{title}` and
+    /// `{body_file}` are replaced
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<String>,
+    /// File the PR body is written to, relative to the project root
+    /// (default: `.work/publish/PR.md`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body_file: Option<PathBuf>,
+    /// Publish when the plan finishes with every stage merged
+    #[serde(default)]
+    pub auto: bool,
+}
+
+fn default_remote() -> String {
+    "origin".to_string()
+}
+
+impl Default for PublishConfig {
+    fn default() -> Self {
+        Self {
+            remote: default_remote(),
+            branch: None,
+            command: None,
+            args: Vec::new(),
+            body_file: None,
+            auto: false,
+        }
+    }
+}
+
+/// Validate a publish block, appending any problems to `errors`
+pub fn validate_publish(publish: Option<&PublishConfig>, errors: &mut Vec<ValidationError>) {
+    let Some(publish) = publish else {
+        return;
+    };
+    let mut problems = Vec::new();
+    if publish.remote.trim().is_empty() {
+        problems.push("Publish remote cannot be empty".to_string());
+    }
+    if let Some(branch) = &publish.branch {
+        if !is_valid_branch_name(branch) {
+            problems.push(format!(
+                "Publish branch is not a valid branch name: '{branch}'"
+            ));
+        }
+    }
+    if publish
+        .command
+        .as_deref()
+        .is_some_and(|c| c.trim().is_empty())
+    {
+        problems.push("Publish command cannot be empty".to_string());
+    }
+    if publish.command.is_none() && !publish.args.is_empty() {
+        problems.push("Publish args are set without a command".to_string());
+    }
+    errors.extend(problems.into_iter().map(|message| ValidationError {
+        message,
+        stage_id: None,
+    }));
+}
+
+/// A conservative subset of `git check-ref-format --branch`
+fn is_valid_branch_name(branch: &str) -> bool {
+    !branch.is_empty()
+        && !branch.starts_with(['-', '/'])
+        && !branch.ends_with(['/', '.'])
+        && !branch.ends_with(".lock")
+        && !branch.contains("..")
+        && !branch.contains("//")
+        && !branch.contains("@{")
+        && !branch
+            .chars()
+            .any(|c| c.is_whitespace() || c.is_control() || "~^:?*[\\".contains(c))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_publish_from_yaml() {
+        let yaml = r#"
+branch: feature/auth
+command: gh
+args: [pr, create, --body-file, "{body_file}"]
+auto: true
+"#;
+        let publish: PublishConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(publish.remote, "origin");
+        assert_eq!(publish.branch.as_deref(), Some("feature/auth"));
+        assert_eq!(publish.args.len(), 4);
+        assert!(publish.auto);
+
+        let mut errors = Vec::new();
+        validate_publish(Some(&publish), &mut errors);
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn test_validate_publish_rejects_bad_values() {
+        let publish = PublishConfig {
+            remote: " ".to_string(),
+            branch: Some("feature..x".to_string()),
+            args: vec!["--title".to_string()],
+            ..PublishConfig::default()
+        };
+        let mut errors = Vec::new();
+        validate_publish(Some(&publish), &mut errors);
+        assert_eq!(errors.len(), 3);
+        assert!(errors[1].message.contains("feature..x"));
+    }
+}
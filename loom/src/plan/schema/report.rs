@@ -0,0 +1,261 @@
+//! Acceptance criteria and their test report formats
+//!
+//! An acceptance criterion is a shell command, optionally annotated with the
+//! test report it produces. The report is parsed into individual test cases
+//! after the criterion runs:
+//!
+//! - `junit:<path>`: JUnit XML written to `<path>`, relative to the
+//!   criterion's working directory
+//! - `tap`: TAP on the command's stdout
+//! - `cargo-test`: libtest output of `cargo test` on stdout
+
+use std::fmt;
+use std::ops::Deref;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::types::{StageDefinition, ValidationError};
+
+/// A shell command judged by its exit code, with an optional test report.
+///
+/// In YAML a criterion is either the command string or a mapping that
+/// annotates it:
+///
+/// ```yaml
+/// acceptance:
+///   - cargo build
+///   - command: cargo test
+///     report: cargo-test
+/// ```
+///
+/// It serializes back to a plain string when it has no report.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AcceptanceCriterion {
+    pub command: String,
+    /// Test report format (`junit:<path>`, `tap` or `cargo-test`)
+    pub report: Option<String>,
+}
+
+impl AcceptanceCriterion {
+    /// A criterion with its test report annotation
+    pub fn with_report(command: impl Into<String>, report: impl Into<String>) -> Self {
+        Self {
+            command: command.into(),
+            report: Some(report.into()),
+        }
+    }
+}
+
+impl Deref for AcceptanceCriterion {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.command
+    }
+}
+
+impl fmt::Display for AcceptanceCriterion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.command)
+    }
+}
+
+impl From<String> for AcceptanceCriterion {
+    fn from(command: String) -> Self {
+        Self {
+            command,
+            report: None,
+        }
+    }
+}
+
+impl From<&str> for AcceptanceCriterion {
+    fn from(command: &str) -> Self {
+        command.to_string().into()
+    }
+}
+
+impl PartialEq<&str> for AcceptanceCriterion {
+    fn eq(&self, other: &&str) -> bool {
+        self.command == *other
+    }
+}
+
+impl PartialEq<String> for AcceptanceCriterion {
+    fn eq(&self, other: &String) -> bool {
+        self.command == *other
+    }
+}
+
+/// YAML shape of a criterion: a bare command or an annotated mapping
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum CriterionRepr {
+    Command(String),
+    Annotated {
+        command: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        report: Option<String>,
+    },
+}
+
+impl Serialize for AcceptanceCriterion {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match &self.report {
+            None => serializer.serialize_str(&self.command),
+            Some(report) => CriterionRepr::Annotated {
+                command: self.command.clone(),
+                report: Some(report.clone()),
+            }
+            .serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for AcceptanceCriterion {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match CriterionRepr::deserialize(deserializer)? {
+            CriterionRepr::Command(command) => command.into(),
+            CriterionRepr::Annotated { command, report } => Self { command, report },
+        })
+    }
+}
+
+/// Where and how a criterion reports its individual test results
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// JUnit XML file, relative to the criterion's working directory
+    Junit { path: String },
+    /// TAP on stdout
+    Tap,
+    /// `cargo test` (libtest) output on stdout
+    CargoTest,
+}
+
+impl FromStr for ReportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "tap" => Ok(Self::Tap),
+            "cargo-test" => Ok(Self::CargoTest),
+            "junit" => Err("junit reports need a path, e.g. junit:target/junit.xml".to_string()),
+            other => match other.strip_prefix("junit:").map(str::trim) {
+                Some("") => Err("junit report path is empty".to_string()),
+                Some(path) => Ok(Self::Junit {
+                    path: path.to_string(),
+                }),
+                None => Err(format!(
+                    "unknown report format '{other}' (expected junit:<path>, tap or cargo-test)"
+                )),
+            },
+        }
+    }
+}
+
+impl fmt::Display for ReportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Junit { path } => write!(f, "junit:{path}"),
+            Self::Tap => write!(f, "tap"),
+            Self::CargoTest => write!(f, "cargo-test"),
+        }
+    }
+}
+
+/// Validate that every criterion's report names a known format
+pub fn validate_reports(stages: &[StageDefinition], errors: &mut Vec<ValidationError>) {
+    for stage in stages {
+        for criterion in &stage.acceptance {
+            let Some(report) = &criterion.report else {
+                continue;
+            };
+            if let Err(e) = report.parse::<ReportFormat>() {
+                errors.push(ValidationError {
+                    message: format!("Invalid report for '{criterion}': {e}"),
+                    stage_id: Some(stage.id.clone()),
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_report_format() {
+        assert_eq!("tap".parse(), Ok(ReportFormat::Tap));
+        assert_eq!("cargo-test".parse(), Ok(ReportFormat::CargoTest));
+        assert_eq!(
+            "junit: target/junit.xml".parse(),
+            Ok(ReportFormat::Junit {
+                path: "target/junit.xml".to_string()
+            })
+        );
+        assert!("junit".parse::<ReportFormat>().is_err());
+        assert!("junit:".parse::<ReportFormat>().is_err());
+        assert!("xunit".parse::<ReportFormat>().is_err());
+        assert_eq!(
+            ReportFormat::Junit {
+                path: "out.xml".to_string()
+            }
+            .to_string(),
+            "junit:out.xml"
+        );
+    }
+
+    #[test]
+    fn test_criterion_yaml_forms() {
+        let criteria: Vec<AcceptanceCriterion> = serde_yaml::from_str(
+            "- cargo build\n- command: cargo test\n  report: cargo-test\n- command: npm test\n",
+        )
+        .unwrap();
+        assert_eq!(criteria[0], AcceptanceCriterion::from("cargo build"));
+        assert_eq!(
+            criteria[1],
+            AcceptanceCriterion::with_report("cargo test", "cargo-test")
+        );
+        assert_eq!(criteria[2], "npm test");
+
+        let yaml = serde_yaml::to_string(&criteria).unwrap();
+        assert_eq!(
+            yaml,
+            "- cargo build\n- command: cargo test\n  report: cargo-test\n- npm test\n"
+        );
+    }
+
+    #[test]
+    fn test_validate_reports() {
+        let stages: Vec<StageDefinition> = serde_yaml::from_str(
+            r#"
+- id: tests
+  name: Tests
+  working_dir: "."
+  acceptance:
+    - command: cargo test
+      report: cargo-test
+    - command: npm test
+      report: junit:reports/junit.xml
+- id: broken
+  name: Broken
+  working_dir: "."
+  acceptance:
+    - cargo build
+    - command: cargo test
+      report: xunit
+"#,
+        )
+        .unwrap();
+
+        let mut errors = Vec::new();
+        validate_reports(&stages, &mut errors);
+        assert_eq!(errors.len(), 1, "{errors:?}");
+        assert_eq!(errors[0].stage_id.as_deref(), Some("broken"));
+        assert!(errors[0]
+            .message
+            .contains("Invalid report for 'cargo test': unknown report format 'xunit'"));
+    }
+}
@@ -49,14 +49,23 @@ fn test_validate_acceptance_criterion_allowed_whitespace() {
 #[test]
 fn test_validate_metadata_with_empty_acceptance() {
     let mut stage = make_stage("stage-1", "Stage One");
-    stage.acceptance = vec!["".to_string()];
+    stage.acceptance = vec!["".into()];
 
     let metadata = LoomMetadata {
         loom: LoomConfig {
             version: 1,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             sandbox: SandboxConfig::default(),
             change_impact: None,
+            default_timeout: None,
+            parallel: None,
+            budget: None,
+            models: None,
+            notifiers: Vec::new(),
+            publish: None,
+            promote_decisions: None,
             stages: vec![stage],
         },
     };
@@ -73,18 +82,24 @@ fn test_validate_metadata_with_empty_acceptance() {
 #[test]
 fn test_validate_metadata_with_valid_acceptance() {
     let mut stage = make_stage("stage-1", "Stage One");
-    stage.acceptance = vec![
-        "cargo test".to_string(),
-        "cargo clippy -- -D warnings".to_string(),
-    ];
+    stage.acceptance = vec!["cargo test".into(), "cargo clippy -- -D warnings".into()];
     stage.truths = vec!["cargo build".to_string()];
 
     let metadata = LoomMetadata {
         loom: LoomConfig {
             version: 1,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             sandbox: SandboxConfig::default(),
             change_impact: None,
+            default_timeout: None,
+            parallel: None,
+            budget: None,
+            models: None,
+            notifiers: Vec::new(),
+            publish: None,
+            promote_decisions: None,
             stages: vec![stage],
         },
     };
@@ -96,14 +111,23 @@ fn test_validate_metadata_with_valid_acceptance() {
 #[test]
 fn test_validate_metadata_multiple_invalid_acceptance() {
     let mut stage = make_stage("stage-1", "Stage One");
-    stage.acceptance = vec!["".to_string(), "   ".to_string(), "cargo test".to_string()];
+    stage.acceptance = vec!["".into(), "   ".into(), "cargo test".into()];
 
     let metadata = LoomMetadata {
         loom: LoomConfig {
             version: 1,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             sandbox: SandboxConfig::default(),
             change_impact: None,
+            default_timeout: None,
+            parallel: None,
+            budget: None,
+            models: None,
+            notifiers: Vec::new(),
+            publish: None,
+            promote_decisions: None,
             stages: vec![stage],
         },
     };
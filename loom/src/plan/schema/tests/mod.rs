@@ -21,9 +21,12 @@ pub(crate) fn make_stage(id: &str, name: &str) -> StageDefinition {
         dependencies: vec![],
         parallel_group: None,
         acceptance: vec![],
+        parallel: None,
         setup: vec![],
         files: vec![],
         auto_merge: None,
+        merge_strategy: None,
+        overlap: None,
         working_dir: ".".to_string(),
         stage_type: StageType::default(),
         truths: vec![],
@@ -35,6 +38,12 @@ pub(crate) fn make_stage(id: &str, name: &str) -> StageDefinition {
         before_stage: vec![],
         after_stage: vec![],
         context_budget: None,
+        timeout: None,
+        budget: None,
+        model: None,
+        for_each: None,
+        when: None,
+        output_keys: vec![],
         sandbox: StageSandboxConfig::default(),
         execution_mode: None,
         bug_fix: None,
@@ -51,7 +60,7 @@ pub(crate) fn create_valid_metadata() -> LoomMetadata {
     stage2.description = Some("Second stage".to_string());
     stage2.dependencies = vec!["stage-1".to_string()];
     stage2.parallel_group = Some("group-a".to_string());
-    stage2.acceptance = vec!["cargo test".to_string()];
+    stage2.acceptance = vec!["cargo test".into()];
     stage2.setup = vec!["source .venv/bin/activate".to_string()];
     stage2.files = vec!["src/*.rs".to_string()];
     stage2.truths = vec!["cargo build".to_string()];
@@ -60,8 +69,17 @@ pub(crate) fn create_valid_metadata() -> LoomMetadata {
         loom: LoomConfig {
             version: 1,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             sandbox: SandboxConfig::default(),
             change_impact: None,
+            default_timeout: None,
+            parallel: None,
+            budget: None,
+            models: None,
+            notifiers: Vec::new(),
+            publish: None,
+            promote_decisions: None,
             stages: vec![stage1, stage2],
         },
     }
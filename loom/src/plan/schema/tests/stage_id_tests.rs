@@ -12,8 +12,17 @@ fn test_validate_stage_id_path_traversal() {
         loom: LoomConfig {
             version: 1,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             sandbox: SandboxConfig::default(),
             change_impact: None,
+            default_timeout: None,
+            parallel: None,
+            budget: None,
+            models: None,
+            notifiers: Vec::new(),
+            publish: None,
+            promote_decisions: None,
             stages: vec![stage],
         },
     };
@@ -34,8 +43,17 @@ fn test_validate_stage_id_with_slashes() {
         loom: LoomConfig {
             version: 1,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             sandbox: SandboxConfig::default(),
             change_impact: None,
+            default_timeout: None,
+            parallel: None,
+            budget: None,
+            models: None,
+            notifiers: Vec::new(),
+            publish: None,
+            promote_decisions: None,
             stages: vec![stage],
         },
     };
@@ -56,8 +74,17 @@ fn test_validate_stage_id_with_dots() {
         loom: LoomConfig {
             version: 1,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             sandbox: SandboxConfig::default(),
             change_impact: None,
+            default_timeout: None,
+            parallel: None,
+            budget: None,
+            models: None,
+            notifiers: Vec::new(),
+            publish: None,
+            promote_decisions: None,
             stages: vec![stage],
         },
     };
@@ -78,8 +105,17 @@ fn test_validate_stage_id_reserved_name_dotdot() {
         loom: LoomConfig {
             version: 1,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             sandbox: SandboxConfig::default(),
             change_impact: None,
+            default_timeout: None,
+            parallel: None,
+            budget: None,
+            models: None,
+            notifiers: Vec::new(),
+            publish: None,
+            promote_decisions: None,
             stages: vec![stage],
         },
     };
@@ -96,8 +132,17 @@ fn test_validate_stage_id_reserved_name_con() {
         loom: LoomConfig {
             version: 1,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             sandbox: SandboxConfig::default(),
             change_impact: None,
+            default_timeout: None,
+            parallel: None,
+            budget: None,
+            models: None,
+            notifiers: Vec::new(),
+            publish: None,
+            promote_decisions: None,
             stages: vec![stage],
         },
     };
@@ -117,8 +162,17 @@ fn test_validate_dependency_id_path_traversal() {
         loom: LoomConfig {
             version: 1,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             sandbox: SandboxConfig::default(),
             change_impact: None,
+            default_timeout: None,
+            parallel: None,
+            budget: None,
+            models: None,
+            notifiers: Vec::new(),
+            publish: None,
+            promote_decisions: None,
             stages: vec![stage],
         },
     };
@@ -140,8 +194,17 @@ fn test_validate_stage_id_too_long() {
         loom: LoomConfig {
             version: 1,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             sandbox: SandboxConfig::default(),
             change_impact: None,
+            default_timeout: None,
+            parallel: None,
+            budget: None,
+            models: None,
+            notifiers: Vec::new(),
+            publish: None,
+            promote_decisions: None,
             stages: vec![stage],
         },
     };
@@ -160,8 +223,17 @@ fn test_validate_stage_id_with_spaces() {
         loom: LoomConfig {
             version: 1,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             sandbox: SandboxConfig::default(),
             change_impact: None,
+            default_timeout: None,
+            parallel: None,
+            budget: None,
+            models: None,
+            notifiers: Vec::new(),
+            publish: None,
+            promote_decisions: None,
             stages: vec![stage],
         },
     };
@@ -24,8 +24,17 @@ fn test_validate_unsupported_version() {
         loom: LoomConfig {
             version: 2, // Invalid version
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             sandbox: SandboxConfig::default(),
             change_impact: None,
+            default_timeout: None,
+            parallel: None,
+            budget: None,
+            models: None,
+            notifiers: Vec::new(),
+            publish: None,
+            promote_decisions: None,
             stages: vec![stage],
         },
     };
@@ -43,8 +52,17 @@ fn test_validate_empty_stages() {
         loom: LoomConfig {
             version: 1,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             sandbox: SandboxConfig::default(),
             change_impact: None,
+            default_timeout: None,
+            parallel: None,
+            budget: None,
+            models: None,
+            notifiers: Vec::new(),
+            publish: None,
+            promote_decisions: None,
             stages: vec![],
         },
     };
@@ -65,8 +83,17 @@ fn test_validate_empty_stage_id() {
         loom: LoomConfig {
             version: 1,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             sandbox: SandboxConfig::default(),
             change_impact: None,
+            default_timeout: None,
+            parallel: None,
+            budget: None,
+            models: None,
+            notifiers: Vec::new(),
+            publish: None,
+            promote_decisions: None,
             stages: vec![stage],
         },
     };
@@ -87,8 +114,17 @@ fn test_validate_empty_stage_name() {
         loom: LoomConfig {
             version: 1,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             sandbox: SandboxConfig::default(),
             change_impact: None,
+            default_timeout: None,
+            parallel: None,
+            budget: None,
+            models: None,
+            notifiers: Vec::new(),
+            publish: None,
+            promote_decisions: None,
             stages: vec![stage],
         },
     };
@@ -110,8 +146,17 @@ fn test_validate_unknown_dependency() {
         loom: LoomConfig {
             version: 1,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             sandbox: SandboxConfig::default(),
             change_impact: None,
+            default_timeout: None,
+            parallel: None,
+            budget: None,
+            models: None,
+            notifiers: Vec::new(),
+            publish: None,
+            promote_decisions: None,
             stages: vec![stage],
         },
     };
@@ -134,8 +179,17 @@ fn test_validate_self_dependency() {
         loom: LoomConfig {
             version: 1,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             sandbox: SandboxConfig::default(),
             change_impact: None,
+            default_timeout: None,
+            parallel: None,
+            budget: None,
+            models: None,
+            notifiers: Vec::new(),
+            publish: None,
+            promote_decisions: None,
             stages: vec![stage],
         },
     };
@@ -158,8 +212,17 @@ fn test_validate_multiple_errors() {
         loom: LoomConfig {
             version: 2,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             sandbox: SandboxConfig::default(),
             change_impact: None,
+            default_timeout: None,
+            parallel: None,
+            budget: None,
+            models: None,
+            notifiers: Vec::new(),
+            publish: None,
+            promote_decisions: None,
             stages: vec![stage1, stage2],
         },
     };
@@ -227,8 +290,17 @@ fn test_complex_dependency_chain() {
         loom: LoomConfig {
             version: 1,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             sandbox: SandboxConfig::default(),
             change_impact: None,
+            default_timeout: None,
+            parallel: None,
+            budget: None,
+            models: None,
+            notifiers: Vec::new(),
+            publish: None,
+            promote_decisions: None,
             stages: vec![stage1, stage2, stage3],
         },
     };
@@ -245,8 +317,17 @@ fn test_validate_duplicate_stage_ids() {
         loom: LoomConfig {
             version: 1,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             sandbox: SandboxConfig::default(),
             change_impact: None,
+            default_timeout: None,
+            parallel: None,
+            budget: None,
+            models: None,
+            notifiers: Vec::new(),
+            publish: None,
+            promote_decisions: None,
             stages: vec![stage1, stage2],
         },
     };
@@ -268,8 +349,17 @@ fn test_validate_working_dir_path_traversal() {
         loom: LoomConfig {
             version: 1,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             sandbox: SandboxConfig::default(),
             change_impact: None,
+            default_timeout: None,
+            parallel: None,
+            budget: None,
+            models: None,
+            notifiers: Vec::new(),
+            publish: None,
+            promote_decisions: None,
             stages: vec![stage],
         },
     };
@@ -289,8 +379,17 @@ fn test_validate_working_dir_absolute_path() {
         loom: LoomConfig {
             version: 1,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             sandbox: SandboxConfig::default(),
             change_impact: None,
+            default_timeout: None,
+            parallel: None,
+            budget: None,
+            models: None,
+            notifiers: Vec::new(),
+            publish: None,
+            promote_decisions: None,
             stages: vec![stage],
         },
     };
@@ -313,8 +412,17 @@ fn test_validate_working_dir_valid_subdirectory() {
         loom: LoomConfig {
             version: 1,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             sandbox: SandboxConfig::default(),
             change_impact: None,
+            default_timeout: None,
+            parallel: None,
+            budget: None,
+            models: None,
+            notifiers: Vec::new(),
+            publish: None,
+            promote_decisions: None,
             stages: vec![stage],
         },
     };
@@ -322,6 +430,109 @@ fn test_validate_working_dir_valid_subdirectory() {
     assert!(validate(&metadata).is_ok());
 }
 
+#[test]
+fn test_validate_stage_and_plan_timeouts() {
+    let mut metadata = create_valid_metadata();
+    metadata.loom.default_timeout = Some("1h".to_string());
+    metadata.loom.stages[0].timeout = Some("45m".to_string());
+    assert!(validate(&metadata).is_ok());
+
+    metadata.loom.default_timeout = Some("soon".to_string());
+    metadata.loom.stages[0].timeout = Some("0m".to_string());
+    let errors = validate(&metadata).unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert!(errors[0].message.contains("Invalid timeout"));
+    assert!(errors[0].stage_id.is_none());
+    assert!(errors[1].message.contains("greater than zero"));
+    assert_eq!(errors[1].stage_id.as_deref(), Some("stage-1"));
+}
+
+#[test]
+fn test_validate_promote_decisions() {
+    let mut metadata = create_valid_metadata();
+    metadata.loom.promote_decisions = Some("patterns".to_string());
+    assert!(validate(&metadata).is_ok());
+
+    metadata.loom.promote_decisions = Some("journal".to_string());
+    let errors = validate(&metadata).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0]
+        .message
+        .contains("Unknown knowledge file: 'journal'"));
+}
+
+#[test]
+fn test_validate_budgets() {
+    use crate::plan::schema::{BudgetConfig, StageBudget};
+
+    let mut metadata = create_valid_metadata();
+    metadata.loom.budget = Some(BudgetConfig {
+        max_tokens: Some(1_000_000),
+        max_cost_usd: Some(20.0),
+        max_stage_tokens: Some(500_000),
+        max_duration: Some("8h".to_string()),
+        ..BudgetConfig::default()
+    });
+    metadata.loom.stages[0].budget = Some(StageBudget {
+        max_tokens: None,
+        max_cost_usd: Some(5.0),
+    });
+    assert!(validate(&metadata).is_ok());
+
+    metadata.loom.budget = Some(BudgetConfig {
+        max_tokens: Some(1_000_000),
+        max_cost_usd: Some(-1.0),
+        max_duration: Some("later".to_string()),
+        ..BudgetConfig::default()
+    });
+    metadata.loom.stages[0].budget = Some(StageBudget {
+        max_tokens: Some(2_000_000),
+        max_cost_usd: Some(0.0),
+    });
+    let errors = validate(&metadata).unwrap_err();
+    let messages: Vec<&str> = errors.iter().map(|e| e.message.as_str()).collect();
+    assert_eq!(errors.len(), 4, "{messages:?}");
+    assert!(messages[0].contains("max_cost_usd must be a positive amount"));
+    assert!(messages[1].contains("Invalid budget.max_duration"));
+    assert!(messages[2].contains("max_cost_usd must be a positive amount"));
+    assert!(messages[3].contains("exceeds the plan budget"));
+    assert_eq!(errors[3].stage_id.as_deref(), Some("stage-1"));
+}
+
+#[test]
+fn test_validate_models() {
+    use crate::plan::schema::ModelConfig;
+
+    let mut metadata = create_valid_metadata();
+    metadata.loom.models = Some(ModelConfig {
+        stage: Some("claude-haiku-4-5".to_string()),
+        escalation: vec![
+            "claude-haiku-4-5".to_string(),
+            "claude-sonnet-4-5".to_string(),
+        ],
+        ..ModelConfig::default()
+    });
+    metadata.loom.stages[0].model = Some("claude-sonnet-4-5".to_string());
+    assert!(validate(&metadata).is_ok());
+
+    metadata.loom.models = Some(ModelConfig {
+        merge: Some(" ".to_string()),
+        escalation: vec![
+            "claude-haiku-4-5".to_string(),
+            "claude-haiku-4-5".to_string(),
+        ],
+        ..ModelConfig::default()
+    });
+    metadata.loom.stages[0].model = Some("claude sonnet".to_string());
+    let errors = validate(&metadata).unwrap_err();
+    let messages: Vec<&str> = errors.iter().map(|e| e.message.as_str()).collect();
+    assert_eq!(errors.len(), 3, "{messages:?}");
+    assert!(messages[0].contains("models.merge must not be empty"));
+    assert!(messages[1].contains("more than once"));
+    assert!(messages[2].contains("must not contain whitespace"));
+    assert_eq!(errors[2].stage_id.as_deref(), Some("stage-1"));
+}
+
 // ============================================================================
 // IntegrationVerify goal-backward requirement tests
 // ============================================================================
@@ -336,8 +547,17 @@ fn test_integration_verify_requires_goal_backward() {
         loom: LoomConfig {
             version: 1,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             sandbox: SandboxConfig::default(),
             change_impact: None,
+            default_timeout: None,
+            parallel: None,
+            budget: None,
+            models: None,
+            notifiers: Vec::new(),
+            publish: None,
+            promote_decisions: None,
             stages: vec![stage],
         },
     };
@@ -360,8 +580,17 @@ fn test_integration_verify_with_truths_passes() {
         loom: LoomConfig {
             version: 1,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             sandbox: SandboxConfig::default(),
             change_impact: None,
+            default_timeout: None,
+            parallel: None,
+            budget: None,
+            models: None,
+            notifiers: Vec::new(),
+            publish: None,
+            promote_decisions: None,
             stages: vec![stage],
         },
     };
@@ -384,8 +613,17 @@ fn test_integration_verify_with_wiring_tests_passes() {
         loom: LoomConfig {
             version: 1,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             sandbox: SandboxConfig::default(),
             change_impact: None,
+            default_timeout: None,
+            parallel: None,
+            budget: None,
+            models: None,
+            notifiers: Vec::new(),
+            publish: None,
+            promote_decisions: None,
             stages: vec![stage],
         },
     };
@@ -407,8 +645,17 @@ fn test_integration_verify_with_wiring_passes() {
         loom: LoomConfig {
             version: 1,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             sandbox: SandboxConfig::default(),
             change_impact: None,
+            default_timeout: None,
+            parallel: None,
+            budget: None,
+            models: None,
+            notifiers: Vec::new(),
+            publish: None,
+            promote_decisions: None,
             stages: vec![stage],
         },
     };
@@ -426,8 +673,17 @@ fn test_knowledge_stage_exempt_from_goal_backward() {
         loom: LoomConfig {
             version: 1,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             sandbox: SandboxConfig::default(),
             change_impact: None,
+            default_timeout: None,
+            parallel: None,
+            budget: None,
+            models: None,
+            notifiers: Vec::new(),
+            publish: None,
+            promote_decisions: None,
             stages: vec![stage],
         },
     };
@@ -444,8 +700,8 @@ fn test_preflight_warns_on_working_dir_redundancy() {
     let mut stage = make_stage("stage-1", "Stage One");
     stage.working_dir = "loom".to_string();
     stage.acceptance = vec![
-        "loom/src/main.rs".to_string(), // Redundant prefix
-        "cargo test".to_string(),       // No redundancy
+        "loom/src/main.rs".into(), // Redundant prefix
+        "cargo test".into(),       // No redundancy
     ];
     stage.truths = vec!["test -f README.md".to_string()];
 
@@ -460,7 +716,7 @@ fn test_preflight_warns_on_working_dir_redundancy() {
 fn test_preflight_no_warning_when_working_dir_is_dot() {
     let mut stage = make_stage("stage-1", "Stage One");
     stage.working_dir = ".".to_string();
-    stage.acceptance = vec!["loom/src/main.rs".to_string()];
+    stage.acceptance = vec!["loom/src/main.rs".into()];
     stage.truths = vec!["test -f README.md".to_string()];
 
     let warnings = validate_structural_preflight(&[stage], None);
@@ -2,6 +2,14 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::budget::BudgetConfig;
+use super::fan_out::ForEach;
+use super::models::ModelConfig;
+use super::notify::NotifierConfig;
+use super::publish::PublishConfig;
+use super::report::AcceptanceCriterion;
+use crate::models::usage::StageBudget;
+
 /// Plan-level sandbox configuration (defaults for all stages)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SandboxConfig {
@@ -187,6 +195,12 @@ pub use crate::models::stage::StageType;
 /// The canonical definition is in crate::models::stage::ExecutionMode.
 pub use crate::models::stage::ExecutionMode;
 
+/// The canonical definition is in crate::models::stage::MergeStrategy.
+pub use crate::models::stage::MergeStrategy;
+
+/// The canonical definition is in crate::models::stage::OverlapPolicy.
+pub use crate::models::stage::OverlapPolicy;
+
 /// Root structure of the loom metadata block
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LoomMetadata {
@@ -199,12 +213,40 @@ pub struct LoomConfig {
     pub version: u32,
     #[serde(default)]
     pub auto_merge: Option<bool>,
+    /// How stage branches are merged, unless a stage sets `merge_strategy`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merge_strategy: Option<MergeStrategy>,
+    /// Whether ready stages wait for overlapping in-flight stages, unless a stage sets `overlap`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub overlap: Option<OverlapPolicy>,
     /// Plan-level sandbox configuration (defaults for all stages)
     #[serde(default)]
     pub sandbox: SandboxConfig,
     /// Plan-level change impact configuration
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub change_impact: Option<ChangeImpactConfig>,
+    /// Default wall-clock timeout per stage attempt (e.g. "45m"), used when a stage sets none
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_timeout: Option<String>,
+    /// Run each stage's acceptance criteria concurrently, unless the stage sets `parallel`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parallel: Option<bool>,
+    /// Plan-level token, cost and wall-clock limits
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub budget: Option<BudgetConfig>,
+    /// Plan-level Claude model defaults and retry escalation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub models: Option<ModelConfig>,
+    /// Plan-level notifiers for orchestrator events
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub notifiers: Vec<NotifierConfig>,
+    /// Where `loom publish` pushes the finished plan
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub publish: Option<PublishConfig>,
+    /// Knowledge file (e.g. `patterns`) each stage's memory decisions are
+    /// promoted to when the stage merges
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub promote_decisions: Option<String>,
     pub stages: Vec<StageDefinition>,
 }
 
@@ -220,13 +262,22 @@ pub struct StageDefinition {
     #[serde(default)]
     pub parallel_group: Option<String>,
     #[serde(default)]
-    pub acceptance: Vec<String>,
+    pub acceptance: Vec<AcceptanceCriterion>,
+    /// Run the acceptance criteria concurrently (overrides the plan's `parallel`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parallel: Option<bool>,
     #[serde(default)]
     pub setup: Vec<String>,
     #[serde(default)]
     pub files: Vec<String>,
     #[serde(default)]
     pub auto_merge: Option<bool>,
+    /// How the stage branch is merged (overrides the plan's `merge_strategy`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merge_strategy: Option<MergeStrategy>,
+    /// Whether the stage waits for overlapping in-flight stages (overrides the plan's `overlap`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub overlap: Option<OverlapPolicy>,
     /// Working directory for acceptance criteria, relative to worktree root.
     /// REQUIRED field - forces explicit choice of execution directory.
     /// Use "." for worktree root, or a subdirectory like "loom".
@@ -268,6 +319,27 @@ pub struct StageDefinition {
     /// When context usage exceeds this, auto-handoff is triggered.
     #[serde(default)]
     pub context_budget: Option<u32>,
+    /// Wall-clock timeout per attempt (e.g. "45m", "1h30m").
+    /// When exceeded, the session is killed and the stage fails with a timeout.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<String>,
+    /// Token/cost limits for this stage (overrides the plan's per-stage limits)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub budget: Option<StageBudget>,
+    /// Claude model for this stage's sessions (overrides the plan's `models` defaults)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// Expand this stage into one child stage per item (alias: `matrix`)
+    #[serde(default, alias = "matrix", skip_serializing_if = "Option::is_none")]
+    pub for_each: Option<ForEach>,
+    /// Run the stage only when this condition over dependency outputs holds,
+    /// e.g. `outputs.detect-db.engine == "postgres"`; otherwise it is skipped
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub when: Option<String>,
+    /// Output keys this stage sets with `loom stage output set`, which
+    /// dependents' conditions may read
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub output_keys: Vec<String>,
     /// Per-stage sandbox configuration (overrides plan-level defaults)
     #[serde(default)]
     pub sandbox: StageSandboxConfig,
@@ -1,7 +1,15 @@
 //! Plan YAML schema validation
 
+use crate::fs::knowledge::KnowledgeFile;
 use crate::validation::validate_id;
 
+use super::budget::validate_budget;
+use super::condition::validate_conditions;
+use super::fan_out::validate_fan_out;
+use super::models::validate_models;
+use super::notify::validate_notifiers;
+use super::publish::validate_publish;
+use super::report::validate_reports;
 use super::types::{
     FilesystemConfig, LoomMetadata, NetworkConfig, SandboxConfig, StageSandboxConfig,
     ValidationError,
@@ -163,6 +171,26 @@ fn validate_stage_sandbox_config(
     }
 }
 
+/// Validate a stage or plan-level timeout duration
+fn validate_timeout(
+    timeout: Option<&str>,
+    stage_id: Option<&str>,
+    errors: &mut Vec<ValidationError>,
+) {
+    let Some(timeout) = timeout else {
+        return;
+    };
+    let message = match crate::utils::parse_duration(timeout) {
+        Ok(duration) if duration.is_zero() => "timeout must be greater than zero".to_string(),
+        Ok(_) => return,
+        Err(e) => format!("Invalid timeout: {e}"),
+    };
+    errors.push(ValidationError {
+        message,
+        stage_id: stage_id.map(String::from),
+    });
+}
+
 /// Validate the loom metadata
 pub fn validate(metadata: &LoomMetadata) -> Result<(), Vec<ValidationError>> {
     let mut errors = Vec::new();
@@ -181,6 +209,48 @@ pub fn validate(metadata: &LoomMetadata) -> Result<(), Vec<ValidationError>> {
     // Validate plan-level sandbox configuration
     validate_sandbox_config(&metadata.loom.sandbox, &mut errors);
 
+    // Validate plan-level notifiers
+    validate_notifiers(&metadata.loom.notifiers, &mut errors);
+
+    // Validate plan-level publish settings
+    validate_publish(metadata.loom.publish.as_ref(), &mut errors);
+
+    // Validate the knowledge file decisions are promoted to on merge
+    if let Some(target) = &metadata.loom.promote_decisions {
+        if let Err(e) = target.parse::<KnowledgeFile>() {
+            errors.push(ValidationError {
+                message: format!("Invalid promote_decisions: {e}"),
+                stage_id: None,
+            });
+        }
+    }
+
+    // Validate plan-level default timeout
+    validate_timeout(metadata.loom.default_timeout.as_deref(), None, &mut errors);
+
+    // Validate plan-level and per-stage budgets
+    validate_budget(
+        metadata.loom.budget.as_ref(),
+        &metadata.loom.stages,
+        &mut errors,
+    );
+
+    // Validate plan-level model defaults and per-stage models
+    validate_models(
+        metadata.loom.models.as_ref(),
+        &metadata.loom.stages,
+        &mut errors,
+    );
+
+    // Validate stage fan-out blocks
+    validate_fan_out(&metadata.loom.stages, &mut errors);
+
+    // Validate stage conditions against dependency output keys
+    validate_conditions(&metadata.loom.stages, &mut errors);
+
+    // Validate acceptance criterion test reports
+    validate_reports(&metadata.loom.stages, &mut errors);
+
     // Check for empty stages
     if metadata.loom.stages.is_empty() {
         errors.push(ValidationError {
@@ -242,6 +312,8 @@ pub fn validate(metadata: &LoomMetadata) -> Result<(), Vec<ValidationError>> {
             });
         }
 
+        validate_timeout(stage.timeout.as_deref(), Some(&stage.id), &mut errors);
+
         // Validate dependencies exist and have valid IDs
         for dep in &stage.dependencies {
             // Validate dependency ID format (prevents path traversal in dependency refs)
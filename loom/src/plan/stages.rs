@@ -0,0 +1,161 @@
+//! Stage records built from plan definitions
+//!
+//! `loom init` writes these as the initial stage files, and plan updates
+//! diff them against the stage files that already exist.
+
+use anyhow::Result;
+use chrono::Utc;
+
+use crate::models::stage::{Stage, StageStatus, StageType};
+use crate::plan::fan_out::{attach_children, expand_stage};
+use crate::plan::parser::ParsedPlan;
+use crate::plan::schema::StageDefinition;
+
+/// Create a stage from its definition with the plan's defaults applied
+pub fn stage_for_plan(stage_def: &StageDefinition, plan: &ParsedPlan) -> Stage {
+    let mut stage = create_stage_from_definition(stage_def, &plan.id);
+    if stage.timeout.is_none() {
+        stage.timeout = plan.metadata.loom.default_timeout.clone();
+    }
+    if stage.parallel.is_none() {
+        stage.parallel = plan.metadata.loom.parallel;
+    }
+    if stage.merge_strategy.is_none() {
+        stage.merge_strategy = plan.metadata.loom.merge_strategy;
+    }
+    if stage.overlap.is_none() {
+        stage.overlap = plan.metadata.loom.overlap;
+    }
+    if let Some(budget) = &plan.metadata.loom.budget {
+        let limits = budget.stage_limits(stage.budget.as_ref());
+        stage.budget = (!limits.is_empty()).then_some(limits);
+    }
+    stage
+}
+
+/// Expand a stage with static `for_each` items into its child stages.
+///
+/// Stages that fan out over a dependency output are expanded by the
+/// orchestrator once that dependency has completed.
+pub fn expand_init_fan_out(stage: &mut Stage) -> Result<Vec<Stage>> {
+    let items = match &stage.for_each {
+        Some(for_each) if for_each.from_output.is_none() => for_each.items.clone(),
+        _ => return Ok(Vec::new()),
+    };
+    let children = expand_stage(stage, &items)?;
+    attach_children(stage, &children);
+    Ok(children)
+}
+
+/// Detect the stage type from the definition.
+///
+/// Uses explicit `stage_type` field if set, otherwise falls back to
+/// detecting stage type based on ID or name patterns (case-insensitive):
+/// - "knowledge" -> Knowledge
+/// - "integration-verify" or "integration verify" -> IntegrationVerify
+fn detect_stage_type(stage_def: &StageDefinition) -> StageType {
+    // Check explicit stage_type field first (if not default Standard)
+    if stage_def.stage_type != StageType::Standard {
+        return stage_def.stage_type;
+    }
+
+    let id_lower = stage_def.id.to_lowercase();
+    let name_lower = stage_def.name.to_lowercase();
+
+    // Detect Knowledge stage
+    if id_lower.contains("knowledge") || name_lower.contains("knowledge") {
+        return StageType::Knowledge;
+    }
+
+    // Detect IntegrationVerify stage
+    if id_lower.contains("integration-verify")
+        || name_lower.contains("integration-verify")
+        || name_lower.contains("integration verify")
+    {
+        return StageType::IntegrationVerify;
+    }
+
+    StageType::Standard
+}
+
+/// Create a Stage from a StageDefinition
+pub fn create_stage_from_definition(stage_def: &StageDefinition, plan_id: &str) -> Stage {
+    let now = Utc::now();
+
+    let status = if stage_def.dependencies.is_empty() {
+        StageStatus::Queued
+    } else {
+        StageStatus::WaitingForDeps
+    };
+
+    let stage_type = detect_stage_type(stage_def);
+
+    Stage {
+        id: stage_def.id.clone(),
+        name: stage_def.name.clone(),
+        description: stage_def.description.clone(),
+        status,
+        dependencies: stage_def.dependencies.clone(),
+        parallel_group: stage_def.parallel_group.clone(),
+        acceptance: stage_def.acceptance.clone(),
+        parallel: stage_def.parallel,
+        setup: stage_def.setup.clone(),
+        files: stage_def.files.clone(),
+        stage_type,
+        plan_id: Some(plan_id.to_string()),
+        worktree: None,
+        session: None,
+        held: false,
+        parent_stage: None,
+        child_stages: Vec::new(),
+        created_at: now,
+        updated_at: now,
+        completed_at: None,
+        started_at: None,
+        duration_secs: None,
+        execution_secs: None,
+        attempt_started_at: None,
+        close_reason: None,
+        auto_merge: stage_def.auto_merge,
+        merge_strategy: stage_def.merge_strategy,
+        overlap: stage_def.overlap,
+        overlap_hold: None,
+        working_dir: Some(stage_def.working_dir.clone()),
+        retry_count: 0,
+        max_retries: None,
+        last_failure_at: None,
+        failure_info: None,
+        resolved_base: None,
+        base_branch: None,
+        base_merged_from: Vec::new(),
+        outputs: Vec::new(),
+        completed_commit: None,
+        merged: false,
+        merge_conflict: false,
+        condition_skipped: false,
+        verification_status: Default::default(),
+        context_budget: stage_def.context_budget,
+        timeout: stage_def.timeout.clone(),
+        budget: stage_def.budget,
+        model: stage_def.model.clone(),
+        model_escalation: 0,
+        for_each: stage_def.for_each.clone(),
+        when: stage_def.when.clone(),
+        truths: stage_def.truths.clone(),
+        artifacts: stage_def.artifacts.clone(),
+        wiring: stage_def.wiring.clone(),
+        truth_checks: stage_def.truth_checks.clone(),
+        wiring_tests: stage_def.wiring_tests.clone(),
+        dead_code_check: stage_def.dead_code_check.clone(),
+        before_stage: stage_def.before_stage.clone(),
+        after_stage: stage_def.after_stage.clone(),
+        fix_attempts: 0,
+        sandbox: stage_def.sandbox.clone(),
+        execution_mode: stage_def.execution_mode,
+        max_fix_attempts: None,
+        review_reason: None,
+        bug_fix: stage_def.bug_fix,
+        regression_test: stage_def.regression_test.clone(),
+        usage: Default::default(),
+    }
+}
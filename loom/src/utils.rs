@@ -1,5 +1,7 @@
+use anyhow::{bail, Result};
 use std::io::{self, Write};
 use std::sync::Once;
+use std::time::Duration;
 
 use crate::models::constants::display::{CONTEXT_HEALTHY_PCT, CONTEXT_WARNING_PCT};
 
@@ -52,6 +54,52 @@ pub fn format_elapsed_verbose(seconds: i64) -> String {
     }
 }
 
+/// Parse a human-readable duration such as `90s`, `45m`, `2h` or `1h30m`.
+///
+/// A bare number is interpreted as seconds.
+///
+/// # Arguments
+/// * `input` - The duration string
+///
+/// # Returns
+/// The parsed duration, or an error describing the accepted format
+pub fn parse_duration(input: &str) -> Result<Duration> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        bail!("Duration cannot be empty");
+    }
+    if let Ok(secs) = trimmed.parse::<u64>() {
+        return Ok(Duration::from_secs(secs));
+    }
+
+    let mut total: u64 = 0;
+    let mut digits = String::new();
+    for c in trimmed.chars() {
+        if c.is_ascii_digit() {
+            digits.push(c);
+            continue;
+        }
+        let unit_secs = match c {
+            's' => 1,
+            'm' => 60,
+            'h' => 3600,
+            _ => bail!(
+                "Invalid duration '{input}': use a number followed by s, m or h (e.g. 45m, 1h30m)"
+            ),
+        };
+        let Ok(value) = digits.parse::<u64>() else {
+            bail!("Invalid duration '{input}': missing number before '{c}'");
+        };
+        total = total.saturating_add(value.saturating_mul(unit_secs));
+        digits.clear();
+    }
+    if !digits.is_empty() {
+        bail!("Invalid duration '{input}': missing unit after '{digits}'");
+    }
+
+    Ok(Duration::from_secs(total))
+}
+
 /// Restore terminal to a clean state.
 ///
 /// This function:
@@ -233,6 +281,26 @@ mod tests {
         assert_eq!(format_elapsed(7200), "2h0m");
     }
 
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("90").unwrap(), Duration::from_secs(90));
+        assert_eq!(parse_duration("90s").unwrap(), Duration::from_secs(90));
+        assert_eq!(parse_duration("45m").unwrap(), Duration::from_secs(2700));
+        assert_eq!(parse_duration("2h").unwrap(), Duration::from_secs(7200));
+        assert_eq!(
+            parse_duration(" 1h30m ").unwrap(),
+            Duration::from_secs(5400)
+        );
+    }
+
+    #[test]
+    fn test_parse_duration_rejects_invalid() {
+        assert!(parse_duration("").is_err());
+        assert!(parse_duration("45x").is_err());
+        assert!(parse_duration("m").is_err());
+        assert!(parse_duration("1h30").is_err());
+    }
+
     #[test]
     fn test_format_elapsed_verbose_seconds() {
         assert_eq!(format_elapsed_verbose(0), "0s");
@@ -8,6 +8,8 @@
 //! - `${WORKTREE}` - The worktree root directory path
 //! - `${PROJECT_ROOT}` - Directory containing the project manifest (Cargo.toml, package.json, etc.)
 //! - `${STAGE_ID}` - The current stage identifier
+//! - `${outputs.<stage-id>.<key>}` - A value persisted by `loom stage output set`
+//! - `${outputs.self.<key>}` - One of the current stage's own outputs
 //!
 //! # Example
 //!
@@ -20,6 +22,13 @@
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
+use anyhow::{bail, Result};
+
+use crate::models::stage::StageOutput;
+
+/// Prefix of stage output variables (`outputs.<stage-id>.<key>`)
+pub const OUTPUTS_PREFIX: &str = "outputs.";
+
 /// Context for expanding variables in acceptance criteria commands.
 ///
 /// Variables are resolved at execution time based on the worktree and stage context.
@@ -64,6 +73,20 @@ impl CriteriaContext {
         self.variables.insert(key.to_string(), value.to_string());
     }
 
+    /// Add an `outputs.<stage-id>.<key>` variable for each of a stage's outputs.
+    ///
+    /// String values are used as-is; other values by their JSON text.
+    pub fn add_stage_outputs(&mut self, stage_id: &str, outputs: &[StageOutput]) {
+        for output in outputs {
+            let value = match output.value.as_str() {
+                Some(s) => s.to_string(),
+                None => output.value.to_string(),
+            };
+            self.variables
+                .insert(format!("{OUTPUTS_PREFIX}{stage_id}.{}", output.key), value);
+        }
+    }
+
     /// Get a variable value.
     pub fn get_variable(&self, key: &str) -> Option<&str> {
         self.variables.get(key).map(String::as_str)
@@ -88,6 +111,26 @@ impl CriteriaContext {
         result
     }
 
+    /// Expand all variables, failing on unresolved `${outputs.…}` references.
+    ///
+    /// Other unknown variables are left unchanged, as with `expand`, since they
+    /// are usually shell variables.
+    pub fn expand_outputs(&self, criterion: &str) -> Result<String> {
+        let missing: Vec<String> = self
+            .find_unresolved(criterion)
+            .into_iter()
+            .filter(|name| name.starts_with(OUTPUTS_PREFIX))
+            .map(|name| format!("${{{name}}}"))
+            .collect();
+        if !missing.is_empty() {
+            bail!(
+                "Unresolved stage output reference {} in '{criterion}'",
+                missing.join(", ")
+            );
+        }
+        Ok(self.expand(criterion))
+    }
+
     /// Check if a criterion contains any unresolved variables.
     ///
     /// Returns a list of variable names that were not resolved.
@@ -228,6 +271,34 @@ mod tests {
         assert!(unresolved.contains(&"WORKTREE".to_string()));
     }
 
+    #[test]
+    fn test_expand_outputs() {
+        let mut ctx = CriteriaContext::default();
+        let outputs = vec![
+            StageOutput {
+                key: "port".to_string(),
+                value: serde_json::json!(8080),
+                description: String::new(),
+            },
+            StageOutput {
+                key: "engine".to_string(),
+                value: serde_json::json!("postgres"),
+                description: String::new(),
+            },
+        ];
+        ctx.add_stage_outputs("detect-db", &outputs);
+
+        let expanded = ctx
+            .expand_outputs("check ${outputs.detect-db.engine}:${outputs.detect-db.port} ${HOME}")
+            .unwrap();
+        assert_eq!(expanded, "check postgres:8080 ${HOME}");
+
+        let err = ctx
+            .expand_outputs("echo ${outputs.detect-db.missing}")
+            .unwrap_err();
+        assert!(err.to_string().contains("${outputs.detect-db.missing}"));
+    }
+
     #[test]
     fn test_find_project_root_at_worktree() {
         let dir = tempdir().expect("failed to create temp dir");
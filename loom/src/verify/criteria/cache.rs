@@ -0,0 +1,69 @@
+//! Result cache lookups for acceptance criteria runs
+
+use chrono::Utc;
+use std::path::{Path, PathBuf};
+
+use super::result::CriterionResult;
+use crate::fs::criteria_cache::{cache_key, load_cache, save_cache, CacheEntry, StageCache};
+use crate::git::working_tree_hash;
+
+/// A stage's cached results for the current contents of its worktree
+pub(super) struct ResultCache {
+    stage_id: String,
+    work_dir: PathBuf,
+    dir: PathBuf,
+    tree: String,
+    entries: StageCache,
+    changed: bool,
+}
+
+impl ResultCache {
+    /// Open the cache for criteria run in `dir`; `None` when `dir` is not in
+    /// a git worktree, since its contents cannot be hashed
+    pub fn open(stage_id: &str, dir: &Path, work_dir: &Path) -> Option<Self> {
+        let dir = dir.canonicalize().ok()?;
+        let tree = working_tree_hash(&dir).ok()?;
+        Some(Self {
+            stage_id: stage_id.to_string(),
+            work_dir: work_dir.to_path_buf(),
+            entries: load_cache(stage_id, work_dir),
+            dir,
+            tree,
+            changed: false,
+        })
+    }
+
+    /// Cached passing result of an expanded command
+    pub fn lookup(&self, command: &str) -> Option<CriterionResult> {
+        self.entries
+            .contains_key(&self.key(command))
+            .then(|| CriterionResult::cached(command.to_string()))
+    }
+
+    /// Record a passing result of an expanded command
+    pub fn insert(&mut self, command: &str, result: &CriterionResult) {
+        let entry = CacheEntry {
+            command: command.to_string(),
+            tree: self.tree.clone(),
+            passed_at: Utc::now(),
+            duration_ms: result.duration.as_millis() as u64,
+        };
+        self.entries.insert(self.key(command), entry);
+        self.changed = true;
+    }
+
+    /// Write new entries, dropping those of earlier worktree contents
+    pub fn save(mut self) {
+        if !self.changed {
+            return;
+        }
+        self.entries.retain(|_, entry| entry.tree == self.tree);
+        if let Err(e) = save_cache(&self.stage_id, &self.entries, &self.work_dir) {
+            eprintln!("Warning: Failed to save criteria cache: {e:#}");
+        }
+    }
+
+    fn key(&self, command: &str) -> String {
+        cache_key(&self.tree, &self.dir, command)
+    }
+}
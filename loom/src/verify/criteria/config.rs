@@ -1,5 +1,6 @@
 //! Configuration types for acceptance criteria execution
 
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 /// Default timeout for command execution (5 minutes)
@@ -10,12 +11,15 @@ pub const DEFAULT_COMMAND_TIMEOUT: Duration = Duration::from_secs(300);
 pub struct CriteriaConfig {
     /// Maximum time to wait for a single command to complete
     pub command_timeout: Duration,
+    /// Work directory holding the result cache; `None` disables caching
+    pub cache_work_dir: Option<PathBuf>,
 }
 
 impl Default for CriteriaConfig {
     fn default() -> Self {
         Self {
             command_timeout: DEFAULT_COMMAND_TIMEOUT,
+            cache_work_dir: None,
         }
     }
 }
@@ -25,6 +29,14 @@ impl CriteriaConfig {
     pub fn with_timeout(timeout: Duration) -> Self {
         Self {
             command_timeout: timeout,
+            cache_work_dir: None,
         }
     }
+
+    /// Skip criteria that already passed on the same worktree contents,
+    /// using the cache under `work_dir`
+    pub fn with_cache(mut self, work_dir: &Path) -> Self {
+        self.cache_work_dir = Some(work_dir.to_path_buf());
+        self
+    }
 }
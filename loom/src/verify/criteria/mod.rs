@@ -37,8 +37,10 @@
 //! - The criterion is marked as failed with a timeout-specific error message
 //! - Subsequent criteria continue to execute (fail-fast is not the default)
 
+mod cache;
 mod config;
 mod executor;
+mod report;
 mod result;
 mod runner;
 
@@ -48,5 +50,6 @@ mod tests;
 // Re-export public types and functions
 pub use config::{CriteriaConfig, DEFAULT_COMMAND_TIMEOUT};
 pub use executor::{run_single_criterion, run_single_criterion_with_timeout};
+pub use report::{parse_report, TestCase, TestStatus};
 pub use result::{AcceptanceResult, CriterionResult};
 pub use runner::{run_acceptance, run_acceptance_with_config};
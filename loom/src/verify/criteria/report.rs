@@ -0,0 +1,266 @@
+//! Test report parsing for acceptance criteria
+//!
+//! A criterion with a `report` annotation has its JUnit XML, TAP or `cargo test`
+//! output parsed into individual test cases, so failures can name the tests
+//! that failed instead of pointing at the whole command output.
+
+use anyhow::{bail, Context, Result};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+use std::time::{Duration, SystemTime};
+
+use crate::plan::schema::ReportFormat;
+
+/// Maximum lines of a failure message kept per test case
+const MAX_MESSAGE_LINES: usize = 20;
+
+/// Slack for filesystems with coarse modification times
+const MTIME_SLACK: Duration = Duration::from_secs(2);
+
+static JUNIT_TESTCASE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?s)<testcase\b([^>]*?)(?:/>|>(.*?)</testcase>)").expect("Invalid regex")
+});
+static JUNIT_FAILURE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?s)<(failure|error)\b([^>]*?)(?:/>|>(.*?)</(?:failure|error)>)")
+        .expect("Invalid regex")
+});
+static XML_ATTRIBUTE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"([\w:-]+)\s*=\s*(?:"([^"]*)"|'([^']*)')"#).expect("Invalid regex")
+});
+static TAP_LINE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(not ok|ok)\b\s*\d*\s*(?:-\s*)?([^#]*)(?:#\s*(\w+))?").expect("Invalid regex")
+});
+static CARGO_TEST_LINE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^test (.+) \.\.\. (ok|FAILED|ignored)").expect("Invalid regex"));
+
+/// Outcome of a single test case
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TestStatus {
+    Passed,
+    Failed,
+    Skipped,
+}
+
+/// One test case from a criterion's test report
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TestCase {
+    pub name: String,
+    pub status: TestStatus,
+    /// Failure message, truncated to `MAX_MESSAGE_LINES` lines
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl TestCase {
+    fn new(name: impl Into<String>, status: TestStatus, message: Option<String>) -> Self {
+        let message = message
+            .map(|m| {
+                let lines: Vec<&str> = m.trim().lines().take(MAX_MESSAGE_LINES).collect();
+                lines.join("\n")
+            })
+            .filter(|m| !m.is_empty());
+        Self {
+            name: name.into().trim().to_string(),
+            status,
+            message,
+        }
+    }
+
+    pub fn failed(&self) -> bool {
+        self.status == TestStatus::Failed
+    }
+
+    /// Test name with the first line of its failure message, if any
+    pub fn summary(&self) -> String {
+        match self.message.as_deref().and_then(|m| m.lines().next()) {
+            Some(first) => format!("{}: {}", self.name, first.trim()),
+            None => self.name.clone(),
+        }
+    }
+}
+
+/// Parse the test report of a criterion that started running at `started`.
+///
+/// JUnit paths are resolved against `working_dir`; a report file that was
+/// not written since `started` is rejected as left over from an earlier run.
+pub fn parse_report(
+    format: &ReportFormat,
+    stdout: &str,
+    working_dir: Option<&Path>,
+    started: SystemTime,
+) -> Result<Vec<TestCase>> {
+    match format {
+        ReportFormat::Junit { path } => {
+            let path = working_dir.map_or_else(|| PathBuf::from(path), |dir| dir.join(path));
+            let modified = std::fs::metadata(&path)
+                .and_then(|m| m.modified())
+                .with_context(|| format!("JUnit report not found: {}", path.display()))?;
+            if modified + MTIME_SLACK < started {
+                bail!(
+                    "JUnit report {} was not updated by this run",
+                    path.display()
+                );
+            }
+            let xml = std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read JUnit report: {}", path.display()))?;
+            parse_junit(&xml)
+        }
+        ReportFormat::Tap => Ok(parse_tap(stdout)),
+        ReportFormat::CargoTest => Ok(parse_cargo_test(stdout)),
+    }
+}
+
+/// Parse the `<testcase>` elements of a JUnit XML report
+pub fn parse_junit(xml: &str) -> Result<Vec<TestCase>> {
+    if !xml.contains("<testsuite") {
+        bail!("Not a JUnit XML report: no <testsuite> element");
+    }
+
+    let mut cases = Vec::new();
+    for testcase in JUNIT_TESTCASE.captures_iter(xml) {
+        let attributes = xml_attributes(&testcase[1]);
+        let Some(name) = attribute(&attributes, "name") else {
+            continue;
+        };
+        let name = match attribute(&attributes, "classname") {
+            Some(class) if !class.is_empty() => format!("{class}.{name}"),
+            _ => name.to_string(),
+        };
+        let body = testcase.get(2).map_or("", |m| m.as_str());
+
+        let case = if let Some(failure) = JUNIT_FAILURE.captures(body) {
+            let failure_attributes = xml_attributes(&failure[2]);
+            let text = failure.get(3).map(|m| xml_text(m.as_str()));
+            let message = [
+                attribute(&failure_attributes, "message").map(str::to_string),
+                text,
+            ]
+            .into_iter()
+            .flatten()
+            .filter(|m| !m.trim().is_empty())
+            .collect::<Vec<_>>()
+            .join("\n");
+            TestCase::new(name, TestStatus::Failed, Some(message))
+        } else if body.contains("<skipped") {
+            TestCase::new(name, TestStatus::Skipped, None)
+        } else {
+            TestCase::new(name, TestStatus::Passed, None)
+        };
+        cases.push(case);
+    }
+    Ok(cases)
+}
+
+fn xml_attributes(tag: &str) -> Vec<(String, String)> {
+    XML_ATTRIBUTE
+        .captures_iter(tag)
+        .map(|c| {
+            let value = c.get(2).or_else(|| c.get(3)).map_or("", |m| m.as_str());
+            (c[1].to_string(), xml_unescape(value))
+        })
+        .collect()
+}
+
+fn attribute<'a>(attributes: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    attributes
+        .iter()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.as_str())
+}
+
+/// Element text with CDATA markers removed and entities unescaped
+fn xml_text(text: &str) -> String {
+    if let Some(inner) = text
+        .trim()
+        .strip_prefix("<![CDATA[")
+        .and_then(|t| t.strip_suffix("]]>"))
+    {
+        return inner.to_string();
+    }
+    xml_unescape(text)
+}
+
+fn xml_unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&#10;", "\n")
+        .replace("&amp;", "&")
+}
+
+/// Parse TAP test lines; diagnostics following a failure become its message
+pub fn parse_tap(output: &str) -> Vec<TestCase> {
+    let mut cases: Vec<TestCase> = Vec::new();
+    let mut diagnostics: Vec<&str> = Vec::new();
+
+    for line in output.lines() {
+        let Some(captures) = TAP_LINE.captures(line) else {
+            if !cases.is_empty() && (line.starts_with(' ') || line.starts_with('#')) {
+                diagnostics.push(line.trim_start_matches('#').trim());
+            }
+            continue;
+        };
+        attach_diagnostics(cases.last_mut(), &mut diagnostics);
+
+        let directive = captures.get(3).map(|m| m.as_str().to_ascii_lowercase());
+        let status = match (&captures[1], directive.as_deref()) {
+            (_, Some("skip")) | ("not ok", Some("todo")) => TestStatus::Skipped,
+            ("ok", _) => TestStatus::Passed,
+            _ => TestStatus::Failed,
+        };
+        cases.push(TestCase::new(&captures[2], status, None));
+    }
+    attach_diagnostics(cases.last_mut(), &mut diagnostics);
+    cases
+}
+
+fn attach_diagnostics(case: Option<&mut TestCase>, diagnostics: &mut Vec<&str>) {
+    if let Some(case) = case.filter(|c| c.failed()) {
+        let lines: Vec<&str> = diagnostics
+            .iter()
+            .copied()
+            .filter(|l| !l.is_empty() && *l != "---" && *l != "...")
+            .collect();
+        *case = TestCase::new(&case.name, case.status, Some(lines.join("\n")));
+    }
+    diagnostics.clear();
+}
+
+/// Parse libtest output; each failure's captured output becomes its message
+pub fn parse_cargo_test(output: &str) -> Vec<TestCase> {
+    let mut cases: Vec<TestCase> = output
+        .lines()
+        .filter_map(|line| CARGO_TEST_LINE.captures(line))
+        .map(|c| {
+            let status = match &c[2] {
+                "ok" => TestStatus::Passed,
+                "ignored" => TestStatus::Skipped,
+                _ => TestStatus::Failed,
+            };
+            TestCase::new(&c[1], status, None)
+        })
+        .collect();
+
+    // Failure output is printed in "---- <name> stdout ----" sections
+    let mut current: Option<(&str, Vec<&str>)> = None;
+    for line in output.lines().chain(std::iter::once("failures:")) {
+        let header = line
+            .strip_prefix("---- ")
+            .and_then(|l| l.strip_suffix(" stdout ----"));
+        if header.is_some() || line == "failures:" {
+            if let Some((name, lines)) = current.take() {
+                if let Some(case) = cases.iter_mut().find(|c| c.name == name && c.failed()) {
+                    *case = TestCase::new(name, TestStatus::Failed, Some(lines.join("\n")));
+                }
+            }
+            current = header.map(|name| (name, Vec::new()));
+        } else if let Some((_, lines)) = current.as_mut() {
+            lines.push(line);
+        }
+    }
+    cases
+}
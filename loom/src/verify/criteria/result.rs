@@ -2,6 +2,8 @@
 
 use std::time::Duration;
 
+use super::report::TestCase;
+
 /// Result of executing a single acceptance criterion (shell command)
 #[derive(Debug, Clone)]
 pub struct CriterionResult {
@@ -13,6 +15,11 @@ pub struct CriterionResult {
     pub duration: Duration,
     /// Whether the command was terminated due to timeout
     pub timed_out: bool,
+    /// Test cases parsed from the criterion's test report, if it has one
+    pub tests: Vec<TestCase>,
+    /// Whether the criterion was skipped because it already passed on the
+    /// same worktree contents
+    pub cached: bool,
 }
 
 impl CriterionResult {
@@ -34,6 +41,24 @@ impl CriterionResult {
             exit_code,
             duration,
             timed_out,
+            tests: Vec::new(),
+            cached: false,
+        }
+    }
+
+    /// Result of a criterion that already passed on the same worktree contents
+    pub fn cached(command: String) -> Self {
+        Self {
+            cached: true,
+            ..Self::new(
+                command,
+                true,
+                String::new(),
+                String::new(),
+                Some(0),
+                Duration::ZERO,
+                false,
+            )
         }
     }
 
@@ -42,6 +67,11 @@ impl CriterionResult {
         self.success
     }
 
+    /// Test cases of the criterion's report that failed
+    pub fn failed_tests(&self) -> impl Iterator<Item = &TestCase> {
+        self.tests.iter().filter(|t| t.failed())
+    }
+
     /// Get a summary of the result
     pub fn summary(&self) -> String {
         let status = if self.timed_out {
@@ -93,6 +123,27 @@ impl AcceptanceResult {
         }
     }
 
+    /// Evidence for a failure: each failed test of criteria with a test
+    /// report, otherwise the criterion's failure message
+    pub fn failure_evidence(&self) -> Vec<String> {
+        let AcceptanceResult::Failed { results, failures } = self else {
+            return Vec::new();
+        };
+        let mut evidence = Vec::new();
+        for (result, failure) in results.iter().filter(|r| !r.passed()).zip(failures) {
+            let failed_tests: Vec<String> = result
+                .failed_tests()
+                .map(|t| format!("Test failed: {}", t.summary()))
+                .collect();
+            if failed_tests.is_empty() {
+                evidence.push(failure.clone());
+            } else {
+                evidence.extend(failed_tests);
+            }
+        }
+        evidence
+    }
+
     /// Get total duration of all criteria
     pub fn total_duration(&self) -> Duration {
         self.results().iter().map(|r| r.duration).sum()
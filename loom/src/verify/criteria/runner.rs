@@ -1,12 +1,16 @@
 //! High-level acceptance criteria runner
 
-use anyhow::{Context, Result};
+use anyhow::{anyhow, Context, Result};
 use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
 
+use super::cache::ResultCache;
 use super::config::CriteriaConfig;
 use super::executor::run_single_criterion_with_timeout;
-use super::result::AcceptanceResult;
+use super::report::{parse_report, TestCase};
+use super::result::{AcceptanceResult, CriterionResult};
 use crate::models::stage::Stage;
+use crate::plan::schema::{AcceptanceCriterion, ReportFormat};
 use crate::verify::context::CriteriaContext;
 
 /// Run all acceptance criteria for a stage with default configuration
@@ -19,7 +23,8 @@ pub fn run_acceptance(stage: &Stage, working_dir: Option<&Path>) -> Result<Accep
 
 /// Run all acceptance criteria for a stage with custom configuration
 ///
-/// Executes each shell command sequentially and collects results.
+/// Executes each shell command sequentially, or concurrently when the stage
+/// sets `parallel`, and collects results in criterion order.
 /// Returns AllPassed if all commands exit with code 0, Failed otherwise.
 ///
 /// If `working_dir` is provided, commands will be executed in that directory.
@@ -33,6 +38,9 @@ pub fn run_acceptance(stage: &Stage, working_dir: Option<&Path>) -> Result<Accep
 ///
 /// Each command is subject to the timeout specified in `config`. Commands that
 /// exceed the timeout are terminated and marked as failed.
+///
+/// With a cache configured, criteria that already passed on the current
+/// worktree contents are not run again and are reported as cached.
 pub fn run_acceptance_with_config(
     stage: &Stage,
     working_dir: Option<&Path>,
@@ -44,13 +52,67 @@ pub fn run_acceptance_with_config(
         });
     }
 
-    // Build context for variable expansion
     let default_dir = PathBuf::from(".");
     let ctx_path = working_dir.unwrap_or(&default_dir);
-    let context = CriteriaContext::with_stage_id(ctx_path, &stage.id);
+    let full_commands = expand_commands(stage, ctx_path);
+
+    // The worktree is hashed before anything runs, so files the criteria
+    // write cannot make a result look valid for contents it never saw
+    let mut cache = config
+        .cache_work_dir
+        .as_deref()
+        .and_then(|work_dir| ResultCache::open(&stage.id, ctx_path, work_dir));
+    let cached: Vec<Option<CriterionResult>> = full_commands
+        .iter()
+        .map(|command| cache.as_ref().and_then(|c| c.lookup(command)))
+        .collect();
+
+    let pending: Vec<&str> = full_commands
+        .iter()
+        .zip(&cached)
+        .filter_map(|(command, hit)| hit.is_none().then_some(command.as_str()))
+        .collect();
+    let parallel = stage.parallel.unwrap_or(false);
+    let mut executed =
+        run_commands(&pending, working_dir, config.command_timeout, parallel).into_iter();
 
     let mut results = Vec::new();
     let mut failures = Vec::new();
+    for ((command, full_command), hit) in stage.acceptance.iter().zip(&full_commands).zip(cached) {
+        let mut result = match hit {
+            Some(result) => result,
+            None => {
+                let run = executed.next().context("Missing criterion result")?;
+                let result = finish_run(command, run, working_dir)?;
+                if let Some(cache) = cache.as_mut().filter(|_| result.success) {
+                    cache.insert(full_command, &result);
+                }
+                result
+            }
+        };
+
+        if !result.success {
+            failures.push(failure_reason(command, &result, config));
+        }
+
+        // Store result with original command for cleaner output
+        result.command = command.command.clone();
+        results.push(result);
+    }
+    if let Some(cache) = cache {
+        cache.save();
+    }
+
+    if failures.is_empty() {
+        Ok(AcceptanceResult::AllPassed { results })
+    } else {
+        Ok(AcceptanceResult::Failed { results, failures })
+    }
+}
+
+/// Expand context variables in each criterion and prepend the setup commands
+fn expand_commands(stage: &Stage, ctx_path: &Path) -> Vec<String> {
+    let context = CriteriaContext::with_stage_id(ctx_path, &stage.id);
 
     // Build setup prefix if setup commands are defined (also expand variables in setup)
     let setup_prefix = if stage.setup.is_empty() {
@@ -60,45 +122,102 @@ pub fn run_acceptance_with_config(
         Some(expanded_setup.join(" && "))
     };
 
-    for command in &stage.acceptance {
-        // Expand context variables in the command
-        let expanded_command = context.expand(command);
+    stage
+        .acceptance
+        .iter()
+        .map(|command| {
+            let expanded_command = context.expand(command);
+            match &setup_prefix {
+                Some(prefix) => format!("{prefix} && {expanded_command}"),
+                None => expanded_command,
+            }
+        })
+        .collect()
+}
 
-        // Combine setup commands with criterion if setup is defined
-        let full_command = match &setup_prefix {
-            Some(prefix) => format!("{prefix} && {expanded_command}"),
-            None => expanded_command,
-        };
+type TimedRun = (SystemTime, Result<CriterionResult>);
 
-        let result =
-            run_single_criterion_with_timeout(&full_command, working_dir, config.command_timeout)
-                .with_context(|| format!("Failed to execute criterion: {command}"))?;
+/// Run commands one after another, or all at once when `parallel`;
+/// results keep the order of `commands`
+fn run_commands(
+    commands: &[&str],
+    working_dir: Option<&Path>,
+    timeout: Duration,
+    parallel: bool,
+) -> Vec<TimedRun> {
+    let run = |command: &str| -> TimedRun {
+        let started = SystemTime::now();
+        (
+            started,
+            run_single_criterion_with_timeout(command, working_dir, timeout),
+        )
+    };
+    if !parallel || commands.len() < 2 {
+        return commands.iter().map(|command| run(command)).collect();
+    }
 
-        if !result.success {
-            let failure_reason = if result.timed_out {
-                format!(
-                    "Command '{}' timed out after {}s",
-                    command,
-                    config.command_timeout.as_secs()
-                )
-            } else {
-                format!(
-                    "Command '{}' failed with exit code {:?}",
-                    command, result.exit_code
-                )
-            };
-            failures.push(failure_reason);
-        }
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = commands
+            .iter()
+            .map(|command| scope.spawn(move || run(command)))
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| {
+                handle.join().unwrap_or_else(|_| {
+                    (SystemTime::now(), Err(anyhow!("Criterion thread panicked")))
+                })
+            })
+            .collect()
+    })
+}
 
-        // Store result with original command for cleaner output
-        let mut result_with_original = result;
-        result_with_original.command = command.clone();
-        results.push(result_with_original);
+/// Attach the parsed test report to a criterion that ran
+fn finish_run(
+    criterion: &AcceptanceCriterion,
+    (started, result): TimedRun,
+    working_dir: Option<&Path>,
+) -> Result<CriterionResult> {
+    let mut result = result.with_context(|| format!("Failed to execute criterion: {criterion}"))?;
+    if let Some(format) = &criterion.report {
+        result.tests = read_test_report(format, &result.stdout, working_dir, started);
     }
+    Ok(result)
+}
 
-    if failures.is_empty() {
-        Ok(AcceptanceResult::AllPassed { results })
+fn failure_reason(command: &str, result: &CriterionResult, config: &CriteriaConfig) -> String {
+    let reason = if result.timed_out {
+        format!(
+            "Command '{}' timed out after {}s",
+            command,
+            config.command_timeout.as_secs()
+        )
     } else {
-        Ok(AcceptanceResult::Failed { results, failures })
+        format!(
+            "Command '{}' failed with exit code {:?}",
+            command, result.exit_code
+        )
+    };
+    match result.failed_tests().count() {
+        0 => reason,
+        1 => format!("{reason} (1 test failed)"),
+        n => format!("{reason} ({n} tests failed)"),
     }
 }
+
+/// Parse a criterion's test report; an unreadable report only warns
+fn read_test_report(
+    format: &str,
+    stdout: &str,
+    working_dir: Option<&Path>,
+    started: SystemTime,
+) -> Vec<TestCase> {
+    let parsed = format
+        .parse::<ReportFormat>()
+        .map_err(anyhow::Error::msg)
+        .and_then(|format| parse_report(&format, stdout, working_dir, started));
+    parsed.unwrap_or_else(|e| {
+        eprintln!("Warning: Failed to parse test report '{format}': {e:#}");
+        Vec::new()
+    })
+}
@@ -16,3 +16,13 @@ fn test_criteria_config_with_timeout() {
     let config = CriteriaConfig::with_timeout(Duration::from_secs(60));
     assert_eq!(config.command_timeout, Duration::from_secs(60));
 }
+
+#[test]
+fn test_criteria_config_with_cache() {
+    assert!(CriteriaConfig::default().cache_work_dir.is_none());
+    let config = CriteriaConfig::default().with_cache(std::path::Path::new(".work"));
+    assert_eq!(
+        config.cache_work_dir.as_deref(),
+        Some(std::path::Path::new(".work"))
+    );
+}
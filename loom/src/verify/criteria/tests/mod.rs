@@ -2,6 +2,7 @@
 
 mod config_tests;
 mod executor_tests;
+mod report_tests;
 mod result_tests;
 mod runner_tests;
 mod setup_tests;
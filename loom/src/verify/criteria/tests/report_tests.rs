@@ -0,0 +1,122 @@
+//! Tests for test report parsing
+
+use std::time::{Duration, SystemTime};
+
+use tempfile::TempDir;
+
+use crate::models::stage::Stage;
+use crate::plan::schema::{AcceptanceCriterion, ReportFormat};
+use crate::verify::criteria::report::{parse_cargo_test, parse_junit, parse_tap};
+use crate::verify::criteria::runner::run_acceptance;
+use crate::verify::criteria::{parse_report, TestStatus};
+
+const JUNIT: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites>
+  <testsuite name="api" tests="3">
+    <testcase classname="api.users" name="creates_user" time="0.01"/>
+    <testcase classname="api.users" name="rejects_duplicate">
+      <failure message="expected 409, got 200">AssertionError: status &lt;200&gt;</failure>
+    </testcase>
+    <testcase classname="api.users" name="slow_path"><skipped/></testcase>
+  </testsuite>
+</testsuites>
+"#;
+
+#[test]
+fn test_parse_junit() {
+    let cases = parse_junit(JUNIT).unwrap();
+    assert_eq!(cases.len(), 3);
+    assert_eq!(cases[0].name, "api.users.creates_user");
+    assert_eq!(cases[0].status, TestStatus::Passed);
+    assert_eq!(cases[1].status, TestStatus::Failed);
+    assert_eq!(
+        cases[1].message.as_deref(),
+        Some("expected 409, got 200\nAssertionError: status <200>")
+    );
+    assert_eq!(
+        cases[1].summary(),
+        "api.users.rejects_duplicate: expected 409, got 200"
+    );
+    assert_eq!(cases[2].status, TestStatus::Skipped);
+
+    assert!(parse_junit("<html></html>").is_err());
+}
+
+#[test]
+fn test_parse_tap() {
+    let output = "TAP version 13\n1..4\nok 1 - parses config\nnot ok 2 - rejects bad input\n  ---\n  message: 'expected error'\n  ...\nok 3 - network # SKIP offline\nnot ok 4 - future feature # TODO\n";
+    let cases = parse_tap(output);
+    assert_eq!(cases.len(), 4);
+    assert_eq!(cases[0].name, "parses config");
+    assert_eq!(cases[0].status, TestStatus::Passed);
+    assert_eq!(cases[1].name, "rejects bad input");
+    assert_eq!(cases[1].status, TestStatus::Failed);
+    assert_eq!(
+        cases[1].message.as_deref(),
+        Some("message: 'expected error'")
+    );
+    assert_eq!(cases[2].status, TestStatus::Skipped);
+    assert_eq!(cases[3].status, TestStatus::Skipped);
+}
+
+#[test]
+fn test_parse_cargo_test() {
+    let output = "\
+running 3 tests
+test config::tests::loads ... ok
+test config::tests::rejects_empty ... FAILED
+test net::tests::live ... ignored
+
+failures:
+
+---- config::tests::rejects_empty stdout ----
+thread 'config::tests::rejects_empty' panicked at src/config.rs:10:5:
+assertion failed: result.is_err()
+
+failures:
+    config::tests::rejects_empty
+
+test result: FAILED. 1 passed; 1 failed; 1 ignored
+";
+    let cases = parse_cargo_test(output);
+    assert_eq!(cases.len(), 3);
+    assert_eq!(cases[0].status, TestStatus::Passed);
+    assert_eq!(cases[1].name, "config::tests::rejects_empty");
+    assert_eq!(cases[1].status, TestStatus::Failed);
+    let message = cases[1].message.as_deref().unwrap();
+    assert!(message.starts_with("thread 'config::tests::rejects_empty' panicked"));
+    assert!(message.ends_with("assertion failed: result.is_err()"));
+    assert_eq!(cases[2].status, TestStatus::Skipped);
+}
+
+#[test]
+fn test_parse_report_rejects_stale_junit_file() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(temp_dir.path().join("junit.xml"), JUNIT).unwrap();
+    let format = ReportFormat::Junit {
+        path: "junit.xml".to_string(),
+    };
+
+    let fresh = parse_report(&format, "", Some(temp_dir.path()), SystemTime::now()).unwrap();
+    assert_eq!(fresh.len(), 3);
+
+    let later = SystemTime::now() + Duration::from_secs(60);
+    let err = parse_report(&format, "", Some(temp_dir.path()), later).unwrap_err();
+    assert!(err.to_string().contains("not updated by this run"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_run_acceptance_lists_failed_tests() {
+    let mut stage = Stage::new("test".to_string(), None);
+    stage.acceptance = vec![AcceptanceCriterion::with_report(
+        "printf 'ok 1 - a\\nnot ok 2 - b\\n# boom\\n'; exit 1",
+        "tap",
+    )];
+
+    let result = run_acceptance(&stage, None).unwrap();
+    assert!(!result.all_passed());
+    assert_eq!(result.results()[0].tests.len(), 2);
+    assert!(result.failures()[0].ends_with("(1 test failed)"));
+    assert_eq!(result.failure_evidence(), vec!["Test failed: b: boom"]);
+}
@@ -1,7 +1,11 @@
 //! Tests for acceptance runner
 
+use tempfile::TempDir;
+
+use crate::git::run_git_checked;
 use crate::models::stage::Stage;
-use crate::verify::criteria::runner::run_acceptance;
+use crate::verify::criteria::runner::{run_acceptance, run_acceptance_with_config};
+use crate::verify::criteria::CriteriaConfig;
 
 #[test]
 fn test_run_acceptance_empty() {
@@ -51,3 +55,62 @@ fn test_run_acceptance_some_fail() {
     assert_eq!(result.failed_count(), 1);
     assert_eq!(result.failures().len(), 1);
 }
+
+#[cfg(unix)]
+#[test]
+fn test_run_acceptance_parallel_keeps_criterion_order() {
+    let mut stage = Stage::new("test".to_string(), None);
+    stage.parallel = Some(true);
+    stage.add_acceptance_criterion("sleep 0.2; echo slow".to_string());
+    stage.add_acceptance_criterion("false".to_string());
+    stage.add_acceptance_criterion("echo fast".to_string());
+
+    let result = run_acceptance(&stage, None).unwrap();
+
+    let outputs: Vec<&str> = result.results().iter().map(|r| r.stdout.trim()).collect();
+    assert_eq!(outputs, vec!["slow", "", "fast"]);
+    assert_eq!(result.results()[1].command, "false");
+    assert_eq!(
+        result.failures(),
+        &["Command 'false' failed with exit code Some(1)"]
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn test_run_acceptance_skips_cached_passes_until_worktree_changes() {
+    let repo = TempDir::new().unwrap();
+    let work = TempDir::new().unwrap();
+    for args in [
+        &["init", "-q"][..],
+        &["config", "user.email", "test@example.com"],
+        &["config", "user.name", "Test"],
+    ] {
+        run_git_checked(args, repo.path()).unwrap();
+    }
+    std::fs::write(repo.path().join(".gitignore"), "runs\n").unwrap();
+
+    let mut stage = Stage::new("cached".to_string(), None);
+    stage.add_acceptance_criterion("echo run >> runs".to_string());
+    stage.add_acceptance_criterion("false".to_string());
+    let config = CriteriaConfig::default().with_cache(work.path());
+    let runs = || {
+        std::fs::read_to_string(repo.path().join("runs"))
+            .unwrap()
+            .lines()
+            .count()
+    };
+
+    let first = run_acceptance_with_config(&stage, Some(repo.path()), &config).unwrap();
+    assert!(!first.results()[0].cached);
+    let second = run_acceptance_with_config(&stage, Some(repo.path()), &config).unwrap();
+    assert!(second.results()[0].cached);
+    assert!(!second.results()[1].cached, "failures are never cached");
+    assert_eq!(runs(), 1);
+
+    // An uncommitted change invalidates the cache
+    std::fs::write(repo.path().join("lib.rs"), "// changed\n").unwrap();
+    let third = run_acceptance_with_config(&stage, Some(repo.path()), &config).unwrap();
+    assert!(!third.results()[0].cached);
+    assert_eq!(runs(), 2);
+}
@@ -3,6 +3,7 @@ pub mod before_after;
 pub mod context;
 pub mod criteria;
 pub mod goal_backward;
+pub mod outputs;
 pub mod transitions;
 pub mod utils;
 
@@ -20,6 +21,7 @@ pub use criteria::{
 pub use goal_backward::{
     run_goal_backward_verification, GapType, GoalBackwardResult, VerificationGap,
 };
+pub use outputs::{interpolate_definition_outputs, interpolate_stage_outputs};
 pub use transitions::{
     list_all_stages, load_stage, save_stage, serialize_stage_to_markdown, transition_stage,
     trigger_dependents,
@@ -0,0 +1,226 @@
+//! Stage output interpolation
+//!
+//! `${outputs.<stage-id>.<key>}` in a stage's verification commands resolves
+//! to a value another stage persisted with `loom stage output set`, and
+//! `${outputs.self.<key>}` to one of the stage's own outputs. Commands are
+//! interpolated right before they run; a reference that does not resolve is
+//! an error rather than a command silently run with the literal placeholder.
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use crate::models::stage::{Stage, StageOutput};
+use crate::plan::schema::{AcceptanceCriterion, StageDefinition, TruthCheck, WiringTest};
+use crate::verify::context::{CriteriaContext, OUTPUTS_PREFIX};
+use crate::verify::transitions::list_all_stages;
+
+/// Stage ID that refers to the stage being verified
+pub const SELF_STAGE: &str = "self";
+
+/// Interpolate outputs into a stage's acceptance, setup and check commands
+pub fn interpolate_stage_outputs(stage: &mut Stage, work_dir: &Path) -> Result<()> {
+    let own = stage.outputs.clone();
+    let commands = command_fields(
+        &mut stage.acceptance,
+        [&mut stage.setup, &mut stage.truths],
+        [
+            &mut stage.truth_checks,
+            &mut stage.before_stage,
+            &mut stage.after_stage,
+        ],
+        &mut stage.wiring_tests,
+    );
+    interpolate(&stage.id, Some(&own), commands, work_dir)
+}
+
+/// Interpolate outputs into a stage's before-stage checks only.
+///
+/// These run before the stage does, when criteria referencing outputs the
+/// stage has yet to set cannot resolve.
+pub fn interpolate_check_outputs(
+    stage_id: &str,
+    own: &[StageOutput],
+    checks: &mut [TruthCheck],
+    work_dir: &Path,
+) -> Result<()> {
+    let commands = checks.iter_mut().map(|check| &mut check.command).collect();
+    interpolate(stage_id, Some(own), commands, work_dir)
+}
+
+/// Interpolate outputs into a plan stage definition's commands.
+///
+/// The stage's own outputs are read from its persisted stage file.
+pub fn interpolate_definition_outputs(def: &mut StageDefinition, work_dir: &Path) -> Result<()> {
+    let commands = command_fields(
+        &mut def.acceptance,
+        [&mut def.setup, &mut def.truths],
+        [
+            &mut def.truth_checks,
+            &mut def.before_stage,
+            &mut def.after_stage,
+        ],
+        &mut def.wiring_tests,
+    );
+    interpolate(&def.id, None, commands, work_dir)
+}
+
+/// Every command string of a stage that may reference outputs, including
+/// the report paths of acceptance criteria
+fn command_fields<'a>(
+    acceptance: &'a mut [AcceptanceCriterion],
+    lists: [&'a mut Vec<String>; 2],
+    checks: [&'a mut Vec<TruthCheck>; 3],
+    wiring_tests: &'a mut [WiringTest],
+) -> Vec<&'a mut String> {
+    let mut commands: Vec<&mut String> = acceptance
+        .iter_mut()
+        .flat_map(|c| std::iter::once(&mut c.command).chain(c.report.as_mut()))
+        .collect();
+    commands.extend(lists.into_iter().flat_map(|l| l.iter_mut()));
+    commands.extend(
+        checks
+            .into_iter()
+            .flat_map(|c| c.iter_mut().map(|check| &mut check.command)),
+    );
+    commands.extend(wiring_tests.iter_mut().map(|test| &mut test.command));
+    commands
+}
+
+fn interpolate(
+    stage_id: &str,
+    own: Option<&[StageOutput]>,
+    commands: Vec<&mut String>,
+    work_dir: &Path,
+) -> Result<()> {
+    let marker = format!("${{{OUTPUTS_PREFIX}");
+    if !commands.iter().any(|c| c.contains(&marker)) {
+        return Ok(());
+    }
+
+    let context = outputs_context(stage_id, own, work_dir)?;
+    for command in commands {
+        *command = context
+            .expand_outputs(command)
+            .with_context(|| format!("Cannot interpolate outputs for stage '{stage_id}'"))?;
+    }
+    Ok(())
+}
+
+/// Context holding the persisted outputs of every stage, plus `self`
+fn outputs_context(
+    stage_id: &str,
+    own: Option<&[StageOutput]>,
+    work_dir: &Path,
+) -> Result<CriteriaContext> {
+    let stages = list_all_stages(work_dir).context("Failed to load stage outputs")?;
+    let mut context = CriteriaContext::default();
+    for stage in &stages {
+        context.add_stage_outputs(&stage.id, &stage.outputs);
+    }
+
+    let own = match own {
+        Some(outputs) => outputs,
+        None => stages
+            .iter()
+            .find(|s| s.id == stage_id)
+            .map_or(&[][..], |s| &s.outputs[..]),
+    };
+    context.add_stage_outputs(SELF_STAGE, own);
+    Ok(context)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::verify::transitions::save_stage;
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    fn output(key: &str, value: serde_json::Value) -> StageOutput {
+        StageOutput {
+            key: key.to_string(),
+            value,
+            description: String::new(),
+        }
+    }
+
+    fn stage(id: &str) -> Stage {
+        let mut stage = Stage::new(id.to_string(), None);
+        stage.id = id.to_string();
+        stage
+    }
+
+    #[test]
+    fn test_interpolate_stage_outputs() {
+        let temp_dir = TempDir::new().unwrap();
+        let work_dir = temp_dir.path();
+        let mut server = stage("server");
+        server.outputs = vec![output("port", json!(8080))];
+        save_stage(&server, work_dir).unwrap();
+
+        let mut client = stage("client");
+        client.outputs = vec![output("binary", json!("target/client"))];
+        client.acceptance = vec![AcceptanceCriterion::with_report(
+            "${outputs.self.binary} --port ${outputs.server.port}",
+            "junit:${outputs.self.binary}.xml",
+        )];
+        client.setup = vec!["cd ${WORKTREE}".to_string()];
+        client.before_stage = vec![TruthCheck {
+            command: "curl localhost:${outputs.server.port}".to_string(),
+            stdout_contains: vec![],
+            stdout_not_contains: vec![],
+            stderr_empty: None,
+            exit_code: None,
+            description: None,
+        }];
+
+        interpolate_stage_outputs(&mut client, work_dir).unwrap();
+        assert_eq!(client.acceptance, vec!["target/client --port 8080"]);
+        assert_eq!(
+            client.acceptance[0].report.as_deref(),
+            Some("junit:target/client.xml")
+        );
+        assert_eq!(client.setup, vec!["cd ${WORKTREE}"]);
+        assert_eq!(client.before_stage[0].command, "curl localhost:8080");
+    }
+
+    #[test]
+    fn test_before_stage_checks_ignore_outputs_not_yet_set() {
+        let temp_dir = TempDir::new().unwrap();
+        let work_dir = temp_dir.path();
+        let mut server = stage("server");
+        server.outputs = vec![output("port", json!(8080))];
+        save_stage(&server, work_dir).unwrap();
+
+        let mut client = stage("client");
+        client.acceptance = vec!["curl ${outputs.self.url}".into()];
+        client.before_stage = vec![TruthCheck {
+            command: "nc -z localhost ${outputs.server.port}".to_string(),
+            stdout_contains: vec![],
+            stdout_not_contains: vec![],
+            stderr_empty: None,
+            exit_code: None,
+            description: None,
+        }];
+
+        let mut checks = client.before_stage.clone();
+        interpolate_check_outputs(&client.id, &client.outputs, &mut checks, work_dir).unwrap();
+        assert_eq!(checks[0].command, "nc -z localhost 8080");
+        assert!(interpolate_stage_outputs(&mut client, work_dir).is_err());
+    }
+
+    #[test]
+    fn test_unresolved_output_is_an_error() {
+        let temp_dir = TempDir::new().unwrap();
+        let work_dir = temp_dir.path();
+        save_stage(&stage("server"), work_dir).unwrap();
+
+        let mut client = stage("client");
+        client.truths = vec!["test -f ${outputs.server.pidfile}".to_string()];
+        let err = interpolate_stage_outputs(&mut client, work_dir).unwrap_err();
+        let message = format!("{err:#}");
+        assert!(message.contains("stage 'client'"), "{message}");
+        assert!(message.contains("${outputs.server.pidfile}"), "{message}");
+    }
+}
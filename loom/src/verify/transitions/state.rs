@@ -99,7 +99,8 @@ pub fn trigger_dependents(completed_stage_id: &str, work_dir: &Path) -> Result<V
 
 /// Check if all dependencies of a stage are satisfied
 ///
-/// A dependency is satisfied if its status is Completed AND merged is true.
+/// A dependency is satisfied if its status is Completed AND merged is true,
+/// or it was skipped because its `when:` condition was false.
 /// This ensures dependent stages can use main as their base, containing all
 /// dependency work.
 ///
@@ -122,10 +123,7 @@ pub fn are_all_dependencies_satisfied(stage: &Stage, work_dir: &Path) -> Result<
             )
         })?;
 
-        if dep_stage.status != StageStatus::Completed {
-            return Ok(false);
-        }
-        if !dep_stage.merged {
+        if !dep_stage.satisfies_dependents() {
             return Ok(false);
         }
     }
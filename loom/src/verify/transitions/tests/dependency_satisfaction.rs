@@ -89,3 +89,29 @@ fn are_all_dependencies_satisfied_requires_merged_flag() {
         "Dependency should be satisfied when Completed AND merged=true"
     );
 }
+
+#[test]
+fn are_all_dependencies_satisfied_by_condition_skip_only() {
+    let temp_dir = TempDir::new().unwrap();
+    let work_dir = temp_dir.path();
+
+    let mut stage1 = create_test_stage("stage-1", "Stage 1", StageStatus::Queued);
+    stage1
+        .try_skip(Some("No longer needed".to_string()))
+        .unwrap();
+    save_stage(&stage1, work_dir).expect("Should save stage 1");
+
+    let mut stage2 = create_test_stage("stage-2", "Stage 2", StageStatus::WaitingForDeps);
+    stage2.add_dependency("stage-1".to_string());
+
+    // A manually skipped dependency does not satisfy dependents
+    assert!(!are_all_dependencies_satisfied(&stage2, work_dir).unwrap());
+
+    let mut stage1 = create_test_stage("stage-1", "Stage 1", StageStatus::Queued);
+    stage1
+        .try_skip_unmet_condition("Condition not met".to_string())
+        .unwrap();
+    save_stage(&stage1, work_dir).expect("Should save stage 1");
+
+    assert!(are_all_dependencies_satisfied(&stage2, work_dir).unwrap());
+}
@@ -20,7 +20,7 @@ fn test_valid_acceptance_criteria() {
 
     for criterion in valid_criteria {
         let mut stage = create_valid_stage("test-stage", "Test");
-        stage.acceptance.push(criterion.to_string());
+        stage.acceptance.push(criterion.into());
         let metadata = create_metadata(vec![stage]);
         let result = validate(&metadata);
         assert!(
@@ -36,7 +36,7 @@ fn test_valid_acceptance_criteria() {
 #[test]
 fn test_empty_acceptance_criterion_rejected() {
     let mut stage = create_valid_stage("test-stage", "Test Stage");
-    stage.acceptance.push("".to_string());
+    stage.acceptance.push("".into());
 
     let metadata = create_metadata(vec![stage]);
     let result = validate(&metadata);
@@ -56,7 +56,7 @@ fn test_whitespace_only_acceptance_criterion_rejected() {
 
     for criterion in whitespace_criteria {
         let mut stage = create_valid_stage("test-stage", "Test Stage");
-        stage.acceptance.push(criterion.to_string());
+        stage.acceptance.push(criterion.into());
 
         let metadata = create_metadata(vec![stage]);
         let result = validate(&metadata);
@@ -81,7 +81,7 @@ fn test_control_chars_in_acceptance_rejected() {
 
     for (name, criterion) in control_chars {
         let mut stage = create_valid_stage("test-stage", "Test Stage");
-        stage.acceptance.push(criterion.to_string());
+        stage.acceptance.push(criterion.into());
 
         let metadata = create_metadata(vec![stage]);
         let result = validate(&metadata);
@@ -109,7 +109,7 @@ fn test_allowed_whitespace_in_acceptance() {
 
     for criterion in allowed_whitespace {
         let mut stage = create_valid_stage("test-stage", "Test Stage");
-        stage.acceptance.push(criterion.to_string());
+        stage.acceptance.push(criterion.into());
 
         let metadata = create_metadata(vec![stage]);
         let result = validate(&metadata);
@@ -129,7 +129,7 @@ fn test_acceptance_criterion_too_long_rejected() {
     let long_criterion = "a".repeat(1025);
 
     let mut stage = create_valid_stage("test-stage", "Test Stage");
-    stage.acceptance.push(long_criterion);
+    stage.acceptance.push(long_criterion.into());
 
     let metadata = create_metadata(vec![stage]);
     let result = validate(&metadata);
@@ -146,10 +146,10 @@ fn test_acceptance_criterion_too_long_rejected() {
 #[test]
 fn test_multiple_invalid_acceptance_criteria() {
     let mut stage = create_valid_stage("test-stage", "Test Stage");
-    stage.acceptance.push("".to_string());
-    stage.acceptance.push("   ".to_string());
-    stage.acceptance.push("valid command".to_string());
-    stage.acceptance.push("\t\n".to_string());
+    stage.acceptance.push("".into());
+    stage.acceptance.push("   ".into());
+    stage.acceptance.push("valid command".into());
+    stage.acceptance.push("\t\n".into());
 
     let metadata = create_metadata(vec![stage]);
     let result = validate(&metadata);
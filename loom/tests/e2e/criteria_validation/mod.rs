@@ -26,9 +26,12 @@ pub(crate) fn create_valid_stage(id: &str, name: &str) -> StageDefinition {
         dependencies: vec![],
         parallel_group: None,
         acceptance: vec![],
+        parallel: None,
         setup: vec![],
         files: vec![],
         auto_merge: None,
+        merge_strategy: None,
+        overlap: None,
         working_dir: ".".to_string(),
         sandbox: Default::default(),
         stage_type: StageType::default(),
@@ -42,6 +45,12 @@ pub(crate) fn create_valid_stage(id: &str, name: &str) -> StageDefinition {
         before_stage: vec![],
         after_stage: vec![],
         context_budget: None,
+        timeout: None,
+        budget: None,
+        model: None,
+        for_each: None,
+        when: None,
+        output_keys: vec![],
         execution_mode: None,
         bug_fix: None,
         regression_test: None,
@@ -55,7 +64,16 @@ pub(crate) fn create_metadata(stages: Vec<StageDefinition>) -> LoomMetadata {
             version: 1,
             sandbox: Default::default(),
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             change_impact: None,
+            default_timeout: None,
+            parallel: None,
+            budget: None,
+            models: None,
+            notifiers: Vec::new(),
+            publish: None,
+            promote_decisions: None,
             stages,
         },
     }
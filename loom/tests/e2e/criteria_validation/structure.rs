@@ -14,7 +14,16 @@ fn test_unsupported_version_rejected() {
             version: 2,
             sandbox: Default::default(),
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             change_impact: None,
+            default_timeout: None,
+            parallel: None,
+            budget: None,
+            models: None,
+            notifiers: Vec::new(),
+            publish: None,
+            promote_decisions: None,
             stages: vec![create_valid_stage("stage-1", "Test")],
         },
     };
@@ -36,7 +45,16 @@ fn test_empty_stages_rejected() {
             version: 1,
             sandbox: Default::default(),
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             change_impact: None,
+            default_timeout: None,
+            parallel: None,
+            budget: None,
+            models: None,
+            notifiers: Vec::new(),
+            publish: None,
+            promote_decisions: None,
             stages: vec![],
         },
     };
@@ -95,12 +113,21 @@ fn test_multiple_errors_accumulated() {
             version: 2,
             sandbox: Default::default(),
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             change_impact: None,
+            default_timeout: None,
+            parallel: None,
+            budget: None,
+            models: None,
+            notifiers: Vec::new(),
+            publish: None,
+            promote_decisions: None,
             stages: vec![create_valid_stage("", ""), {
                 let mut s = create_valid_stage("stage-2", "Stage Two");
                 s.dependencies.push("nonexistent".to_string());
                 s.dependencies.push("stage-2".to_string());
-                s.acceptance.push("".to_string());
+                s.acceptance.push("".into());
                 s
             }],
         },
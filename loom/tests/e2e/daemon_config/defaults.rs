@@ -41,6 +41,7 @@ fn test_orchestrator_config_custom_values() {
         max_skill_recommendations: 5,
         sandbox_config: SandboxConfig::default(),
         shutdown_flag: None,
+        control_queue: None,
     };
 
     assert_eq!(config.max_parallel_sessions, 8);
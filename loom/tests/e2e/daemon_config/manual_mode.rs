@@ -65,6 +65,7 @@ fn test_orchestrator_with_manual_mode() {
         max_skill_recommendations: 5,
         sandbox_config: SandboxConfig::default(),
         shutdown_flag: None,
+        control_queue: None,
     };
 
     let mut orchestrator =
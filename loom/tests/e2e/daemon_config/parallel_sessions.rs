@@ -137,6 +137,7 @@ fn test_orchestrator_respects_max_parallel_sessions() {
         max_skill_recommendations: 5,
         sandbox_config: SandboxConfig::default(),
         shutdown_flag: None,
+        control_queue: None,
     };
 
     let mut orchestrator = Orchestrator::new(config, graph).expect("Should create orchestrator");
@@ -41,6 +41,7 @@ fn test_orchestrator_creation_with_config() {
         max_skill_recommendations: 5,
         sandbox_config: SandboxConfig::default(),
         shutdown_flag: None,
+        control_queue: None,
     };
 
     let orchestrator = Orchestrator::new(config.clone(), graph);
@@ -65,10 +66,13 @@ fn test_auto_merge_config_cascade() {
         dependencies: vec![],
         parallel_group: None,
         acceptance: vec![],
+        parallel: None,
         setup: vec![],
         files: vec![],
         auto_merge: Some(true), // Stage-level override
         working_dir: ".".to_string(),
+        merge_strategy: None,
+        overlap: None,
         sandbox: Default::default(),
         stage_type: loom::plan::schema::StageType::default(),
         truths: vec![],
@@ -80,6 +84,12 @@ fn test_auto_merge_config_cascade() {
         before_stage: vec![],
         after_stage: vec![],
         context_budget: None,
+        timeout: None,
+        budget: None,
+        model: None,
+        for_each: None,
+        when: None,
+        output_keys: vec![],
         execution_mode: None,
         bug_fix: None,
         regression_test: None,
@@ -95,10 +105,13 @@ fn test_auto_merge_config_cascade() {
         dependencies: vec![],
         parallel_group: None,
         acceptance: vec![],
+        parallel: None,
         setup: vec![],
         files: vec![],
         auto_merge: None, // Uses plan default
         working_dir: ".".to_string(),
+        merge_strategy: None,
+        overlap: None,
         sandbox: Default::default(),
         stage_type: loom::plan::schema::StageType::default(),
         truths: vec![],
@@ -110,6 +123,12 @@ fn test_auto_merge_config_cascade() {
         after_stage: vec![],
         dead_code_check: None,
         context_budget: None,
+        timeout: None,
+        budget: None,
+        model: None,
+        for_each: None,
+        when: None,
+        output_keys: vec![],
         execution_mode: None,
         bug_fix: None,
         regression_test: None,
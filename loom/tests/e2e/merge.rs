@@ -15,6 +15,7 @@ fn create_test_stage(id: &str, auto_merge: Option<bool>) -> Stage {
         dependencies: vec![],
         parallel_group: None,
         acceptance: vec![],
+        parallel: None,
         setup: vec![],
         files: vec![],
         stage_type: loom::models::stage::StageType::default(),
@@ -33,6 +34,9 @@ fn create_test_stage(id: &str, auto_merge: Option<bool>) -> Stage {
         attempt_started_at: None,
         close_reason: None,
         auto_merge,
+        merge_strategy: None,
+        overlap: None,
+        overlap_hold: None,
         working_dir: None,
         sandbox: Default::default(),
         fix_attempts: 0,
@@ -47,8 +51,15 @@ fn create_test_stage(id: &str, auto_merge: Option<bool>) -> Stage {
         completed_commit: None,
         merged: false,
         merge_conflict: false,
+        condition_skipped: false,
         verification_status: Default::default(),
         context_budget: None,
+        timeout: None,
+        budget: None,
+        model: None,
+        model_escalation: 0,
+        for_each: None,
+        when: None,
         truths: Vec::new(),
         artifacts: Vec::new(),
         wiring: Vec::new(),
@@ -62,6 +73,7 @@ fn create_test_stage(id: &str, auto_merge: Option<bool>) -> Stage {
         review_reason: None,
         bug_fix: None,
         regression_test: None,
+        usage: Default::default(),
     }
 }
 
@@ -144,10 +144,11 @@ fn test_loom_run_fails_with_staged_changes() {
 
     // Try to run loom - should fail due to staged changes
     let result = run::execute(
-        false, // manual
-        None,  // max_parallel
-        false, // watch
-        true,  // auto_merge
+        false,      // manual
+        None,       // max_parallel
+        false,      // watch
+        Some(true), // auto_merge
+        None,       // backend
     );
 
     // Restore original directory
@@ -191,10 +192,11 @@ fn test_loom_run_fails_with_mixed_changes() {
 
     // Try to run loom - should fail due to mixed changes
     let result = run::execute(
-        false, // manual
-        None,  // max_parallel
-        false, // watch
-        true,  // auto_merge
+        false,      // manual
+        None,       // max_parallel
+        false,      // watch
+        Some(true), // auto_merge
+        None,       // backend
     );
 
     // Restore original directory
@@ -264,10 +266,11 @@ fn test_loom_run_fails_with_modified_tracked_files() {
 
     // Try to run loom - should fail due to modified file
     let result = run::execute(
-        false, // manual
-        None,  // max_parallel
-        false, // watch
-        true,  // auto_merge
+        false,      // manual
+        None,       // max_parallel
+        false,      // watch
+        Some(true), // auto_merge
+        None,       // backend
     );
 
     // Restore original directory
@@ -94,10 +94,13 @@ pub fn build_test_graph(stages: Vec<(&str, Vec<&str>)>) -> ExecutionGraph {
             description: Some(format!("Test stage {id}")),
             dependencies: deps.into_iter().map(String::from).collect(),
             acceptance: vec![],
+            parallel: None,
             setup: vec![],
             files: vec![],
             parallel_group: None,
             auto_merge: None,
+            merge_strategy: None,
+            overlap: None,
             working_dir: ".".to_string(),
             sandbox: Default::default(),
             stage_type: loom::plan::schema::StageType::default(),
@@ -110,6 +113,12 @@ pub fn build_test_graph(stages: Vec<(&str, Vec<&str>)>) -> ExecutionGraph {
             before_stage: vec![],
             after_stage: vec![],
             context_budget: None,
+            timeout: None,
+            budget: None,
+            model: None,
+            for_each: None,
+            when: None,
+            output_keys: vec![],
             execution_mode: None,
             bug_fix: None,
             regression_test: None,